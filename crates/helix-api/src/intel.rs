@@ -1,28 +1,79 @@
+use crate::intel_dossier::{verify_dossier, CaseDossier, DossierData, DossierFormat};
+use crate::intel_email::{parse_email_message, split_mailbox, EmailMessage};
+use crate::intel_feed::{canonical_feed_timestamp, parse_feed, FeedEntry, FeedFormat};
+use crate::intel_file_import::{parse_import_rows, FileImportFormat, ImportRow};
+use crate::intel_webhook::{
+    signature_header_value, verify_webhook_signature, VerifiedDelivery, WebhookReplayGuard,
+};
+use crate::intel_website::{capture_website_blocks, WebsiteCapture};
 use crate::{
-    api_error_response, credential_encrypter_from_env, record_audit_event, AppState, AuditEvent,
+    api_error_response, bearer_auth_rejection, credential_encrypter_from_env,
+    evaluate_automation_event, record_audit_event, ApiErrorResponse, AppState, AuditEvent,
 };
 use axum::{
+    body::Bytes,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Json, Response},
 };
+use chrono::{DateTime, SecondsFormat, Utc};
+use helix_core::deterministic_agents::{
+    BreakerDecision, BreakerInput, BreakerPhase, CircuitBreakerMachine,
+};
+use helix_core::event::Event;
+use helix_core::intel_case_reasoning::{
+    build_case_reasoning_request, canonicalize_rule_pack, CaseReasoningGate, CaseReasoningOptions,
+    IntelRulePack, CASE_PLACEHOLDER, DEFAULT_CASE_REASONING_QUERY,
+};
+use helix_core::intel_case_sla::{
+    canonicalize_case_sla_policy, CaseSla, CaseSlaPolicy, CaseSlaStatus,
+};
+use helix_core::intel_case_timeline::{CaseEvent, CaseTimeline};
+use helix_core::intel_claim_adjudication::{
+    canonicalize_adjudication_config, ClaimAdjudication, ClaimAdjudicationConfig,
+    ClaimAdjudicationOutcome, ClaimVote, ClaimVoteChoice,
+};
+use helix_core::intel_claim_graph::{ClaimAssertion, ClaimContradiction, ClaimGraph};
+use helix_core::intel_decay::{canonicalize_decay_policy, decayed_confidence_bps, DecayPolicy};
 use helix_core::intel_desk::{
     canonicalize_claims, canonicalize_evidence, canonicalize_source, canonicalize_watchlist,
-    evaluate_watchlists, new_case, transition_case, CaseCommand, CaseDecision, CaseFile,
-    CaseStatus, CaseTransition, ClaimRecord, ClaimReviewStatus, EvidenceDraft, EvidenceItem,
-    ProposedClaim, SourceDefinition, SourceKind, Watchlist, WatchlistHit, WatchlistSeverity,
+    diff_snapshot_blocks, evaluate_watchlists, evidence_minhash, merge_cases, new_case, split_case,
+    transition_case, BlockChangeKind, CaseCommand, CaseDecision, CaseFile, CaseStatus, CaseStep,
+    CaseTransition, ClaimRecord, ClaimReviewStatus, CompiledWatchlists, EvidenceDraft,
+    EvidenceItem, FileImportMapping, ProposedClaim, SourceDefinition, SourceKind, Watchlist,
+    WatchlistHit, WatchlistSeverity, WebhookSignatureConfig, WebsiteDiffConfig,
+};
+use helix_core::intel_entity_extraction::{extract_evidence_entities, EntityGazetteer};
+use helix_core::intel_entity_registry::{EntityRegistry, RegistryEntity, RegistryEntityKind};
+use helix_core::intel_json_mapping::{
+    canonicalize_json_api_mapping, map_json_item, select_json_items, JsonApiMapping, MappedJsonItem,
 };
+use helix_core::intel_near_duplicate::{nearest_story_cluster, MinHashSignature};
+use helix_core::intel_notes::{thread_notes, AnalystNote, NoteTargetKind, NoteThread};
 use helix_core::intel_priority::{
     score_case, score_claim, score_evidence, CasePriorityInput, ClaimPriorityInput,
     EvidencePriorityInput, IntelPriorityBreakdown, IntelSignalWindow,
 };
+use helix_core::intel_source_calibration::{
+    calibrate_source, canonicalize_trust_calibration_policy, trust_score_at, TrustAdjustmentOrigin,
+    TrustCalibrationInput, TrustCalibrationPolicy, TrustHistoryEntry, TrustRecommendation,
+};
+use helix_core::intel_watchlist_suppression::{
+    apply_watchlist_suppressions, canonicalize_suppression_note, canonicalize_suppression_rule,
+    SuppressionRule, WatchlistSuppression, MAX_WATCHLIST_SUPPRESSIONS,
+};
+use helix_core::intel_watchlist_window::{
+    apply_watchlist_triggers, WatchlistTrigger, WatchlistWindow,
+};
 use helix_core::market_intel::{
     score_market_company, score_market_theme, MarketCompanyPriorityInput, MarketSignalWindow,
     MarketThemePriorityInput,
 };
+use helix_core::reasoning::{evaluate_reasoning, ReasoningEvaluationRequest, SymbolicRule};
 use helix_core::types::{CredentialId, ProfileId};
 use helix_core::HelixError;
 use helix_embeddings::{cosine_similarity, EmbeddingGenerator};
+use helix_rule_engine::rules::RecipeTriggerPlan;
 use helix_security::encryption::CredentialEncrypterDecrypter;
 use reqwest::header::{HeaderName, HeaderValue};
 use serde::de::DeserializeOwned;
@@ -34,22 +85,61 @@ use std::collections::{BTreeMap, BTreeSet};
 const MAX_COLLECT_ITEMS: usize = 50;
 const MAX_SOURCE_FETCH_BYTES: usize = 1_048_576;
 const MAX_COLLECT_CONTENT_LEN: usize = 16_384;
+const MAX_EVIDENCE_TAGS: usize = 16;
 const MAX_FILE_IMPORT_CONTENT_LEN: usize = MAX_COLLECT_CONTENT_LEN;
+const MAX_FILE_IMPORT_ROWS: usize = 500;
 const MAX_SEMANTIC_QUERY_LEN: usize = 512;
+const SOURCE_BREAKER_FAILURE_THRESHOLD: u8 = 3;
+const SOURCE_BREAKER_OPEN_ATTEMPTS: u8 = 3;
+const DECAY_POLICY_ID: &str = "default";
+const DEFAULT_CASE_ACTOR: &str = "operator";
+const MAX_CASE_ACTOR_LEN: usize = 128;
+const ADJUDICATION_CONFIG_ID: &str = "default";
+const TRUST_POLICY_ID: &str = "default";
+const TRUST_ADJUSTMENT_ID_PREFIX: &str = "trust_adjustment_";
+const CASE_SLA_POLICY_ID: &str = "default";
+const SOURCE_SCHEDULER_STATE_ID: &str = "default";
+const CASE_SLA_EVENT_SOURCE: &str = "helix/intel/cases";
+const CASE_SLA_BREACHED_EVENT: &str = "helix.intel.case.sla_breached";
 
 #[derive(Debug, Clone)]
 struct SourceFetchAuth {
-    credential_id: String,
     header_name: HeaderName,
     header_value: HeaderValue,
 }
 
 #[derive(Debug, Clone)]
-struct PendingScheduledCollection {
+pub(crate) struct PendingScheduledCollection {
     source: SourceDefinition,
     fetched_url: String,
     schedule_phase_minute: u16,
     requests: Vec<IngestEvidenceRequest>,
+    website_capture: Option<WebsiteCapture>,
+    /// Validators from a full response; `None` when the endpoint answered `304 Not Modified`.
+    validators: Option<SourceFetchValidators>,
+}
+
+/// Cache validators sent back to a pull source as `If-None-Match` / `If-Modified-Since`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct SourceFetchValidators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct SourceFetchResponse {
+    status: u16,
+    bytes: u64,
+    validators: SourceFetchValidators,
+    /// `None` when the endpoint answered `304 Not Modified`.
+    body: Option<String>,
+}
+
+#[derive(Debug)]
+struct SourceFetchFailure {
+    status: Option<u16>,
+    bytes: u64,
+    error: HelixError,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,7 +167,10 @@ pub(crate) struct MarketIntelThemeCard {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct MarketIntelCompanyCard {
+    /// Canonical entity id.
     pub(crate) company: String,
+    /// Registry display name, or the id when the company is not registered.
+    pub(crate) display_name: String,
     pub(crate) priority: IntelPriorityBreakdown,
     pub(crate) mention_count: usize,
     pub(crate) claim_count: usize,
@@ -138,6 +231,31 @@ pub(crate) struct GenerateMarketIntelBriefResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct SourceCatalogResponse {
     pub(crate) sources: Vec<SourceDefinition>,
+    /// Fetch health for pull sources, keyed by source id.
+    #[serde(default)]
+    pub(crate) health: BTreeMap<String, SourceHealth>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SourceHealthStatus {
+    Unknown,
+    Healthy,
+    Degraded,
+    CircuitOpen,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SourceHealth {
+    pub(crate) status: SourceHealthStatus,
+    pub(crate) breaker: BreakerPhase,
+    pub(crate) last_status: Option<u16>,
+    pub(crate) last_success_at: Option<String>,
+    pub(crate) last_failure_at: Option<String>,
+    pub(crate) last_error: Option<String>,
+    pub(crate) consecutive_failures: u32,
+    pub(crate) last_bytes_fetched: u64,
+    pub(crate) bytes_fetched_total: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -159,6 +277,14 @@ pub(crate) struct CreateSourceRequest {
     pub(crate) trust_score: u8,
     pub(crate) enabled: bool,
     pub(crate) tags: Vec<String>,
+    #[serde(default)]
+    pub(crate) website_diff: Option<WebsiteDiffConfig>,
+    #[serde(default)]
+    pub(crate) file_import: Option<FileImportMapping>,
+    #[serde(default)]
+    pub(crate) json_api: Option<JsonApiMapping>,
+    #[serde(default)]
+    pub(crate) webhook_signature: Option<WebhookSignatureConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,6 +306,10 @@ pub(crate) struct CollectSourceResponse {
     pub(crate) collected_count: usize,
     pub(crate) duplicate_count: usize,
     pub(crate) results: Vec<IngestEvidenceResponse>,
+    #[serde(default)]
+    pub(crate) website_diff: Option<WebsiteDiffSummary>,
+    #[serde(default)]
+    pub(crate) not_modified: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -200,6 +330,10 @@ pub(crate) struct ScheduledSourceCollection {
     pub(crate) collected_count: usize,
     pub(crate) duplicate_count: usize,
     pub(crate) results: Vec<IngestEvidenceResponse>,
+    #[serde(default)]
+    pub(crate) website_diff: Option<WebsiteDiffSummary>,
+    #[serde(default)]
+    pub(crate) not_modified: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -211,6 +345,127 @@ pub(crate) struct CollectDueSourcesResponse {
     pub(crate) collections: Vec<ScheduledSourceCollection>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct WebsiteSnapshot {
+    pub(crate) source_id: String,
+    pub(crate) url: String,
+    pub(crate) observed_at: String,
+    pub(crate) title: String,
+    pub(crate) content_hash: String,
+    pub(crate) blocks: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct WebsiteDiffSummary {
+    pub(crate) baseline: bool,
+    pub(crate) previous_observed_at: Option<String>,
+    pub(crate) previous_content_hash: Option<String>,
+    pub(crate) content_hash: String,
+    pub(crate) block_count: usize,
+    pub(crate) added_blocks: usize,
+    pub(crate) changed_blocks: usize,
+    pub(crate) removed_blocks: usize,
+    pub(crate) unchanged_blocks: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SourceCollectionRun {
+    pub(crate) source_id: String,
+    #[serde(default)]
+    pub(crate) last_tick_minute: Option<u64>,
+    #[serde(default)]
+    pub(crate) last_attempted_at: Option<String>,
+    #[serde(default)]
+    pub(crate) last_collected_at: Option<String>,
+    #[serde(default)]
+    pub(crate) last_error: Option<String>,
+    #[serde(default)]
+    pub(crate) consecutive_failures: u32,
+    #[serde(default)]
+    pub(crate) collected_total: u64,
+    #[serde(default)]
+    pub(crate) fetch: SourceFetchState,
+    /// Accepted signed webhook deliveries, for replay checks.
+    #[serde(default)]
+    pub(crate) webhook_replay: Option<WebhookReplayGuard>,
+}
+
+/// HTTP fetch bookkeeping for a pull source: cache validators, the last response, and the
+/// breaker that stops polling an endpoint after repeated failures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SourceFetchState {
+    #[serde(default)]
+    pub(crate) etag: Option<String>,
+    #[serde(default)]
+    pub(crate) last_modified: Option<String>,
+    #[serde(default)]
+    pub(crate) last_status: Option<u16>,
+    #[serde(default)]
+    pub(crate) last_success_at: Option<String>,
+    #[serde(default)]
+    pub(crate) last_failure_at: Option<String>,
+    #[serde(default)]
+    pub(crate) last_error: Option<String>,
+    #[serde(default)]
+    pub(crate) consecutive_failures: u32,
+    #[serde(default)]
+    pub(crate) last_bytes_fetched: u64,
+    #[serde(default)]
+    pub(crate) bytes_fetched_total: u64,
+    #[serde(default = "source_fetch_breaker")]
+    pub(crate) breaker: CircuitBreakerMachine,
+}
+
+impl Default for SourceFetchState {
+    fn default() -> Self {
+        Self {
+            etag: None,
+            last_modified: None,
+            last_status: None,
+            last_success_at: None,
+            last_failure_at: None,
+            last_error: None,
+            consecutive_failures: 0,
+            last_bytes_fetched: 0,
+            bytes_fetched_total: 0,
+            breaker: source_fetch_breaker(),
+        }
+    }
+}
+
+fn source_fetch_breaker() -> CircuitBreakerMachine {
+    CircuitBreakerMachine::new(
+        SOURCE_BREAKER_FAILURE_THRESHOLD,
+        SOURCE_BREAKER_OPEN_ATTEMPTS,
+    )
+}
+
+impl SourceFetchState {
+    fn health(&self) -> SourceHealth {
+        let breaker = self.breaker.phase();
+        let status = if breaker != BreakerPhase::Closed {
+            SourceHealthStatus::CircuitOpen
+        } else if self.consecutive_failures > 0 {
+            SourceHealthStatus::Degraded
+        } else if self.last_status.is_some() {
+            SourceHealthStatus::Healthy
+        } else {
+            SourceHealthStatus::Unknown
+        };
+        SourceHealth {
+            status,
+            breaker,
+            last_status: self.last_status,
+            last_success_at: self.last_success_at.clone(),
+            last_failure_at: self.last_failure_at.clone(),
+            last_error: self.last_error.clone(),
+            consecutive_failures: self.consecutive_failures,
+            last_bytes_fetched: self.last_bytes_fetched,
+            bytes_fetched_total: self.bytes_fetched_total,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct WebhookIngestResponse {
     pub(crate) source: SourceDefinition,
@@ -236,6 +491,11 @@ pub(crate) struct FileImportRequest {
     pub(crate) entity_labels: Vec<String>,
     #[serde(default)]
     pub(crate) proposed_claims: Vec<ProposedClaim>,
+    /// Overrides the format implied by the file extension.
+    #[serde(default)]
+    pub(crate) format: Option<FileImportFormat>,
+    #[serde(default)]
+    pub(crate) dry_run: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -244,6 +504,143 @@ pub(crate) struct FileImportResponse {
     pub(crate) result: IngestEvidenceResponse,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FileImportRowStatus {
+    Accepted,
+    Duplicate,
+    Invalid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FileImportRowResult {
+    pub(crate) line: usize,
+    pub(crate) status: FileImportRowStatus,
+    #[serde(default)]
+    pub(crate) provenance_hash: Option<String>,
+    #[serde(default)]
+    pub(crate) error: Option<String>,
+    #[serde(default)]
+    pub(crate) result: Option<IngestEvidenceResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FileRowImportResponse {
+    pub(crate) source: SourceDefinition,
+    pub(crate) format: FileImportFormat,
+    pub(crate) dry_run: bool,
+    pub(crate) row_count: usize,
+    pub(crate) accepted_count: usize,
+    pub(crate) duplicate_count: usize,
+    pub(crate) error_count: usize,
+    pub(crate) rows: Vec<FileImportRowResult>,
+}
+
+impl FileRowImportResponse {
+    fn new(
+        source: SourceDefinition,
+        format: FileImportFormat,
+        dry_run: bool,
+        rows: Vec<FileImportRowResult>,
+    ) -> Self {
+        let count = |status| rows.iter().filter(|row| row.status == status).count();
+        Self {
+            format,
+            dry_run,
+            row_count: rows.len(),
+            accepted_count: count(FileImportRowStatus::Accepted),
+            duplicate_count: count(FileImportRowStatus::Duplicate),
+            error_count: count(FileImportRowStatus::Invalid),
+            source,
+            rows,
+        }
+    }
+}
+
+/// A file row after validation: ready to store, or already settled.
+enum FileRowCheck {
+    Ready {
+        line: usize,
+        prepared: PreparedEvidence,
+    },
+    Done(FileImportRowResult),
+}
+
+/// Row result carrying the stored provenance hash of the evidence it produced or matched.
+fn file_row_result(
+    line: usize,
+    status: FileImportRowStatus,
+    result: IngestEvidenceResponse,
+) -> FileImportRowResult {
+    FileImportRowResult {
+        line,
+        status,
+        provenance_hash: Some(result.evidence.provenance_hash.clone()),
+        error: None,
+        result: Some(result),
+    }
+}
+
+fn invalid_file_row(
+    line: usize,
+    provenance_hash: Option<String>,
+    error: String,
+) -> FileImportRowResult {
+    FileImportRowResult {
+        line,
+        status: FileImportRowStatus::Invalid,
+        provenance_hash,
+        error: Some(error),
+        result: None,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FileImportRowRequest {
+    line: usize,
+    request: Result<IngestEvidenceRequest, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JsonMappingPreviewRequest {
+    pub(crate) mapping: JsonApiMapping,
+    pub(crate) payload: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JsonMappingPreviewItem {
+    pub(crate) index: usize,
+    #[serde(default)]
+    pub(crate) item: Option<MappedJsonItem>,
+    #[serde(default)]
+    pub(crate) error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JsonMappingPreviewResponse {
+    pub(crate) mapping: JsonApiMapping,
+    pub(crate) item_count: usize,
+    pub(crate) mapped_count: usize,
+    pub(crate) items: Vec<JsonMappingPreviewItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmailImportRequest {
+    pub(crate) content: String,
+    pub(crate) observed_at: String,
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmailImportResponse {
+    pub(crate) source: SourceDefinition,
+    pub(crate) message_count: usize,
+    pub(crate) accepted_count: usize,
+    pub(crate) duplicate_count: usize,
+    pub(crate) results: Vec<IngestEvidenceResponse>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 enum JsonCollectionPayload {
@@ -295,6 +692,12 @@ pub(crate) struct CreateWatchlistRequest {
     pub(crate) description: String,
     pub(crate) keywords: Vec<String>,
     pub(crate) entities: Vec<String>,
+    #[serde(default)]
+    pub(crate) aliases: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub(crate) query: Option<String>,
+    #[serde(default)]
+    pub(crate) trigger: Option<WatchlistTrigger>,
     pub(crate) min_source_trust: u8,
     pub(crate) severity: WatchlistSeverity,
     pub(crate) enabled: bool,
@@ -305,6 +708,101 @@ pub(crate) struct WatchlistResponse {
     pub(crate) watchlist: Watchlist,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CreateSuppressionRequest {
+    #[serde(flatten)]
+    pub(crate) rule: SuppressionRule,
+    #[serde(default)]
+    pub(crate) note: String,
+}
+
+/// A hit a suppression rule matched, kept so suppressed coverage stays reviewable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SuppressedHitRecord {
+    pub(crate) id: String,
+    pub(crate) observed_at: String,
+    pub(crate) hit: WatchlistHit,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SuppressedHitCatalogResponse {
+    pub(crate) hits: Vec<SuppressedHitRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EntityRequest {
+    pub(crate) name: String,
+    pub(crate) kind: RegistryEntityKind,
+    #[serde(default)]
+    pub(crate) aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EntityMergeRequest {
+    /// Entity folded into the path entity and removed.
+    pub(crate) merge_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EntityCatalogResponse {
+    pub(crate) entities: Vec<RegistryEntity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EntityResponse {
+    pub(crate) entity: RegistryEntity,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EntitySplitResponse {
+    pub(crate) entity: RegistryEntity,
+    pub(crate) split: RegistryEntity,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct WatchlistBacktestRequest {
+    /// Existing watchlist the draft would replace; its current definition is the baseline.
+    #[serde(default)]
+    pub(crate) watchlist_id: Option<String>,
+    pub(crate) watchlist: CreateWatchlistRequest,
+    /// Inclusive RFC 3339 lower bound on evidence `observed_at`.
+    #[serde(default)]
+    pub(crate) observed_from: Option<String>,
+    /// Exclusive RFC 3339 upper bound on evidence `observed_at`.
+    #[serde(default)]
+    pub(crate) observed_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct WatchlistBacktestResponse {
+    pub(crate) watchlist: Watchlist,
+    pub(crate) baseline_watchlist_id: Option<String>,
+    pub(crate) evaluated_evidence: usize,
+    pub(crate) hit_count: usize,
+    pub(crate) baseline_hit_count: usize,
+    pub(crate) hits: Vec<WatchlistHit>,
+    /// Draft hits its suppression rules silenced; excluded from the counts and cases.
+    pub(crate) suppressed_hits: Vec<WatchlistHit>,
+    /// Evidence the draft hits and the baseline does not.
+    pub(crate) newly_matched: Vec<String>,
+    /// Evidence the baseline hits and the draft does not.
+    pub(crate) no_longer_matched: Vec<String>,
+    pub(crate) cases: Vec<WatchlistBacktestCase>,
+    pub(crate) severity_distribution: BTreeMap<String, usize>,
+    pub(crate) baseline_severity_distribution: BTreeMap<String, usize>,
+}
+
+/// A case the draft's hits would open, or update when `existing` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct WatchlistBacktestCase {
+    pub(crate) case_id: String,
+    pub(crate) title: String,
+    pub(crate) primary_entity: Option<String>,
+    pub(crate) evidence_ids: Vec<String>,
+    pub(crate) escalated: bool,
+    pub(crate) existing: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct EvidenceCatalogResponse {
     pub(crate) evidence: Vec<EvidenceQueueEntry>,
@@ -337,17 +835,107 @@ pub(crate) struct ClaimQueueEntry {
     pub(crate) priority: IntelPriorityBreakdown,
     pub(crate) linked_case_count: usize,
     pub(crate) max_linked_severity: Option<WatchlistSeverity>,
+    /// Canonical assertion this claim supports, shared with identical claims elsewhere.
+    pub(crate) assertion_id: String,
+    pub(crate) supporting_evidence_ids: Vec<String>,
+    pub(crate) contested: bool,
+    pub(crate) contradictions: Vec<ClaimContradiction>,
+    /// Reviewer votes, when the claim has been put to adjudication.
+    pub(crate) adjudication: Option<ClaimAdjudication>,
     pub(crate) semantic_score_bps: Option<i32>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ClaimGraphResponse {
+    pub(crate) assertions: Vec<ClaimAssertion>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct ClaimReviewRequest {
     pub(crate) status: ClaimReviewStatus,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ClaimVoteRequest {
+    pub(crate) reviewer: String,
+    pub(crate) choice: ClaimVoteChoice,
+    pub(crate) rationale: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ClaimAdjudicationResponse {
+    pub(crate) claim: ClaimRecord,
+    pub(crate) adjudication: ClaimAdjudication,
+    /// Source trust changes auto-applied because this vote decided the claim.
+    #[serde(default)]
+    pub(crate) trust_adjustments: Vec<TrustHistoryEntry>,
+}
+
+/// Persisted wrapper for the desk-wide trust calibration policy, stored under a fixed id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TrustCalibrationPolicyRecord {
+    id: String,
+    #[serde(flatten)]
+    policy: TrustCalibrationPolicy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TrustCalibrationResponse {
+    pub(crate) as_of: String,
+    pub(crate) policy: TrustCalibrationPolicy,
+    pub(crate) recommendations: Vec<TrustRecommendation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TrustCalibrationPolicyResponse {
+    pub(crate) policy: TrustCalibrationPolicy,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ApplyTrustCalibrationRequest {
+    /// Sources whose recommendations to accept; all of them when omitted.
+    #[serde(default)]
+    pub(crate) source_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplyTrustCalibrationResponse {
+    pub(crate) applied: Vec<TrustHistoryEntry>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct TrustHistoryQuery {
+    /// RFC 3339 time to report the trust score in effect at.
+    pub(crate) at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TrustHistoryResponse {
+    pub(crate) source_id: String,
+    pub(crate) trust_score: u8,
+    pub(crate) entries: Vec<TrustHistoryEntry>,
+    pub(crate) trust_score_at: Option<u8>,
+}
+
+/// Persisted wrapper for the desk-wide adjudication config, stored under a fixed id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ClaimAdjudicationConfigRecord {
+    id: String,
+    #[serde(flatten)]
+    config: ClaimAdjudicationConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ClaimAdjudicationConfigResponse {
+    pub(crate) config: ClaimAdjudicationConfig,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct ClaimResponse {
     pub(crate) claim: ClaimRecord,
+    /// Source trust changes auto-applied because of this review.
+    #[serde(default)]
+    pub(crate) trust_adjustments: Vec<TrustHistoryEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -362,6 +950,19 @@ pub(crate) struct CaseQueueEntry {
     pub(crate) severity: WatchlistSeverity,
     pub(crate) priority: IntelPriorityBreakdown,
     pub(crate) latest_signal_at: Option<String>,
+    /// Latest briefing SLA window, for cases that have been escalated.
+    #[serde(default)]
+    pub(crate) sla: Option<CaseSla>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CaseQueueView {
+    /// Cases assigned to the operator named by `assignee`.
+    Mine,
+    Unassigned,
+    /// Live cases whose briefing SLA has breached.
+    Breaching,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -370,37 +971,153 @@ pub(crate) struct CaseQueueFilterQuery {
     pub(crate) severity: Option<WatchlistSeverity>,
     pub(crate) watchlist_id: Option<String>,
     pub(crate) primary_entity: Option<String>,
+    pub(crate) view: Option<CaseQueueView>,
+    pub(crate) assignee: Option<String>,
+    pub(crate) watch_group: Option<String>,
+    /// RFC 3339 time whose source trust scores priority scoring uses instead of the current ones.
+    #[serde(default)]
+    pub(crate) trust_as_of: Option<String>,
     pub(crate) limit: Option<usize>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub(crate) struct EvidenceQueueFilterQuery {
-    pub(crate) source_id: Option<String>,
-    pub(crate) tag: Option<String>,
-    pub(crate) entity: Option<String>,
-    pub(crate) linked_status: Option<CaseStatus>,
-    pub(crate) min_trust: Option<u8>,
-    #[serde(default, alias = "semantic_query")]
-    pub(crate) q: Option<String>,
-    pub(crate) limit: Option<usize>,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CreateNoteRequest {
+    pub(crate) author: String,
+    pub(crate) body: String,
+    /// Note being answered; the reply joins that note's thread.
+    #[serde(default)]
+    pub(crate) parent_id: Option<String>,
+    #[serde(default)]
+    pub(crate) pinned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct UpdateNoteRequest {
+    pub(crate) editor: String,
+    #[serde(default)]
+    pub(crate) body: Option<String>,
+    #[serde(default)]
+    pub(crate) pinned: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct NoteThreadsResponse {
+    pub(crate) target_kind: NoteTargetKind,
+    pub(crate) target_id: String,
+    pub(crate) threads: Vec<NoteThread>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub(crate) struct ClaimQueueFilterQuery {
-    pub(crate) review_status: Option<ClaimReviewStatus>,
-    pub(crate) predicate: Option<String>,
-    pub(crate) subject: Option<String>,
-    pub(crate) linked_status: Option<CaseStatus>,
-    pub(crate) min_confidence_bps: Option<u16>,
-    #[serde(default, alias = "semantic_query")]
+pub(crate) struct NoteQueueFilterQuery {
+    pub(crate) target_kind: Option<NoteTargetKind>,
+    pub(crate) target_id: Option<String>,
+    pub(crate) author: Option<String>,
+    /// Operator handle, with or without the leading `@`.
+    pub(crate) mention: Option<String>,
+    pub(crate) pinned: Option<bool>,
+    /// Case-insensitive text match on body, author and mentions.
     pub(crate) q: Option<String>,
     pub(crate) limit: Option<usize>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub(crate) enum AutopilotReviewKind {
-    Case,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct NoteCatalogResponse {
+    pub(crate) notes: Vec<AnalystNote>,
+}
+
+/// Persisted wrapper for the desk-wide case SLA policy, stored under a fixed id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CaseSlaPolicyRecord {
+    id: String,
+    #[serde(flatten)]
+    policy: CaseSlaPolicy,
+}
+
+/// Persisted source scheduler controls, stored under a fixed id so a pause outlives the
+/// process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SourceSchedulerStateRecord {
+    id: String,
+    paused: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaseSlaPolicyResponse {
+    pub(crate) policy: CaseSlaPolicy,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct CaseSlaTickRequest {
+    /// RFC 3339 wall-clock time to tick to; the server clock when omitted.
+    #[serde(default)]
+    pub(crate) now: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaseSlaBreach {
+    pub(crate) case_id: String,
+    pub(crate) title: String,
+    pub(crate) watchlist_id: String,
+    pub(crate) severity: WatchlistSeverity,
+    pub(crate) assignee: Option<String>,
+    pub(crate) watch_group: Option<String>,
+    pub(crate) started_at: String,
+    pub(crate) deadline_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaseSlaTickResponse {
+    pub(crate) now: String,
+    /// Windows still running after the tick, breached or not.
+    pub(crate) open_count: usize,
+    pub(crate) breaches: Vec<CaseSlaBreach>,
+    /// One `helix.intel.case.sla_breached` event per breach, as sent to automation rules.
+    pub(crate) events: Vec<Event>,
+    pub(crate) trigger_plans: Vec<RecipeTriggerPlan>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct EvidenceQueueFilterQuery {
+    pub(crate) source_id: Option<String>,
+    pub(crate) tag: Option<String>,
+    pub(crate) entity: Option<String>,
+    pub(crate) linked_status: Option<CaseStatus>,
+    pub(crate) min_trust: Option<u8>,
+    #[serde(default, alias = "semantic_query")]
+    pub(crate) q: Option<String>,
+    /// RFC 3339 time whose source trust scores priority scoring uses instead of the current ones.
+    #[serde(default)]
+    pub(crate) trust_as_of: Option<String>,
+    pub(crate) limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ClaimQueueFilterQuery {
+    pub(crate) review_status: Option<ClaimReviewStatus>,
+    pub(crate) predicate: Option<String>,
+    pub(crate) subject: Option<String>,
+    pub(crate) linked_status: Option<CaseStatus>,
+    pub(crate) min_confidence_bps: Option<u16>,
+    pub(crate) contested: Option<bool>,
+    #[serde(default, alias = "semantic_query")]
+    pub(crate) q: Option<String>,
+    /// RFC 3339 time whose source trust scores priority scoring uses instead of the current ones.
+    #[serde(default)]
+    pub(crate) trust_as_of: Option<String>,
+    pub(crate) limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ClaimGraphQuery {
+    pub(crate) contested: Option<bool>,
+    pub(crate) subject: Option<String>,
+    pub(crate) limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AutopilotReviewKind {
+    Case,
     Claim,
     Evidence,
 }
@@ -441,12 +1158,32 @@ pub(crate) struct AutopilotReviewExportPacketResponse {
     pub(crate) supporting_cases: Vec<CaseQueueEntry>,
     pub(crate) supporting_claims: Vec<ClaimQueueEntry>,
     pub(crate) supporting_evidence: Vec<EvidenceQueueEntry>,
+    /// Notes on the item and every supporting record.
+    pub(crate) notes: Vec<AnalystNote>,
+    pub(crate) notes_redacted: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct AutopilotReviewExportQuery {
     pub(crate) review_kind: AutopilotReviewKind,
     pub(crate) item_id: String,
+    /// Withholds note bodies and mentions from the packet.
+    #[serde(default)]
+    pub(crate) redact_notes: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct CaseDossierQuery {
+    #[serde(default)]
+    pub(crate) format: DossierFormat,
+    #[serde(default)]
+    pub(crate) redact_notes: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct MarketBriefExportQuery {
+    #[serde(default)]
+    pub(crate) redact_notes: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -458,6 +1195,9 @@ pub(crate) struct MarketIntelBriefExportPacketResponse {
     pub(crate) watchlist: Watchlist,
     pub(crate) evidence: Vec<EvidenceQueueEntry>,
     pub(crate) claims: Vec<ClaimQueueEntry>,
+    /// Notes on the case, its evidence and its claims.
+    pub(crate) notes: Vec<AnalystNote>,
+    pub(crate) notes_redacted: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -485,11 +1225,113 @@ pub(crate) struct IngestEvidenceResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct CaseTransitionRequest {
     pub(crate) command: CaseCommand,
+    /// Recorded on the case timeline; defaults to `operator`.
+    #[serde(default)]
+    pub(crate) actor: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct CaseTransitionResponse {
     pub(crate) transition: CaseTransition,
+    /// Other cases changed by the command: the merge target, or the case opened by a
+    /// split.
+    #[serde(default)]
+    pub(crate) related: Vec<CaseTransition>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct CaseTimelineQuery {
+    /// Also fold the log up to this RFC 3339 time.
+    #[serde(default)]
+    pub(crate) as_of: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaseTimelineResponse {
+    pub(crate) case: CaseFile,
+    /// True when folding the whole log reproduces `case`.
+    pub(crate) replay_matches: bool,
+    /// Case state the log starts from, for cases stored before events were recorded.
+    pub(crate) baseline: Option<CaseFile>,
+    pub(crate) events: Vec<CaseEvent>,
+    pub(crate) as_of: Option<String>,
+    /// Case at `as_of`; `None` when no time was asked for or the case did not exist yet.
+    pub(crate) case_as_of: Option<CaseFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaseReasoningRequest {
+    pub(crate) rule_pack_id: String,
+    #[serde(flatten)]
+    pub(crate) options: CaseReasoningOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CaseReasoningResponse {
+    pub(crate) case: CaseFile,
+    /// The exact request sent to the reasoning kernel, for replay.
+    pub(crate) request: ReasoningEvaluationRequest,
+    pub(crate) gate: CaseReasoningGate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RulePackCatalogResponse {
+    pub(crate) rule_packs: Vec<IntelRulePack>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RulePackResponse {
+    pub(crate) rule_pack: IntelRulePack,
+}
+
+/// Persisted wrapper for the desk-wide decay policy, stored under a fixed id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DecayPolicyRecord {
+    id: String,
+    #[serde(flatten)]
+    policy: DecayPolicy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DecayPolicyResponse {
+    pub(crate) policy: DecayPolicy,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct DecayApplyRequest {
+    /// Evaluation time; defaults to the newest evidence `observed_at` so runs replay.
+    #[serde(default)]
+    pub(crate) as_of: Option<String>,
+    #[serde(default)]
+    pub(crate) dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DecayedClaim {
+    pub(crate) claim_id: String,
+    pub(crate) evidence_id: String,
+    pub(crate) confidence_bps: u16,
+    pub(crate) relevance_bps: u16,
+    pub(crate) decayed_confidence_bps: u16,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ExpiredEvidence {
+    pub(crate) evidence_id: String,
+    pub(crate) relevance_bps: u16,
+    /// Monitoring cases the evidence and its claims were detached from. A case
+    /// holding no unexpired evidence keeps what it has.
+    pub(crate) detached_case_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DecayApplyResponse {
+    pub(crate) as_of: String,
+    pub(crate) dry_run: bool,
+    pub(crate) policy: DecayPolicy,
+    /// Corroborated claims moved back to needs-review.
+    pub(crate) claims: Vec<DecayedClaim>,
+    pub(crate) expired_evidence: Vec<ExpiredEvidence>,
 }
 
 #[derive(Debug, Clone)]
@@ -499,6 +1341,174 @@ pub(crate) struct IntelDeskStore {
     evidence: BTreeMap<String, EvidenceItem>,
     claims: BTreeMap<String, ClaimRecord>,
     cases: BTreeMap<String, CaseFile>,
+    source_runs: BTreeMap<String, SourceCollectionRun>,
+    website_snapshots: BTreeMap<String, WebsiteSnapshot>,
+    /// Match history for watchlists with a trigger, keyed by watchlist id.
+    watchlist_windows: BTreeMap<String, WatchlistWindow>,
+    entity_registry: EntityRegistry,
+    suppressed_hits: BTreeMap<String, SuppressedHitRecord>,
+    rule_packs: BTreeMap<String, IntelRulePack>,
+    decay_policy: DecayPolicy,
+    /// Vote histories keyed by claim id.
+    claim_adjudications: BTreeMap<String, ClaimAdjudication>,
+    adjudication_config: ClaimAdjudicationConfig,
+    trust_policy: TrustCalibrationPolicy,
+    /// Applied source trust changes; ids carry a sequence number, so id order is
+    /// recording order.
+    trust_history: BTreeMap<String, TrustHistoryEntry>,
+    /// Append-only case event logs keyed by case id.
+    case_timelines: BTreeMap<String, CaseTimeline>,
+    case_sla_policy: CaseSlaPolicy,
+    /// Latest briefing SLA window per case id.
+    case_slas: BTreeMap<String, CaseSla>,
+    /// Analyst notes; ids carry a sequence number, so id order is writing order.
+    notes: BTreeMap<String, AnalystNote>,
+    /// Set while the in-process source scheduler is paused.
+    source_scheduler_paused: bool,
+    /// Derived from `evidence`; rebuilt on load and kept current on ingest.
+    story_clusters: StoryClusterIndex,
+}
+
+/// Story-cluster membership of stored evidence, so clustering a new item and counting a
+/// cluster's sources do not rescan and re-parse every record.
+#[derive(Debug, Clone, Default)]
+struct StoryClusterIndex {
+    /// Cluster and parsed signature of each item with a signature, in evidence id order.
+    signatures: BTreeMap<String, (String, MinHashSignature)>,
+    /// Evidence ids per `(band, band key)`, so clustering compares only items that share
+    /// a signature band.
+    bands: BTreeMap<(usize, u32), BTreeSet<String>>,
+    /// Items per source in each cluster.
+    sources: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+impl StoryClusterIndex {
+    fn from_evidence<'a>(evidence: impl IntoIterator<Item = &'a EvidenceItem>) -> Self {
+        let mut index = Self::default();
+        for item in evidence {
+            index.insert(item);
+        }
+        index
+    }
+
+    fn insert(&mut self, evidence: &EvidenceItem) {
+        let cluster_id = story_cluster_key(evidence).to_string();
+        if let Some(signature) = evidence
+            .minhash
+            .as_deref()
+            .and_then(MinHashSignature::from_hex)
+        {
+            for key in signature.bands().into_iter().enumerate() {
+                self.bands
+                    .entry(key)
+                    .or_default()
+                    .insert(evidence.id.clone());
+            }
+            self.signatures
+                .insert(evidence.id.clone(), (cluster_id.clone(), signature));
+        }
+        *self
+            .sources
+            .entry(cluster_id)
+            .or_default()
+            .entry(evidence.source_id.clone())
+            .or_default() += 1;
+    }
+
+    fn remove(&mut self, evidence: &EvidenceItem) {
+        let cluster_id = story_cluster_key(evidence);
+        if let Some((_, signature)) = self.signatures.remove(&evidence.id) {
+            for key in signature.bands().into_iter().enumerate() {
+                if let Some(members) = self.bands.get_mut(&key) {
+                    members.remove(&evidence.id);
+                    if members.is_empty() {
+                        self.bands.remove(&key);
+                    }
+                }
+            }
+        }
+        let Some(sources) = self.sources.get_mut(cluster_id) else {
+            return;
+        };
+        if let Some(count) = sources.get_mut(&evidence.source_id) {
+            *count -= 1;
+            if *count == 0 {
+                sources.remove(&evidence.source_id);
+            }
+        }
+        if sources.is_empty() {
+            self.sources.remove(cluster_id);
+        }
+    }
+
+    fn nearest(&self, signature: &MinHashSignature) -> Option<&str> {
+        let candidates = signature
+            .bands()
+            .into_iter()
+            .enumerate()
+            .filter_map(|key| self.bands.get(&key))
+            .flatten()
+            .collect::<BTreeSet<_>>();
+        nearest_story_cluster(
+            signature,
+            candidates
+                .into_iter()
+                .filter_map(|evidence_id| self.signatures.get(evidence_id))
+                .map(|(cluster_id, member)| (cluster_id.as_str(), member)),
+        )
+    }
+
+    fn source_count(&self, cluster_id: &str) -> usize {
+        self.sources.get(cluster_id).map_or(0, BTreeMap::len)
+    }
+}
+
+/// Outcome of validating an ingest against the desk without storing it.
+enum PreparedIngest {
+    /// The evidence id is already stored.
+    Duplicate(IngestEvidenceResponse),
+    New(PreparedEvidence),
+}
+
+struct PreparedEvidence {
+    evidence: EvidenceItem,
+    claims: Vec<ClaimRecord>,
+    /// Unsuppressed hits, after trigger windows.
+    hits: Vec<WatchlistHit>,
+    suppressed: Vec<WatchlistHit>,
+    /// Trigger windows the hits advanced, to store once the hits apply.
+    windows: BTreeMap<String, WatchlistWindow>,
+}
+
+/// A case's records before an ingest touched them; `None` where the record was absent.
+struct CaseSnapshot {
+    case: Option<CaseFile>,
+    timeline: Option<CaseTimeline>,
+    sla: Option<CaseSla>,
+}
+
+/// Watchlist queries and the entity gazetteer, compiled once for a batch of ingests so
+/// each item, duplicates included, does not parse every query again.
+struct IngestMatchers<'a> {
+    watchlists: &'a [Watchlist],
+    compiled: CompiledWatchlists<'a>,
+    gazetteer: EntityGazetteer,
+}
+
+impl<'a> IngestMatchers<'a> {
+    fn compile(watchlists: &'a [Watchlist], registry: &EntityRegistry) -> Result<Self, HelixError> {
+        Ok(Self {
+            watchlists,
+            compiled: CompiledWatchlists::compile(watchlists)?,
+            gazetteer: EntityGazetteer::from_watchlists_with_registry(watchlists, registry),
+        })
+    }
+
+    fn is_triggered(&self, watchlist_id: &str) -> bool {
+        self.watchlists
+            .iter()
+            .any(|watchlist| watchlist.id == watchlist_id && watchlist.trigger.is_some())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -518,12 +1528,64 @@ impl IntelDeskPostgresStore {
     }
 
     pub(crate) async fn load_or_seed(&self) -> Result<IntelDeskStore, HelixError> {
-        let store = IntelDeskStore {
+        let mut store = IntelDeskStore {
             sources: load_records(&self.pool, "intel_sources").await?,
             watchlists: load_records(&self.pool, "intel_watchlists").await?,
             evidence: load_records(&self.pool, "intel_evidence").await?,
             claims: load_records(&self.pool, "intel_claims").await?,
             cases: load_records(&self.pool, "intel_cases").await?,
+            source_runs: load_records(&self.pool, "intel_source_runs").await?,
+            website_snapshots: load_records(&self.pool, "intel_website_snapshots").await?,
+            watchlist_windows: load_records(&self.pool, "intel_watchlist_windows").await?,
+            entity_registry: EntityRegistry::from_entities(
+                load_records::<RegistryEntity>(&self.pool, "intel_entities")
+                    .await?
+                    .into_values(),
+            )?,
+            suppressed_hits: load_records(&self.pool, "intel_suppressed_hits").await?,
+            rule_packs: load_records(&self.pool, "intel_rule_packs").await?,
+            decay_policy: load_records::<DecayPolicyRecord>(&self.pool, "intel_decay_policies")
+                .await?
+                .remove(DECAY_POLICY_ID)
+                .map(|record| record.policy)
+                .unwrap_or_default(),
+            claim_adjudications: load_records(&self.pool, "intel_claim_adjudications").await?,
+            adjudication_config: load_records::<ClaimAdjudicationConfigRecord>(
+                &self.pool,
+                "intel_adjudication_configs",
+            )
+            .await?
+            .remove(ADJUDICATION_CONFIG_ID)
+            .map(|record| record.config)
+            .unwrap_or_default(),
+            trust_policy: load_records::<TrustCalibrationPolicyRecord>(
+                &self.pool,
+                "intel_trust_calibration_policies",
+            )
+            .await?
+            .remove(TRUST_POLICY_ID)
+            .map(|record| record.policy)
+            .unwrap_or_default(),
+            trust_history: load_records(&self.pool, "intel_source_trust_history").await?,
+            case_timelines: load_case_timelines(&self.pool).await?,
+            case_sla_policy: load_records::<CaseSlaPolicyRecord>(
+                &self.pool,
+                "intel_case_sla_policies",
+            )
+            .await?
+            .remove(CASE_SLA_POLICY_ID)
+            .map(|record| record.policy)
+            .unwrap_or_default(),
+            case_slas: load_records(&self.pool, "intel_case_slas").await?,
+            notes: load_records(&self.pool, "intel_analyst_notes").await?,
+            source_scheduler_paused: load_records::<SourceSchedulerStateRecord>(
+                &self.pool,
+                "intel_source_scheduler_states",
+            )
+            .await?
+            .remove(SOURCE_SCHEDULER_STATE_ID)
+            .is_some_and(|record| record.paused),
+            story_clusters: StoryClusterIndex::default(),
         };
 
         if store.is_empty() {
@@ -531,6 +1593,10 @@ impl IntelDeskPostgresStore {
             self.save(&seeded).await?;
             Ok(seeded)
         } else {
+            store.story_clusters = StoryClusterIndex::from_evidence(store.evidence.values());
+            store.backfill_story_clusters();
+            store.backfill_case_timelines();
+            store.backfill_case_slas();
             Ok(store)
         }
     }
@@ -538,6 +1604,62 @@ impl IntelDeskPostgresStore {
     pub(crate) async fn save(&self, store: &IntelDeskStore) -> Result<(), HelixError> {
         let mut tx = self.pool.begin().await.map_err(db_error)?;
 
+        sqlx::query("DELETE FROM intel_suppressed_hits")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_rule_packs")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_decay_policies")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_claim_adjudications")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_adjudication_configs")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_trust_calibration_policies")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_case_sla_policies")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_source_scheduler_states")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_case_slas")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_analyst_notes")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_entities")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_watchlist_windows")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_website_snapshots")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        sqlx::query("DELETE FROM intel_source_runs")
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
         sqlx::query("DELETE FROM intel_cases")
             .execute(&mut *tx)
             .await
@@ -620,21 +1742,284 @@ impl IntelDeskPostgresStore {
             .map_err(db_error)?;
         }
 
-        tx.commit().await.map_err(db_error)
-    }
-}
+        for run in store.source_runs.values() {
+            sqlx::query(
+                "INSERT INTO intel_source_runs (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&run.source_id)
+            .bind(serde_json::to_value(run).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
 
-impl IntelDeskStore {
-    pub(crate) fn seeded() -> Self {
-        let mut store = Self {
-            sources: BTreeMap::new(),
-            watchlists: BTreeMap::new(),
-            evidence: BTreeMap::new(),
-            claims: BTreeMap::new(),
-            cases: BTreeMap::new(),
+        for snapshot in store.website_snapshots.values() {
+            sqlx::query(
+                "INSERT INTO intel_website_snapshots (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&snapshot.source_id)
+            .bind(serde_json::to_value(snapshot).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        for window in store.watchlist_windows.values() {
+            sqlx::query(
+                "INSERT INTO intel_watchlist_windows (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&window.watchlist_id)
+            .bind(serde_json::to_value(window).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        for entity in store.entity_registry.entities() {
+            sqlx::query(
+                "INSERT INTO intel_entities (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&entity.id)
+            .bind(serde_json::to_value(entity).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        for record in store.suppressed_hits.values() {
+            sqlx::query(
+                "INSERT INTO intel_suppressed_hits (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&record.id)
+            .bind(serde_json::to_value(record).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        for pack in store.rule_packs.values() {
+            sqlx::query(
+                "INSERT INTO intel_rule_packs (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&pack.id)
+            .bind(serde_json::to_value(pack).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        let decay_policy = DecayPolicyRecord {
+            id: DECAY_POLICY_ID.to_string(),
+            policy: store.decay_policy.clone(),
         };
+        sqlx::query(
+            "INSERT INTO intel_decay_policies (id, record, updated_at) VALUES ($1, $2, now())",
+        )
+        .bind(&decay_policy.id)
+        .bind(serde_json::to_value(&decay_policy).map_err(serde_error)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
 
-        let sources = [
+        for adjudication in store.claim_adjudications.values() {
+            sqlx::query(
+                "INSERT INTO intel_claim_adjudications (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&adjudication.claim_id)
+            .bind(serde_json::to_value(adjudication).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        let adjudication_config = ClaimAdjudicationConfigRecord {
+            id: ADJUDICATION_CONFIG_ID.to_string(),
+            config: store.adjudication_config,
+        };
+        sqlx::query(
+            "INSERT INTO intel_adjudication_configs (id, record, updated_at) VALUES ($1, $2, now())",
+        )
+        .bind(&adjudication_config.id)
+        .bind(serde_json::to_value(&adjudication_config).map_err(serde_error)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        let trust_policy = TrustCalibrationPolicyRecord {
+            id: TRUST_POLICY_ID.to_string(),
+            policy: store.trust_policy,
+        };
+        sqlx::query(
+            "INSERT INTO intel_trust_calibration_policies (id, record, updated_at) VALUES ($1, $2, now())",
+        )
+        .bind(&trust_policy.id)
+        .bind(serde_json::to_value(&trust_policy).map_err(serde_error)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        // Trust history is insert-only: ids are zero-padded sequence numbers, so only
+        // entries past the largest stored id are new.
+        let persisted_trust_id = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT max(id) FROM intel_source_trust_history",
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .unwrap_or_default();
+        for entry in store
+            .trust_history
+            .range::<str, _>((
+                std::ops::Bound::Excluded(persisted_trust_id.as_str()),
+                std::ops::Bound::Unbounded,
+            ))
+            .map(|(_, entry)| entry)
+        {
+            sqlx::query(
+                "INSERT INTO intel_source_trust_history (id, record, updated_at) VALUES ($1, $2, now()) \
+                 ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(&entry.id)
+            .bind(serde_json::to_value(entry).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        // Case logs are insert-only: the header row holds the baseline and each event
+        // gets its own `(case_id, sequence)` row, so a save never rewrites history.
+        let persisted_sequences = sqlx::query(
+            "SELECT case_id, max(sequence) AS sequence FROM intel_case_events GROUP BY case_id",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .into_iter()
+        .map(|row| {
+            Ok((
+                row.try_get::<String, _>("case_id").map_err(db_error)?,
+                row.try_get::<i64, _>("sequence").map_err(db_error)?,
+            ))
+        })
+        .collect::<Result<BTreeMap<_, _>, HelixError>>()?;
+        for timeline in store.case_timelines.values() {
+            let header = CaseTimeline {
+                case_id: timeline.case_id.clone(),
+                baseline: timeline.baseline.clone(),
+                baseline_recorded_at: timeline.baseline_recorded_at.clone(),
+                events: Vec::new(),
+            };
+            sqlx::query(
+                "INSERT INTO intel_case_timelines (id, record, updated_at) VALUES ($1, $2, now()) \
+                 ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(&timeline.case_id)
+            .bind(serde_json::to_value(&header).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+            let persisted = persisted_sequences
+                .get(&timeline.case_id)
+                .copied()
+                .unwrap_or(0);
+            for event in timeline
+                .events
+                .iter()
+                .filter(|event| event.sequence as i64 > persisted)
+            {
+                sqlx::query(
+                    "INSERT INTO intel_case_events (case_id, sequence, record) VALUES ($1, $2, $3) \
+                     ON CONFLICT (case_id, sequence) DO NOTHING",
+                )
+                .bind(&timeline.case_id)
+                .bind(event.sequence as i64)
+                .bind(serde_json::to_value(event).map_err(serde_error)?)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+            }
+        }
+
+        let case_sla_policy = CaseSlaPolicyRecord {
+            id: CASE_SLA_POLICY_ID.to_string(),
+            policy: store.case_sla_policy,
+        };
+        sqlx::query(
+            "INSERT INTO intel_case_sla_policies (id, record, updated_at) VALUES ($1, $2, now())",
+        )
+        .bind(&case_sla_policy.id)
+        .bind(serde_json::to_value(&case_sla_policy).map_err(serde_error)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        for sla in store.case_slas.values() {
+            sqlx::query(
+                "INSERT INTO intel_case_slas (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&sla.case_id)
+            .bind(serde_json::to_value(sla).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        for note in store.notes.values() {
+            sqlx::query(
+                "INSERT INTO intel_analyst_notes (id, record, updated_at) VALUES ($1, $2, now())",
+            )
+            .bind(&note.id)
+            .bind(serde_json::to_value(note).map_err(serde_error)?)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        let scheduler_state = SourceSchedulerStateRecord {
+            id: SOURCE_SCHEDULER_STATE_ID.to_string(),
+            paused: store.source_scheduler_paused,
+        };
+        sqlx::query(
+            "INSERT INTO intel_source_scheduler_states (id, record, updated_at) VALUES ($1, $2, now())",
+        )
+        .bind(&scheduler_state.id)
+        .bind(serde_json::to_value(&scheduler_state).map_err(serde_error)?)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(db_error)
+    }
+}
+
+impl IntelDeskStore {
+    pub(crate) fn seeded() -> Self {
+        let mut store = Self {
+            sources: BTreeMap::new(),
+            watchlists: BTreeMap::new(),
+            evidence: BTreeMap::new(),
+            claims: BTreeMap::new(),
+            cases: BTreeMap::new(),
+            source_runs: BTreeMap::new(),
+            website_snapshots: BTreeMap::new(),
+            watchlist_windows: BTreeMap::new(),
+            entity_registry: EntityRegistry::default(),
+            suppressed_hits: BTreeMap::new(),
+            rule_packs: BTreeMap::new(),
+            decay_policy: DecayPolicy::default(),
+            claim_adjudications: BTreeMap::new(),
+            adjudication_config: ClaimAdjudicationConfig::default(),
+            trust_policy: TrustCalibrationPolicy::default(),
+            trust_history: BTreeMap::new(),
+            case_timelines: BTreeMap::new(),
+            case_sla_policy: CaseSlaPolicy::default(),
+            case_slas: BTreeMap::new(),
+            notes: BTreeMap::new(),
+            source_scheduler_paused: false,
+            story_clusters: StoryClusterIndex::default(),
+        };
+
+        let sources = [
             SourceDefinition {
                 id: "rss_national_security".to_string(),
                 profile_id: "50000000-0000-0000-0000-000000000010".to_string(),
@@ -650,6 +2035,10 @@ impl IntelDeskStore {
                 trust_score: 88,
                 enabled: true,
                 tags: vec!["osint".to_string(), "security".to_string()],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
             },
             SourceDefinition {
                 id: "website_orion_dynamics".to_string(),
@@ -666,6 +2055,10 @@ impl IntelDeskStore {
                 trust_score: 76,
                 enabled: true,
                 tags: vec!["company".to_string(), "website".to_string()],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
             },
             SourceDefinition {
                 id: "json_api_cloud_pricing".to_string(),
@@ -686,6 +2079,10 @@ impl IntelDeskStore {
                     "pricing".to_string(),
                     "competitor".to_string(),
                 ],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
             },
             SourceDefinition {
                 id: "website_vector_launches".to_string(),
@@ -706,6 +2103,10 @@ impl IntelDeskStore {
                     "product".to_string(),
                     "release".to_string(),
                 ],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
             },
             SourceDefinition {
                 id: "rss_partner_ecosystem".to_string(),
@@ -726,6 +2127,10 @@ impl IntelDeskStore {
                     "partnerships".to_string(),
                     "ecosystem".to_string(),
                 ],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
             },
             SourceDefinition {
                 id: "rss_gtm_hiring_tracker".to_string(),
@@ -746,6 +2151,10 @@ impl IntelDeskStore {
                     "hiring".to_string(),
                     "go-to-market".to_string(),
                 ],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
             },
         ];
         for source in sources {
@@ -764,6 +2173,10 @@ impl IntelDeskStore {
                     "appointed".to_string(),
                 ],
                 entities: vec!["alice north".to_string(), "orion dynamics".to_string()],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: None,
+                suppressions: Vec::new(),
                 min_source_trust: 60,
                 severity: WatchlistSeverity::High,
                 enabled: true,
@@ -779,6 +2192,10 @@ impl IntelDeskStore {
                     "explosion".to_string(),
                 ],
                 entities: vec!["orion dynamics".to_string(), "north harbor".to_string()],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: None,
+                suppressions: Vec::new(),
                 min_source_trust: 50,
                 severity: WatchlistSeverity::Critical,
                 enabled: true,
@@ -798,6 +2215,10 @@ impl IntelDeskStore {
                     "boreal cloud".to_string(),
                     "northstar analytics".to_string(),
                 ],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: None,
+                suppressions: Vec::new(),
                 min_source_trust: 65,
                 severity: WatchlistSeverity::High,
                 enabled: true,
@@ -814,6 +2235,10 @@ impl IntelDeskStore {
                     "ga".to_string(),
                 ],
                 entities: vec!["vector works".to_string(), "atlas crm".to_string()],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: None,
+                suppressions: Vec::new(),
                 min_source_trust: 60,
                 severity: WatchlistSeverity::Medium,
                 enabled: true,
@@ -830,6 +2255,10 @@ impl IntelDeskStore {
                     "ecosystem".to_string(),
                 ],
                 entities: vec!["atlas crm".to_string(), "nebula retail".to_string()],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: None,
+                suppressions: Vec::new(),
                 min_source_trust: 55,
                 severity: WatchlistSeverity::Medium,
                 enabled: true,
@@ -846,6 +2275,10 @@ impl IntelDeskStore {
                     "open role".to_string(),
                 ],
                 entities: vec!["boreal cloud".to_string(), "vector works".to_string()],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: None,
+                suppressions: Vec::new(),
                 min_source_trust: 50,
                 severity: WatchlistSeverity::Low,
                 enabled: true,
@@ -857,6 +2290,26 @@ impl IntelDeskStore {
             store.watchlists.insert(watchlist.id.clone(), watchlist);
         }
 
+        let rule_pack = canonicalize_rule_pack(IntelRulePack {
+            id: "default_escalation".to_string(),
+            name: "Default escalation".to_string(),
+            description: "Escalate high and critical cases once a claim is corroborated."
+                .to_string(),
+            rules: ["high", "critical"]
+                .into_iter()
+                .map(|severity| SymbolicRule {
+                    id: format!("{severity}_with_corroborated_claim"),
+                    antecedents: vec![
+                        format!("severity({CASE_PLACEHOLDER},{severity})"),
+                        format!("has_corroborated_claim({CASE_PLACEHOLDER})"),
+                    ],
+                    consequent: DEFAULT_CASE_REASONING_QUERY.to_string(),
+                })
+                .collect(),
+        })
+        .expect("seed rule pack should be valid");
+        store.rule_packs.insert(rule_pack.id.clone(), rule_pack);
+
         store.seed_market_activity_demo();
 
         store
@@ -925,10 +2378,8 @@ impl IntelDeskStore {
             },
         ];
 
-        for request in seed_requests {
-            self.ingest_evidence(request)
-                .expect("market intel demo seed should be valid");
-        }
+        self.ingest_evidence_batch(Vec::from(seed_requests))
+            .expect("market intel demo seed should be valid");
     }
 
     fn overview(&self) -> IntelDeskOverviewResponse {
@@ -976,7 +2427,7 @@ impl IntelDeskStore {
             .cases
             .values()
             .filter(|case| {
-                case.status != CaseStatus::Closed
+                case.status.is_live()
                     && self
                         .watchlists
                         .get(&case.watchlist_id)
@@ -1198,6 +2649,10 @@ impl IntelDeskStore {
 
                 MarketIntelCompanyCard {
                     company: company.clone(),
+                    display_name: self
+                        .entity_registry
+                        .get(company)
+                        .map_or_else(|| company.clone(), |entity| entity.name.clone()),
                     priority,
                     mention_count,
                     claim_count: company_claims.len(),
@@ -1263,7 +2718,7 @@ impl IntelDeskStore {
         let watchlist = self.watchlists.get(&case.watchlist_id)?;
         let theme_id = market_theme_id_for_watchlist(watchlist)?;
         let theme_name = market_theme_name(theme_id).to_string();
-        let priority = self.case_priority(case, signal_window)?;
+        let priority = self.case_priority(case, signal_window, None)?;
         let evidence = self.case_evidence(case);
         let claims = self.case_claims(case);
         let latest_signal_at = latest_signal_at(&evidence);
@@ -1321,6 +2776,7 @@ impl IntelDeskStore {
                 CaseCommand::AttachBrief {
                     summary: briefing_text(&briefing),
                 },
+                "market_brief",
             )?;
             Some(attached)
         } else {
@@ -1367,10 +2823,37 @@ impl IntelDeskStore {
         let primary_entity =
             normalized_optional_filter(filters.primary_entity.as_deref(), "primary_entity")?
                 .map(|value| value.to_lowercase());
+        let assignee = normalized_optional_filter(filters.assignee.as_deref(), "assignee")?;
+        let watch_group =
+            normalized_optional_filter(filters.watch_group.as_deref(), "watch_group")?;
+        let trust_as_of = normalized_trust_as_of(filters.trust_as_of.as_deref())?;
+        if filters.view == Some(CaseQueueView::Mine) && assignee.is_none() {
+            return Err(HelixError::validation_error(
+                "assignee",
+                "is required for the mine view",
+            ));
+        }
         let signal_window = self.case_signal_window();
         let mut cases = self
             .cases
             .values()
+            .filter(|case| match filters.view {
+                Some(CaseQueueView::Mine) | None => true,
+                Some(CaseQueueView::Unassigned) => case.assignee.is_none(),
+                Some(CaseQueueView::Breaching) => self.case_is_breaching(case),
+            })
+            .filter(|case| {
+                assignee
+                    .as_deref()
+                    .map(|target| case.assignee.as_deref() == Some(target))
+                    .unwrap_or(true)
+            })
+            .filter(|case| {
+                watch_group
+                    .as_deref()
+                    .map(|target| case.watch_group.as_deref() == Some(target))
+                    .unwrap_or(true)
+            })
             .filter(|case| {
                 filters
                     .status
@@ -1405,7 +2888,7 @@ impl IntelDeskStore {
                     })
                     .unwrap_or(true)
             })
-            .map(|case| self.case_queue_entry(case, &signal_window))
+            .map(|case| self.case_queue_entry(case, &signal_window, trust_as_of))
             .collect::<Result<Vec<_>, _>>()?;
         cases.sort_by(|left, right| {
             right
@@ -1425,13 +2908,14 @@ impl IntelDeskStore {
         &self,
         case: &CaseFile,
         signal_window: &IntelSignalWindow,
+        trust_as_of: Option<DateTime<Utc>>,
     ) -> Result<CaseQueueEntry, HelixError> {
         let watchlist = self
             .watchlists
             .get(&case.watchlist_id)
             .ok_or_else(|| HelixError::internal_error("case references unknown watchlist"))?;
         let priority = self
-            .case_priority(case, signal_window)
+            .case_priority(case, signal_window, trust_as_of)
             .ok_or_else(|| HelixError::internal_error("case priority could not be computed"))?;
         Ok(CaseQueueEntry {
             case: case.clone(),
@@ -1439,6 +2923,7 @@ impl IntelDeskStore {
             severity: watchlist.severity,
             priority,
             latest_signal_at: latest_signal_at(&self.case_evidence(case)),
+            sla: self.case_slas.get(&case.id).cloned(),
         })
     }
 
@@ -1446,6 +2931,7 @@ impl IntelDeskStore {
         &self,
         case: &CaseFile,
         signal_window: &IntelSignalWindow,
+        trust_as_of: Option<DateTime<Utc>>,
     ) -> Option<IntelPriorityBreakdown> {
         let watchlist = self.watchlists.get(&case.watchlist_id)?;
         let evidence = self.case_evidence(case);
@@ -1455,19 +2941,29 @@ impl IntelDeskStore {
             .filter_map(|item| {
                 self.sources
                     .get(&item.source_id)
-                    .map(|source| source.trust_score)
+                    .map(|source| self.source_trust_score(source, trust_as_of))
             })
             .collect::<Vec<_>>();
         let latest_signal_at = latest_signal_at(&evidence);
         let (corroborated_claim_count, rejected_claim_count, max_claim_confidence_bps) =
             claim_review_metrics(&claims);
+        let story_clusters = evidence
+            .iter()
+            .map(story_cluster_key)
+            .collect::<BTreeSet<_>>();
+        let cluster_source_count = story_clusters
+            .iter()
+            .map(|cluster_id| self.story_cluster_source_count(cluster_id))
+            .max()
+            .unwrap_or(0);
 
         Some(score_case(
             &CasePriorityInput {
                 status: case.status,
                 severity: watchlist.severity,
                 source_trust_scores,
-                evidence_count: evidence.len(),
+                evidence_count: story_clusters.len(),
+                cluster_source_count,
                 claim_count: claims.len(),
                 corroborated_claim_count,
                 rejected_claim_count,
@@ -1531,6 +3027,7 @@ impl IntelDeskStore {
         let entity = normalized_optional_filter(filters.entity.as_deref(), "entity")?
             .map(|value| value.to_lowercase());
         let min_trust = normalized_trust_score(filters.min_trust)?;
+        let trust_as_of = normalized_trust_as_of(filters.trust_as_of.as_deref())?;
         let semantic_ranker = normalized_semantic_query(filters.q.as_deref(), "q")?
             .map(|query| SemanticRanker::new(&query))
             .transpose()?;
@@ -1552,7 +3049,13 @@ impl IntelDeskStore {
             .filter(|item| {
                 entity
                     .as_deref()
-                    .map(|value| item.entity_labels.iter().any(|entity| entity == value))
+                    .map(|value| {
+                        item.entity_labels.iter().any(|entity| entity == value)
+                            || item
+                                .extracted_entities
+                                .iter()
+                                .any(|extracted| extracted.label == value)
+                    })
                     .unwrap_or(true)
             })
             .filter(|item| {
@@ -1560,7 +3063,7 @@ impl IntelDeskStore {
                     .map(|min_trust| {
                         self.sources
                             .get(&item.source_id)
-                            .map(|source| source.trust_score >= min_trust)
+                            .map(|source| self.source_trust_score(source, trust_as_of) >= min_trust)
                             .unwrap_or(false)
                     })
                     .unwrap_or(true)
@@ -1575,7 +3078,7 @@ impl IntelDeskStore {
                     })
                     .unwrap_or(true)
             })
-            .map(|item| self.evidence_queue_entry(item, &signal_window))
+            .map(|item| self.evidence_queue_entry(item, &signal_window, trust_as_of))
             .collect::<Result<Vec<_>, _>>()?;
         if let Some(ranker) = &semantic_ranker {
             for entry in &mut evidence {
@@ -1611,11 +3114,13 @@ impl IntelDeskStore {
         &self,
         evidence: &EvidenceItem,
         signal_window: &IntelSignalWindow,
+        trust_as_of: Option<DateTime<Utc>>,
     ) -> Result<EvidenceQueueEntry, HelixError> {
         let source = self
             .sources
             .get(&evidence.source_id)
             .ok_or_else(|| HelixError::internal_error("evidence references unknown source"))?;
+        let source_trust_score = self.source_trust_score(source, trust_as_of);
         let linked_cases = self.evidence_cases(&evidence.id);
         let linked_claims = self
             .claims
@@ -1629,7 +3134,8 @@ impl IntelDeskStore {
             &EvidencePriorityInput {
                 linked_case_statuses: linked_cases.iter().map(|case| case.status).collect(),
                 max_linked_severity: self.max_linked_severity(&linked_cases),
-                source_trust_scores: vec![source.trust_score],
+                source_trust_scores: vec![source_trust_score],
+                cluster_source_count: self.story_cluster_source_count(story_cluster_key(evidence)),
                 claim_count: linked_claims.len(),
                 corroborated_claim_count,
                 rejected_claim_count,
@@ -1643,7 +3149,7 @@ impl IntelDeskStore {
         Ok(EvidenceQueueEntry {
             evidence: evidence.clone(),
             source_name: source.name.clone(),
-            source_trust_score: source.trust_score,
+            source_trust_score,
             priority,
             linked_case_count: linked_cases.len(),
             linked_claim_count: linked_claims.len(),
@@ -1662,10 +3168,12 @@ impl IntelDeskStore {
         let subject = normalized_optional_filter(filters.subject.as_deref(), "subject")?
             .map(|value| value.to_lowercase());
         let min_confidence_bps = normalized_confidence_bps(filters.min_confidence_bps)?;
+        let trust_as_of = normalized_trust_as_of(filters.trust_as_of.as_deref())?;
         let semantic_ranker = normalized_semantic_query(filters.q.as_deref(), "q")?
             .map(|query| SemanticRanker::new(&query))
             .transpose()?;
         let signal_window = self.case_signal_window();
+        let claim_graph = self.claim_graph();
         let mut claims = self
             .claims
             .values()
@@ -1702,7 +3210,13 @@ impl IntelDeskStore {
                     })
                     .unwrap_or(true)
             })
-            .map(|claim| self.claim_queue_entry(claim, &signal_window))
+            .filter(|claim| {
+                filters
+                    .contested
+                    .map(|contested| self.claim_contested(&claim.id, &claim_graph) == contested)
+                    .unwrap_or(true)
+            })
+            .map(|claim| self.claim_queue_entry(claim, &claim_graph, &signal_window, trust_as_of))
             .collect::<Result<Vec<_>, _>>()?;
         if let Some(ranker) = &semantic_ranker {
             for entry in &mut claims {
@@ -1733,10 +3247,71 @@ impl IntelDeskStore {
         Ok(claims)
     }
 
+    fn claim_graph(&self) -> ClaimGraph {
+        ClaimGraph::build(self.claims.values())
+    }
+
+    /// Canonical assertions, contested ones first.
+    fn claim_assertions(
+        &self,
+        filters: &ClaimGraphQuery,
+    ) -> Result<Vec<ClaimAssertion>, HelixError> {
+        let limit = normalized_limit(filters.limit, "assertion")?;
+        let subject = normalized_optional_filter(filters.subject.as_deref(), "subject")?
+            .map(|value| self.entity_registry.canonical_label(&value));
+        let mut assertions = self
+            .claim_graph()
+            .assertions()
+            .filter(|assertion| {
+                filters
+                    .contested
+                    .map(|contested| assertion.contested() == contested)
+                    .unwrap_or(true)
+            })
+            .filter(|assertion| {
+                subject
+                    .as_deref()
+                    .map(|value| assertion.subject == value)
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        assertions.sort_by(|left, right| {
+            right
+                .contested()
+                .cmp(&left.contested())
+                .then(right.evidence_ids.len().cmp(&left.evidence_ids.len()))
+                .then(left.id.cmp(&right.id))
+        });
+        if let Some(limit) = limit {
+            assertions.truncate(limit);
+        }
+        Ok(assertions)
+    }
+
+    /// Siblings are the other claims on the same canonical assertion, across evidence.
+    /// Corroborated claims on contradicting assertions count against this claim like
+    /// rejected siblings.
+    /// A claim is contested when its assertion is contradicted or its reviewers split
+    /// without reaching quorum.
+    fn claim_contested(&self, claim_id: &str, claim_graph: &ClaimGraph) -> bool {
+        claim_graph
+            .assertion_for_claim(claim_id)
+            .is_some_and(ClaimAssertion::contested)
+            || self
+                .claim_adjudications
+                .get(claim_id)
+                .is_some_and(|adjudication| {
+                    adjudication.outcome == ClaimAdjudicationOutcome::Contested
+                })
+    }
+
     fn claim_queue_entry(
         &self,
         claim: &ClaimRecord,
+        claim_graph: &ClaimGraph,
         signal_window: &IntelSignalWindow,
+        trust_as_of: Option<DateTime<Utc>>,
     ) -> Result<ClaimQueueEntry, HelixError> {
         let evidence = self
             .evidence
@@ -1745,32 +3320,34 @@ impl IntelDeskStore {
         let source = self.sources.get(&evidence.source_id).ok_or_else(|| {
             HelixError::internal_error("claim evidence references unknown source")
         })?;
+        let source_trust_score = self.source_trust_score(source, trust_as_of);
         let linked_cases = self.claim_cases(&claim.id);
-        let sibling_claims = self
-            .claims
-            .values()
-            .filter(|item| item.evidence_id == claim.evidence_id)
-            .cloned()
-            .collect::<Vec<_>>();
-        let corroborated_sibling_count = sibling_claims
+        let adjudication = self.claim_adjudications.get(&claim.id).cloned();
+        let assertion = claim_graph
+            .assertion_for_claim(&claim.id)
+            .ok_or_else(|| HelixError::internal_error("claim missing from claim graph"))?;
+        let is_status = |status| usize::from(claim.review_status == status);
+        let corroborated_sibling_count = assertion
+            .corroborated_claim_count
+            .saturating_sub(is_status(ClaimReviewStatus::Corroborated));
+        let contradicting_corroborated = claim_graph
+            .contradicting(assertion)
             .iter()
-            .filter(|item| {
-                item.id != claim.id && item.review_status == ClaimReviewStatus::Corroborated
-            })
-            .count();
-        let rejected_sibling_count = sibling_claims
-            .iter()
-            .filter(|item| item.id != claim.id && item.review_status == ClaimReviewStatus::Rejected)
-            .count();
+            .map(|contradicting| contradicting.corroborated_claim_count)
+            .sum::<usize>();
+        let rejected_sibling_count = assertion
+            .rejected_claim_count
+            .saturating_sub(is_status(ClaimReviewStatus::Rejected))
+            .saturating_add(contradicting_corroborated);
         let priority = score_claim(
             &ClaimPriorityInput {
                 review_status: claim.review_status,
                 confidence_bps: claim.confidence_bps,
                 linked_case_statuses: linked_cases.iter().map(|case| case.status).collect(),
                 max_linked_severity: self.max_linked_severity(&linked_cases),
-                source_trust_scores: vec![source.trust_score],
+                source_trust_scores: vec![source_trust_score],
                 evidence_observed_at: Some(evidence.observed_at.clone()),
-                sibling_claim_count: sibling_claims.len(),
+                sibling_claim_count: assertion.claim_ids.len(),
                 corroborated_sibling_count,
                 rejected_sibling_count,
             },
@@ -1782,10 +3359,15 @@ impl IntelDeskStore {
             evidence_title: evidence.title.clone(),
             evidence_observed_at: evidence.observed_at.clone(),
             source_name: source.name.clone(),
-            source_trust_score: source.trust_score,
+            source_trust_score,
             priority,
             linked_case_count: linked_cases.len(),
             max_linked_severity: self.max_linked_severity(&linked_cases),
+            assertion_id: assertion.id.clone(),
+            supporting_evidence_ids: assertion.evidence_ids.clone(),
+            contested: self.claim_contested(&claim.id, claim_graph),
+            contradictions: assertion.contradictions.clone(),
+            adjudication,
             semantic_score_bps: None,
         })
     }
@@ -1801,7 +3383,7 @@ impl IntelDeskStore {
             for entry in self
                 .case_queue(&CaseQueueFilterQuery::default())?
                 .into_iter()
-                .filter(|entry| entry.case.status != CaseStatus::Closed)
+                .filter(|entry| entry.case.status.is_live())
             {
                 items.push(AutopilotReviewQueueEntry {
                     kind: AutopilotReviewKind::Case,
@@ -1915,8 +3497,10 @@ impl IntelDeskStore {
         &self,
         kind: AutopilotReviewKind,
         item_id: &str,
+        redact_notes: bool,
     ) -> Result<AutopilotReviewExportPacketResponse, HelixError> {
         let signal_window = self.case_signal_window();
+        let claim_graph = self.claim_graph();
         let item = self.autopilot_review_item(kind, item_id)?;
 
         let (mut supporting_cases, mut supporting_claims, mut supporting_evidence) = match kind {
@@ -1925,16 +3509,16 @@ impl IntelDeskStore {
                     .cases
                     .get(item_id)
                     .ok_or_else(|| HelixError::internal_error("review case missing"))?;
-                let cases = vec![self.case_queue_entry(case, &signal_window)?];
+                let cases = vec![self.case_queue_entry(case, &signal_window, None)?];
                 let claims = self
                     .case_claims(case)
                     .iter()
-                    .map(|claim| self.claim_queue_entry(claim, &signal_window))
+                    .map(|claim| self.claim_queue_entry(claim, &claim_graph, &signal_window, None))
                     .collect::<Result<Vec<_>, _>>()?;
                 let evidence = self
                     .case_evidence(case)
                     .iter()
-                    .map(|evidence| self.evidence_queue_entry(evidence, &signal_window))
+                    .map(|evidence| self.evidence_queue_entry(evidence, &signal_window, None))
                     .collect::<Result<Vec<_>, _>>()?;
                 (cases, claims, evidence)
             }
@@ -1946,13 +3530,14 @@ impl IntelDeskStore {
                 let cases = self
                     .claim_cases(&claim.id)
                     .iter()
-                    .map(|case| self.case_queue_entry(case, &signal_window))
+                    .map(|case| self.case_queue_entry(case, &signal_window, None))
                     .collect::<Result<Vec<_>, _>>()?;
-                let claims = vec![self.claim_queue_entry(claim, &signal_window)?];
+                let claims =
+                    vec![self.claim_queue_entry(claim, &claim_graph, &signal_window, None)?];
                 let evidence = self
                     .evidence
                     .get(&claim.evidence_id)
-                    .map(|evidence| self.evidence_queue_entry(evidence, &signal_window))
+                    .map(|evidence| self.evidence_queue_entry(evidence, &signal_window, None))
                     .transpose()?
                     .into_iter()
                     .collect::<Vec<_>>();
@@ -1966,14 +3551,15 @@ impl IntelDeskStore {
                 let cases = self
                     .evidence_cases(&evidence.id)
                     .iter()
-                    .map(|case| self.case_queue_entry(case, &signal_window))
+                    .map(|case| self.case_queue_entry(case, &signal_window, None))
                     .collect::<Result<Vec<_>, _>>()?;
                 let claims = self
                     .claims_for_evidence(&evidence.id)
                     .iter()
-                    .map(|claim| self.claim_queue_entry(claim, &signal_window))
+                    .map(|claim| self.claim_queue_entry(claim, &claim_graph, &signal_window, None))
                     .collect::<Result<Vec<_>, _>>()?;
-                let evidence_entries = vec![self.evidence_queue_entry(evidence, &signal_window)?];
+                let evidence_entries =
+                    vec![self.evidence_queue_entry(evidence, &signal_window, None)?];
                 (cases, claims, evidence_entries)
             }
         };
@@ -2003,6 +3589,22 @@ impl IntelDeskStore {
                 .then(left.evidence.id.cmp(&right.evidence.id))
         });
 
+        let note_targets = supporting_cases
+            .iter()
+            .map(|entry| (NoteTargetKind::Case, entry.case.id.as_str()))
+            .chain(
+                supporting_claims
+                    .iter()
+                    .map(|entry| (NoteTargetKind::Claim, entry.claim.id.as_str())),
+            )
+            .chain(
+                supporting_evidence
+                    .iter()
+                    .map(|entry| (NoteTargetKind::Evidence, entry.evidence.id.as_str())),
+            )
+            .collect::<BTreeSet<_>>();
+        let notes = self.packet_notes(&note_targets, redact_notes);
+
         Ok(AutopilotReviewExportPacketResponse {
             packet_id: stable_id("review_export", &[review_kind_label(kind), item_id]),
             kind,
@@ -2016,12 +3618,15 @@ impl IntelDeskStore {
             supporting_cases,
             supporting_claims,
             supporting_evidence,
+            notes,
+            notes_redacted: redact_notes,
         })
     }
 
     pub(crate) fn build_market_brief_export_packet(
         &self,
         case_id: &str,
+        redact_notes: bool,
     ) -> Result<MarketIntelBriefExportPacketResponse, HelixError> {
         let case = self
             .cases
@@ -2046,15 +3651,16 @@ impl IntelDeskStore {
             HelixError::validation_error("case", "case is not a market intelligence case")
         })?;
         let signal_window = self.case_signal_window();
+        let claim_graph = self.claim_graph();
         let mut evidence = self
             .case_evidence(&case)
             .iter()
-            .map(|item| self.evidence_queue_entry(item, &signal_window))
+            .map(|item| self.evidence_queue_entry(item, &signal_window, None))
             .collect::<Result<Vec<_>, _>>()?;
         let mut claims = self
             .case_claims(&case)
             .iter()
-            .map(|claim| self.claim_queue_entry(claim, &signal_window))
+            .map(|claim| self.claim_queue_entry(claim, &claim_graph, &signal_window, None))
             .collect::<Result<Vec<_>, _>>()?;
 
         evidence.sort_by(|left, right| {
@@ -2074,6 +3680,20 @@ impl IntelDeskStore {
                 .then(left.claim.id.cmp(&right.claim.id))
         });
 
+        let note_targets = std::iter::once((NoteTargetKind::Case, case.id.as_str()))
+            .chain(
+                evidence
+                    .iter()
+                    .map(|entry| (NoteTargetKind::Evidence, entry.evidence.id.as_str())),
+            )
+            .chain(
+                claims
+                    .iter()
+                    .map(|entry| (NoteTargetKind::Claim, entry.claim.id.as_str())),
+            )
+            .collect::<BTreeSet<_>>();
+        let notes = self.packet_notes(&note_targets, redact_notes);
+
         Ok(MarketIntelBriefExportPacketResponse {
             packet_id: stable_id("market_brief_export", &[case_id]),
             narrative: briefing_text(&briefing),
@@ -2082,6 +3702,76 @@ impl IntelDeskStore {
             watchlist,
             evidence,
             claims,
+            notes,
+            notes_redacted: redact_notes,
+        })
+    }
+
+    /// Collects a case's brief, log, ranked evidence and claims, and notes for a dossier.
+    pub(crate) fn case_dossier(
+        &self,
+        case_id: &str,
+        redact_notes: bool,
+    ) -> Result<CaseDossier, HelixError> {
+        let case = self
+            .cases
+            .get(case_id)
+            .ok_or_else(|| HelixError::not_found(format!("case {case_id}")))?;
+        let signal_window = self.case_signal_window();
+        let claim_graph = self.claim_graph();
+        let mut evidence = self
+            .case_evidence(case)
+            .iter()
+            .map(|item| self.evidence_queue_entry(item, &signal_window, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut claims = self
+            .case_claims(case)
+            .iter()
+            .map(|claim| self.claim_queue_entry(claim, &claim_graph, &signal_window, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        evidence.sort_by(|left, right| {
+            right
+                .priority
+                .total
+                .cmp(&left.priority.total)
+                .then(right.evidence.observed_at.cmp(&left.evidence.observed_at))
+                .then(left.evidence.id.cmp(&right.evidence.id))
+        });
+        claims.sort_by(|left, right| {
+            right
+                .priority
+                .total
+                .cmp(&left.priority.total)
+                .then(right.claim.confidence_bps.cmp(&left.claim.confidence_bps))
+                .then(left.claim.id.cmp(&right.claim.id))
+        });
+        let note_targets = std::iter::once((NoteTargetKind::Case, case.id.as_str()))
+            .chain(
+                evidence
+                    .iter()
+                    .map(|entry| (NoteTargetKind::Evidence, entry.evidence.id.as_str())),
+            )
+            .chain(
+                claims
+                    .iter()
+                    .map(|entry| (NoteTargetKind::Claim, entry.claim.id.as_str())),
+            )
+            .collect::<BTreeSet<_>>();
+        let notes = self.packet_notes(&note_targets, redact_notes);
+
+        Ok(CaseDossier {
+            case: self.case_queue_entry(case, &signal_window, None)?,
+            market_brief: self.market_case_brief(case),
+            timeline: self
+                .case_timelines
+                .get(case_id)
+                .map(|timeline| timeline.events.clone())
+                .unwrap_or_default(),
+            evidence,
+            claims,
+            notes,
+            notes_redacted: redact_notes,
+            generated_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
         })
     }
 
@@ -2109,7 +3799,29 @@ impl IntelDeskStore {
             trust_score: request.trust_score,
             enabled: request.enabled,
             tags: request.tags,
+            website_diff: request.website_diff,
+            file_import: request.file_import,
+            json_api: request.json_api,
+            webhook_signature: request.webhook_signature,
         })?;
+        if let Some(previous) = self
+            .sources
+            .get(&source.id)
+            .filter(|previous| previous.trust_score != source.trust_score)
+        {
+            let entry = TrustHistoryEntry {
+                id: next_trust_adjustment_id(&self.trust_history),
+                source_id: source.id.clone(),
+                previous_trust_score: previous.trust_score,
+                trust_score: source.trust_score,
+                origin: TrustAdjustmentOrigin::Manual,
+                posterior_bps: 0,
+                corroborated_claim_count: 0,
+                rejected_claim_count: 0,
+                recorded_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            };
+            self.trust_history.insert(entry.id.clone(), entry);
+        }
         self.sources.insert(source.id.clone(), source.clone());
         Ok(source)
     }
@@ -2118,12 +3830,16 @@ impl IntelDeskStore {
         &mut self,
         request: CreateWatchlistRequest,
     ) -> Result<Watchlist, HelixError> {
-        let watchlist = canonicalize_watchlist(Watchlist {
+        let watchlist = self.canonical_watchlist(Watchlist {
             id: slugify(&request.name),
             name: request.name,
             description: request.description,
             keywords: request.keywords,
             entities: request.entities,
+            aliases: request.aliases,
+            query: request.query,
+            trigger: request.trigger,
+            suppressions: Vec::new(),
             min_source_trust: request.min_source_trust,
             severity: request.severity,
             enabled: request.enabled,
@@ -2133,81 +3849,628 @@ impl IntelDeskStore {
         Ok(watchlist)
     }
 
-    fn ingest_evidence(
+    /// Adds a suppression rule, or replaces the note of an identical one.
+    fn add_watchlist_suppression(
         &mut self,
-        request: IngestEvidenceRequest,
-    ) -> Result<IngestEvidenceResponse, HelixError> {
-        let source = self
-            .sources
-            .get(&request.source_id)
-            .cloned()
-            .ok_or_else(|| HelixError::not_found(format!("source {}", request.source_id)))?;
-        if !source.enabled {
-            return Err(HelixError::validation_error("source", "source is disabled"));
+        watchlist_id: &str,
+        request: CreateSuppressionRequest,
+    ) -> Result<(Watchlist, WatchlistSuppression), HelixError> {
+        let rule = match canonicalize_suppression_rule(request.rule)? {
+            SuppressionRule::Entity { entity } => SuppressionRule::Entity {
+                entity: self.entity_registry.canonical_label(&entity),
+            },
+            SuppressionRule::Snooze { until, entity } => SuppressionRule::Snooze {
+                until,
+                entity: entity.map(|entity| self.entity_registry.canonical_label(&entity)),
+            },
+            rule => rule,
+        };
+        let suppression = WatchlistSuppression {
+            id: stable_id(
+                "suppression",
+                &[
+                    watchlist_id,
+                    &serde_json::to_string(&rule).map_err(serde_error)?,
+                ],
+            ),
+            rule,
+            note: canonicalize_suppression_note(&request.note)?,
+        };
+        let watchlist = self
+            .watchlists
+            .get_mut(watchlist_id)
+            .ok_or_else(|| HelixError::not_found(format!("watchlist {watchlist_id}")))?;
+        if let Some(existing) = watchlist
+            .suppressions
+            .iter_mut()
+            .find(|existing| existing.id == suppression.id)
+        {
+            *existing = suppression.clone();
+        } else if watchlist.suppressions.len() >= MAX_WATCHLIST_SUPPRESSIONS {
+            return Err(HelixError::validation_error(
+                "watchlist.suppressions".to_string(),
+                format!("too many items; max is {MAX_WATCHLIST_SUPPRESSIONS}"),
+            ));
+        } else {
+            watchlist.suppressions.push(suppression.clone());
         }
+        Ok((watchlist.clone(), suppression))
+    }
 
-        let evidence_id = stable_id(
-            "evidence",
-            &[
-                &request.source_id,
-                &request.title,
-                &request.summary,
-                &request.observed_at,
-                request.url.as_deref().unwrap_or(""),
-            ],
-        );
-        if let Some(existing) = self.evidence.get(&evidence_id).cloned() {
-            let claims = self.claims_for_evidence(&existing.id);
-            let hits = evaluate_watchlists(
-                &source,
-                &existing,
-                &claims,
-                &self.watchlists.values().cloned().collect::<Vec<_>>(),
-            );
-            return Ok(IngestEvidenceResponse {
-                duplicate: true,
-                evidence: existing,
-                claims,
-                hits,
-                case_updates: Vec::new(),
-            });
+    fn remove_watchlist_suppression(
+        &mut self,
+        watchlist_id: &str,
+        suppression_id: &str,
+    ) -> Result<Watchlist, HelixError> {
+        let watchlist = self
+            .watchlists
+            .get_mut(watchlist_id)
+            .ok_or_else(|| HelixError::not_found(format!("watchlist {watchlist_id}")))?;
+        let before = watchlist.suppressions.len();
+        watchlist
+            .suppressions
+            .retain(|suppression| suppression.id != suppression_id);
+        if watchlist.suppressions.len() == before {
+            return Err(HelixError::not_found(format!(
+                "suppression {suppression_id}"
+            )));
         }
+        Ok(watchlist.clone())
+    }
 
-        let provenance_hash = provenance_hash(&request);
-        let proposed_claims = request.proposed_claims.clone();
-        let evidence = canonicalize_evidence(
-            evidence_id,
-            provenance_hash,
-            EvidenceDraft {
-                source_id: request.source_id,
-                title: request.title,
-                summary: request.summary,
-                content: request.content,
-                url: request.url,
-                observed_at: request.observed_at,
-                tags: request.tags,
-                entity_labels: request.entity_labels,
-                proposed_claims: request.proposed_claims,
-            },
+    /// Recorded suppressed hits for a watchlist, newest first.
+    fn suppressed_hits_for(
+        &self,
+        watchlist_id: &str,
+    ) -> Result<Vec<SuppressedHitRecord>, HelixError> {
+        if !self.watchlists.contains_key(watchlist_id) {
+            return Err(HelixError::not_found(format!("watchlist {watchlist_id}")));
+        }
+        let mut hits = self
+            .suppressed_hits
+            .values()
+            .filter(|record| record.hit.watchlist_id == watchlist_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        hits.sort_by(|left, right| {
+            right
+                .observed_at
+                .cmp(&left.observed_at)
+                .then(left.id.cmp(&right.id))
+        });
+        Ok(hits)
+    }
+
+    /// Replays stored evidence in `observed_at` order through the draft and, when it
+    /// modifies an existing watchlist, through the current definition. Both run as if
+    /// enabled, with fresh trigger windows, and nothing in the desk changes.
+    fn backtest_watchlist(
+        &self,
+        request: WatchlistBacktestRequest,
+    ) -> Result<WatchlistBacktestResponse, HelixError> {
+        let baseline = request
+            .watchlist_id
+            .as_deref()
+            .map(|watchlist_id| {
+                self.watchlists
+                    .get(watchlist_id.trim())
+                    .cloned()
+                    .ok_or_else(|| HelixError::not_found(format!("watchlist {watchlist_id}")))
+            })
+            .transpose()?
+            .map(|baseline| Watchlist {
+                enabled: true,
+                ..baseline
+            });
+        let draft = request.watchlist;
+        let draft = self.canonical_watchlist(Watchlist {
+            id: baseline
+                .as_ref()
+                .map_or_else(|| slugify(&draft.name), |baseline| baseline.id.clone()),
+            name: draft.name,
+            description: draft.description,
+            keywords: draft.keywords,
+            entities: draft.entities,
+            aliases: draft.aliases,
+            query: draft.query,
+            trigger: draft.trigger,
+            // Suppressions are managed separately, so the draft keeps the baseline's rules.
+            suppressions: baseline
+                .as_ref()
+                .map(|baseline| baseline.suppressions.clone())
+                .unwrap_or_default(),
+            min_source_trust: draft.min_source_trust,
+            severity: draft.severity,
+            enabled: true,
+        })?;
+        let observed_from = parse_backtest_bound("observed_from", request.observed_from)?;
+        let observed_to = parse_backtest_bound("observed_to", request.observed_to)?;
+        if let (Some(from), Some(to)) = (observed_from, observed_to) {
+            if from >= to {
+                return Err(HelixError::validation_error(
+                    "observed_to",
+                    "must be later than observed_from",
+                ));
+            }
+        }
+
+        let mut replay = self
+            .evidence
+            .values()
+            .filter_map(|evidence| {
+                let observed_at = DateTime::parse_from_rfc3339(evidence.observed_at.trim()).ok()?;
+                let in_range = observed_from.is_none_or(|from| observed_at >= from)
+                    && observed_to.is_none_or(|to| observed_at < to);
+                in_range.then_some((observed_at, evidence))
+            })
+            .collect::<Vec<_>>();
+        replay.sort_by(|left, right| left.0.cmp(&right.0).then(left.1.id.cmp(&right.1.id)));
+
+        // The draft's entities and aliases did not exist at ingest, so its side re-extracts.
+        let gazetteer = EntityGazetteer::from_watchlists_with_registry(
+            self.watchlists
+                .values()
+                .filter(|watchlist| watchlist.id != draft.id)
+                .chain(std::iter::once(&draft)),
+            &self.entity_registry,
+        );
+        let draft_watchlists = [draft.clone()];
+        let baseline_watchlists = baseline.clone().into_iter().collect::<Vec<_>>();
+        let compiled_draft = CompiledWatchlists::compile(&draft_watchlists)?;
+        let compiled_baseline = CompiledWatchlists::compile(&baseline_watchlists)?;
+        let mut draft_windows = BTreeMap::new();
+        let mut baseline_windows = BTreeMap::new();
+        let mut evaluated_evidence = 0;
+        let mut hits = Vec::new();
+        let mut baseline_hits = Vec::new();
+        let mut suppressed_hits = Vec::new();
+        let mut cases = BTreeMap::<String, WatchlistBacktestCase>::new();
+
+        for (_, evidence) in replay {
+            let Some(source) = self.sources.get(&evidence.source_id) else {
+                continue;
+            };
+            evaluated_evidence += 1;
+            let claims = self.claims_for_evidence(&evidence.id);
+
+            let baseline_evaluated = apply_watchlist_suppressions(
+                &baseline_watchlists,
+                evidence,
+                evaluate_watchlists(source, evidence, &claims, &compiled_baseline),
+            )
+            .into_iter()
+            .filter(|hit| hit.suppressed_by.is_none())
+            .collect();
+            baseline_hits.extend(apply_watchlist_triggers(
+                &baseline_watchlists,
+                &mut baseline_windows,
+                evidence,
+                baseline_evaluated,
+            ));
+
+            let mut replayed = evidence.clone();
+            replayed.extracted_entities = extract_evidence_entities(
+                &gazetteer,
+                &replayed.title,
+                &replayed.summary,
+                &replayed.content,
+            );
+            self.resolve_evidence_entities(&mut replayed);
+            let (suppressed, draft_evaluated): (Vec<_>, Vec<_>) = apply_watchlist_suppressions(
+                &draft_watchlists,
+                &replayed,
+                evaluate_watchlists(source, &replayed, &claims, &compiled_draft),
+            )
+            .into_iter()
+            .partition(|hit| hit.suppressed_by.is_some());
+            suppressed_hits.extend(suppressed);
+            for hit in apply_watchlist_triggers(
+                &draft_watchlists,
+                &mut draft_windows,
+                &replayed,
+                draft_evaluated,
+            ) {
+                let primary_entity = hit
+                    .matched_entities
+                    .first()
+                    .cloned()
+                    .or_else(|| replayed.entity_labels.first().cloned());
+                let existing_case_id = self
+                    .cases
+                    .values()
+                    .find(|case| {
+                        case.status.is_live()
+                            && case.watchlist_id == hit.watchlist_id
+                            && case.primary_entity == primary_entity
+                    })
+                    .map(|case| case.id.clone());
+                let case_id = existing_case_id.clone().unwrap_or_else(|| {
+                    stable_id(
+                        "case",
+                        &[
+                            &hit.watchlist_id,
+                            primary_entity.as_deref().unwrap_or(replayed.id.as_str()),
+                        ],
+                    )
+                });
+                let case = cases
+                    .entry(case_id.clone())
+                    .or_insert_with(|| WatchlistBacktestCase {
+                        case_id,
+                        title: build_case_title(&hit, &replayed, primary_entity.as_deref()),
+                        primary_entity,
+                        evidence_ids: Vec::new(),
+                        escalated: hit.severity.weight() >= WatchlistSeverity::High.weight(),
+                        existing: existing_case_id.is_some(),
+                    });
+                let window_evidence = hit.aggregate.as_ref().map_or_else(
+                    || vec![hit.evidence_id.clone()],
+                    |aggregate| aggregate.evidence_ids.clone(),
+                );
+                for evidence_id in window_evidence {
+                    if !case.evidence_ids.contains(&evidence_id) {
+                        case.evidence_ids.push(evidence_id);
+                    }
+                }
+                hits.push(hit);
+            }
+        }
+
+        let matched = |hits: &[WatchlistHit]| {
+            hits.iter()
+                .map(|hit| hit.evidence_id.clone())
+                .collect::<BTreeSet<_>>()
+        };
+        let severity_distribution = |hits: &[WatchlistHit]| {
+            let mut distribution = BTreeMap::new();
+            for hit in hits {
+                *distribution.entry(json_string(&hit.severity)).or_insert(0) += 1;
+            }
+            distribution
+        };
+        let draft_matched = matched(&hits);
+        let baseline_matched = matched(&baseline_hits);
+
+        Ok(WatchlistBacktestResponse {
+            baseline_watchlist_id: baseline.map(|baseline| baseline.id),
+            evaluated_evidence,
+            hit_count: hits.len(),
+            baseline_hit_count: baseline_hits.len(),
+            newly_matched: draft_matched
+                .difference(&baseline_matched)
+                .cloned()
+                .collect(),
+            no_longer_matched: baseline_matched
+                .difference(&draft_matched)
+                .cloned()
+                .collect(),
+            cases: cases.into_values().collect(),
+            severity_distribution: severity_distribution(&hits),
+            baseline_severity_distribution: severity_distribution(&baseline_hits),
+            hits,
+            suppressed_hits,
+            watchlist: draft,
+        })
+    }
+
+    /// Ingests structured import rows one at a time so a bad row is reported without
+    /// rejecting the rest of the file. Each row is stored whole or not at all. Rows whose
+    /// provenance hash is already stored, including earlier rows of the same file, come
+    /// back as duplicates.
+    fn ingest_file_rows(
+        &mut self,
+        rows: Vec<FileImportRowRequest>,
+    ) -> Result<Vec<FileImportRowResult>, HelixError> {
+        let watchlists = self.watchlists.values().cloned().collect::<Vec<_>>();
+        let matchers = IngestMatchers::compile(&watchlists, &self.entity_registry)?;
+        let mut stored = self.evidence_by_provenance();
+        let mut results = Vec::with_capacity(rows.len());
+        for row in rows {
+            let result = match self.check_file_row(row, &stored, &matchers) {
+                FileRowCheck::Ready { line, prepared } => {
+                    let hash = prepared.evidence.provenance_hash.clone();
+                    match self.commit_evidence(prepared) {
+                        Ok(result) => {
+                            stored.insert(hash, result.evidence.id.clone());
+                            file_row_result(line, FileImportRowStatus::Accepted, result)
+                        }
+                        Err(error) => invalid_file_row(line, Some(hash), error.to_string()),
+                    }
+                }
+                FileRowCheck::Done(result) => result,
+            };
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    /// Dry run of `ingest_file_rows`: validates and deduplicates each row against the
+    /// stored desk and reports the evidence, claims, and hits it would produce. Nothing is
+    /// stored, so case updates are not previewed and rows only see each other for
+    /// duplicate detection.
+    fn preview_file_rows(
+        &self,
+        rows: Vec<FileImportRowRequest>,
+    ) -> Result<Vec<FileImportRowResult>, HelixError> {
+        let watchlists = self.watchlists.values().cloned().collect::<Vec<_>>();
+        let matchers = IngestMatchers::compile(&watchlists, &self.entity_registry)?;
+        let mut stored = self.evidence_by_provenance();
+        let mut results = Vec::with_capacity(rows.len());
+        for row in rows {
+            let result = match self.check_file_row(row, &stored, &matchers) {
+                FileRowCheck::Ready { line, prepared } => {
+                    let PreparedEvidence {
+                        evidence,
+                        claims,
+                        mut hits,
+                        suppressed,
+                        ..
+                    } = prepared;
+                    stored.insert(evidence.provenance_hash.clone(), evidence.id.clone());
+                    hits.extend(suppressed);
+                    file_row_result(
+                        line,
+                        FileImportRowStatus::Accepted,
+                        IngestEvidenceResponse {
+                            duplicate: false,
+                            evidence,
+                            claims,
+                            hits,
+                            case_updates: Vec::new(),
+                        },
+                    )
+                }
+                FileRowCheck::Done(result) => result,
+            };
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    /// Evidence ids keyed by stored provenance hash.
+    fn evidence_by_provenance(&self) -> BTreeMap<String, String> {
+        self.evidence
+            .values()
+            .map(|item| (item.provenance_hash.clone(), item.id.clone()))
+            .collect()
+    }
+
+    fn check_file_row(
+        &self,
+        row: FileImportRowRequest,
+        stored: &BTreeMap<String, String>,
+        matchers: &IngestMatchers<'_>,
+    ) -> FileRowCheck {
+        let line = row.line;
+        let request = match row.request {
+            Ok(request) => request,
+            Err(error) => return FileRowCheck::Done(invalid_file_row(line, None, error)),
+        };
+        let hash = provenance_hash(&request);
+        if let Some(evidence_id) = stored.get(&hash) {
+            let existing = self.evidence.get(evidence_id).cloned();
+            let source = self.sources.get(&request.source_id);
+            return FileRowCheck::Done(match existing.zip(source) {
+                Some((existing, source)) => file_row_result(
+                    line,
+                    FileImportRowStatus::Duplicate,
+                    self.duplicate_evidence(source, existing, matchers),
+                ),
+                // An earlier row of the same dry run, which is not stored.
+                None => FileImportRowResult {
+                    line,
+                    status: FileImportRowStatus::Duplicate,
+                    provenance_hash: Some(hash),
+                    error: None,
+                    result: None,
+                },
+            });
+        }
+        match self.prepare_evidence(request, matchers) {
+            Ok(PreparedIngest::New(prepared)) => FileRowCheck::Ready { line, prepared },
+            // Same evidence id as a stored item with other content: the stored item
+            // stands, and its provenance hash is the one reported.
+            Ok(PreparedIngest::Duplicate(result)) => FileRowCheck::Done(file_row_result(
+                line,
+                FileImportRowStatus::Duplicate,
+                result,
+            )),
+            Err(error) => FileRowCheck::Done(invalid_file_row(line, Some(hash), error.to_string())),
+        }
+    }
+
+    fn ingest_evidence(
+        &mut self,
+        request: IngestEvidenceRequest,
+    ) -> Result<IngestEvidenceResponse, HelixError> {
+        let watchlists = self.watchlists.values().cloned().collect::<Vec<_>>();
+        let matchers = IngestMatchers::compile(&watchlists, &self.entity_registry)?;
+        self.ingest_evidence_with(request, &matchers)
+    }
+
+    /// Ingests a batch in order with the watchlists compiled once. The first failure
+    /// fails the batch.
+    fn ingest_evidence_batch(
+        &mut self,
+        requests: Vec<IngestEvidenceRequest>,
+    ) -> Result<Vec<IngestEvidenceResponse>, HelixError> {
+        let watchlists = self.watchlists.values().cloned().collect::<Vec<_>>();
+        let matchers = IngestMatchers::compile(&watchlists, &self.entity_registry)?;
+        requests
+            .into_iter()
+            .map(|request| self.ingest_evidence_with(request, &matchers))
+            .collect()
+    }
+
+    fn ingest_evidence_with(
+        &mut self,
+        request: IngestEvidenceRequest,
+        matchers: &IngestMatchers<'_>,
+    ) -> Result<IngestEvidenceResponse, HelixError> {
+        match self.prepare_evidence(request, matchers)? {
+            PreparedIngest::Duplicate(response) => Ok(response),
+            PreparedIngest::New(prepared) => self.commit_evidence(prepared),
+        }
+    }
+
+    /// Validates an ingest and works out its evidence, claims, and watchlist hits without
+    /// touching the desk. Trigger windows advance on copies carried in the result.
+    fn prepare_evidence(
+        &self,
+        request: IngestEvidenceRequest,
+        matchers: &IngestMatchers<'_>,
+    ) -> Result<PreparedIngest, HelixError> {
+        let source = self
+            .sources
+            .get(&request.source_id)
+            .cloned()
+            .ok_or_else(|| HelixError::not_found(format!("source {}", request.source_id)))?;
+        if !source.enabled {
+            return Err(HelixError::validation_error("source", "source is disabled"));
+        }
+
+        let evidence_id = stable_id(
+            "evidence",
+            &[
+                &request.source_id,
+                &request.title,
+                &request.summary,
+                &request.observed_at,
+                request.url.as_deref().unwrap_or(""),
+            ],
+        );
+        if let Some(existing) = self.evidence.get(&evidence_id).cloned() {
+            return Ok(PreparedIngest::Duplicate(
+                self.duplicate_evidence(&source, existing, matchers),
+            ));
+        }
+
+        let provenance_hash = provenance_hash(&request);
+        let proposed_claims = request.proposed_claims.clone();
+        let mut evidence = canonicalize_evidence(
+            evidence_id,
+            provenance_hash,
+            EvidenceDraft {
+                source_id: request.source_id,
+                title: request.title,
+                summary: request.summary,
+                content: request.content,
+                url: request.url,
+                observed_at: request.observed_at,
+                tags: request.tags,
+                entity_labels: request.entity_labels,
+                proposed_claims: request.proposed_claims,
+            },
         )?;
+        evidence.story_cluster_id = Some(self.story_cluster_for(&evidence));
+        evidence.extracted_entities = extract_evidence_entities(
+            &matchers.gazetteer,
+            &evidence.title,
+            &evidence.summary,
+            &evidence.content,
+        );
+        self.resolve_evidence_entities(&mut evidence);
         let claim_drafts = canonicalize_claims(&evidence, proposed_claims)?;
         let claims = claim_drafts
             .into_iter()
+            .map(|claim| ProposedClaim {
+                subject: self.entity_registry.canonical_label(&claim.subject),
+                object: self.entity_registry.canonical_label(&claim.object),
+                ..claim
+            })
             .map(|claim| self.materialize_claim(&evidence, claim))
             .collect::<Vec<_>>();
-        let hits = evaluate_watchlists(
-            &source,
+        let (suppressed, hits): (Vec<_>, Vec<_>) = apply_watchlist_suppressions(
+            matchers.watchlists,
             &evidence,
-            &claims,
-            &self.watchlists.values().cloned().collect::<Vec<_>>(),
-        );
+            evaluate_watchlists(&source, &evidence, &claims, &matchers.compiled),
+        )
+        .into_iter()
+        .partition(|hit| hit.suppressed_by.is_some());
+        let mut windows = hits
+            .iter()
+            .filter_map(|hit| {
+                self.watchlist_windows
+                    .get(&hit.watchlist_id)
+                    .map(|window| (hit.watchlist_id.clone(), window.clone()))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let hits = apply_watchlist_triggers(matchers.watchlists, &mut windows, &evidence, hits);
+
+        Ok(PreparedIngest::New(PreparedEvidence {
+            evidence,
+            claims,
+            hits,
+            suppressed,
+            windows,
+        }))
+    }
+
+    /// Response for an item that is already stored: its claims and current hits, with no
+    /// case updates.
+    fn duplicate_evidence(
+        &self,
+        source: &SourceDefinition,
+        existing: EvidenceItem,
+        matchers: &IngestMatchers<'_>,
+    ) -> IngestEvidenceResponse {
+        let claims = self.claims_for_evidence(&existing.id);
+        // Triggered watchlists already counted this item when it first arrived.
+        let hits = evaluate_watchlists(source, &existing, &claims, &matchers.compiled)
+            .into_iter()
+            .filter(|hit| !matchers.is_triggered(&hit.watchlist_id))
+            .collect();
+        let hits = apply_watchlist_suppressions(matchers.watchlists, &existing, hits);
+        IngestEvidenceResponse {
+            duplicate: true,
+            evidence: existing,
+            claims,
+            hits,
+            case_updates: Vec::new(),
+        }
+    }
 
+    /// Stores a prepared ingest and applies its hits to cases. A failure leaves the desk
+    /// as it was, so one bad item in a batch does not leave half its records behind.
+    fn commit_evidence(
+        &mut self,
+        prepared: PreparedEvidence,
+    ) -> Result<IngestEvidenceResponse, HelixError> {
+        let PreparedEvidence {
+            evidence,
+            claims,
+            mut hits,
+            suppressed,
+            windows,
+        } = prepared;
+        self.story_clusters.insert(&evidence);
         self.evidence.insert(evidence.id.clone(), evidence.clone());
         for claim in &claims {
             self.claims.insert(claim.id.clone(), claim.clone());
         }
 
-        let case_updates = self.apply_watchlist_hits(&evidence, &claims, &hits)?;
+        let case_updates = match self.apply_watchlist_hits(&evidence, &claims, &hits) {
+            Ok(case_updates) => case_updates,
+            Err(error) => {
+                for claim in &claims {
+                    self.claims.remove(&claim.id);
+                }
+                self.evidence.remove(&evidence.id);
+                self.story_clusters.remove(&evidence);
+                return Err(error);
+            }
+        };
+        // Trigger windows are kept only once the hits have landed.
+        self.watchlist_windows.extend(windows);
+        for hit in &suppressed {
+            let record = SuppressedHitRecord {
+                id: stable_id("suppressed_hit", &[&hit.watchlist_id, &hit.evidence_id]),
+                observed_at: evidence.observed_at.clone(),
+                hit: hit.clone(),
+            };
+            self.suppressed_hits.insert(record.id.clone(), record);
+        }
+        hits.extend(suppressed);
 
         Ok(IngestEvidenceResponse {
             duplicate: false,
@@ -2218,1286 +4481,4444 @@ impl IntelDeskStore {
         })
     }
 
+    /// Joins the story cluster of the most similar stored item, or starts a new cluster
+    /// named after the evidence itself.
+    fn story_cluster_for(&self, evidence: &EvidenceItem) -> String {
+        let Some(signature) = evidence
+            .minhash
+            .as_deref()
+            .and_then(MinHashSignature::from_hex)
+        else {
+            return evidence.id.clone();
+        };
+        self.story_clusters
+            .nearest(&signature)
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| evidence.id.clone())
+    }
+
+    /// Clusters records stored before near-duplicate detection, oldest first so the
+    /// earliest report names each story.
+    fn backfill_story_clusters(&mut self) {
+        let mut pending = self
+            .evidence
+            .values()
+            .filter(|item| item.story_cluster_id.is_none())
+            .map(|item| (item.observed_at.clone(), item.id.clone()))
+            .collect::<Vec<_>>();
+        pending.sort();
+        for (_, evidence_id) in pending {
+            let Some(mut evidence) = self.evidence.remove(&evidence_id) else {
+                continue;
+            };
+            self.story_clusters.remove(&evidence);
+            if evidence.minhash.is_none() {
+                evidence.minhash =
+                    evidence_minhash(&evidence.title, &evidence.summary, &evidence.content);
+            }
+            evidence.story_cluster_id = Some(self.story_cluster_for(&evidence));
+            self.story_clusters.insert(&evidence);
+            self.evidence.insert(evidence_id, evidence);
+        }
+    }
+
+    /// Distinct sources that reported a story cluster.
+    fn story_cluster_source_count(&self, cluster_id: &str) -> usize {
+        self.story_clusters.source_count(cluster_id)
+    }
+
     fn transition_case(
         &mut self,
         case_id: &str,
         command: CaseCommand,
+        actor: &str,
     ) -> Result<CaseTransition, HelixError> {
-        let case = self
-            .cases
-            .get(case_id)
-            .cloned()
-            .ok_or_else(|| HelixError::not_found(format!("case {}", case_id)))?;
-        let transition = transition_case(&case, command)?;
-        self.cases
-            .insert(transition.case.id.clone(), transition.case.clone());
-        Ok(transition)
+        if !self.cases.contains_key(case_id) {
+            return Err(HelixError::not_found(format!("case {}", case_id)));
+        }
+        self.apply_case_command(case_id, command, actor)
     }
 
-    fn review_claim(
+    /// Runs a command through the case kernel, stores the resulting case and appends the
+    /// command and its decision to the case's event log. Unknown case ids take an open
+    /// command. Denied commands are logged too.
+    fn apply_case_command(
         &mut self,
-        claim_id: &str,
-        status: ClaimReviewStatus,
-    ) -> Result<ClaimRecord, HelixError> {
-        let claim = self
-            .claims
-            .get_mut(claim_id)
-            .ok_or_else(|| HelixError::not_found(format!("claim {}", claim_id)))?;
-        claim.review_status = status;
-        Ok(claim.clone())
-    }
-
-    fn materialize_claim(&self, evidence: &EvidenceItem, proposed: ProposedClaim) -> ClaimRecord {
-        ClaimRecord {
-            id: stable_id(
-                "claim",
-                &[
-                    &evidence.id,
-                    &proposed.subject,
-                    &proposed.predicate,
-                    &proposed.object,
-                ],
-            ),
-            evidence_id: evidence.id.clone(),
-            subject: proposed.subject,
-            predicate: proposed.predicate,
-            object: proposed.object,
-            confidence_bps: proposed.confidence_bps,
-            review_status: ClaimReviewStatus::NeedsReview,
-            rationale: proposed
-                .rationale
-                .unwrap_or_else(|| "operator_provided_or_deterministically_derived".to_string()),
-        }
+        case_id: &str,
+        command: CaseCommand,
+        actor: &str,
+    ) -> Result<CaseTransition, HelixError> {
+        let transition = match self.cases.get(case_id) {
+            Some(case) => transition_case(case, command.clone())?,
+            None => new_case(command.clone())?,
+        };
+        self.record_case_step(
+            CaseStep {
+                command,
+                transition: transition.clone(),
+            },
+            actor,
+        );
+        Ok(transition)
     }
 
-    fn claims_for_evidence(&self, evidence_id: &str) -> Vec<ClaimRecord> {
-        self.claims
-            .values()
-            .filter(|claim| claim.evidence_id == evidence_id)
-            .cloned()
-            .collect()
+    fn record_case_step(&mut self, step: CaseStep, actor: &str) {
+        let case_id = step.transition.case.id.clone();
+        let now = Utc::now();
+        let recorded_at = now.to_rfc3339_opts(SecondsFormat::Millis, true);
+        self.sync_case_sla(&step.transition.case, now);
+        self.case_timelines
+            .entry(case_id.clone())
+            .or_insert_with(|| CaseTimeline::new(&case_id))
+            .append(
+                actor,
+                &recorded_at,
+                step.command,
+                step.transition.decision.clone(),
+            );
+        self.cases.insert(case_id, step.transition.case);
     }
 
-    fn apply_watchlist_hits(
+    /// Applies an operator command. Merges and splits touch a second case, whose
+    /// transition is returned alongside the addressed case's.
+    fn operate_case(
         &mut self,
-        evidence: &EvidenceItem,
-        claims: &[ClaimRecord],
-        hits: &[WatchlistHit],
-    ) -> Result<Vec<CaseTransition>, HelixError> {
-        let mut transitions = Vec::new();
-
-        for hit in hits {
-            let primary_entity = hit
-                .matched_entities
-                .first()
-                .cloned()
-                .or_else(|| evidence.entity_labels.first().cloned());
-            let claim_ids = claims
-                .iter()
-                .map(|claim| claim.id.clone())
-                .collect::<Vec<_>>();
-
-            let existing_case_id = self
-                .cases
-                .values()
-                .find(|case| {
-                    case.status != CaseStatus::Closed
-                        && case.watchlist_id == hit.watchlist_id
-                        && case.primary_entity == primary_entity
-                })
-                .map(|case| case.id.clone());
-
-            let transition = if let Some(case_id) = existing_case_id {
-                let case = self
+        case_id: &str,
+        command: CaseCommand,
+        actor: &str,
+    ) -> Result<CaseTransitionResponse, HelixError> {
+        let case = self
+            .cases
+            .get(case_id)
+            .ok_or_else(|| HelixError::not_found(format!("case {}", case_id)))?;
+        let steps = match command {
+            CaseCommand::Merge { into_case_id } => {
+                let into_case_id = into_case_id.trim();
+                let target = self
                     .cases
-                    .get(&case_id)
-                    .cloned()
-                    .ok_or_else(|| HelixError::internal_error("case missing during update"))?;
-                let updated = transition_case(
-                    &case,
-                    CaseCommand::AppendEvidence {
-                        evidence_id: evidence.id.clone(),
-                        claim_ids: claim_ids.clone(),
-                        reason: hit.reason.clone(),
+                    .get(into_case_id)
+                    .ok_or_else(|| HelixError::not_found(format!("case {into_case_id}")))?;
+                merge_cases(case, target)?
+            }
+            CaseCommand::Split {
+                evidence_ids,
+                new_case_id,
+                ..
+            } => {
+                if self.cases.contains_key(new_case_id.trim()) {
+                    return Err(HelixError::validation_error(
+                        "case.new_case_id".to_string(),
+                        format!("case {} already exists", new_case_id.trim()),
+                    ));
+                }
+                // Claims move with the evidence they were extracted from.
+                let claim_ids = evidence_ids
+                    .iter()
+                    .flat_map(|evidence_id| self.claims_for_evidence(evidence_id.trim()))
+                    .map(|claim| claim.id)
+                    .collect();
+                split_case(
+                    case,
+                    CaseCommand::Split {
+                        evidence_ids,
+                        new_case_id,
+                        claim_ids,
                     },
-                )?;
-                maybe_escalate_case(updated, hit)?
-            } else {
-                let opened = new_case(CaseCommand::Open {
-                    case_id: stable_id(
-                        "case",
-                        &[
-                            &hit.watchlist_id,
-                            primary_entity.as_deref().unwrap_or(evidence.id.as_str()),
-                        ],
-                    ),
-                    title: build_case_title(hit, evidence, primary_entity.as_deref()),
-                    watchlist_id: hit.watchlist_id.clone(),
-                    primary_entity,
-                    evidence_id: evidence.id.clone(),
-                    claim_ids: claim_ids.clone(),
-                    reason: hit.reason.clone(),
-                })?;
-                maybe_escalate_case(opened, hit)?
-            };
+                )?
+            }
+            command => {
+                let transition = self.transition_case(case_id, command, actor)?;
+                return Ok(CaseTransitionResponse {
+                    transition,
+                    related: Vec::new(),
+                });
+            }
+        };
+        let mut transitions = steps.into_iter().map(|step| {
+            let transition = step.transition.clone();
+            self.record_case_step(step, actor);
+            transition
+        });
+        let transition = transitions
+            .next()
+            .ok_or_else(|| HelixError::internal_error("case operation produced no steps"))?;
+        Ok(CaseTransitionResponse {
+            transition,
+            related: transitions.collect(),
+        })
+    }
 
-            self.cases
-                .insert(transition.case.id.clone(), transition.case.clone());
-            transitions.push(transition);
+    /// Keeps a case's briefing SLA in step with its status: escalation opens a window,
+    /// a brief completes it, and any other status cancels it.
+    fn sync_case_sla(&mut self, case: &CaseFile, at: DateTime<Utc>) {
+        let open = self
+            .case_slas
+            .get(&case.id)
+            .is_some_and(|sla| sla.is_open());
+        match case.status {
+            CaseStatus::Escalated if !open => {
+                if let Some(watchlist) = self.watchlists.get(&case.watchlist_id) {
+                    self.case_slas.insert(
+                        case.id.clone(),
+                        CaseSla::start(&case.id, watchlist.severity, &self.case_sla_policy, at),
+                    );
+                }
+            }
+            CaseStatus::Escalated => {}
+            CaseStatus::BriefReady if open => {
+                if let Some(sla) = self.case_slas.get_mut(&case.id) {
+                    sla.complete(at);
+                }
+            }
+            _ if open => {
+                if let Some(sla) = self.case_slas.get_mut(&case.id) {
+                    sla.cancel(at);
+                }
+            }
+            _ => {}
         }
-
-        transitions.sort_by(|left, right| left.case.id.cmp(&right.case.id));
-        Ok(transitions)
     }
-}
 
-fn build_case_title(
-    hit: &WatchlistHit,
-    evidence: &EvidenceItem,
-    primary_entity: Option<&str>,
-) -> String {
-    match primary_entity {
-        Some(entity) => format!("{}: {}", hit.watchlist_name, entity),
-        None => format!("{}: {}", hit.watchlist_name, evidence.title),
+    /// Starts SLA windows for escalated cases stored before SLAs were tracked.
+    fn backfill_case_slas(&mut self) {
+        let now = Utc::now();
+        let escalated = self
+            .cases
+            .values()
+            .filter(|case| {
+                case.status == CaseStatus::Escalated && !self.case_slas.contains_key(&case.id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        for case in escalated {
+            self.sync_case_sla(&case, now);
+        }
     }
-}
 
-fn maybe_escalate_case(
-    transition: CaseTransition,
-    hit: &WatchlistHit,
-) -> Result<CaseTransition, HelixError> {
-    if hit.severity.weight() < WatchlistSeverity::High.weight() {
-        return Ok(transition);
+    fn set_case_sla_policy(&mut self, policy: CaseSlaPolicy) -> Result<CaseSlaPolicy, HelixError> {
+        self.case_sla_policy = canonicalize_case_sla_policy(policy)?;
+        Ok(self.case_sla_policy)
     }
 
-    match transition.decision {
-        CaseDecision::Denied { .. } => Ok(transition),
-        _ => transition_case(
-            &transition.case,
-            CaseCommand::Escalate {
-                reason: format!("{}:{}", hit.watchlist_name, hit.reason),
-            },
-        ),
+    fn case_slas_due(&self, now: DateTime<Utc>) -> bool {
+        self.case_slas.values().any(|sla| sla.is_due(now))
     }
-}
-
-fn provenance_hash(request: &IngestEvidenceRequest) -> String {
-    stable_hash(&[
-        &request.source_id,
-        &request.title,
-        &request.summary,
-        &request.content,
-        &request.observed_at,
-        request.url.as_deref().unwrap_or(""),
-    ])
-}
 
-async fn fetch_source_body(
-    endpoint_url: &str,
-    auth: Option<&SourceFetchAuth>,
-) -> Result<String, HelixError> {
-    let client = reqwest::Client::new();
-    let mut request = client.get(endpoint_url);
-    if let Some(auth) = auth {
-        request = request.header(auth.header_name.clone(), auth.header_value.clone());
+    fn open_case_sla_count(&self) -> usize {
+        self.case_slas.values().filter(|sla| sla.is_open()).count()
     }
 
-    let response = request
-        .send()
-        .await
-        .map_err(|error| source_fetch_error(error.to_string()))?;
-    let status = response.status();
-    if !status.is_success() {
-        return Err(source_fetch_error(format!(
-            "source endpoint returned HTTP {status}"
-        )));
+    /// Advances every open SLA window to `now` and returns the cases that breached on
+    /// this tick, the open window count, and whether any window moved.
+    fn tick_case_slas(&mut self, now: DateTime<Utc>) -> (Vec<CaseSlaBreach>, usize, bool) {
+        let mut breaches = Vec::new();
+        let mut changed = false;
+        for sla in self.case_slas.values_mut() {
+            changed |= sla.is_due(now);
+            if !sla.tick(now) {
+                continue;
+            }
+            if let Some(case) = self.cases.get(&sla.case_id) {
+                breaches.push(CaseSlaBreach {
+                    case_id: case.id.clone(),
+                    title: case.title.clone(),
+                    watchlist_id: case.watchlist_id.clone(),
+                    severity: sla.severity,
+                    assignee: case.assignee.clone(),
+                    watch_group: case.watch_group.clone(),
+                    started_at: sla.started_at.clone(),
+                    deadline_at: sla.deadline_at.clone(),
+                });
+            }
+        }
+        (breaches, self.open_case_sla_count(), changed)
     }
 
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|error| source_fetch_error(error.to_string()))?;
-    if bytes.len() > MAX_SOURCE_FETCH_BYTES {
-        return Err(HelixError::validation_error(
-            "source.payload",
-            "source payload exceeds 1 MiB",
-        ));
+    fn case_is_breaching(&self, case: &CaseFile) -> bool {
+        case.status.is_live()
+            && self
+                .case_slas
+                .get(&case.id)
+                .is_some_and(|sla| sla.status == CaseSlaStatus::Breached && sla.is_open())
     }
-    String::from_utf8(bytes.to_vec()).map_err(|_| {
-        HelixError::validation_error("source.payload", "source payload must be UTF-8 text")
-    })
-}
-
-fn source_fetch_error(details: String) -> HelixError {
-    HelixError::external_service_error("source_fetch".to_string(), details)
-}
-
-async fn source_fetch_auth(
-    state: &AppState,
-    source: &SourceDefinition,
-) -> Result<Option<SourceFetchAuth>, HelixError> {
-    let Some(credential_id) = source.credential_id.as_deref() else {
-        return Ok(None);
-    };
-    let profile_id: ProfileId = source
-        .profile_id
-        .parse()
-        .map_err(|_| HelixError::validation_error("source.profile_id", "must be a valid UUID"))?;
-    let credential_id_uuid: CredentialId = credential_id.parse().map_err(|_| {
-        HelixError::validation_error("source.credential_id", "must be a valid UUID")
-    })?;
-    let Some(persistence) = state.state_persistence.as_ref() else {
-        return Err(HelixError::config_error(
-            "source credential collection requires DATABASE_URL",
-        ));
-    };
-    let Some(encrypted_data) = persistence
-        .encrypted_credential_data(&profile_id, &credential_id_uuid)
-        .await?
-    else {
-        return Err(HelixError::not_found(format!(
-            "credential {credential_id} for source {}",
-            source.id
-        )));
-    };
-    let encrypter = credential_encrypter_from_env()?;
-    let secret = encrypter
-        .decrypt(&encrypted_data)
-        .await
-        .map_err(|error| HelixError::encryption_error(error.to_string()))?;
-    let header_name =
-        HeaderName::from_bytes(source.credential_header_name.as_bytes()).map_err(|_| {
-            HelixError::validation_error(
-                "source.credential_header_name",
-                "must be a valid HTTP header name",
-            )
-        })?;
-    let header_value =
-        source_credential_header_value(source.credential_header_prefix.as_deref(), &secret)?;
 
-    Ok(Some(SourceFetchAuth {
-        credential_id: credential_id.to_string(),
-        header_name,
-        header_value,
-    }))
-}
+    fn ensure_note_target(&self, kind: NoteTargetKind, target_id: &str) -> Result<(), HelixError> {
+        let (exists, label) = match kind {
+            NoteTargetKind::Case => (self.cases.contains_key(target_id), "case"),
+            NoteTargetKind::Evidence => (self.evidence.contains_key(target_id), "evidence"),
+            NoteTargetKind::Claim => (self.claims.contains_key(target_id), "claim"),
+        };
+        if exists {
+            Ok(())
+        } else {
+            Err(HelixError::not_found(format!("{label} {target_id}")))
+        }
+    }
 
-fn source_credential_header_value(
-    prefix: Option<&str>,
-    secret: &str,
-) -> Result<HeaderValue, HelixError> {
-    if secret.trim().is_empty() {
-        return Err(HelixError::validation_error(
-            "credential.secret",
-            "decrypted credential must not be empty",
-        ));
+    fn target_notes(
+        &self,
+        kind: NoteTargetKind,
+        target_id: &str,
+    ) -> Result<NoteThreadsResponse, HelixError> {
+        self.ensure_note_target(kind, target_id)?;
+        let notes = self
+            .notes
+            .values()
+            .filter(|note| note.target_kind == kind && note.target_id == target_id)
+            .cloned()
+            .collect();
+        Ok(NoteThreadsResponse {
+            target_kind: kind,
+            target_id: target_id.to_string(),
+            threads: thread_notes(notes),
+        })
     }
-    let value = match prefix.map(str::trim).filter(|value| !value.is_empty()) {
-        Some(prefix) => format!("{prefix} {secret}"),
-        None => secret.to_string(),
-    };
-    HeaderValue::from_str(&value).map_err(|_| {
-        HelixError::validation_error(
-            "credential.secret",
-            "decrypted credential cannot be represented as an HTTP header value",
-        )
-    })
-}
 
-fn collect_requests_from_payload(
-    source: &SourceDefinition,
-    payload: &str,
-    fallback_observed_at: &str,
-    max_items: usize,
-) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
-    if fallback_observed_at.trim().is_empty() {
-        return Err(HelixError::validation_error(
-            "observed_at",
-            "fallback observed_at is required",
-        ));
+    fn create_note(
+        &mut self,
+        kind: NoteTargetKind,
+        target_id: &str,
+        request: CreateNoteRequest,
+    ) -> Result<AnalystNote, HelixError> {
+        self.ensure_note_target(kind, target_id)?;
+        let parent = match normalized_optional_filter(request.parent_id.as_deref(), "parent_id")? {
+            Some(parent_id) => Some(
+                self.notes
+                    .get(&parent_id)
+                    .ok_or_else(|| HelixError::not_found(format!("note {parent_id}")))?,
+            ),
+            None => None,
+        };
+        let note = AnalystNote::new(
+            &format!("note_{:08}", self.notes.len() + 1),
+            kind,
+            target_id,
+            parent,
+            &request.author,
+            &request.body,
+            request.pinned,
+            &Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        )?;
+        self.notes.insert(note.id.clone(), note.clone());
+        Ok(note)
     }
-    let endpoint_url = source.endpoint_url.as_deref().ok_or_else(|| {
-        HelixError::validation_error("source.endpoint_url", "source has no endpoint_url")
-    })?;
 
-    let mut requests = match source.kind {
-        SourceKind::JsonApi => json_collection_requests(source, payload, fallback_observed_at)?,
-        SourceKind::RssFeed => rss_collection_requests(source, payload, fallback_observed_at),
-        SourceKind::WebsiteDiff => {
-            website_collection_requests(source, payload, endpoint_url, fallback_observed_at)
-        }
-        SourceKind::WebhookIngest | SourceKind::EmailDigest | SourceKind::FileImport => {
+    fn update_note(
+        &mut self,
+        note_id: &str,
+        request: UpdateNoteRequest,
+    ) -> Result<AnalystNote, HelixError> {
+        if request.body.is_none() && request.pinned.is_none() {
             return Err(HelixError::validation_error(
-                "source.kind",
-                "source kind does not support pull collection",
+                "note",
+                "body or pinned is required",
             ));
         }
-    };
-    requests.truncate(max_items);
-    Ok(requests)
-}
-
-fn source_supports_pull_collection(source: &SourceDefinition) -> bool {
-    matches!(
-        source.kind,
-        SourceKind::JsonApi | SourceKind::RssFeed | SourceKind::WebsiteDiff
-    )
-}
-
-fn source_schedule_phase_minute(source: &SourceDefinition) -> u16 {
-    let cadence = source.cadence_minutes.max(1);
-    let mut hasher = Sha256::new();
-    hasher.update(source.id.as_bytes());
-    hasher.update(source.profile_id.as_bytes());
-    hasher.update(source.kind_string().as_bytes());
-    hasher.update(cadence.to_be_bytes());
-    let digest = hasher.finalize();
-    let value = u64::from_be_bytes(
-        digest[..8]
-            .try_into()
-            .expect("sha256 digest has at least eight bytes"),
-    );
-    (value % u64::from(cadence)) as u16
-}
-
-fn source_is_due_at_tick(source: &SourceDefinition, tick_minute: u64) -> bool {
-    let cadence = u64::from(source.cadence_minutes.max(1));
-    tick_minute % cadence == u64::from(source_schedule_phase_minute(source))
-}
-
-trait SourceKindName {
-    fn kind_string(&self) -> &'static str;
-}
+        let mut note = self
+            .notes
+            .get(note_id)
+            .cloned()
+            .ok_or_else(|| HelixError::not_found(format!("note {note_id}")))?;
+        let at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        if let Some(body) = request.body.as_deref() {
+            note.edit(&request.editor, body, &at)?;
+        }
+        if let Some(pinned) = request.pinned {
+            note.set_pinned(pinned, &at)?;
+        }
+        self.notes.insert(note.id.clone(), note.clone());
+        Ok(note)
+    }
 
-impl SourceKindName for SourceDefinition {
-    fn kind_string(&self) -> &'static str {
-        match self.kind {
-            SourceKind::RssFeed => "rss_feed",
-            SourceKind::WebsiteDiff => "website_diff",
-            SourceKind::JsonApi => "json_api",
-            SourceKind::WebhookIngest => "webhook_ingest",
-            SourceKind::EmailDigest => "email_digest",
-            SourceKind::FileImport => "file_import",
+    fn note_queue(&self, filters: &NoteQueueFilterQuery) -> Result<Vec<AnalystNote>, HelixError> {
+        let limit = normalized_limit(filters.limit, "note")?;
+        let target_id = normalized_optional_filter(filters.target_id.as_deref(), "target_id")?;
+        let author = normalized_optional_filter(filters.author.as_deref(), "author")?
+            .map(|value| value.to_lowercase());
+        let mention = normalized_optional_filter(filters.mention.as_deref(), "mention")?
+            .map(|value| value.trim_start_matches('@').to_lowercase());
+        let query = normalized_optional_filter(filters.q.as_deref(), "q")?
+            .map(|value| value.to_lowercase());
+        let mut notes = self
+            .notes
+            .values()
+            .filter(|note| {
+                filters
+                    .target_kind
+                    .map(|kind| note.target_kind == kind)
+                    .unwrap_or(true)
+            })
+            .filter(|note| {
+                target_id
+                    .as_deref()
+                    .map(|target| note.target_id == target)
+                    .unwrap_or(true)
+            })
+            .filter(|note| {
+                author
+                    .as_deref()
+                    .map(|target| note.author.to_lowercase() == target)
+                    .unwrap_or(true)
+            })
+            .filter(|note| {
+                mention
+                    .as_deref()
+                    .map(|target| note.mentions.iter().any(|mention| mention == target))
+                    .unwrap_or(true)
+            })
+            .filter(|note| {
+                filters
+                    .pinned
+                    .map(|pinned| note.pinned == pinned)
+                    .unwrap_or(true)
+            })
+            .filter(|note| {
+                query
+                    .as_deref()
+                    .map(|query| note.matches(query))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        notes.sort_by(|left, right| {
+            right
+                .updated_at
+                .cmp(&left.updated_at)
+                .then(right.id.cmp(&left.id))
+        });
+        if let Some(limit) = limit {
+            notes.truncate(limit);
         }
+        Ok(notes)
     }
-}
 
-fn normalize_collect_limit(limit: Option<usize>) -> Result<usize, HelixError> {
-    let limit = limit.unwrap_or(10);
-    if limit == 0 || limit > MAX_COLLECT_ITEMS {
-        return Err(HelixError::validation_error(
-            "max_items",
-            &format!("must be between 1 and {MAX_COLLECT_ITEMS}"),
-        ));
+    /// Notes on the given records for an export packet, grouped by record in id order.
+    fn packet_notes(
+        &self,
+        targets: &BTreeSet<(NoteTargetKind, &str)>,
+        redact: bool,
+    ) -> Vec<AnalystNote> {
+        let mut notes = self
+            .notes
+            .values()
+            .filter(|note| targets.contains(&(note.target_kind, note.target_id.as_str())))
+            .map(|note| {
+                if redact {
+                    note.redacted()
+                } else {
+                    note.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        notes.sort_by(|left, right| {
+            (left.target_kind, &left.target_id, &left.id).cmp(&(
+                right.target_kind,
+                &right.target_id,
+                &right.id,
+            ))
+        });
+        notes
     }
-    Ok(limit)
-}
 
-fn json_collection_requests(
-    source: &SourceDefinition,
-    payload: &str,
-    fallback_observed_at: &str,
-) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
-    let payload: JsonCollectionPayload = serde_json::from_str(payload).map_err(HelixError::from)?;
-    let items = match payload {
-        JsonCollectionPayload::Envelope { items } | JsonCollectionPayload::Array(items) => items,
-        JsonCollectionPayload::Single(item) => vec![item],
-    };
-    items
-        .into_iter()
-        .map(|item| collected_item_to_request(source, item, fallback_observed_at))
-        .collect()
-}
+    /// Starts an event log from a snapshot for cases stored before events were recorded.
+    fn backfill_case_timelines(&mut self) {
+        let recorded_at = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+        for case in self.cases.values() {
+            if !self.case_timelines.contains_key(&case.id) {
+                self.case_timelines.insert(
+                    case.id.clone(),
+                    CaseTimeline::from_baseline(case.clone(), &recorded_at),
+                );
+            }
+        }
+    }
 
-fn collected_item_to_request(
-    source: &SourceDefinition,
-    item: CollectedEvidencePayload,
-    fallback_observed_at: &str,
-) -> Result<IngestEvidenceRequest, HelixError> {
-    let title = item.title.trim();
-    if title.is_empty() {
-        return Err(HelixError::validation_error(
-            "source.item.title",
-            "title is required",
-        ));
+    fn case_timeline(
+        &self,
+        case_id: &str,
+        query: &CaseTimelineQuery,
+    ) -> Result<CaseTimelineResponse, HelixError> {
+        let case = self
+            .cases
+            .get(case_id)
+            .cloned()
+            .ok_or_else(|| HelixError::not_found(format!("case {case_id}")))?;
+        let timeline = self
+            .case_timelines
+            .get(case_id)
+            .cloned()
+            .unwrap_or_else(|| CaseTimeline::from_baseline(case.clone(), ""));
+        let replay_matches = timeline
+            .replay()
+            .is_ok_and(|replayed| replayed.as_ref() == Some(&case));
+        let case_as_of = match query.as_of.as_deref().map(str::trim) {
+            Some(value) => {
+                let at = DateTime::parse_from_rfc3339(value)
+                    .map(|value| value.with_timezone(&Utc))
+                    .map_err(|_| {
+                        HelixError::validation_error(
+                            "case_timeline.as_of",
+                            "must be an RFC 3339 timestamp",
+                        )
+                    })?;
+                timeline.case_as_of(at)?
+            }
+            None => None,
+        };
+        Ok(CaseTimelineResponse {
+            case,
+            replay_matches,
+            as_of: query.as_of.clone(),
+            case_as_of,
+            baseline: timeline.baseline,
+            events: timeline.events,
+        })
     }
 
-    let content = first_non_empty([
-        item.content.as_deref(),
-        item.summary.as_deref(),
-        Some(title),
-    ])
-    .unwrap_or(title);
-    let summary = first_non_empty([item.summary.as_deref(), Some(content)]).unwrap_or(content);
-    let observed_at = first_non_empty([item.observed_at.as_deref(), Some(fallback_observed_at)])
-        .unwrap_or(fallback_observed_at);
-    let url = item
-        .url
-        .or_else(|| source.endpoint_url.as_ref().map(ToString::to_string));
+    fn upsert_rule_pack(&mut self, pack: IntelRulePack) -> Result<IntelRulePack, HelixError> {
+        let pack = canonicalize_rule_pack(pack)?;
+        self.rule_packs.insert(pack.id.clone(), pack.clone());
+        Ok(pack)
+    }
 
-    Ok(IngestEvidenceRequest {
-        source_id: source.id.clone(),
-        title: truncate_text(title, 240),
-        summary: truncate_text(summary, 1_024),
-        content: truncate_text(content, MAX_COLLECT_CONTENT_LEN),
-        url,
-        observed_at: observed_at.trim().to_string(),
-        tags: merge_source_tags(source, item.tags),
-        entity_labels: item.entity_labels,
-        proposed_claims: item.proposed_claims,
-    })
-}
+    /// Evaluates a rule pack over the case's claims and attaches the decision as the
+    /// case's reasoning gate.
+    fn evaluate_case_reasoning(
+        &mut self,
+        case_id: &str,
+        request: CaseReasoningRequest,
+    ) -> Result<CaseReasoningResponse, HelixError> {
+        let case = self
+            .cases
+            .get(case_id)
+            .cloned()
+            .ok_or_else(|| HelixError::not_found(format!("case {case_id}")))?;
+        let rule_pack_id = request.rule_pack_id.trim();
+        let pack = self
+            .rule_packs
+            .get(rule_pack_id)
+            .ok_or_else(|| HelixError::not_found(format!("rule pack {rule_pack_id}")))?;
+        let severity = self
+            .watchlists
+            .get(&case.watchlist_id)
+            .map(|watchlist| watchlist.severity)
+            .ok_or_else(|| HelixError::internal_error("case references unknown watchlist"))?;
+        let (reasoning_request, claim_ids) = build_case_reasoning_request(
+            &case,
+            severity,
+            &self.case_claims(&case),
+            pack,
+            &request.options,
+        )?;
+        let decision = evaluate_reasoning(reasoning_request.clone())?;
+        let ReasoningEvaluationRequest::KrrSymbolic { query, .. } = &reasoning_request else {
+            return Err(HelixError::internal_error(
+                "case reasoning must build a symbolic request",
+            ));
+        };
+        let gate = CaseReasoningGate {
+            rule_pack_id: pack.id.clone(),
+            query: query.clone(),
+            include_needs_review: request.options.include_needs_review,
+            claim_ids,
+            evaluated_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            decision,
+        };
+        let transition = self.apply_case_command(
+            case_id,
+            CaseCommand::AttachReasoningGate {
+                gate: Box::new(gate.clone()),
+            },
+            &format!("reasoning:{}", gate.rule_pack_id),
+        )?;
+        Ok(CaseReasoningResponse {
+            case: transition.case,
+            request: reasoning_request,
+            gate,
+        })
+    }
 
-fn webhook_ingest_requests(
-    source: &SourceDefinition,
-    payload: WebhookIngestPayload,
-) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
-    let (fallback_observed_at, items) = match payload {
-        WebhookIngestPayload::Envelope { observed_at, items } => (observed_at, items),
-        WebhookIngestPayload::Array(items) => (None, items),
-        WebhookIngestPayload::Single(item) => (None, vec![item]),
-    };
-    if items.is_empty() {
-        return Err(HelixError::validation_error(
-            "webhook.items",
-            "at least one item is required",
-        ));
+    fn set_decay_policy(&mut self, policy: DecayPolicy) -> Result<DecayPolicy, HelixError> {
+        self.decay_policy = canonicalize_decay_policy(policy)?;
+        Ok(self.decay_policy.clone())
     }
-    if items.len() > MAX_COLLECT_ITEMS {
-        return Err(HelixError::validation_error(
-            "webhook.items",
-            &format!("must contain at most {MAX_COLLECT_ITEMS} items"),
-        ));
+
+    fn evidence_relevance_bps(&self, evidence: &EvidenceItem, as_of: DateTime<Utc>) -> u16 {
+        self.decay_policy.relevance_bps(
+            self.sources
+                .get(&evidence.source_id)
+                .map(|source| source.kind),
+            &evidence.tags,
+            &evidence.observed_at,
+            as_of,
+        )
     }
 
-    let fallback_observed_at = fallback_observed_at.unwrap_or_default();
-    items
-        .into_iter()
-        .map(|item| {
-            if first_non_empty([
-                item.observed_at.as_deref(),
-                Some(fallback_observed_at.as_str()),
-            ])
-            .is_none()
-            {
-                return Err(HelixError::validation_error(
-                    "webhook.observed_at",
-                    "observed_at is required on each item or on the envelope",
-                ));
-            }
-            collected_item_to_request(source, item, &fallback_observed_at)
+    /// Works out which corroborated claims fall below the review threshold and which
+    /// evidence expires at `as_of`, without changing anything.
+    fn decay_report(&self, request: &DecayApplyRequest) -> Result<DecayApplyResponse, HelixError> {
+        let as_of = match request.as_of.as_deref().map(str::trim) {
+            Some(value) => DateTime::parse_from_rfc3339(value)
+                .map(|value| value.with_timezone(&Utc))
+                .map_err(|_| {
+                    HelixError::validation_error("decay.as_of", "must be an RFC 3339 timestamp")
+                })?,
+            None => self
+                .case_signal_window()
+                .newest_signal_at()
+                .ok_or_else(|| {
+                    HelixError::validation_error(
+                        "decay.as_of",
+                        "is required when the desk holds no evidence",
+                    )
+                })?,
+        };
+
+        let mut relevance = BTreeMap::new();
+        for evidence in self.evidence.values() {
+            relevance.insert(
+                evidence.id.as_str(),
+                self.evidence_relevance_bps(evidence, as_of),
+            );
+        }
+
+        let claims = self
+            .claims
+            .values()
+            .filter(|claim| claim.review_status == ClaimReviewStatus::Corroborated)
+            .filter_map(|claim| {
+                let relevance_bps = *relevance.get(claim.evidence_id.as_str())?;
+                let decayed = decayed_confidence_bps(claim.confidence_bps, relevance_bps);
+                (decayed < self.decay_policy.claim_review_threshold_bps).then(|| DecayedClaim {
+                    claim_id: claim.id.clone(),
+                    evidence_id: claim.evidence_id.clone(),
+                    confidence_bps: claim.confidence_bps,
+                    relevance_bps,
+                    decayed_confidence_bps: decayed,
+                })
+            })
+            .collect();
+
+        let expiry_bps = self.decay_policy.evidence_expiry_bps;
+        let is_expired = |evidence_id: &str| {
+            relevance
+                .get(evidence_id)
+                .is_some_and(|relevance_bps| *relevance_bps < expiry_bps)
+        };
+        let expired_evidence = relevance
+            .iter()
+            .filter(|(_, relevance_bps)| **relevance_bps < expiry_bps)
+            .map(|(evidence_id, relevance_bps)| ExpiredEvidence {
+                evidence_id: evidence_id.to_string(),
+                relevance_bps: *relevance_bps,
+                detached_case_ids: self
+                    .cases
+                    .values()
+                    .filter(|case| {
+                        case.status == CaseStatus::Monitoring
+                            && case.evidence_ids.iter().any(|id| id == evidence_id)
+                            && case.evidence_ids.iter().any(|id| !is_expired(id))
+                    })
+                    .map(|case| case.id.clone())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(DecayApplyResponse {
+            as_of: as_of.to_rfc3339_opts(SecondsFormat::Secs, true),
+            dry_run: request.dry_run,
+            policy: self.decay_policy.clone(),
+            claims,
+            expired_evidence,
         })
-        .collect()
-}
+    }
 
-fn file_import_request(
-    source: &SourceDefinition,
-    request: FileImportRequest,
-) -> Result<IngestEvidenceRequest, HelixError> {
-    let file_name = request.file_name.trim();
-    if file_name.is_empty() {
-        return Err(HelixError::validation_error(
-            "file_name",
-            "file_name is required",
-        ));
+    /// Applies a decay report: decayed claims return to needs-review, closing any decided
+    /// vote so reviewers can vote again, and expired evidence is detached, with its
+    /// claims, from monitoring cases.
+    fn apply_decay(
+        &mut self,
+        request: &DecayApplyRequest,
+    ) -> Result<DecayApplyResponse, HelixError> {
+        let report = self.decay_report(request)?;
+        if report.dry_run {
+            return Ok(report);
+        }
+        for decayed in &report.claims {
+            if let Some(claim) = self.claims.get_mut(&decayed.claim_id) {
+                claim.review_status = ClaimReviewStatus::NeedsReview;
+            }
+            if let Some(adjudication) = self
+                .claim_adjudications
+                .get_mut(&decayed.claim_id)
+                .filter(|adjudication| adjudication.review_status().is_some())
+            {
+                adjudication.reopen(&self.adjudication_config, "decay", &report.as_of);
+            }
+        }
+        for expired in &report.expired_evidence {
+            let claim_ids = self
+                .claims_for_evidence(&expired.evidence_id)
+                .into_iter()
+                .map(|claim| claim.id)
+                .collect::<Vec<_>>();
+            for case_id in &expired.detached_case_ids {
+                self.transition_case(
+                    case_id,
+                    CaseCommand::DetachEvidence {
+                        evidence_id: expired.evidence_id.clone(),
+                        claim_ids: claim_ids.clone(),
+                        reason: format!("evidence_expired:{}", expired.evidence_id),
+                    },
+                    "decay",
+                )?;
+            }
+        }
+        Ok(report)
     }
-    if file_name.len() > 240 {
-        return Err(HelixError::validation_error(
-            "file_name",
-            "must be at most 240 characters",
-        ));
+
+    pub(crate) fn scheduled_source_candidates(
+        &self,
+    ) -> Vec<(SourceDefinition, Option<SourceCollectionRun>)> {
+        self.sources
+            .values()
+            .map(|source| (source.clone(), self.source_runs.get(&source.id).cloned()))
+            .collect()
     }
-    if request.observed_at.trim().is_empty() {
-        return Err(HelixError::validation_error(
-            "observed_at",
-            "observed_at is required",
-        ));
+
+    pub(crate) fn source_scheduler_paused(&self) -> bool {
+        self.source_scheduler_paused
     }
-    let content = request.content.trim();
-    if content.is_empty() {
-        return Err(HelixError::validation_error(
-            "content",
-            "content is required",
-        ));
+
+    fn admit_webhook_delivery(
+        &mut self,
+        source_id: &str,
+        config: &WebhookSignatureConfig,
+        delivery: &VerifiedDelivery,
+        now: i64,
+    ) -> bool {
+        let guard = self
+            .source_run_mut(source_id)
+            .webhook_replay
+            .get_or_insert_with(|| WebhookReplayGuard::new(config.tolerance_seconds, now));
+        if guard.tolerance_seconds() != config.tolerance_seconds {
+            guard.retune(config.tolerance_seconds, now);
+        }
+        guard.admit(delivery, now)
     }
-    if content.len() > MAX_FILE_IMPORT_CONTENT_LEN {
-        return Err(HelixError::validation_error(
-            "content",
-            &format!("must be at most {MAX_FILE_IMPORT_CONTENT_LEN} bytes"),
-        ));
+
+    fn source_run_mut(&mut self, source_id: &str) -> &mut SourceCollectionRun {
+        self.source_runs
+            .entry(source_id.to_string())
+            .or_insert_with(|| SourceCollectionRun {
+                source_id: source_id.to_string(),
+                ..SourceCollectionRun::default()
+            })
     }
 
-    let title = first_non_empty([request.title.as_deref(), Some(file_name)]).unwrap_or(file_name);
-    let summary_fallback = summarize_text(content);
-    let summary = first_non_empty([request.summary.as_deref(), Some(summary_fallback.as_str())])
-        .unwrap_or(summary_fallback.as_str());
+    /// Steps the source breaker for one fetch attempt. Returns the stored cache validators
+    /// when the fetch may proceed, or `None` while the circuit is open.
+    fn admit_source_fetch(&mut self, source_id: &str) -> Option<SourceFetchValidators> {
+        let fetch = &mut self.source_run_mut(source_id).fetch;
+        fetch.breaker.step(BreakerInput::Tick);
+        match fetch.breaker.step(BreakerInput::Request) {
+            BreakerDecision::Allow => Some(SourceFetchValidators {
+                etag: fetch.etag.clone(),
+                last_modified: fetch.last_modified.clone(),
+            }),
+            BreakerDecision::DenyOpen | BreakerDecision::Noop => None,
+        }
+    }
 
-    Ok(IngestEvidenceRequest {
-        source_id: source.id.clone(),
-        title: truncate_text(title, 240),
-        summary: truncate_text(summary, 1_024),
-        content: content.to_string(),
-        url: request.url,
-        observed_at: request.observed_at.trim().to_string(),
-        tags: merge_source_tags(source, request.tags),
-        entity_labels: request.entity_labels,
-        proposed_claims: request.proposed_claims,
-    })
-}
+    fn record_source_fetch(
+        &mut self,
+        source_id: &str,
+        attempted_at: &str,
+        fetched: &Result<SourceFetchResponse, SourceFetchFailure>,
+    ) {
+        let fetch = &mut self.source_run_mut(source_id).fetch;
+        let (status, bytes) = match fetched {
+            Ok(response) => (Some(response.status), response.bytes),
+            Err(failure) => (failure.status, failure.bytes),
+        };
+        fetch.last_status = status;
+        fetch.last_bytes_fetched = bytes;
+        fetch.bytes_fetched_total = fetch.bytes_fetched_total.saturating_add(bytes);
+        match fetched {
+            Ok(_) => {
+                fetch.last_success_at = Some(attempted_at.trim().to_string());
+                fetch.last_error = None;
+                fetch.consecutive_failures = 0;
+                fetch.breaker.step(BreakerInput::Success);
+            }
+            Err(failure) => {
+                fetch.last_failure_at = Some(attempted_at.trim().to_string());
+                fetch.last_error = Some(truncate_text(&failure.error.to_string(), 512));
+                fetch.consecutive_failures = fetch.consecutive_failures.saturating_add(1);
+                fetch.breaker.step(BreakerInput::Failure);
+            }
+        }
+    }
 
-fn rss_collection_requests(
-    source: &SourceDefinition,
-    payload: &str,
-    fallback_observed_at: &str,
-) -> Vec<IngestEvidenceRequest> {
-    let endpoint_url = source.endpoint_url.clone();
-    extract_blocks(payload, "item")
-        .into_iter()
-        .filter_map(|item| {
-            let title = xml_tag_text(&item, "title")?;
-            let description = xml_tag_text(&item, "description")
-                .or_else(|| xml_tag_text(&item, "content:encoded"))
-                .unwrap_or_else(|| title.clone());
-            let content = strip_markup(&description);
-            let link = xml_tag_text(&item, "link").or_else(|| endpoint_url.clone());
-            let observed_at =
-                xml_tag_text(&item, "pubDate").unwrap_or_else(|| fallback_observed_at.to_string());
-            Some(IngestEvidenceRequest {
-                source_id: source.id.clone(),
-                title: truncate_text(&title, 240),
-                summary: summarize_text(&content),
-                content: truncate_text(&content, MAX_COLLECT_CONTENT_LEN),
-                url: link,
-                observed_at,
-                tags: merge_source_tags(source, vec!["rss".to_string()]),
-                entity_labels: Vec::new(),
-                proposed_claims: Vec::new(),
+    fn source_health(&self) -> BTreeMap<String, SourceHealth> {
+        self.sources
+            .values()
+            .filter(|source| source_supports_pull_collection(source))
+            .map(|source| {
+                let health = self
+                    .source_runs
+                    .get(&source.id)
+                    .map(|run| run.fetch.health())
+                    .unwrap_or_else(|| SourceFetchState::default().health());
+                (source.id.clone(), health)
             })
-        })
-        .collect()
-}
+            .collect()
+    }
 
-fn website_collection_requests(
-    source: &SourceDefinition,
-    payload: &str,
-    endpoint_url: &str,
-    fallback_observed_at: &str,
-) -> Vec<IngestEvidenceRequest> {
-    let title = html_title(payload).unwrap_or_else(|| source.name.clone());
-    let content = strip_markup(payload);
-    vec![IngestEvidenceRequest {
-        source_id: source.id.clone(),
-        title: truncate_text(&title, 240),
-        summary: summarize_text(&content),
-        content: truncate_text(&content, MAX_COLLECT_CONTENT_LEN),
-        url: Some(endpoint_url.to_string()),
-        observed_at: fallback_observed_at.trim().to_string(),
-        tags: merge_source_tags(source, vec!["website-diff".to_string()]),
-        entity_labels: Vec::new(),
-        proposed_claims: Vec::new(),
-    }]
-}
+    fn record_source_collection(
+        &mut self,
+        source_id: &str,
+        tick_minute: Option<u64>,
+        observed_at: &str,
+        collected_count: usize,
+    ) -> SourceCollectionRun {
+        let run = self.source_run_mut(source_id);
+        if tick_minute.is_some() {
+            run.last_tick_minute = tick_minute;
+        }
+        run.last_attempted_at = Some(observed_at.trim().to_string());
+        run.last_collected_at = Some(observed_at.trim().to_string());
+        run.last_error = None;
+        run.consecutive_failures = 0;
+        run.collected_total = run.collected_total.saturating_add(collected_count as u64);
+        run.clone()
+    }
 
-fn merge_source_tags(source: &SourceDefinition, tags: Vec<String>) -> Vec<String> {
-    let mut merged = source.tags.clone();
-    for tag in tags {
-        if !merged.iter().any(|existing| existing == &tag) {
-            merged.push(tag);
+    fn record_source_failure(
+        &mut self,
+        source_id: &str,
+        tick_minute: Option<u64>,
+        attempted_at: &str,
+        error: &str,
+    ) -> SourceCollectionRun {
+        let run = self.source_run_mut(source_id);
+        if tick_minute.is_some() {
+            run.last_tick_minute = tick_minute;
         }
+        run.last_attempted_at = Some(attempted_at.trim().to_string());
+        run.last_error = Some(truncate_text(error, 512));
+        run.consecutive_failures = run.consecutive_failures.saturating_add(1);
+        run.clone()
     }
-    merged
-}
 
-fn first_non_empty<const N: usize>(values: [Option<&str>; N]) -> Option<&str> {
-    values
-        .into_iter()
-        .flatten()
-        .map(str::trim)
-        .find(|value| !value.is_empty())
-}
+    fn ingest_pending_collection(
+        &mut self,
+        pending: PendingScheduledCollection,
+        tick_minute: Option<u64>,
+        observed_at: &str,
+    ) -> Result<ScheduledSourceCollection, HelixError> {
+        let mut requests = pending.requests;
+        let website_diff = pending.website_capture.map(|capture| {
+            let (request, snapshot, summary) = self.website_diff_request(
+                &pending.source,
+                &pending.fetched_url,
+                capture,
+                observed_at,
+            );
+            requests.extend(request);
+            (snapshot, summary)
+        });
+        let results = self.ingest_evidence_batch(requests)?;
+        let duplicate_count = results.iter().filter(|result| result.duplicate).count();
+        self.record_source_collection(&pending.source.id, tick_minute, observed_at, results.len());
+        let not_modified = pending.validators.is_none();
+        if let Some(validators) = pending.validators {
+            // Validators are kept only once the payload has been ingested, so a body that
+            // failed to parse is fetched in full again instead of answered with 304.
+            let fetch = &mut self.source_run_mut(&pending.source.id).fetch;
+            fetch.etag = validators.etag;
+            fetch.last_modified = validators.last_modified;
+        }
+        let website_diff = website_diff.map(|(snapshot, summary)| {
+            self.website_snapshots
+                .insert(snapshot.source_id.clone(), snapshot);
+            summary
+        });
+        Ok(ScheduledSourceCollection {
+            source: pending.source,
+            fetched_url: pending.fetched_url,
+            schedule_phase_minute: pending.schedule_phase_minute,
+            collected_count: results.len(),
+            duplicate_count,
+            results,
+            website_diff,
+            not_modified,
+        })
+    }
 
-fn extract_blocks(input: &str, tag: &str) -> Vec<String> {
-    let open = format!("<{tag}");
-    let close = format!("</{tag}>");
-    let mut blocks = Vec::new();
-    let mut rest = input;
-    while let Some(open_start) = rest.find(&open) {
-        let after_open = &rest[open_start..];
-        let Some(open_end) = after_open.find('>') else {
-            break;
+    fn website_diff_request(
+        &self,
+        source: &SourceDefinition,
+        fetched_url: &str,
+        capture: WebsiteCapture,
+        observed_at: &str,
+    ) -> (
+        Option<IngestEvidenceRequest>,
+        WebsiteSnapshot,
+        WebsiteDiffSummary,
+    ) {
+        let previous = self.website_snapshots.get(&source.id);
+        let diff = diff_snapshot_blocks(
+            previous
+                .map(|snapshot| snapshot.blocks.as_slice())
+                .unwrap_or_default(),
+            &capture.blocks,
+        );
+        let block_refs = capture
+            .blocks
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        let snapshot = WebsiteSnapshot {
+            source_id: source.id.clone(),
+            url: fetched_url.to_string(),
+            observed_at: observed_at.trim().to_string(),
+            title: truncate_text(
+                capture.title.as_deref().unwrap_or(source.name.as_str()),
+                240,
+            ),
+            content_hash: stable_hash(&block_refs),
+            blocks: capture.blocks,
         };
-        let body_start = open_start + open_end + 1;
-        let after_body_start = &rest[body_start..];
-        let Some(close_start) = after_body_start.find(&close) else {
-            break;
+        let summary = WebsiteDiffSummary {
+            baseline: previous.is_none(),
+            previous_observed_at: previous.map(|snapshot| snapshot.observed_at.clone()),
+            previous_content_hash: previous.map(|snapshot| snapshot.content_hash.clone()),
+            content_hash: snapshot.content_hash.clone(),
+            block_count: snapshot.blocks.len(),
+            added_blocks: diff.count(BlockChangeKind::Added),
+            changed_blocks: diff.count(BlockChangeKind::Changed),
+            removed_blocks: diff.count(BlockChangeKind::Removed),
+            unchanged_blocks: diff.unchanged_count,
+        };
+        if !diff.has_new_content() {
+            return (None, snapshot, summary);
+        }
+
+        let content = diff
+            .changes
+            .iter()
+            .filter_map(|change| {
+                let marker = match change.kind {
+                    BlockChangeKind::Added => "+",
+                    BlockChangeKind::Changed => "~",
+                    BlockChangeKind::Removed => return None,
+                };
+                change
+                    .current
+                    .as_deref()
+                    .map(|block| format!("{marker} {block}"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (title, change_tag) = if summary.baseline {
+            (snapshot.title.clone(), "website-baseline")
+        } else {
+            (format!("{} changed", snapshot.title), "website-change")
+        };
+        let request = IngestEvidenceRequest {
+            source_id: source.id.clone(),
+            title: truncate_text(&title, 240),
+            summary: website_diff_summary_text(&summary),
+            content: truncate_text(&content, MAX_COLLECT_CONTENT_LEN),
+            url: Some(fetched_url.to_string()),
+            observed_at: observed_at.trim().to_string(),
+            tags: merge_source_tags(
+                source,
+                vec!["website-diff".to_string(), change_tag.to_string()],
+            ),
+            entity_labels: Vec::new(),
+            proposed_claims: Vec::new(),
         };
-        blocks.push(after_body_start[..close_start].to_string());
-        rest = &after_body_start[close_start + close.len()..];
+        (Some(request), snapshot, summary)
     }
-    blocks
-}
 
-fn xml_tag_text(input: &str, tag: &str) -> Option<String> {
-    extract_blocks(input, tag)
-        .into_iter()
-        .next()
-        .map(|value| decode_xml_entities(strip_cdata(&value).trim()))
-        .filter(|value| !value.trim().is_empty())
-}
+    fn review_claim(
+        &mut self,
+        claim_id: &str,
+        status: ClaimReviewStatus,
+    ) -> Result<ClaimRecord, HelixError> {
+        if !self.claims.contains_key(claim_id) {
+            return Err(HelixError::not_found(format!("claim {}", claim_id)));
+        }
+        let severity = self.max_linked_severity(&self.claim_cases(claim_id));
+        if self.adjudication_config.requires_adjudication(severity) {
+            return Err(HelixError::validation_error(
+                "claim.review_status",
+                "claims on cases at this severity are reviewed by reviewer vote",
+            ));
+        }
+        let claim = self
+            .claims
+            .get_mut(claim_id)
+            .ok_or_else(|| HelixError::not_found(format!("claim {}", claim_id)))?;
+        claim.review_status = status;
+        Ok(claim.clone())
+    }
 
-fn html_title(input: &str) -> Option<String> {
-    extract_blocks(input, "title")
-        .into_iter()
-        .next()
-        .map(|value| decode_xml_entities(strip_cdata(&value).trim()))
-        .filter(|value| !value.trim().is_empty())
-}
+    fn set_adjudication_config(
+        &mut self,
+        config: ClaimAdjudicationConfig,
+    ) -> Result<ClaimAdjudicationConfig, HelixError> {
+        self.adjudication_config = canonicalize_adjudication_config(config)?;
+        Ok(self.adjudication_config)
+    }
 
-fn strip_cdata(value: &str) -> &str {
-    value
-        .strip_prefix("<![CDATA[")
-        .and_then(|value| value.strip_suffix("]]>"))
-        .unwrap_or(value)
-}
+    fn claim_adjudication(&self, claim_id: &str) -> Result<ClaimAdjudicationResponse, HelixError> {
+        let claim = self
+            .claims
+            .get(claim_id)
+            .cloned()
+            .ok_or_else(|| HelixError::not_found(format!("claim {claim_id}")))?;
+        let adjudication = self
+            .claim_adjudications
+            .get(claim_id)
+            .cloned()
+            .unwrap_or_else(|| ClaimAdjudication::new(claim_id, &self.adjudication_config));
+        Ok(ClaimAdjudicationResponse {
+            claim,
+            adjudication,
+            trust_adjustments: Vec::new(),
+        })
+    }
 
-fn strip_markup(input: &str) -> String {
-    let mut output = String::with_capacity(input.len());
-    let mut in_tag = false;
-    let mut previous_space = false;
-    for ch in input.chars() {
-        match ch {
-            '<' => in_tag = true,
-            '>' => {
-                in_tag = false;
-                if !previous_space {
-                    output.push(' ');
-                    previous_space = true;
-                }
-            }
-            _ if in_tag => {}
-            _ if ch.is_whitespace() => {
-                if !previous_space {
-                    output.push(' ');
-                    previous_space = true;
-                }
-            }
-            _ => {
-                output.push(ch);
-                previous_space = false;
-            }
+    /// Records a reviewer vote; the claim's review status changes only once a side
+    /// reaches quorum.
+    fn cast_claim_vote(
+        &mut self,
+        claim_id: &str,
+        request: ClaimVoteRequest,
+    ) -> Result<ClaimAdjudicationResponse, HelixError> {
+        let ClaimAdjudicationResponse {
+            mut claim,
+            mut adjudication,
+            ..
+        } = self.claim_adjudication(claim_id)?;
+        let cast_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        if adjudication.is_deadlocked() {
+            adjudication.reopen(&self.adjudication_config, "deadlocked", &cast_at);
+        }
+        adjudication.cast(ClaimVote {
+            reviewer: request.reviewer,
+            choice: request.choice,
+            rationale: request.rationale,
+            cast_at,
+        })?;
+        if let Some(status) = adjudication.review_status() {
+            claim.review_status = status;
+            self.claims.insert(claim.id.clone(), claim.clone());
         }
+        self.claim_adjudications
+            .insert(claim.id.clone(), adjudication.clone());
+        let trust_adjustments = if adjudication.review_status().is_some() {
+            self.auto_calibrate_claim_source(&claim)
+        } else {
+            Vec::new()
+        };
+        Ok(ClaimAdjudicationResponse {
+            claim,
+            adjudication,
+            trust_adjustments,
+        })
     }
-    decode_xml_entities(output.trim())
-}
 
-fn decode_xml_entities(input: &str) -> String {
-    input
-        .replace("&amp;", "&")
-        .replace("&lt;", "<")
-        .replace("&gt;", ">")
-        .replace("&quot;", "\"")
-        .replace("&#39;", "'")
-        .replace("&apos;", "'")
-}
+    fn set_trust_policy(
+        &mut self,
+        policy: TrustCalibrationPolicy,
+    ) -> Result<TrustCalibrationPolicy, HelixError> {
+        self.trust_policy = canonicalize_trust_calibration_policy(policy)?;
+        Ok(self.trust_policy)
+    }
 
-fn summarize_text(input: &str) -> String {
-    truncate_text(input, 280)
-}
+    /// Trust score priority scoring reads for `source`: the current score, or the score
+    /// in effect at `as_of` when reproducing an earlier ranking.
+    fn source_trust_score(&self, source: &SourceDefinition, as_of: Option<DateTime<Utc>>) -> u8 {
+        match as_of {
+            Some(at) => trust_score_at(
+                &self.source_trust_history(&source.id),
+                source.trust_score,
+                at,
+            ),
+            None => source.trust_score,
+        }
+    }
 
-fn truncate_text(input: &str, max_len: usize) -> String {
-    let trimmed = input.trim();
-    if trimmed.len() <= max_len {
-        return trimmed.to_string();
+    fn source_trust_history(&self, source_id: &str) -> Vec<TrustHistoryEntry> {
+        let mut entries = self
+            .trust_history
+            .values()
+            .filter(|entry| entry.source_id == source_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        entries.sort_by(|left, right| left.id.cmp(&right.id));
+        entries
     }
-    let mut end = 0;
-    for (idx, _) in trimmed.char_indices() {
-        if idx > max_len {
-            break;
+
+    fn source_trust_recommendation(
+        &self,
+        source: &SourceDefinition,
+        as_of: DateTime<Utc>,
+    ) -> Option<TrustRecommendation> {
+        let mut input = TrustCalibrationInput {
+            source_id: source.id.clone(),
+            trust_score: source.trust_score,
+            corroborated_claim_count: 0,
+            rejected_claim_count: 0,
+        };
+        for claim in self.claims.values() {
+            let from_source = self
+                .evidence
+                .get(&claim.evidence_id)
+                .is_some_and(|evidence| evidence.source_id == source.id);
+            match claim.review_status {
+                ClaimReviewStatus::Corroborated if from_source => {
+                    input.corroborated_claim_count += 1
+                }
+                ClaimReviewStatus::Rejected if from_source => input.rejected_claim_count += 1,
+                _ => {}
+            }
         }
-        end = idx;
+        calibrate_source(
+            &input,
+            &self.source_trust_history(&source.id),
+            &self.trust_policy,
+            as_of,
+        )
     }
-    trimmed[..end].trim().to_string()
-}
-
-fn stable_id(prefix: &str, parts: &[&str]) -> String {
-    format!("{}_{}", prefix, &stable_hash(parts)[..12])
-}
 
-fn stable_hash(parts: &[&str]) -> String {
-    let mut hasher = Sha256::new();
-    for part in parts {
-        hasher.update(part.as_bytes());
-        hasher.update([0x1f]);
+    fn trust_calibration(&self, as_of: DateTime<Utc>) -> TrustCalibrationResponse {
+        TrustCalibrationResponse {
+            as_of: as_of.to_rfc3339_opts(SecondsFormat::Secs, true),
+            policy: self.trust_policy,
+            recommendations: self
+                .sources
+                .values()
+                .filter_map(|source| self.source_trust_recommendation(source, as_of))
+                .collect(),
+        }
     }
-    format!("{:x}", hasher.finalize())
-}
 
-fn slugify(input: &str) -> String {
-    let mut slug = String::new();
-    let mut prev_dash = false;
-    for ch in input.trim().chars() {
-        let next = if ch.is_ascii_alphanumeric() {
-            ch.to_ascii_lowercase()
-        } else {
-            '-'
-        };
-        if next == '-' {
-            if prev_dash {
-                continue;
+    /// Applies the current recommendations for `source_ids`, or every source, and records
+    /// each change in the trust history.
+    fn apply_trust_recommendations(
+        &mut self,
+        source_ids: Option<&[String]>,
+        origin: TrustAdjustmentOrigin,
+    ) -> Result<Vec<TrustHistoryEntry>, HelixError> {
+        if let Some(source_ids) = source_ids {
+            if let Some(missing) = source_ids
+                .iter()
+                .find(|source_id| !self.sources.contains_key(source_id.trim()))
+            {
+                return Err(HelixError::not_found(format!("source {missing}")));
             }
-            prev_dash = true;
-        } else {
-            prev_dash = false;
         }
-        slug.push(next);
+        let now = Utc::now();
+        let recorded_at = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+        let recommendations = self
+            .trust_calibration(now)
+            .recommendations
+            .into_iter()
+            .filter(|recommendation| {
+                source_ids.is_none_or(|source_ids| {
+                    source_ids
+                        .iter()
+                        .any(|source_id| source_id.trim() == recommendation.source_id)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut applied = Vec::new();
+        for recommendation in recommendations {
+            let id = next_trust_adjustment_id(&self.trust_history);
+            let Some(source) = self.sources.get_mut(&recommendation.source_id) else {
+                continue;
+            };
+            source.trust_score = recommendation.proposed_trust_score;
+            let entry = TrustHistoryEntry {
+                id,
+                source_id: recommendation.source_id,
+                previous_trust_score: recommendation.current_trust_score,
+                trust_score: recommendation.proposed_trust_score,
+                origin,
+                posterior_bps: recommendation.posterior_bps,
+                corroborated_claim_count: recommendation.corroborated_claim_count,
+                rejected_claim_count: recommendation.rejected_claim_count,
+                recorded_at: recorded_at.clone(),
+            };
+            self.trust_history.insert(entry.id.clone(), entry.clone());
+            applied.push(entry);
+        }
+        Ok(applied)
     }
-    slug.trim_matches('-').to_string()
-}
 
-fn is_market_source(source: &SourceDefinition) -> bool {
-    source
-        .tags
-        .iter()
-        .any(|tag| tag == "market-intel" || tag == "competitor")
-}
+    /// Applies the recommendation for a reviewed claim's source when the policy allows it.
+    fn auto_calibrate_claim_source(&mut self, claim: &ClaimRecord) -> Vec<TrustHistoryEntry> {
+        if !self.trust_policy.auto_apply {
+            return Vec::new();
+        }
+        let Some(source_id) = self
+            .evidence
+            .get(&claim.evidence_id)
+            .map(|evidence| evidence.source_id.clone())
+        else {
+            return Vec::new();
+        };
+        self.apply_trust_recommendations(Some(&[source_id]), TrustAdjustmentOrigin::Auto)
+            .unwrap_or_default()
+    }
 
-fn is_market_watchlist(watchlist: &Watchlist) -> bool {
-    market_theme_id_for_watchlist(watchlist).is_some()
-}
+    fn trust_history_report(
+        &self,
+        source_id: &str,
+        query: &TrustHistoryQuery,
+    ) -> Result<TrustHistoryResponse, HelixError> {
+        let source = self
+            .sources
+            .get(source_id)
+            .ok_or_else(|| HelixError::not_found(format!("source {source_id}")))?;
+        let entries = self.source_trust_history(source_id);
+        let trust_score_at = match query.at.as_deref() {
+            Some(at) => {
+                let at = DateTime::parse_from_rfc3339(at.trim()).map_err(|_| {
+                    HelixError::validation_error(
+                        "trust_history.at",
+                        "must be an RFC 3339 timestamp",
+                    )
+                })?;
+                Some(trust_score_at(
+                    &entries,
+                    source.trust_score,
+                    at.with_timezone(&Utc),
+                ))
+            }
+            None => None,
+        };
+        Ok(TrustHistoryResponse {
+            source_id: source.id.clone(),
+            trust_score: source.trust_score,
+            entries,
+            trust_score_at,
+        })
+    }
 
-fn market_theme_descriptors() -> &'static [(&'static str, &'static str, &'static str)] {
-    &[
-        (
-            "pricing",
-            "Pricing Intelligence",
-            "Detect discounting, packaging changes, and monetization pressure across the market.",
-        ),
-        (
-            "product",
-            "Product Launch Radar",
-            "Track launches, betas, new SKUs, and roadmap shifts that change competitive positioning.",
-        ),
-        (
-            "partnerships",
-            "Ecosystem Mapping",
-            "Monitor partner, reseller, and integration announcements to see channel momentum.",
-        ),
-        (
-            "hiring",
-            "Expansion Signals",
-            "Use hiring bursts and role mix to infer GTM push, product investment, or geographic expansion.",
-        ),
-    ]
-}
+    fn materialize_claim(&self, evidence: &EvidenceItem, proposed: ProposedClaim) -> ClaimRecord {
+        ClaimRecord {
+            id: stable_id(
+                "claim",
+                &[
+                    &evidence.id,
+                    &proposed.subject,
+                    &proposed.predicate,
+                    &proposed.object,
+                ],
+            ),
+            evidence_id: evidence.id.clone(),
+            subject: proposed.subject,
+            predicate: proposed.predicate,
+            object: proposed.object,
+            confidence_bps: proposed.confidence_bps,
+            review_status: ClaimReviewStatus::NeedsReview,
+            rationale: proposed
+                .rationale
+                .unwrap_or_else(|| "operator_provided_or_deterministically_derived".to_string()),
+        }
+    }
 
-fn market_theme_id_for_watchlist(watchlist: &Watchlist) -> Option<&'static str> {
-    let keywords = watchlist.keywords.join(" ");
-    let id = watchlist.id.as_str();
-    if id.contains("pricing")
-        || ["pricing", "discount", "bundle", "seat"]
-            .iter()
-            .any(|needle| keywords.contains(needle))
-    {
-        Some("pricing")
-    } else if id.contains("launch")
-        || ["launch", "release", "beta", "ga"]
-            .iter()
-            .any(|needle| keywords.contains(needle))
-    {
-        Some("product")
-    } else if id.contains("partnership")
-        || ["partnership", "integration", "reseller", "ecosystem"]
-            .iter()
-            .any(|needle| keywords.contains(needle))
-    {
-        Some("partnerships")
-    } else if id.contains("hiring")
-        || ["hiring", "headcount", "recruiting", "open role"]
-            .iter()
-            .any(|needle| keywords.contains(needle))
-    {
-        Some("hiring")
-    } else {
-        None
+    fn claims_for_evidence(&self, evidence_id: &str) -> Vec<ClaimRecord> {
+        self.claims
+            .values()
+            .filter(|claim| claim.evidence_id == evidence_id)
+            .cloned()
+            .collect()
     }
-}
 
-fn market_theme_name(theme_id: &'static str) -> &'static str {
-    market_theme_descriptors()
-        .iter()
-        .find_map(|(id, name, _)| (*id == theme_id).then_some(*name))
-        .unwrap_or("Unclassified")
-}
+    /// Rewrites operator labels and extracted spans to canonical entity ids. Extracted
+    /// spans whose label is unregistered fall back to resolving their source text.
+    fn resolve_evidence_entities(&self, evidence: &mut EvidenceItem) {
+        evidence.entity_labels = self
+            .entity_registry
+            .canonical_labels(std::mem::take(&mut evidence.entity_labels));
+        for extracted in &mut evidence.extracted_entities {
+            if let Some(entity) = self
+                .entity_registry
+                .resolve(&extracted.label)
+                .or_else(|| self.entity_registry.resolve(&extracted.text))
+            {
+                extracted.label = entity.id.clone();
+            }
+        }
+    }
 
-fn market_intelligence_playbooks() -> Vec<MarketIntelPlaybook> {
-    vec![
-        MarketIntelPlaybook {
-            id: "competitor_pricing_watch".to_string(),
-            name: "Competitor Pricing Watch".to_string(),
-            objective: "Track packaging, discounting, and seat-level monetization shifts before renewal pressure lands."
-                .to_string(),
-            signals: vec![
-                "pricing page diffs".to_string(),
-                "discount language".to_string(),
-                "seat or bundle changes".to_string(),
-            ],
-        },
-        MarketIntelPlaybook {
-            id: "launch_radar".to_string(),
-            name: "Launch Radar".to_string(),
-            objective: "Capture launch, beta, and release signals that change category positioning or feature parity."
-                .to_string(),
-            signals: vec![
-                "release notes".to_string(),
-                "landing page diffs".to_string(),
-                "SKU announcements".to_string(),
-            ],
-        },
-        MarketIntelPlaybook {
-            id: "partner_ecosystem_map".to_string(),
-            name: "Partner Ecosystem Map".to_string(),
-            objective: "Measure channel strength through reseller, integration, and alliance announcements."
-                .to_string(),
-            signals: vec![
-                "integration posts".to_string(),
-                "reseller pages".to_string(),
-                "ecosystem announcements".to_string(),
-            ],
-        },
-        MarketIntelPlaybook {
-            id: "gtm_expansion_signals".to_string(),
-            name: "GTM Expansion Signals".to_string(),
-            objective: "Infer territory, segment, and product-line investment from hiring velocity and role mix."
-                .to_string(),
-            signals: vec![
-                "sales hiring".to_string(),
-                "channel roles".to_string(),
-                "regional expansion postings".to_string(),
-            ],
-        },
-    ]
-}
+    /// Resolves watchlist entities and alias keys to canonical ids, then validates.
+    fn canonical_watchlist(&self, watchlist: Watchlist) -> Result<Watchlist, HelixError> {
+        let mut aliases = BTreeMap::<String, Vec<String>>::new();
+        for (entity, values) in watchlist.aliases {
+            aliases
+                .entry(self.entity_registry.canonical_label(&entity))
+                .or_default()
+                .extend(values);
+        }
+        canonicalize_watchlist(Watchlist {
+            entities: watchlist
+                .entities
+                .iter()
+                .map(|entity| self.entity_registry.canonical_label(entity))
+                .collect(),
+            aliases,
+            ..watchlist
+        })
+    }
 
-fn summarize_key_claims(claims: &[ClaimRecord]) -> Vec<String> {
-    let mut ranked = claims.to_vec();
-    ranked.sort_by(|left, right| {
-        right
-            .confidence_bps
-            .cmp(&left.confidence_bps)
-            .then(left.subject.cmp(&right.subject))
-            .then(left.predicate.cmp(&right.predicate))
-            .then(left.object.cmp(&right.object))
-    });
-    ranked
-        .into_iter()
-        .take(3)
-        .map(|claim| format!("{} {} {}", claim.subject, claim.predicate, claim.object))
-        .collect()
-}
+    fn upsert_entity(&mut self, request: EntityRequest) -> Result<RegistryEntity, HelixError> {
+        let entity = self.entity_registry.upsert(RegistryEntity::new(
+            &request.name,
+            request.kind,
+            request.aliases,
+        )?)?;
+        self.relabel_entity_aliases(&entity)?;
+        Ok(entity)
+    }
 
-fn claim_review_metrics(claims: &[ClaimRecord]) -> (usize, usize, u16) {
-    let corroborated = claims
-        .iter()
-        .filter(|claim| claim.review_status == ClaimReviewStatus::Corroborated)
-        .count();
-    let rejected = claims
-        .iter()
-        .filter(|claim| claim.review_status == ClaimReviewStatus::Rejected)
-        .count();
-    let max_confidence_bps = claims
-        .iter()
-        .map(|claim| claim.confidence_bps)
-        .max()
-        .unwrap_or(0);
-    (corroborated, rejected, max_confidence_bps)
-}
+    fn merge_entity(
+        &mut self,
+        target_id: &str,
+        source_id: &str,
+    ) -> Result<RegistryEntity, HelixError> {
+        let entity = self.entity_registry.merge(target_id, source_id)?;
+        self.relabel_entity_aliases(&entity)?;
+        Ok(entity)
+    }
 
-fn review_kind_label(kind: AutopilotReviewKind) -> &'static str {
-    match kind {
-        AutopilotReviewKind::Case => "case",
-        AutopilotReviewKind::Claim => "claim",
-        AutopilotReviewKind::Evidence => "evidence",
+    /// Splits aliases into a new entity. Stored labels keep the original id, except
+    /// extracted spans whose text now resolves to the new entity.
+    fn split_entity(
+        &mut self,
+        entity_id: &str,
+        request: EntityRequest,
+    ) -> Result<EntitySplitResponse, HelixError> {
+        let (original, split) = self.entity_registry.split(
+            entity_id,
+            RegistryEntity::new(&request.name, request.kind, request.aliases)?,
+        )?;
+        for evidence in self.evidence.values_mut() {
+            for extracted in &mut evidence.extracted_entities {
+                if extracted.label == original.id
+                    && self
+                        .entity_registry
+                        .resolve(&extracted.text)
+                        .is_some_and(|entity| entity.id == split.id)
+                {
+                    extracted.label = split.id.clone();
+                }
+            }
+        }
+        Ok(EntitySplitResponse {
+            entity: original,
+            split,
+        })
     }
-}
 
-fn autopilot_review_kind_rank(kind: AutopilotReviewKind) -> u8 {
-    match kind {
-        AutopilotReviewKind::Case => 0,
-        AutopilotReviewKind::Claim => 1,
-        AutopilotReviewKind::Evidence => 2,
+    /// Rewrites stored labels that are now aliases of `entity` to its canonical id, so
+    /// records ingested before a registration or merge group with later ones.
+    /// Rewrites stored labels that are aliases of `entity` to its id. Claims keep their
+    /// ids, which were derived from the labels at extraction time, so case claim lists,
+    /// adjudications, and links to them stay valid.
+    fn relabel_entity_aliases(&mut self, entity: &RegistryEntity) -> Result<(), HelixError> {
+        let canonical = |label: &str| {
+            if entity.aliases.iter().any(|alias| alias == label) {
+                entity.id.clone()
+            } else {
+                label.to_string()
+            }
+        };
+        for evidence in self.evidence.values_mut() {
+            let mut labels = evidence
+                .entity_labels
+                .iter()
+                .map(|label| canonical(label))
+                .collect::<Vec<_>>();
+            labels.sort();
+            labels.dedup();
+            evidence.entity_labels = labels;
+            for extracted in &mut evidence.extracted_entities {
+                extracted.label = canonical(&extracted.label);
+            }
+        }
+        for claim in self.claims.values_mut() {
+            claim.subject = canonical(&claim.subject);
+            claim.object = canonical(&claim.object);
+        }
+        for watchlist in self.watchlists.values_mut() {
+            let mut entities = watchlist
+                .entities
+                .iter()
+                .map(|label| canonical(label))
+                .collect::<Vec<_>>();
+            entities.sort();
+            entities.dedup();
+            watchlist.entities = entities;
+            let mut aliases = BTreeMap::<String, Vec<String>>::new();
+            for (label, values) in std::mem::take(&mut watchlist.aliases) {
+                let values_for = aliases.entry(canonical(&label)).or_default();
+                values_for.extend(values);
+                values_for.sort();
+                values_for.dedup();
+            }
+            watchlist.aliases = aliases;
+        }
+        let relabeled = self
+            .cases
+            .values()
+            .filter_map(|case| {
+                let current = case.primary_entity.as_deref()?;
+                let label = canonical(current);
+                (label != current).then(|| (case.id.clone(), label))
+            })
+            .collect::<Vec<_>>();
+        for (case_id, primary_entity) in relabeled {
+            self.apply_case_command(
+                &case_id,
+                CaseCommand::RelabelPrimaryEntity { primary_entity },
+                "entity_registry",
+            )?;
+        }
+        Ok(())
     }
-}
 
-fn case_goal_hint(case: &CaseFile, watchlist_name: &str) -> String {
-    let entity = case
-        .primary_entity
-        .as_deref()
-        .unwrap_or("unassigned entity");
-    let status = match case.status {
-        CaseStatus::Open => "open",
-        CaseStatus::Monitoring => "monitoring",
-        CaseStatus::BriefReady => "brief_ready",
-        CaseStatus::Escalated => "escalated",
-        CaseStatus::Closed => "closed",
-    };
-    format!(
-        "Review the {} case '{}' for {} on watchlist '{}'. Validate the linked evidence, decide whether to keep monitoring, escalate, or attach a brief, and keep the proposal bounded to deterministic follow-up steps.",
-        status, case.title, entity, watchlist_name
-    )
-}
+    /// Opens or updates a case per hit. If any hit fails, every case it touched is put
+    /// back as it was.
+    fn apply_watchlist_hits(
+        &mut self,
+        evidence: &EvidenceItem,
+        claims: &[ClaimRecord],
+        hits: &[WatchlistHit],
+    ) -> Result<Vec<CaseTransition>, HelixError> {
+        let mut touched = BTreeMap::new();
+        let result = self.apply_watchlist_hits_to_cases(evidence, claims, hits, &mut touched);
+        if result.is_err() {
+            for (case_id, snapshot) in touched {
+                self.restore_case(&case_id, snapshot);
+            }
+        }
+        result
+    }
 
-fn claim_goal_hint(claim: &ClaimRecord, evidence_title: &str) -> String {
-    format!(
-        "Review the claim '{} {} {}' from evidence '{}'. Propose only deterministic next steps for corroboration, rejection, or case linkage with cited evidence.",
-        claim.subject, claim.predicate, claim.object, evidence_title
-    )
-}
+    fn apply_watchlist_hits_to_cases(
+        &mut self,
+        evidence: &EvidenceItem,
+        claims: &[ClaimRecord],
+        hits: &[WatchlistHit],
+        touched: &mut BTreeMap<String, CaseSnapshot>,
+    ) -> Result<Vec<CaseTransition>, HelixError> {
+        let mut transitions = Vec::new();
 
-fn evidence_goal_hint(evidence: &EvidenceItem, linked_case_count: usize) -> String {
-    let entity = evidence
-        .entity_labels
-        .first()
-        .map(String::as_str)
-        .unwrap_or("tracked entity");
-    format!(
-        "Triage evidence '{}' observed at {} for {}. Linked cases: {}. Propose a bounded deterministic follow-up such as claim review, case escalation, watchlist refinement, or briefing preparation.",
-        evidence.title, evidence.observed_at, entity, linked_case_count
-    )
+        for hit in hits {
+            let primary_entity = hit
+                .matched_entities
+                .first()
+                .cloned()
+                .or_else(|| evidence.entity_labels.first().cloned());
+            let claim_ids = claims
+                .iter()
+                .map(|claim| claim.id.clone())
+                .collect::<Vec<_>>();
+
+            let (case_id, exists) =
+                self.watchlist_hit_case(&hit.watchlist_id, primary_entity.as_deref(), &evidence.id);
+
+            if !touched.contains_key(&case_id) {
+                touched.insert(case_id.clone(), self.case_snapshot(&case_id));
+            }
+            let actor = format!("watchlist:{}", hit.watchlist_id);
+            let command = if exists {
+                CaseCommand::AppendEvidence {
+                    evidence_id: evidence.id.clone(),
+                    claim_ids: claim_ids.clone(),
+                    reason: hit.reason.clone(),
+                }
+            } else {
+                CaseCommand::Open {
+                    case_id: case_id.clone(),
+                    title: build_case_title(hit, evidence, primary_entity.as_deref()),
+                    watchlist_id: hit.watchlist_id.clone(),
+                    primary_entity,
+                    evidence_id: evidence.id.clone(),
+                    claim_ids: claim_ids.clone(),
+                    reason: hit.reason.clone(),
+                }
+            };
+            let transition = self.apply_case_command(&case_id, command, &actor)?;
+            let transition = self.append_window_evidence(transition, hit, &actor)?;
+            let transition = self.maybe_escalate_case(transition, hit, &actor)?;
+            transitions.push(transition);
+        }
+
+        transitions.sort_by(|left, right| left.case.id.cmp(&right.case.id));
+        Ok(transitions)
+    }
+
+    fn case_snapshot(&self, case_id: &str) -> CaseSnapshot {
+        CaseSnapshot {
+            case: self.cases.get(case_id).cloned(),
+            timeline: self.case_timelines.get(case_id).cloned(),
+            sla: self.case_slas.get(case_id).cloned(),
+        }
+    }
+
+    fn restore_case(&mut self, case_id: &str, snapshot: CaseSnapshot) {
+        fn put<T>(records: &mut BTreeMap<String, T>, case_id: &str, record: Option<T>) {
+            match record {
+                Some(record) => records.insert(case_id.to_string(), record),
+                None => records.remove(case_id),
+            };
+        }
+        put(&mut self.cases, case_id, snapshot.case);
+        put(&mut self.case_timelines, case_id, snapshot.timeline);
+        put(&mut self.case_slas, case_id, snapshot.sla);
+    }
+
+    /// Case a watchlist hit lands on: the live case for the watchlist and entity, the
+    /// live case a merge tombstone at the stable id redirects to, or a new case. The
+    /// flag is true when the case already exists.
+    fn watchlist_hit_case(
+        &self,
+        watchlist_id: &str,
+        primary_entity: Option<&str>,
+        evidence_id: &str,
+    ) -> (String, bool) {
+        if let Some(case) = self.cases.values().find(|case| {
+            case.status.is_live()
+                && case.watchlist_id == watchlist_id
+                && case.primary_entity.as_deref() == primary_entity
+        }) {
+            return (case.id.clone(), true);
+        }
+        let anchor = primary_entity.unwrap_or(evidence_id);
+        let case_id = stable_id("case", &[watchlist_id, anchor]);
+        let Some(mut case) = self.cases.get(&case_id) else {
+            return (case_id, false);
+        };
+        // Merge targets are live when merged into, so redirects cannot loop.
+        while let (CaseStatus::Merged, Some(into)) = (case.status, case.merged_into.as_ref()) {
+            match self.cases.get(into) {
+                Some(next) => case = next,
+                None => break,
+            }
+        }
+        if case.status.is_live() {
+            (case.id.clone(), true)
+        } else {
+            // The stable id belongs to a closed case, so the hit opens a fresh one.
+            (
+                stable_id("case", &[watchlist_id, anchor, evidence_id]),
+                false,
+            )
+        }
+    }
+
+    /// Adds the earlier evidence behind an aggregate hit so the case shows the whole
+    /// window, keeping the decision of the transition that opened or updated it.
+    fn append_window_evidence(
+        &mut self,
+        transition: CaseTransition,
+        hit: &WatchlistHit,
+        actor: &str,
+    ) -> Result<CaseTransition, HelixError> {
+        let Some(aggregate) = &hit.aggregate else {
+            return Ok(transition);
+        };
+        if matches!(transition.decision, CaseDecision::Denied { .. }) {
+            return Ok(transition);
+        }
+
+        let mut case = transition.case;
+        for evidence_id in &aggregate.evidence_ids {
+            if case.evidence_ids.contains(evidence_id) || !self.evidence.contains_key(evidence_id) {
+                continue;
+            }
+            let claim_ids = self
+                .claims_for_evidence(evidence_id)
+                .into_iter()
+                .map(|claim| claim.id)
+                .collect();
+            case = self
+                .apply_case_command(
+                    &case.id,
+                    CaseCommand::AppendEvidence {
+                        evidence_id: evidence_id.clone(),
+                        claim_ids,
+                        reason: hit.reason.clone(),
+                    },
+                    actor,
+                )?
+                .case;
+        }
+        Ok(CaseTransition {
+            case,
+            decision: transition.decision,
+        })
+    }
+
+    /// Escalates the case for high-severity hits. A denied escalation is logged but
+    /// keeps the transition that opened or updated the case.
+    fn maybe_escalate_case(
+        &mut self,
+        transition: CaseTransition,
+        hit: &WatchlistHit,
+        actor: &str,
+    ) -> Result<CaseTransition, HelixError> {
+        if hit.severity.weight() < WatchlistSeverity::High.weight()
+            || matches!(transition.decision, CaseDecision::Denied { .. })
+        {
+            return Ok(transition);
+        }
+        let escalated = self.apply_case_command(
+            &transition.case.id,
+            CaseCommand::Escalate {
+                reason: format!("{}:{}", hit.watchlist_name, hit.reason),
+            },
+            actor,
+        )?;
+        if matches!(escalated.decision, CaseDecision::Denied { .. }) {
+            Ok(transition)
+        } else {
+            Ok(escalated)
+        }
+    }
+}
+
+fn parse_backtest_bound(
+    context: &str,
+    value: Option<String>,
+) -> Result<Option<DateTime<chrono::FixedOffset>>, HelixError> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value.trim())
+                .map_err(|_| HelixError::validation_error(context, "must be an RFC 3339 timestamp"))
+        })
+        .transpose()
+}
+
+fn build_case_title(
+    hit: &WatchlistHit,
+    evidence: &EvidenceItem,
+    primary_entity: Option<&str>,
+) -> String {
+    match primary_entity {
+        Some(entity) => format!("{}: {}", hit.watchlist_name, entity),
+        None => format!("{}: {}", hit.watchlist_name, evidence.title),
+    }
+}
+
+fn provenance_hash(request: &IngestEvidenceRequest) -> String {
+    stable_hash(&[
+        &request.source_id,
+        &request.title,
+        &request.summary,
+        &request.content,
+        &request.observed_at,
+        request.url.as_deref().unwrap_or(""),
+    ])
+}
+
+async fn fetch_source_body(
+    endpoint_url: &str,
+    auth: Option<&SourceFetchAuth>,
+    validators: &SourceFetchValidators,
+) -> Result<SourceFetchResponse, SourceFetchFailure> {
+    let client = reqwest::Client::new();
+    let mut request = client.get(endpoint_url);
+    if let Some(auth) = auth {
+        request = request.header(auth.header_name.clone(), auth.header_value.clone());
+    }
+    if let Some(etag) = validators.etag.as_deref() {
+        request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = validators.last_modified.as_deref() {
+        request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+    }
+
+    let failure = |status: Option<u16>, bytes: u64, error: HelixError| SourceFetchFailure {
+        status,
+        bytes,
+        error,
+    };
+    let response = request
+        .send()
+        .await
+        .map_err(|error| failure(None, 0, source_fetch_error(error.to_string())))?;
+    let status = response.status();
+    let header_text = |name: reqwest::header::HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| truncate_text(value, 256))
+    };
+    let response_validators = SourceFetchValidators {
+        etag: header_text(reqwest::header::ETAG),
+        last_modified: header_text(reqwest::header::LAST_MODIFIED),
+    };
+    if status == reqwest::StatusCode::NOT_MODIFIED {
+        return Ok(SourceFetchResponse {
+            status: status.as_u16(),
+            bytes: 0,
+            validators: response_validators,
+            body: None,
+        });
+    }
+    if !status.is_success() {
+        return Err(failure(
+            Some(status.as_u16()),
+            0,
+            source_fetch_error(format!("source endpoint returned HTTP {status}")),
+        ));
+    }
+
+    let bytes = response.bytes().await.map_err(|error| {
+        failure(
+            Some(status.as_u16()),
+            0,
+            source_fetch_error(error.to_string()),
+        )
+    })?;
+    let byte_count = bytes.len() as u64;
+    if bytes.len() > MAX_SOURCE_FETCH_BYTES {
+        return Err(failure(
+            Some(status.as_u16()),
+            byte_count,
+            HelixError::validation_error("source.payload", "source payload exceeds 1 MiB"),
+        ));
+    }
+    let body = String::from_utf8(bytes.to_vec()).map_err(|_| {
+        failure(
+            Some(status.as_u16()),
+            byte_count,
+            HelixError::validation_error("source.payload", "source payload must be UTF-8 text"),
+        )
+    })?;
+    Ok(SourceFetchResponse {
+        status: status.as_u16(),
+        bytes: byte_count,
+        validators: response_validators,
+        body: Some(body),
+    })
+}
+
+fn source_fetch_error(details: String) -> HelixError {
+    HelixError::external_service_error("source_fetch".to_string(), details)
+}
+
+async fn source_fetch_auth(
+    state: &AppState,
+    source: &SourceDefinition,
+) -> Result<Option<SourceFetchAuth>, HelixError> {
+    let Some(secret) = source_credential_secret(state, source).await? else {
+        return Ok(None);
+    };
+    let header_name =
+        HeaderName::from_bytes(source.credential_header_name.as_bytes()).map_err(|_| {
+            HelixError::validation_error(
+                "source.credential_header_name",
+                "must be a valid HTTP header name",
+            )
+        })?;
+    let header_value =
+        source_credential_header_value(source.credential_header_prefix.as_deref(), &secret)?;
+
+    Ok(Some(SourceFetchAuth {
+        header_name,
+        header_value,
+    }))
+}
+
+/// Resolves and decrypts the vaulted credential a source references, if any.
+async fn source_credential_secret(
+    state: &AppState,
+    source: &SourceDefinition,
+) -> Result<Option<String>, HelixError> {
+    let Some(credential_id) = source.credential_id.as_deref() else {
+        return Ok(None);
+    };
+    let profile_id: ProfileId = source
+        .profile_id
+        .parse()
+        .map_err(|_| HelixError::validation_error("source.profile_id", "must be a valid UUID"))?;
+    let credential_id_uuid: CredentialId = credential_id.parse().map_err(|_| {
+        HelixError::validation_error("source.credential_id", "must be a valid UUID")
+    })?;
+    let Some(persistence) = state.state_persistence.as_ref() else {
+        return Err(HelixError::config_error(
+            "source credential collection requires DATABASE_URL",
+        ));
+    };
+    let Some(encrypted_data) = persistence
+        .encrypted_credential_data(&profile_id, &credential_id_uuid)
+        .await?
+    else {
+        return Err(HelixError::not_found(format!(
+            "credential {credential_id} for source {}",
+            source.id
+        )));
+    };
+    let encrypter = credential_encrypter_from_env()?;
+    encrypter
+        .decrypt(&encrypted_data)
+        .await
+        .map(Some)
+        .map_err(|error| HelixError::encryption_error(error.to_string()))
+}
+
+fn source_credential_header_value(
+    prefix: Option<&str>,
+    secret: &str,
+) -> Result<HeaderValue, HelixError> {
+    if secret.trim().is_empty() {
+        return Err(HelixError::validation_error(
+            "credential.secret",
+            "decrypted credential must not be empty",
+        ));
+    }
+    let value = match prefix.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(prefix) => format!("{prefix} {secret}"),
+        None => secret.to_string(),
+    };
+    HeaderValue::from_str(&value).map_err(|_| {
+        HelixError::validation_error(
+            "credential.secret",
+            "decrypted credential cannot be represented as an HTTP header value",
+        )
+    })
+}
+
+fn collect_requests_from_payload(
+    source: &SourceDefinition,
+    payload: &str,
+    fallback_observed_at: &str,
+    max_items: usize,
+) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
+    if fallback_observed_at.trim().is_empty() {
+        return Err(HelixError::validation_error(
+            "observed_at",
+            "fallback observed_at is required",
+        ));
+    }
+    if source.endpoint_url.is_none() {
+        return Err(HelixError::validation_error(
+            "source.endpoint_url",
+            "source has no endpoint_url",
+        ));
+    }
+
+    let mut requests = match source.kind {
+        SourceKind::JsonApi => json_collection_requests(source, payload, fallback_observed_at)?,
+        SourceKind::RssFeed => feed_collection_requests(source, payload, fallback_observed_at)?,
+        SourceKind::WebsiteDiff => {
+            return Err(HelixError::validation_error(
+                "source.kind",
+                "website_diff sources collect through snapshot diffs",
+            ));
+        }
+        SourceKind::WebhookIngest | SourceKind::EmailDigest | SourceKind::FileImport => {
+            return Err(HelixError::validation_error(
+                "source.kind",
+                "source kind does not support pull collection",
+            ));
+        }
+    };
+    requests.truncate(max_items);
+    Ok(requests)
+}
+
+pub(crate) fn source_supports_pull_collection(source: &SourceDefinition) -> bool {
+    matches!(
+        source.kind,
+        SourceKind::JsonApi | SourceKind::RssFeed | SourceKind::WebsiteDiff
+    )
+}
+
+pub(crate) fn source_schedule_phase_minute(source: &SourceDefinition) -> u16 {
+    let cadence = source.cadence_minutes.max(1);
+    let mut hasher = Sha256::new();
+    hasher.update(source.id.as_bytes());
+    hasher.update(source.profile_id.as_bytes());
+    hasher.update(source.kind_string().as_bytes());
+    hasher.update(cadence.to_be_bytes());
+    let digest = hasher.finalize();
+    let value = u64::from_be_bytes(
+        digest[..8]
+            .try_into()
+            .expect("sha256 digest has at least eight bytes"),
+    );
+    (value % u64::from(cadence)) as u16
+}
+
+pub(crate) fn source_is_due_at_tick(source: &SourceDefinition, tick_minute: u64) -> bool {
+    let cadence = u64::from(source.cadence_minutes.max(1));
+    tick_minute % cadence == u64::from(source_schedule_phase_minute(source))
+}
+
+pub(crate) fn source_next_due_minute(source: &SourceDefinition, from_minute: u64) -> u64 {
+    let cadence = u64::from(source.cadence_minutes.max(1));
+    let phase = u64::from(source_schedule_phase_minute(source));
+    from_minute + (phase + cadence - from_minute % cadence) % cadence
+}
+
+pub(crate) fn source_is_due_between(
+    source: &SourceDefinition,
+    after_minute: u64,
+    tick_minute: u64,
+) -> bool {
+    tick_minute > after_minute && source_next_due_minute(source, after_minute + 1) <= tick_minute
+}
+
+trait SourceKindName {
+    fn kind_string(&self) -> &'static str;
+}
+
+impl SourceKindName for SourceDefinition {
+    fn kind_string(&self) -> &'static str {
+        match self.kind {
+            SourceKind::RssFeed => "rss_feed",
+            SourceKind::WebsiteDiff => "website_diff",
+            SourceKind::JsonApi => "json_api",
+            SourceKind::WebhookIngest => "webhook_ingest",
+            SourceKind::EmailDigest => "email_digest",
+            SourceKind::FileImport => "file_import",
+        }
+    }
+}
+
+pub(crate) fn normalize_collect_limit(limit: Option<usize>) -> Result<usize, HelixError> {
+    let limit = limit.unwrap_or(10);
+    if limit == 0 || limit > MAX_COLLECT_ITEMS {
+        return Err(HelixError::validation_error(
+            "max_items",
+            &format!("must be between 1 and {MAX_COLLECT_ITEMS}"),
+        ));
+    }
+    Ok(limit)
+}
+
+fn json_collection_requests(
+    source: &SourceDefinition,
+    payload: &str,
+    fallback_observed_at: &str,
+) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
+    if let Some(mapping) = &source.json_api {
+        let payload: serde_json::Value = serde_json::from_str(payload).map_err(HelixError::from)?;
+        return select_json_items(mapping, &payload)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let mapped = map_json_item(mapping, item).map_err(|error| match error {
+                    HelixError::ValidationError { context, message } => {
+                        HelixError::validation_error(context, format!("item {index}: {message}"))
+                    }
+                    other => other,
+                })?;
+                collected_item_to_request(
+                    source,
+                    mapped_json_payload(normalize_mapped_json_item(mapped)),
+                    fallback_observed_at,
+                )
+            })
+            .collect();
+    }
+
+    let payload: JsonCollectionPayload = serde_json::from_str(payload).map_err(HelixError::from)?;
+    let items = match payload {
+        JsonCollectionPayload::Envelope { items } | JsonCollectionPayload::Array(items) => items,
+        JsonCollectionPayload::Single(item) => vec![item],
+    };
+    items
+        .into_iter()
+        .map(|item| collected_item_to_request(source, item, fallback_observed_at))
+        .collect()
+}
+
+fn mapped_json_payload(item: MappedJsonItem) -> CollectedEvidencePayload {
+    CollectedEvidencePayload {
+        title: item.title,
+        summary: item.summary,
+        content: item.content,
+        url: item.url,
+        observed_at: item.observed_at,
+        tags: item.tags,
+        entity_labels: item.entity_labels,
+        proposed_claims: item.proposed_claims,
+    }
+}
+
+/// Canonicalizes extracted timestamps, including Unix epoch seconds or milliseconds, so
+/// previews show the same `observed_at` that collection stores.
+fn normalize_mapped_json_item(mut item: MappedJsonItem) -> MappedJsonItem {
+    item.observed_at = item
+        .observed_at
+        .map(|value| json_timestamp(&value).unwrap_or(value));
+    item
+}
+
+fn json_timestamp(value: &str) -> Option<String> {
+    if let Some(timestamp) = canonical_feed_timestamp(value) {
+        return Some(timestamp);
+    }
+    let epoch = value.trim().parse::<i64>().ok()?;
+    let timestamp = if epoch.abs() >= 100_000_000_000 {
+        DateTime::from_timestamp_millis(epoch)?
+    } else {
+        DateTime::from_timestamp(epoch, 0)?
+    };
+    Some(timestamp.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+fn collected_item_to_request(
+    source: &SourceDefinition,
+    item: CollectedEvidencePayload,
+    fallback_observed_at: &str,
+) -> Result<IngestEvidenceRequest, HelixError> {
+    let title = item.title.trim();
+    if title.is_empty() {
+        return Err(HelixError::validation_error(
+            "source.item.title",
+            "title is required",
+        ));
+    }
+
+    let content = first_non_empty([
+        item.content.as_deref(),
+        item.summary.as_deref(),
+        Some(title),
+    ])
+    .unwrap_or(title);
+    let summary = first_non_empty([item.summary.as_deref(), Some(content)]).unwrap_or(content);
+    let observed_at = first_non_empty([item.observed_at.as_deref(), Some(fallback_observed_at)])
+        .unwrap_or(fallback_observed_at);
+    let url = item
+        .url
+        .or_else(|| source.endpoint_url.as_ref().map(ToString::to_string));
+
+    Ok(IngestEvidenceRequest {
+        source_id: source.id.clone(),
+        title: truncate_text(title, 240),
+        summary: truncate_text(summary, 1_024),
+        content: truncate_text(content, MAX_COLLECT_CONTENT_LEN),
+        url,
+        observed_at: observed_at.trim().to_string(),
+        tags: merge_source_tags(source, item.tags),
+        entity_labels: item.entity_labels,
+        proposed_claims: item.proposed_claims,
+    })
+}
+
+fn webhook_ingest_requests(
+    source: &SourceDefinition,
+    payload: WebhookIngestPayload,
+) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
+    let (fallback_observed_at, items) = match payload {
+        WebhookIngestPayload::Envelope { observed_at, items } => (observed_at, items),
+        WebhookIngestPayload::Array(items) => (None, items),
+        WebhookIngestPayload::Single(item) => (None, vec![item]),
+    };
+    if items.is_empty() {
+        return Err(HelixError::validation_error(
+            "webhook.items",
+            "at least one item is required",
+        ));
+    }
+    if items.len() > MAX_COLLECT_ITEMS {
+        return Err(HelixError::validation_error(
+            "webhook.items",
+            &format!("must contain at most {MAX_COLLECT_ITEMS} items"),
+        ));
+    }
+
+    let fallback_observed_at = fallback_observed_at.unwrap_or_default();
+    items
+        .into_iter()
+        .map(|item| {
+            if first_non_empty([
+                item.observed_at.as_deref(),
+                Some(fallback_observed_at.as_str()),
+            ])
+            .is_none()
+            {
+                return Err(HelixError::validation_error(
+                    "webhook.observed_at",
+                    "observed_at is required on each item or on the envelope",
+                ));
+            }
+            collected_item_to_request(source, item, &fallback_observed_at)
+        })
+        .collect()
+}
+
+fn validate_file_import_envelope(request: &FileImportRequest) -> Result<(), HelixError> {
+    let file_name = request.file_name.trim();
+    if file_name.is_empty() {
+        return Err(HelixError::validation_error(
+            "file_name",
+            "file_name is required",
+        ));
+    }
+    if file_name.len() > 240 {
+        return Err(HelixError::validation_error(
+            "file_name",
+            "must be at most 240 characters",
+        ));
+    }
+    if request.observed_at.trim().is_empty() {
+        return Err(HelixError::validation_error(
+            "observed_at",
+            "observed_at is required",
+        ));
+    }
+    if request.content.trim().is_empty() {
+        return Err(HelixError::validation_error(
+            "content",
+            "content is required",
+        ));
+    }
+    Ok(())
+}
+
+fn file_import_request(
+    source: &SourceDefinition,
+    request: FileImportRequest,
+) -> Result<IngestEvidenceRequest, HelixError> {
+    validate_file_import_envelope(&request)?;
+    if request.dry_run {
+        return Err(HelixError::validation_error(
+            "dry_run",
+            "dry_run is only supported for csv, tsv, and jsonl imports",
+        ));
+    }
+    let file_name = request.file_name.trim();
+    let content = request.content.trim();
+    if content.len() > MAX_FILE_IMPORT_CONTENT_LEN {
+        return Err(HelixError::validation_error(
+            "content",
+            &format!("must be at most {MAX_FILE_IMPORT_CONTENT_LEN} bytes"),
+        ));
+    }
+
+    let title = first_non_empty([request.title.as_deref(), Some(file_name)]).unwrap_or(file_name);
+    let summary_fallback = summarize_text(content);
+    let summary = first_non_empty([request.summary.as_deref(), Some(summary_fallback.as_str())])
+        .unwrap_or(summary_fallback.as_str());
+
+    Ok(IngestEvidenceRequest {
+        source_id: source.id.clone(),
+        title: truncate_text(title, 240),
+        summary: truncate_text(summary, 1_024),
+        content: content.to_string(),
+        url: request.url,
+        observed_at: request.observed_at.trim().to_string(),
+        tags: merge_source_tags(source, request.tags),
+        entity_labels: request.entity_labels,
+        proposed_claims: request.proposed_claims,
+    })
+}
+
+/// Splits a CSV, TSV, or JSON Lines import into per-row evidence requests using the
+/// source's column mapping. Rows that cannot become evidence keep their error.
+fn file_import_row_requests(
+    source: &SourceDefinition,
+    request: FileImportRequest,
+    format: FileImportFormat,
+) -> Result<Vec<FileImportRowRequest>, HelixError> {
+    validate_file_import_envelope(&request)?;
+    if request.content.len() > MAX_SOURCE_FETCH_BYTES {
+        return Err(HelixError::validation_error(
+            "content",
+            &format!("must be at most {MAX_SOURCE_FETCH_BYTES} bytes"),
+        ));
+    }
+    if !request.proposed_claims.is_empty() {
+        return Err(HelixError::validation_error(
+            "proposed_claims",
+            "proposed_claims are only supported for text imports",
+        ));
+    }
+    let mapping = source.file_import.clone().unwrap_or_default();
+    let rows = parse_import_rows(format, &request.content, &mapping)?;
+    if rows.is_empty() {
+        return Err(HelixError::validation_error(
+            "content",
+            "file contains no rows",
+        ));
+    }
+    if rows.len() > MAX_FILE_IMPORT_ROWS {
+        return Err(HelixError::validation_error(
+            "content",
+            &format!("must contain at most {MAX_FILE_IMPORT_ROWS} rows"),
+        ));
+    }
+
+    let observed_at = request.observed_at.trim();
+    Ok(rows
+        .into_iter()
+        .map(|parsed| FileImportRowRequest {
+            line: parsed.line,
+            request: parsed.row.and_then(|row| {
+                file_import_row_request(
+                    source,
+                    row,
+                    observed_at,
+                    &request.tags,
+                    &request.entity_labels,
+                )
+            }),
+        })
+        .collect())
+}
+
+fn file_import_row_request(
+    source: &SourceDefinition,
+    row: ImportRow,
+    fallback_observed_at: &str,
+    tags: &[String],
+    entity_labels: &[String],
+) -> Result<IngestEvidenceRequest, String> {
+    let title = row.title.ok_or_else(|| "title is required".to_string())?;
+    let observed_at = match row.observed_at {
+        Some(value) => canonical_feed_timestamp(&value)
+            .ok_or_else(|| format!("observed_at `{value}` is not a recognized timestamp"))?,
+        None => fallback_observed_at.to_string(),
+    };
+    let content = row.content.unwrap_or_default();
+    let summary = row.summary.unwrap_or_else(|| summarize_text(&content));
+    if summary.is_empty() {
+        return Err("summary or content is required".to_string());
+    }
+
+    let mut row_tags = tags.to_vec();
+    row_tags.extend(row.tags);
+    let mut row_entity_labels = entity_labels.to_vec();
+    row_entity_labels.extend(row.entity_labels);
+    Ok(IngestEvidenceRequest {
+        source_id: source.id.clone(),
+        title: truncate_text(&title, 240),
+        summary: truncate_text(&summary, 1_024),
+        content,
+        url: row.url,
+        observed_at,
+        tags: merge_source_tags(source, row_tags),
+        entity_labels: row_entity_labels,
+        proposed_claims: Vec::new(),
+    })
+}
+
+/// Parses an `.eml` message or mbox payload into evidence requests and returns them with
+/// the number of messages read.
+pub(crate) fn email_import_requests(
+    source: &SourceDefinition,
+    payload: &[u8],
+    fallback_observed_at: &str,
+    tags: &[String],
+) -> Result<(usize, Vec<IngestEvidenceRequest>), HelixError> {
+    if payload.iter().all(u8::is_ascii_whitespace) {
+        return Err(HelixError::validation_error(
+            "email.content",
+            "content is required",
+        ));
+    }
+    if payload.len() > MAX_SOURCE_FETCH_BYTES {
+        return Err(HelixError::validation_error(
+            "email.content",
+            &format!("must be at most {MAX_SOURCE_FETCH_BYTES} bytes"),
+        ));
+    }
+    if fallback_observed_at.trim().is_empty() {
+        return Err(HelixError::validation_error(
+            "observed_at",
+            "observed_at is required",
+        ));
+    }
+    let messages = split_mailbox(payload);
+    if messages.len() > MAX_COLLECT_ITEMS {
+        return Err(HelixError::validation_error(
+            "email.content",
+            &format!("must contain at most {MAX_COLLECT_ITEMS} messages"),
+        ));
+    }
+
+    let mut requests = Vec::new();
+    for raw in &messages {
+        let message = parse_email_message(raw)?;
+        requests.extend(email_message_requests(
+            source,
+            message,
+            fallback_observed_at,
+            tags,
+        ));
+    }
+    if requests.is_empty() {
+        return Err(HelixError::validation_error(
+            "email.content",
+            "messages produced no evidence",
+        ));
+    }
+    Ok((messages.len(), requests))
+}
+
+fn email_message_requests(
+    source: &SourceDefinition,
+    message: EmailMessage,
+    fallback_observed_at: &str,
+    tags: &[String],
+) -> Vec<IngestEvidenceRequest> {
+    let observed_at = message
+        .date
+        .as_deref()
+        .and_then(canonical_feed_timestamp)
+        .unwrap_or_else(|| fallback_observed_at.trim().to_string());
+    let message_url = message.message_id.as_ref().map(|id| format!("mid:{id}"));
+    let email_tags = |kind: &[&str]| {
+        let mut merged = kind.iter().map(ToString::to_string).collect::<Vec<_>>();
+        merged.extend(tags.iter().cloned());
+        let mut merged = merge_source_tags(source, merged);
+        merged.truncate(MAX_EVIDENCE_TAGS);
+        merged
+    };
+    let mut entity_labels = message.sender_domains.clone();
+    entity_labels.truncate(MAX_EVIDENCE_TAGS);
+
+    let stories = message.digest_stories();
+    if !stories.is_empty() {
+        let digest_reference = match (message.subject.as_deref(), message_url.as_deref()) {
+            (Some(subject), Some(url)) => format!("\n\nDigest: {subject} ({url})"),
+            (Some(subject), None) => format!("\n\nDigest: {subject}"),
+            (None, Some(url)) => format!("\n\nDigest: {url}"),
+            (None, None) => String::new(),
+        };
+        return stories
+            .into_iter()
+            .map(|story| {
+                let body = if story.text.is_empty() {
+                    story.title.clone()
+                } else {
+                    story.text
+                };
+                IngestEvidenceRequest {
+                    source_id: source.id.clone(),
+                    title: truncate_text(&story.title, 240),
+                    summary: summarize_text(&body),
+                    content: truncate_text(
+                        &format!("{body}{digest_reference}"),
+                        MAX_COLLECT_CONTENT_LEN,
+                    ),
+                    url: Some(story.url),
+                    observed_at: observed_at.clone(),
+                    tags: email_tags(&["email", "email-digest"]),
+                    entity_labels: entity_labels.clone(),
+                    proposed_claims: Vec::new(),
+                }
+            })
+            .collect();
+    }
+
+    let body = message.body_text();
+    let Some(title) = message
+        .subject
+        .clone()
+        .or_else(|| body.lines().next().map(|line| truncate_text(line, 120)))
+        .filter(|title| !title.is_empty())
+    else {
+        return Vec::new();
+    };
+    let content = if body.is_empty() { title.clone() } else { body };
+    vec![IngestEvidenceRequest {
+        source_id: source.id.clone(),
+        title: truncate_text(&title, 240),
+        summary: summarize_text(&content),
+        content: truncate_text(&content, MAX_COLLECT_CONTENT_LEN),
+        url: message_url,
+        observed_at,
+        tags: email_tags(&["email"]),
+        entity_labels,
+        proposed_claims: Vec::new(),
+    }]
+}
+
+fn feed_collection_requests(
+    source: &SourceDefinition,
+    payload: &str,
+    fallback_observed_at: &str,
+) -> Result<Vec<IngestEvidenceRequest>, HelixError> {
+    let feed = parse_feed(payload)?;
+    Ok(feed
+        .entries
+        .into_iter()
+        .filter_map(|entry| feed_entry_request(source, feed.format, entry, fallback_observed_at))
+        .collect())
+}
+
+fn feed_entry_request(
+    source: &SourceDefinition,
+    format: FeedFormat,
+    entry: FeedEntry,
+    fallback_observed_at: &str,
+) -> Option<IngestEvidenceRequest> {
+    let body = first_non_empty([entry.content.as_deref(), entry.summary.as_deref()])
+        .map(strip_markup)
+        .unwrap_or_default();
+    let title = entry
+        .title
+        .as_deref()
+        .map(strip_markup)
+        .filter(|title| !title.is_empty())
+        .or_else(|| (!body.is_empty()).then(|| truncate_text(&body, 120)))?;
+    let summary = entry
+        .summary
+        .as_deref()
+        .map(strip_markup)
+        .filter(|summary| !summary.is_empty())
+        .map(|summary| truncate_text(&summary, 1_024))
+        .unwrap_or_else(|| summarize_text(if body.is_empty() { &title } else { &body }));
+
+    let mut content = if body.is_empty() { title.clone() } else { body };
+    if !entry.authors.is_empty() {
+        content.push_str(&format!("\n\nAuthors: {}", entry.authors.join(", ")));
+    }
+    for enclosure in &entry.enclosures {
+        let mut details = Vec::new();
+        details.extend(enclosure.media_type.clone());
+        details.extend(enclosure.length.map(|length| format!("{length} bytes")));
+        content.push_str(&format!("\nEnclosure: {}", enclosure.url));
+        if !details.is_empty() {
+            content.push_str(&format!(" ({})", details.join(", ")));
+        }
+    }
+
+    let mut tags = vec![format.tag().to_string()];
+    tags.extend(
+        entry
+            .categories
+            .iter()
+            .map(|category| slugify(category))
+            .filter(|category| !category.is_empty()),
+    );
+    let mut tags = merge_source_tags(source, tags);
+    tags.truncate(MAX_EVIDENCE_TAGS);
+
+    Some(IngestEvidenceRequest {
+        source_id: source.id.clone(),
+        title: truncate_text(&title, 240),
+        summary,
+        content: truncate_text(&content, MAX_COLLECT_CONTENT_LEN),
+        url: entry
+            .link
+            .or_else(|| source.endpoint_url.as_ref().map(ToString::to_string)),
+        observed_at: entry
+            .published
+            .as_deref()
+            .and_then(canonical_feed_timestamp)
+            .unwrap_or_else(|| fallback_observed_at.trim().to_string()),
+        tags,
+        entity_labels: Vec::new(),
+        proposed_claims: Vec::new(),
+    })
+}
+
+fn website_diff_summary_text(summary: &WebsiteDiffSummary) -> String {
+    if summary.baseline {
+        return format!(
+            "Baseline snapshot captured with {} content blocks.",
+            summary.block_count
+        );
+    }
+    format!(
+        "Website changed since {}: {} added, {} changed, {} removed, {} unchanged blocks.",
+        summary
+            .previous_observed_at
+            .as_deref()
+            .unwrap_or("the previous snapshot"),
+        summary.added_blocks,
+        summary.changed_blocks,
+        summary.removed_blocks,
+        summary.unchanged_blocks
+    )
+}
+
+fn merge_source_tags(source: &SourceDefinition, tags: Vec<String>) -> Vec<String> {
+    let mut merged = source.tags.clone();
+    for tag in tags {
+        if !merged.iter().any(|existing| existing == &tag) {
+            merged.push(tag);
+        }
+    }
+    merged
+}
+
+fn first_non_empty<const N: usize>(values: [Option<&str>; N]) -> Option<&str> {
+    values
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .find(|value| !value.is_empty())
+}
+
+fn strip_markup(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut in_tag = false;
+    let mut previous_space = false;
+    for ch in input.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                if !previous_space {
+                    output.push(' ');
+                    previous_space = true;
+                }
+            }
+            _ if in_tag => {}
+            _ if ch.is_whitespace() => {
+                if !previous_space {
+                    output.push(' ');
+                    previous_space = true;
+                }
+            }
+            _ => {
+                output.push(ch);
+                previous_space = false;
+            }
+        }
+    }
+    decode_xml_entities(output.trim())
+}
+
+pub(crate) fn decode_xml_entities(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|end| *end <= 12).and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse::<u32>))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            ch.map(|ch| (ch, end))
+        });
+        match decoded {
+            Some((ch, end)) => {
+                output.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn summarize_text(input: &str) -> String {
+    truncate_text(input, 280)
+}
+
+fn truncate_text(input: &str, max_len: usize) -> String {
+    let trimmed = input.trim();
+    if trimmed.len() <= max_len {
+        return trimmed.to_string();
+    }
+    let mut end = 0;
+    for (idx, _) in trimmed.char_indices() {
+        if idx > max_len {
+            break;
+        }
+        end = idx;
+    }
+    trimmed[..end].trim().to_string()
+}
+
+fn stable_id(prefix: &str, parts: &[&str]) -> String {
+    format!("{}_{}", prefix, &stable_hash(parts)[..12])
+}
+
+fn stable_hash(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update([0x1f]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn slugify(input: &str) -> String {
+    let mut slug = String::new();
+    let mut prev_dash = false;
+    for ch in input.trim().chars() {
+        let next = if ch.is_ascii_alphanumeric() {
+            ch.to_ascii_lowercase()
+        } else {
+            '-'
+        };
+        if next == '-' {
+            if prev_dash {
+                continue;
+            }
+            prev_dash = true;
+        } else {
+            prev_dash = false;
+        }
+        slug.push(next);
+    }
+    slug.trim_matches('-').to_string()
+}
+
+fn is_market_source(source: &SourceDefinition) -> bool {
+    source
+        .tags
+        .iter()
+        .any(|tag| tag == "market-intel" || tag == "competitor")
+}
+
+fn is_market_watchlist(watchlist: &Watchlist) -> bool {
+    market_theme_id_for_watchlist(watchlist).is_some()
+}
+
+fn market_theme_descriptors() -> &'static [(&'static str, &'static str, &'static str)] {
+    &[
+        (
+            "pricing",
+            "Pricing Intelligence",
+            "Detect discounting, packaging changes, and monetization pressure across the market.",
+        ),
+        (
+            "product",
+            "Product Launch Radar",
+            "Track launches, betas, new SKUs, and roadmap shifts that change competitive positioning.",
+        ),
+        (
+            "partnerships",
+            "Ecosystem Mapping",
+            "Monitor partner, reseller, and integration announcements to see channel momentum.",
+        ),
+        (
+            "hiring",
+            "Expansion Signals",
+            "Use hiring bursts and role mix to infer GTM push, product investment, or geographic expansion.",
+        ),
+    ]
+}
+
+fn market_theme_id_for_watchlist(watchlist: &Watchlist) -> Option<&'static str> {
+    let keywords = watchlist.keywords.join(" ");
+    let id = watchlist.id.as_str();
+    if id.contains("pricing")
+        || ["pricing", "discount", "bundle", "seat"]
+            .iter()
+            .any(|needle| keywords.contains(needle))
+    {
+        Some("pricing")
+    } else if id.contains("launch")
+        || ["launch", "release", "beta", "ga"]
+            .iter()
+            .any(|needle| keywords.contains(needle))
+    {
+        Some("product")
+    } else if id.contains("partnership")
+        || ["partnership", "integration", "reseller", "ecosystem"]
+            .iter()
+            .any(|needle| keywords.contains(needle))
+    {
+        Some("partnerships")
+    } else if id.contains("hiring")
+        || ["hiring", "headcount", "recruiting", "open role"]
+            .iter()
+            .any(|needle| keywords.contains(needle))
+    {
+        Some("hiring")
+    } else {
+        None
+    }
+}
+
+fn market_theme_name(theme_id: &'static str) -> &'static str {
+    market_theme_descriptors()
+        .iter()
+        .find_map(|(id, name, _)| (*id == theme_id).then_some(*name))
+        .unwrap_or("Unclassified")
+}
+
+fn market_intelligence_playbooks() -> Vec<MarketIntelPlaybook> {
+    vec![
+        MarketIntelPlaybook {
+            id: "competitor_pricing_watch".to_string(),
+            name: "Competitor Pricing Watch".to_string(),
+            objective: "Track packaging, discounting, and seat-level monetization shifts before renewal pressure lands."
+                .to_string(),
+            signals: vec![
+                "pricing page diffs".to_string(),
+                "discount language".to_string(),
+                "seat or bundle changes".to_string(),
+            ],
+        },
+        MarketIntelPlaybook {
+            id: "launch_radar".to_string(),
+            name: "Launch Radar".to_string(),
+            objective: "Capture launch, beta, and release signals that change category positioning or feature parity."
+                .to_string(),
+            signals: vec![
+                "release notes".to_string(),
+                "landing page diffs".to_string(),
+                "SKU announcements".to_string(),
+            ],
+        },
+        MarketIntelPlaybook {
+            id: "partner_ecosystem_map".to_string(),
+            name: "Partner Ecosystem Map".to_string(),
+            objective: "Measure channel strength through reseller, integration, and alliance announcements."
+                .to_string(),
+            signals: vec![
+                "integration posts".to_string(),
+                "reseller pages".to_string(),
+                "ecosystem announcements".to_string(),
+            ],
+        },
+        MarketIntelPlaybook {
+            id: "gtm_expansion_signals".to_string(),
+            name: "GTM Expansion Signals".to_string(),
+            objective: "Infer territory, segment, and product-line investment from hiring velocity and role mix."
+                .to_string(),
+            signals: vec![
+                "sales hiring".to_string(),
+                "channel roles".to_string(),
+                "regional expansion postings".to_string(),
+            ],
+        },
+    ]
+}
+
+fn summarize_key_claims(claims: &[ClaimRecord]) -> Vec<String> {
+    let mut ranked = claims.to_vec();
+    ranked.sort_by(|left, right| {
+        right
+            .confidence_bps
+            .cmp(&left.confidence_bps)
+            .then(left.subject.cmp(&right.subject))
+            .then(left.predicate.cmp(&right.predicate))
+            .then(left.object.cmp(&right.object))
+    });
+    ranked
+        .into_iter()
+        .take(3)
+        .map(|claim| format!("{} {} {}", claim.subject, claim.predicate, claim.object))
+        .collect()
+}
+
+fn claim_review_metrics(claims: &[ClaimRecord]) -> (usize, usize, u16) {
+    let corroborated = claims
+        .iter()
+        .filter(|claim| claim.review_status == ClaimReviewStatus::Corroborated)
+        .count();
+    let rejected = claims
+        .iter()
+        .filter(|claim| claim.review_status == ClaimReviewStatus::Rejected)
+        .count();
+    let max_confidence_bps = claims
+        .iter()
+        .map(|claim| claim.confidence_bps)
+        .max()
+        .unwrap_or(0);
+    (corroborated, rejected, max_confidence_bps)
+}
+
+fn review_kind_label(kind: AutopilotReviewKind) -> &'static str {
+    match kind {
+        AutopilotReviewKind::Case => "case",
+        AutopilotReviewKind::Claim => "claim",
+        AutopilotReviewKind::Evidence => "evidence",
+    }
+}
+
+fn autopilot_review_kind_rank(kind: AutopilotReviewKind) -> u8 {
+    match kind {
+        AutopilotReviewKind::Case => 0,
+        AutopilotReviewKind::Claim => 1,
+        AutopilotReviewKind::Evidence => 2,
+    }
+}
+
+fn case_goal_hint(case: &CaseFile, watchlist_name: &str) -> String {
+    let entity = case
+        .primary_entity
+        .as_deref()
+        .unwrap_or("unassigned entity");
+    let status = match case.status {
+        CaseStatus::Open => "open",
+        CaseStatus::Monitoring => "monitoring",
+        CaseStatus::BriefReady => "brief_ready",
+        CaseStatus::Escalated => "escalated",
+        CaseStatus::Closed => "closed",
+        CaseStatus::Merged => "merged",
+    };
+    format!(
+        "Review the {} case '{}' for {} on watchlist '{}'. Validate the linked evidence, decide whether to keep monitoring, escalate, or attach a brief, and keep the proposal bounded to deterministic follow-up steps.",
+        status, case.title, entity, watchlist_name
+    )
+}
+
+fn claim_goal_hint(claim: &ClaimRecord, evidence_title: &str) -> String {
+    format!(
+        "Review the claim '{} {} {}' from evidence '{}'. Propose only deterministic next steps for corroboration, rejection, or case linkage with cited evidence.",
+        claim.subject, claim.predicate, claim.object, evidence_title
+    )
+}
+
+fn evidence_goal_hint(evidence: &EvidenceItem, linked_case_count: usize) -> String {
+    let entity = evidence
+        .entity_labels
+        .first()
+        .map(String::as_str)
+        .unwrap_or("tracked entity");
+    format!(
+        "Triage evidence '{}' observed at {} for {}. Linked cases: {}. Propose a bounded deterministic follow-up such as claim review, case escalation, watchlist refinement, or briefing preparation.",
+        evidence.title, evidence.observed_at, entity, linked_case_count
+    )
+}
+
+fn build_review_export_narrative(
+    item: &AutopilotReviewQueueEntry,
+    cases: &[CaseQueueEntry],
+    claims: &[ClaimQueueEntry],
+    evidence: &[EvidenceQueueEntry],
+) -> String {
+    format!(
+        "{} export packet for '{}'. Supporting cases: {}. Supporting claims: {}. Supporting evidence: {}.",
+        review_kind_label(item.kind),
+        item.title,
+        cases.len(),
+        claims.len(),
+        evidence.len()
+    )
+}
+
+struct SemanticRanker {
+    generator: EmbeddingGenerator,
+    query_vector: Vec<f32>,
+}
+
+impl SemanticRanker {
+    fn new(query: &str) -> Result<Self, HelixError> {
+        let generator = EmbeddingGenerator;
+        let query_vector = generator
+            .generate_text_embedding(query)
+            .map_err(semantic_embedding_error)?;
+        Ok(Self {
+            generator,
+            query_vector,
+        })
+    }
+
+    fn score_bps(&self, document: &str) -> Result<i32, HelixError> {
+        let document_vector = self
+            .generator
+            .generate_text_embedding(document)
+            .map_err(semantic_embedding_error)?;
+        let score = cosine_similarity(&self.query_vector, &document_vector)
+            .map_err(semantic_embedding_error)?;
+        Ok((score.clamp(-1.0, 1.0) * 10_000.0).round() as i32)
+    }
+}
+
+fn evidence_semantic_document(entry: &EvidenceQueueEntry) -> String {
+    format!(
+        "{} {} {} {} {} {} trust:{} cases:{} claims:{}",
+        entry.evidence.title,
+        entry.evidence.summary,
+        entry.evidence.content,
+        entry.evidence.tags.join(" "),
+        entry.evidence.entity_labels.join(" "),
+        entry.source_name,
+        entry.source_trust_score,
+        entry.linked_case_count,
+        entry.linked_claim_count
+    )
+}
+
+fn claim_semantic_document(entry: &ClaimQueueEntry) -> String {
+    format!(
+        "{} {} {} {} {} {} {} confidence:{} status:{} cases:{}",
+        entry.claim.subject,
+        entry.claim.predicate,
+        entry.claim.object,
+        entry.claim.rationale,
+        entry.evidence_title,
+        entry.evidence_observed_at,
+        entry.source_name,
+        entry.claim.confidence_bps,
+        json_string(&entry.claim.review_status),
+        entry.linked_case_count
+    )
+}
+
+fn semantic_embedding_error(error: helix_embeddings::EmbeddingError) -> HelixError {
+    HelixError::InternalError(format!("semantic retrieval error: {error}"))
+}
+
+fn normalized_limit(limit: Option<usize>, subject: &str) -> Result<Option<usize>, HelixError> {
+    match limit {
+        None => Ok(None),
+        Some(0) => Err(HelixError::validation_error(
+            "limit".to_string(),
+            format!("{subject} limit must be between 1 and 100"),
+        )),
+        Some(limit) if limit > 100 => Err(HelixError::validation_error(
+            "limit".to_string(),
+            format!("{subject} limit must be between 1 and 100"),
+        )),
+        Some(limit) => Ok(Some(limit)),
+    }
+}
+
+fn normalized_semantic_query(
+    value: Option<&str>,
+    field: &str,
+) -> Result<Option<String>, HelixError> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) if value.len() > MAX_SEMANTIC_QUERY_LEN => Err(HelixError::validation_error(
+            field.to_string(),
+            format!("semantic query must be at most {MAX_SEMANTIC_QUERY_LEN} bytes"),
+        )),
+        Some(value) if !value.chars().any(char::is_alphanumeric) => {
+            Err(HelixError::validation_error(
+                field.to_string(),
+                "semantic query must contain at least one alphanumeric character".to_string(),
+            ))
+        }
+        Some(value) => Ok(Some(value.to_string())),
+    }
+}
+
+fn normalized_trust_score(value: Option<u8>) -> Result<Option<u8>, HelixError> {
+    match value {
+        None => Ok(None),
+        Some(score) if score > 100 => Err(HelixError::validation_error(
+            "min_trust",
+            "min_trust must be between 0 and 100",
+        )),
+        Some(score) => Ok(Some(score)),
+    }
+}
+
+fn normalized_trust_as_of(value: Option<&str>) -> Result<Option<DateTime<Utc>>, HelixError> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => DateTime::parse_from_rfc3339(value)
+            .map(|at| Some(at.with_timezone(&Utc)))
+            .map_err(|_| {
+                HelixError::validation_error("trust_as_of", "must be an RFC 3339 timestamp")
+            }),
+    }
+}
+
+fn normalized_confidence_bps(value: Option<u16>) -> Result<Option<u16>, HelixError> {
+    match value {
+        None => Ok(None),
+        Some(score) if score > 10_000 => Err(HelixError::validation_error(
+            "min_confidence_bps",
+            "min_confidence_bps must be between 0 and 10000",
+        )),
+        Some(score) => Ok(Some(score)),
+    }
+}
+
+fn normalized_optional_filter(
+    value: Option<&str>,
+    _field: &str,
+) -> Result<Option<String>, HelixError> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => Ok(Some(value.to_string())),
+    }
+}
+
+/// Story cluster of an evidence item; records not yet clustered stand alone.
+fn story_cluster_key(evidence: &EvidenceItem) -> &str {
+    evidence
+        .story_cluster_id
+        .as_deref()
+        .unwrap_or(evidence.id.as_str())
+}
+
+fn latest_signal_at(evidence: &[EvidenceItem]) -> Option<String> {
+    evidence.iter().map(|item| item.observed_at.clone()).max()
+}
+
+fn build_market_case_summary(
+    case: &CaseFile,
+    theme_name: &str,
+    latest_signal_at: Option<&str>,
+    latest_titles: &[String],
+    key_claims: &[String],
+) -> String {
+    let company = case.primary_entity.as_deref().unwrap_or("tracked company");
+    let timing = latest_signal_at.unwrap_or("unknown_time");
+    let title_context = if latest_titles.is_empty() {
+        "no evidence titles captured".to_string()
+    } else {
+        latest_titles.join("; ")
+    };
+    let claims = if key_claims.is_empty() {
+        "no high-confidence claims yet".to_string()
+    } else {
+        key_claims.join("; ")
+    };
+
+    format!(
+        "{} signal for {} at {}. Recent evidence: {}. Key claims: {}.",
+        theme_name, company, timing, title_context, claims
+    )
+}
+
+fn market_brief_actions(theme_id: &str) -> Vec<String> {
+    match theme_id {
+        "pricing" => vec![
+            "compare current packaging against previous snapshot".to_string(),
+            "brief account team on renewal pressure".to_string(),
+            "record monetization deltas in competitor dossier".to_string(),
+        ],
+        "product" => vec![
+            "map launch claims against current product parity".to_string(),
+            "brief product and field teams on positioning impact".to_string(),
+            "capture supporting release-note evidence".to_string(),
+        ],
+        "partnerships" => vec![
+            "update ecosystem map and partner overlap notes".to_string(),
+            "assess channel displacement or expansion risk".to_string(),
+            "brief alliance team with cited evidence".to_string(),
+        ],
+        "hiring" => vec![
+            "track role clusters for territory or segment expansion".to_string(),
+            "update GTM expansion hypothesis with cited evidence".to_string(),
+            "brief field leadership on hiring velocity changes".to_string(),
+        ],
+        _ => vec![
+            "review case evidence and claims".to_string(),
+            "decide whether to escalate or attach a brief".to_string(),
+        ],
+    }
+}
+
+fn briefing_text(briefing: &MarketIntelCaseBrief) -> String {
+    format!(
+        "{} | key_claims: {} | actions: {}",
+        briefing.summary,
+        if briefing.key_claims.is_empty() {
+            "none".to_string()
+        } else {
+            briefing.key_claims.join("; ")
+        },
+        briefing.recommended_actions.join("; ")
+    )
+}
+
+async fn load_records<T>(pool: &PgPool, table: &str) -> Result<BTreeMap<String, T>, HelixError>
+where
+    T: DeserializeOwned + HasIntelRecordId,
+{
+    let sql = format!("SELECT record FROM {table} ORDER BY id");
+    let rows = sqlx::query(&sql).fetch_all(pool).await.map_err(db_error)?;
+    let mut records = BTreeMap::new();
+    for row in rows {
+        let record_value: serde_json::Value = row.try_get("record").map_err(db_error)?;
+        let record: T = serde_json::from_value(record_value).map_err(serde_error)?;
+        records.insert(record.record_id().to_string(), record);
+    }
+    Ok(records)
+}
+
+async fn load_case_timelines(pool: &PgPool) -> Result<BTreeMap<String, CaseTimeline>, HelixError> {
+    let timelines = load_records(pool, "intel_case_timelines").await?;
+    let rows =
+        sqlx::query("SELECT case_id, record FROM intel_case_events ORDER BY case_id, sequence")
+            .fetch_all(pool)
+            .await
+            .map_err(db_error)?;
+    let mut events = Vec::with_capacity(rows.len());
+    for row in rows {
+        let case_id: String = row.try_get("case_id").map_err(db_error)?;
+        let record_value: serde_json::Value = row.try_get("record").map_err(db_error)?;
+        events.push((
+            case_id,
+            serde_json::from_value(record_value).map_err(serde_error)?,
+        ));
+    }
+    Ok(merge_case_event_rows(timelines, events))
+}
+
+/// Id for the next trust history entry. Ids are zero-padded sequence numbers and stored
+/// entries are never removed, so the sequence continues from the largest stored id.
+pub(crate) fn next_trust_adjustment_id(history: &BTreeMap<String, TrustHistoryEntry>) -> String {
+    let last = history
+        .keys()
+        .filter_map(|id| {
+            id.strip_prefix(TRUST_ADJUSTMENT_ID_PREFIX)?
+                .parse::<u64>()
+                .ok()
+        })
+        .max()
+        .unwrap_or(0);
+    format!("{TRUST_ADJUSTMENT_ID_PREFIX}{:08}", last + 1)
+}
+
+/// Joins case event rows onto their timeline headers by sequence. Headers saved before
+/// events had their own rows still embed them; a row with the same sequence wins.
+pub(crate) fn merge_case_event_rows(
+    mut timelines: BTreeMap<String, CaseTimeline>,
+    events: Vec<(String, CaseEvent)>,
+) -> BTreeMap<String, CaseTimeline> {
+    let mut rows = BTreeMap::<String, BTreeMap<u64, CaseEvent>>::new();
+    for (case_id, event) in events {
+        rows.entry(case_id)
+            .or_default()
+            .insert(event.sequence, event);
+    }
+    for (case_id, case_rows) in rows {
+        let timeline = timelines
+            .entry(case_id.clone())
+            .or_insert_with(|| CaseTimeline::new(&case_id));
+        let mut merged = std::mem::take(&mut timeline.events)
+            .into_iter()
+            .map(|event| (event.sequence, event))
+            .collect::<BTreeMap<_, _>>();
+        merged.extend(case_rows);
+        timeline.events = merged.into_values().collect();
+    }
+    timelines
+}
+
+trait HasIntelRecordId {
+    fn record_id(&self) -> &str;
+}
+
+impl HasIntelRecordId for SourceDefinition {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for Watchlist {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for EvidenceItem {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for ClaimRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for CaseFile {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for SourceCollectionRun {
+    fn record_id(&self) -> &str {
+        &self.source_id
+    }
+}
+
+impl HasIntelRecordId for WebsiteSnapshot {
+    fn record_id(&self) -> &str {
+        &self.source_id
+    }
+}
+
+impl HasIntelRecordId for WatchlistWindow {
+    fn record_id(&self) -> &str {
+        &self.watchlist_id
+    }
+}
+
+impl HasIntelRecordId for RegistryEntity {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for IntelRulePack {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for SuppressedHitRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for ClaimAdjudication {
+    fn record_id(&self) -> &str {
+        &self.claim_id
+    }
+}
+
+impl HasIntelRecordId for ClaimAdjudicationConfigRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for TrustCalibrationPolicyRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for TrustHistoryEntry {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for CaseTimeline {
+    fn record_id(&self) -> &str {
+        &self.case_id
+    }
+}
+
+impl HasIntelRecordId for CaseSlaPolicyRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for SourceSchedulerStateRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for CaseSla {
+    fn record_id(&self) -> &str {
+        &self.case_id
+    }
+}
+
+impl HasIntelRecordId for AnalystNote {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl HasIntelRecordId for DecayPolicyRecord {
+    fn record_id(&self) -> &str {
+        &self.id
+    }
+}
+
+fn json_string<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn db_error(error: sqlx::Error) -> HelixError {
+    HelixError::InternalError(format!("intel desk persistence error: {error}"))
+}
+
+fn serde_error(error: serde_json::Error) -> HelixError {
+    HelixError::InternalError(format!("intel desk serialization error: {error}"))
+}
+
+async fn mutate_intel_desk<T>(
+    state: &AppState,
+    mutation: impl FnOnce(&mut IntelDeskStore) -> Result<T, HelixError>,
+) -> Result<T, HelixError> {
+    mutate_intel_desk_if_changed(state, |store| mutation(store).map(|result| (result, true))).await
+}
+
+/// Like `mutate_intel_desk`, but persists only when the mutation reports a change.
+async fn mutate_intel_desk_if_changed<T>(
+    state: &AppState,
+    mutation: impl FnOnce(&mut IntelDeskStore) -> Result<(T, bool), HelixError>,
+) -> Result<T, HelixError> {
+    let persistence = state.intel_persistence.clone();
+    let mut store = state.intel_desk.write().await;
+    // A failed mutation or save leaves the desk as it was before the call.
+    let rollback = store.clone();
+    let (result, changed) = match mutation(&mut store) {
+        Ok(outcome) => outcome,
+        Err(error) => {
+            *store = rollback;
+            return Err(error);
+        }
+    };
+
+    if let Some(persistence) = persistence.filter(|_| changed) {
+        if let Err(error) = persistence.save(&store).await {
+            *store = rollback;
+            return Err(error);
+        }
+    }
+
+    Ok(result)
+}
+
+pub(crate) async fn get_intel_overview(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (StatusCode::OK, Json(store.overview()))
+}
+
+pub(crate) async fn get_market_intel_overview(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (StatusCode::OK, Json(store.market_intelligence_overview()))
+}
+
+pub(crate) async fn generate_market_intel_brief_handler(
+    State(state): State<AppState>,
+    Path(case_id): Path<String>,
+    Json(request): Json<GenerateMarketIntelBriefRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| {
+        store.generate_market_brief(&case_id, request.attach_to_case)
+    })
+    .await;
+    match result {
+        Ok(response) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.market_brief.generate",
+                    format!("cases/{case_id}/brief"),
+                    serde_json::json!({
+                        "case_id": case_id,
+                        "attach_to_case": request.attach_to_case,
+                        "transition": response.transition,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn list_sources(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(SourceCatalogResponse {
+            sources: store.sources.values().cloned().collect(),
+            health: store.source_health(),
+        }),
+    )
+}
+
+pub(crate) async fn create_source(
+    State(state): State<AppState>,
+    Json(request): Json<CreateSourceRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| store.create_source(request)).await;
+    match result {
+        Ok(source) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.source.create",
+                    format!("sources/{}", source.id),
+                    serde_json::json!({
+                        "source_id": source.id,
+                        "profile_id": source.profile_id,
+                        "kind": source.kind,
+                        "endpoint_url": source.endpoint_url,
+                        "credential_id": source.credential_id,
+                        "credential_header_name": source.credential_header_name,
+                        "enabled": source.enabled,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(SourceResponse { source })).into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn collect_source_handler(
+    State(state): State<AppState>,
+    Path(source_id): Path<String>,
+    Json(request): Json<CollectSourceRequest>,
+) -> Response {
+    let limit = match normalize_collect_limit(request.max_items) {
+        Ok(limit) => limit,
+        Err(error) => return api_error_response(error),
+    };
+    let source = {
+        let store = state.intel_desk.read().await;
+        match store.sources.get(&source_id).cloned() {
+            Some(source) => source,
+            None => {
+                return api_error_response(HelixError::not_found(format!("source {source_id}")))
+            }
+        }
+    };
+    if !source.enabled {
+        return api_error_response(HelixError::validation_error("source", "source is disabled"));
+    }
+    let pending =
+        match fetch_scheduled_collection(&state, source, &request.observed_at, limit).await {
+            Ok(Some(pending)) => pending,
+            Ok(None) => {
+                return api_error_response(source_fetch_error(format!(
+                    "source {source_id} circuit is open after repeated fetch failures"
+                )))
+            }
+            Err(error) => return api_error_response(error),
+        };
+
+    let result = mutate_intel_desk(&state, |store| {
+        store.ingest_pending_collection(pending, None, &request.observed_at)
+    })
+    .await;
+
+    match result {
+        Ok(collection) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.source.collect",
+                    format!("sources/{}/collect", collection.source.id),
+                    serde_json::json!({
+                        "source_id": collection.source.id,
+                        "fetched_url": collection.fetched_url,
+                        "credential_id": collection.source.credential_id,
+                        "collected_count": collection.collected_count,
+                        "duplicate_count": collection.duplicate_count,
+                        "website_diff": collection.website_diff,
+                        "not_modified": collection.not_modified,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            let status = if collection.results.is_empty() {
+                StatusCode::OK
+            } else {
+                StatusCode::CREATED
+            };
+            (
+                status,
+                Json(CollectSourceResponse {
+                    source: collection.source,
+                    fetched_url: collection.fetched_url,
+                    collected_count: collection.collected_count,
+                    duplicate_count: collection.duplicate_count,
+                    results: collection.results,
+                    website_diff: collection.website_diff,
+                    not_modified: collection.not_modified,
+                }),
+            )
+                .into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn collect_due_sources_handler(
+    State(state): State<AppState>,
+    Json(request): Json<CollectDueSourcesRequest>,
+) -> Response {
+    if request.observed_at.trim().is_empty() {
+        return api_error_response(HelixError::validation_error(
+            "observed_at",
+            "observed_at is required",
+        ));
+    }
+    let limit = match normalize_collect_limit(request.max_items_per_source) {
+        Ok(limit) => limit,
+        Err(error) => return api_error_response(error),
+    };
+
+    let requested_ids = request
+        .source_ids
+        .iter()
+        .map(|source_id| source_id.trim().to_string())
+        .filter(|source_id| !source_id.is_empty())
+        .collect::<BTreeSet<_>>();
+    let sources = {
+        let store = state.intel_desk.read().await;
+        for source_id in &requested_ids {
+            if !store.sources.contains_key(source_id) {
+                return api_error_response(HelixError::not_found(format!("source {source_id}")));
+            }
+        }
+        store.sources.values().cloned().collect::<Vec<_>>()
+    };
+
+    let mut skipped_count = 0usize;
+    let mut due_sources = Vec::new();
+    for source in sources {
+        if !requested_ids.is_empty() && !requested_ids.contains(&source.id) {
+            continue;
+        }
+        if !source.enabled
+            || !source_supports_pull_collection(&source)
+            || source.endpoint_url.is_none()
+            || !source_is_due_at_tick(&source, request.tick_minute)
+        {
+            skipped_count += 1;
+            continue;
+        }
+        due_sources.push(source);
+    }
+
+    let mut pending = Vec::new();
+    for source in due_sources {
+        match fetch_scheduled_collection(&state, source, &request.observed_at, limit).await {
+            Ok(Some(collection)) => pending.push(collection),
+            Ok(None) => skipped_count += 1,
+            Err(error) => return api_error_response(error),
+        }
+    }
+
+    let due_count = pending.len();
+    let result = mutate_intel_desk(&state, |store| {
+        pending
+            .into_iter()
+            .map(|pending| {
+                store.ingest_pending_collection(
+                    pending,
+                    Some(request.tick_minute),
+                    &request.observed_at,
+                )
+            })
+            .collect::<Result<Vec<_>, HelixError>>()
+    })
+    .await;
+
+    match result {
+        Ok(collections) => {
+            let collected_count = collections
+                .iter()
+                .map(|collection| collection.collected_count)
+                .sum::<usize>();
+            let duplicate_count = collections
+                .iter()
+                .map(|collection| collection.duplicate_count)
+                .sum::<usize>();
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.source.collect_due",
+                    "sources/collect-due",
+                    serde_json::json!({
+                        "observed_at": request.observed_at.clone(),
+                        "tick_minute": request.tick_minute,
+                        "due_count": due_count,
+                        "skipped_count": skipped_count,
+                        "collected_count": collected_count,
+                        "duplicate_count": duplicate_count,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            let status = if collections.is_empty() {
+                StatusCode::OK
+            } else {
+                StatusCode::CREATED
+            };
+            (
+                status,
+                Json(CollectDueSourcesResponse {
+                    observed_at: request.observed_at.trim().to_string(),
+                    tick_minute: request.tick_minute,
+                    due_count,
+                    skipped_count,
+                    collections,
+                }),
+            )
+                .into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn fetch_scheduled_collection(
+    state: &AppState,
+    source: SourceDefinition,
+    observed_at: &str,
+    limit: usize,
+) -> Result<Option<PendingScheduledCollection>, HelixError> {
+    let endpoint_url = source.endpoint_url.clone().ok_or_else(|| {
+        HelixError::validation_error("source.endpoint_url", "source has no endpoint_url")
+    })?;
+    if observed_at.trim().is_empty() {
+        return Err(HelixError::validation_error(
+            "observed_at",
+            "fallback observed_at is required",
+        ));
+    }
+    let fetch_auth = source_fetch_auth(state, &source).await?;
+    let Some(validators) =
+        mutate_intel_desk(state, |store| Ok(store.admit_source_fetch(&source.id))).await?
+    else {
+        return Ok(None);
+    };
+    let fetched = fetch_source_body(&endpoint_url, fetch_auth.as_ref(), &validators).await;
+    mutate_intel_desk(state, |store| {
+        store.record_source_fetch(&source.id, observed_at, &fetched);
+        Ok(())
+    })
+    .await?;
+    let response = fetched.map_err(|failure| failure.error)?;
+    let Some(payload) = response.body else {
+        return Ok(Some(PendingScheduledCollection {
+            schedule_phase_minute: source_schedule_phase_minute(&source),
+            source,
+            fetched_url: endpoint_url,
+            requests: Vec::new(),
+            website_capture: None,
+            validators: None,
+        }));
+    };
+    let (requests, website_capture) = if source.kind == SourceKind::WebsiteDiff {
+        let capture = capture_website_blocks(&payload, source.website_diff.as_ref());
+        (Vec::new(), Some(capture))
+    } else {
+        let requests = collect_requests_from_payload(&source, &payload, observed_at, limit)?;
+        if requests.is_empty() {
+            return Err(HelixError::validation_error(
+                "source.payload",
+                "source payload produced no evidence",
+            ));
+        }
+        (requests, None)
+    };
+    Ok(Some(PendingScheduledCollection {
+        schedule_phase_minute: source_schedule_phase_minute(&source),
+        source,
+        fetched_url: endpoint_url,
+        requests,
+        website_capture,
+        validators: Some(response.validators),
+    }))
 }
 
-fn build_review_export_narrative(
-    item: &AutopilotReviewQueueEntry,
-    cases: &[CaseQueueEntry],
-    claims: &[ClaimQueueEntry],
-    evidence: &[EvidenceQueueEntry],
-) -> String {
-    format!(
-        "{} export packet for '{}'. Supporting cases: {}. Supporting claims: {}. Supporting evidence: {}.",
-        review_kind_label(item.kind),
-        item.title,
-        cases.len(),
-        claims.len(),
-        evidence.len()
-    )
+pub(crate) async fn ingest_scheduled_collection(
+    state: &AppState,
+    pending: PendingScheduledCollection,
+    tick_minute: u64,
+    observed_at: &str,
+) -> Result<ScheduledSourceCollection, HelixError> {
+    mutate_intel_desk(state, |store| {
+        store.ingest_pending_collection(pending, Some(tick_minute), observed_at)
+    })
+    .await
 }
 
-struct SemanticRanker {
-    generator: EmbeddingGenerator,
-    query_vector: Vec<f32>,
+pub(crate) async fn record_scheduled_collection_failure(
+    state: &AppState,
+    source_id: &str,
+    tick_minute: u64,
+    attempted_at: &str,
+    error: &HelixError,
+) -> Result<SourceCollectionRun, HelixError> {
+    let message = error.to_string();
+    mutate_intel_desk(state, |store| {
+        Ok(store.record_source_failure(source_id, Some(tick_minute), attempted_at, &message))
+    })
+    .await
 }
 
-impl SemanticRanker {
-    fn new(query: &str) -> Result<Self, HelixError> {
-        let generator = EmbeddingGenerator;
-        let query_vector = generator
-            .generate_text_embedding(query)
-            .map_err(semantic_embedding_error)?;
-        Ok(Self {
-            generator,
-            query_vector,
-        })
-    }
+/// Persists the scheduler pause flag and returns whether it was already paused.
+pub(crate) async fn set_source_scheduler_paused(
+    state: &AppState,
+    paused: bool,
+) -> Result<bool, HelixError> {
+    mutate_intel_desk_if_changed(state, |store| {
+        let previous = std::mem::replace(&mut store.source_scheduler_paused, paused);
+        Ok((previous, previous != paused))
+    })
+    .await
+}
 
-    fn score_bps(&self, document: &str) -> Result<i32, HelixError> {
-        let document_vector = self
-            .generator
-            .generate_text_embedding(document)
-            .map_err(semantic_embedding_error)?;
-        let score = cosine_similarity(&self.query_vector, &document_vector)
-            .map_err(semantic_embedding_error)?;
-        Ok((score.clamp(-1.0, 1.0) * 10_000.0).round() as i32)
+pub(crate) async fn webhook_ingest_handler(
+    State(state): State<AppState>,
+    Path(source_id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let source = state
+        .intel_desk
+        .read()
+        .await
+        .sources
+        .get(&source_id)
+        .cloned();
+    let signature = source
+        .as_ref()
+        .and_then(|source| source.webhook_signature.clone());
+    if signature.is_none() {
+        if let Some(rejection) = bearer_auth_rejection(&state, &headers) {
+            return rejection;
+        }
+    }
+    let Some(source) = source else {
+        return api_error_response(HelixError::not_found(format!("source {source_id}")));
+    };
+    if !source.enabled {
+        return api_error_response(HelixError::validation_error("source", "source is disabled"));
+    }
+    if source.kind != SourceKind::WebhookIngest {
+        return api_error_response(HelixError::validation_error(
+            "source.kind",
+            "source must be webhook_ingest",
+        ));
     }
+
+    let verified = if let Some(config) = signature.as_ref() {
+        let now = chrono::Utc::now().timestamp();
+        let delivery = match verify_webhook_delivery(&state, &source, config, &headers, &body, now)
+            .await
+        {
+            Ok(delivery) => delivery,
+            Err(WebhookDeliveryError::Rejected(reason)) => {
+                return reject_webhook_delivery(&state, &source, StatusCode::UNAUTHORIZED, reason)
+                    .await
+            }
+            Err(WebhookDeliveryError::Internal(error)) => return api_error_response(error),
+        };
+        Some((config, delivery, now))
+    } else {
+        None
+    };
+    ingest_webhook_delivery(&state, source, verified, &body).await
 }
 
-fn evidence_semantic_document(entry: &EvidenceQueueEntry) -> String {
-    format!(
-        "{} {} {} {} {} {} trust:{} cases:{} claims:{}",
-        entry.evidence.title,
-        entry.evidence.summary,
-        entry.evidence.content,
-        entry.evidence.tags.join(" "),
-        entry.evidence.entity_labels.join(" "),
-        entry.source_name,
-        entry.source_trust_score,
-        entry.linked_case_count,
-        entry.linked_claim_count
-    )
+/// Ingests a webhook body whose signature, if any, has already been verified. The
+/// delivery is admitted to the replay guard in the same mutation as the ingest, so a
+/// rejected body rolls the guard back and the sender's retry is still accepted.
+pub(crate) async fn ingest_webhook_delivery(
+    state: &AppState,
+    source: SourceDefinition,
+    verified: Option<(&WebhookSignatureConfig, VerifiedDelivery, i64)>,
+    body: &[u8],
+) -> Response {
+    let payload = match serde_json::from_slice::<WebhookIngestPayload>(body) {
+        Ok(payload) => payload,
+        Err(error) => {
+            return api_error_response(HelixError::validation_error(
+                "payload".to_string(),
+                format!("webhook payload is not valid JSON: {error}"),
+            ))
+        }
+    };
+    let requests = match webhook_ingest_requests(&source, payload) {
+        Ok(requests) => requests,
+        Err(error) => return api_error_response(error),
+    };
+    let result = mutate_intel_desk(state, |store| {
+        if let Some((config, delivery, now)) = &verified {
+            if !store.admit_webhook_delivery(&source.id, config, delivery, *now) {
+                return Ok(None);
+            }
+        }
+        store.ingest_evidence_batch(requests).map(Some)
+    })
+    .await;
+
+    match result {
+        Ok(None) => {
+            reject_webhook_delivery(
+                state,
+                &source,
+                StatusCode::CONFLICT,
+                "delivery signature was already accepted".to_string(),
+            )
+            .await
+        }
+        Ok(Some(results)) => {
+            let duplicate_count = results.iter().filter(|result| result.duplicate).count();
+            let accepted_count = results.len();
+            if let Err(error) = record_audit_event(
+                state,
+                AuditEvent::allow(
+                    "intel.source.webhook_ingest",
+                    format!("sources/{}/webhook", source.id),
+                    serde_json::json!({
+                        "source_id": source.id,
+                        "accepted_count": accepted_count,
+                        "duplicate_count": duplicate_count,
+                        "case_update_count": results
+                            .iter()
+                            .map(|result| result.case_updates.len())
+                            .sum::<usize>(),
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (
+                StatusCode::CREATED,
+                Json(WebhookIngestResponse {
+                    source,
+                    accepted_count,
+                    duplicate_count,
+                    results,
+                }),
+            )
+                .into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
 }
 
-fn claim_semantic_document(entry: &ClaimQueueEntry) -> String {
-    format!(
-        "{} {} {} {} {} {} {} confidence:{} status:{} cases:{}",
-        entry.claim.subject,
-        entry.claim.predicate,
-        entry.claim.object,
-        entry.claim.rationale,
-        entry.evidence_title,
-        entry.evidence_observed_at,
-        entry.source_name,
-        entry.claim.confidence_bps,
-        json_string(&entry.claim.review_status),
-        entry.linked_case_count
-    )
+enum WebhookDeliveryError {
+    Rejected(String),
+    Internal(HelixError),
 }
 
-fn semantic_embedding_error(error: helix_embeddings::EmbeddingError) -> HelixError {
-    HelixError::InternalError(format!("semantic retrieval error: {error}"))
+async fn verify_webhook_delivery(
+    state: &AppState,
+    source: &SourceDefinition,
+    config: &WebhookSignatureConfig,
+    headers: &HeaderMap,
+    body: &[u8],
+    now: i64,
+) -> Result<VerifiedDelivery, WebhookDeliveryError> {
+    signature_header_value(config, headers).map_err(WebhookDeliveryError::Rejected)?;
+    let secret = source_credential_secret(state, source)
+        .await
+        .map_err(WebhookDeliveryError::Internal)?
+        .ok_or_else(|| {
+            WebhookDeliveryError::Internal(HelixError::validation_error(
+                "source.credential_id",
+                "signed webhook source has no credential_id",
+            ))
+        })?;
+    verify_webhook_signature(config, secret.as_bytes(), headers, body, now)
+        .map_err(WebhookDeliveryError::Rejected)
 }
 
-fn normalized_limit(limit: Option<usize>, subject: &str) -> Result<Option<usize>, HelixError> {
-    match limit {
-        None => Ok(None),
-        Some(0) => Err(HelixError::validation_error(
-            "limit".to_string(),
-            format!("{subject} limit must be between 1 and 100"),
-        )),
-        Some(limit) if limit > 100 => Err(HelixError::validation_error(
-            "limit".to_string(),
-            format!("{subject} limit must be between 1 and 100"),
-        )),
-        Some(limit) => Ok(Some(limit)),
+async fn reject_webhook_delivery(
+    state: &AppState,
+    source: &SourceDefinition,
+    status: StatusCode,
+    reason: String,
+) -> Response {
+    if let Err(error) = record_audit_event(
+        state,
+        AuditEvent::deny(
+            "intel.source.webhook_reject",
+            format!("sources/{}/webhook", source.id),
+            reason.clone(),
+            serde_json::json!({
+                "source_id": source.id,
+                "scheme": source
+                    .webhook_signature
+                    .as_ref()
+                    .map(|config| config.scheme),
+                "status": status.as_u16(),
+            }),
+        ),
+    )
+    .await
+    {
+        return api_error_response(error);
     }
+    (status, Json(ApiErrorResponse { error: reason })).into_response()
 }
 
-fn normalized_semantic_query(
-    value: Option<&str>,
-    field: &str,
-) -> Result<Option<String>, HelixError> {
-    match value.map(str::trim) {
-        None | Some("") => Ok(None),
-        Some(value) if value.len() > MAX_SEMANTIC_QUERY_LEN => Err(HelixError::validation_error(
-            field.to_string(),
-            format!("semantic query must be at most {MAX_SEMANTIC_QUERY_LEN} bytes"),
-        )),
-        Some(value) if !value.chars().any(char::is_alphanumeric) => {
-            Err(HelixError::validation_error(
-                field.to_string(),
-                "semantic query must contain at least one alphanumeric character".to_string(),
-            ))
+pub(crate) async fn file_import_handler(
+    State(state): State<AppState>,
+    Path(source_id): Path<String>,
+    Json(request): Json<FileImportRequest>,
+) -> Response {
+    let source = {
+        let store = state.intel_desk.read().await;
+        match store.sources.get(&source_id).cloned() {
+            Some(source) => source,
+            None => {
+                return api_error_response(HelixError::not_found(format!("source {source_id}")))
+            }
         }
-        Some(value) => Ok(Some(value.to_string())),
+    };
+    if !source.enabled {
+        return api_error_response(HelixError::validation_error("source", "source is disabled"));
+    }
+    if source.kind != SourceKind::FileImport {
+        return api_error_response(HelixError::validation_error(
+            "source.kind",
+            "source must be file_import",
+        ));
     }
-}
 
-fn normalized_trust_score(value: Option<u8>) -> Result<Option<u8>, HelixError> {
-    match value {
-        None => Ok(None),
-        Some(score) if score > 100 => Err(HelixError::validation_error(
-            "min_trust",
-            "min_trust must be between 0 and 100",
-        )),
-        Some(score) => Ok(Some(score)),
+    let format = request
+        .format
+        .unwrap_or_else(|| FileImportFormat::from_file_name(&request.file_name));
+    if format != FileImportFormat::Text {
+        return file_row_import(&state, source, request, format).await;
+    }
+
+    let ingest_request = match file_import_request(&source, request) {
+        Ok(request) => request,
+        Err(error) => return api_error_response(error),
+    };
+    let result = mutate_intel_desk(&state, |store| store.ingest_evidence(ingest_request)).await;
+
+    match result {
+        Ok(result) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.source.file_import",
+                    format!("sources/{}/import", source.id),
+                    serde_json::json!({
+                        "source_id": source.id,
+                        "evidence_id": result.evidence.id,
+                        "duplicate": result.duplicate,
+                        "claim_count": result.claims.len(),
+                        "hit_count": result.hits.len(),
+                        "case_update_count": result.case_updates.len(),
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (
+                StatusCode::CREATED,
+                Json(FileImportResponse { source, result }),
+            )
+                .into_response()
+        }
+        Err(error) => api_error_response(error),
     }
 }
 
-fn normalized_confidence_bps(value: Option<u16>) -> Result<Option<u16>, HelixError> {
-    match value {
-        None => Ok(None),
-        Some(score) if score > 10_000 => Err(HelixError::validation_error(
-            "min_confidence_bps",
-            "min_confidence_bps must be between 0 and 10000",
-        )),
-        Some(score) => Ok(Some(score)),
+async fn file_row_import(
+    state: &AppState,
+    source: SourceDefinition,
+    request: FileImportRequest,
+    format: FileImportFormat,
+) -> Response {
+    let dry_run = request.dry_run;
+    let rows = match file_import_row_requests(&source, request, format) {
+        Ok(rows) => rows,
+        Err(error) => return api_error_response(error),
+    };
+    if dry_run {
+        let rows = match state.intel_desk.read().await.preview_file_rows(rows) {
+            Ok(rows) => rows,
+            Err(error) => return api_error_response(error),
+        };
+        return (
+            StatusCode::OK,
+            Json(FileRowImportResponse::new(source, format, true, rows)),
+        )
+            .into_response();
     }
-}
 
-fn normalized_optional_filter(
-    value: Option<&str>,
-    _field: &str,
-) -> Result<Option<String>, HelixError> {
-    match value.map(str::trim) {
-        None | Some("") => Ok(None),
-        Some(value) => Ok(Some(value.to_string())),
+    let rows = match mutate_intel_desk(state, |store| store.ingest_file_rows(rows)).await {
+        Ok(rows) => rows,
+        Err(error) => return api_error_response(error),
+    };
+    let response = FileRowImportResponse::new(source, format, false, rows);
+    if let Err(error) = record_audit_event(
+        state,
+        AuditEvent::allow(
+            "intel.source.file_import",
+            format!("sources/{}/import", response.source.id),
+            serde_json::json!({
+                "source_id": response.source.id,
+                "format": response.format,
+                "row_count": response.row_count,
+                "accepted_count": response.accepted_count,
+                "duplicate_count": response.duplicate_count,
+                "error_count": response.error_count,
+            }),
+        ),
+    )
+    .await
+    {
+        return api_error_response(error);
     }
+    (StatusCode::CREATED, Json(response)).into_response()
 }
 
-fn latest_signal_at(evidence: &[EvidenceItem]) -> Option<String> {
-    evidence.iter().map(|item| item.observed_at.clone()).max()
+pub(crate) async fn preview_json_mapping_handler(
+    Json(request): Json<JsonMappingPreviewRequest>,
+) -> Response {
+    let mapping = match canonicalize_json_api_mapping(request.mapping) {
+        Ok(mapping) => mapping,
+        Err(error) => return api_error_response(error),
+    };
+    let selected = match select_json_items(&mapping, &request.payload) {
+        Ok(selected) => selected,
+        Err(error) => return api_error_response(error),
+    };
+    let items = selected
+        .iter()
+        .take(MAX_COLLECT_ITEMS)
+        .enumerate()
+        .map(|(index, item)| match map_json_item(&mapping, item) {
+            Ok(item) => JsonMappingPreviewItem {
+                index,
+                item: Some(normalize_mapped_json_item(item)),
+                error: None,
+            },
+            Err(error) => JsonMappingPreviewItem {
+                index,
+                item: None,
+                error: Some(error.to_string()),
+            },
+        })
+        .collect::<Vec<_>>();
+    let response = JsonMappingPreviewResponse {
+        item_count: selected.len(),
+        mapped_count: items.iter().filter(|item| item.item.is_some()).count(),
+        mapping,
+        items,
+    };
+    (StatusCode::OK, Json(response)).into_response()
 }
 
-fn build_market_case_summary(
-    case: &CaseFile,
-    theme_name: &str,
-    latest_signal_at: Option<&str>,
-    latest_titles: &[String],
-    key_claims: &[String],
-) -> String {
-    let company = case.primary_entity.as_deref().unwrap_or("tracked company");
-    let timing = latest_signal_at.unwrap_or("unknown_time");
-    let title_context = if latest_titles.is_empty() {
-        "no evidence titles captured".to_string()
-    } else {
-        latest_titles.join("; ")
+pub(crate) async fn email_import_handler(
+    State(state): State<AppState>,
+    Path(source_id): Path<String>,
+    Json(request): Json<EmailImportRequest>,
+) -> Response {
+    let source = {
+        let store = state.intel_desk.read().await;
+        match store.sources.get(&source_id).cloned() {
+            Some(source) => source,
+            None => {
+                return api_error_response(HelixError::not_found(format!("source {source_id}")))
+            }
+        }
     };
-    let claims = if key_claims.is_empty() {
-        "no high-confidence claims yet".to_string()
-    } else {
-        key_claims.join("; ")
+    if !source.enabled {
+        return api_error_response(HelixError::validation_error("source", "source is disabled"));
+    }
+    if source.kind != SourceKind::EmailDigest {
+        return api_error_response(HelixError::validation_error(
+            "source.kind",
+            "source must be email_digest",
+        ));
+    }
+
+    let (message_count, requests) = match email_import_requests(
+        &source,
+        request.content.as_bytes(),
+        &request.observed_at,
+        &request.tags,
+    ) {
+        Ok(parsed) => parsed,
+        Err(error) => return api_error_response(error),
     };
+    let result = mutate_intel_desk(&state, |store| store.ingest_evidence_batch(requests)).await;
 
-    format!(
-        "{} signal for {} at {}. Recent evidence: {}. Key claims: {}.",
-        theme_name, company, timing, title_context, claims
+    match result {
+        Ok(results) => {
+            let duplicate_count = results.iter().filter(|result| result.duplicate).count();
+            let accepted_count = results.len();
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.source.email_import",
+                    format!("sources/{}/email", source.id),
+                    serde_json::json!({
+                        "source_id": source.id,
+                        "message_count": message_count,
+                        "accepted_count": accepted_count,
+                        "duplicate_count": duplicate_count,
+                        "case_update_count": results
+                            .iter()
+                            .map(|result| result.case_updates.len())
+                            .sum::<usize>(),
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (
+                StatusCode::CREATED,
+                Json(EmailImportResponse {
+                    source,
+                    message_count,
+                    accepted_count,
+                    duplicate_count,
+                    results,
+                }),
+            )
+                .into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn ingest_watched_email(
+    state: &AppState,
+    source_id: &str,
+    requests: Vec<IngestEvidenceRequest>,
+    observed_at: &str,
+) -> Result<Vec<IngestEvidenceResponse>, HelixError> {
+    mutate_intel_desk(state, |store| {
+        let results = store.ingest_evidence_batch(requests)?;
+        store.record_source_collection(source_id, None, observed_at, results.len());
+        Ok(results)
+    })
+    .await
+}
+
+pub(crate) async fn record_watched_email_failure(
+    state: &AppState,
+    source_id: &str,
+    attempted_at: &str,
+    error: &HelixError,
+) -> Result<SourceCollectionRun, HelixError> {
+    let message = error.to_string();
+    mutate_intel_desk(state, |store| {
+        Ok(store.record_source_failure(source_id, None, attempted_at, &message))
+    })
+    .await
+}
+
+pub(crate) async fn list_watchlists(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(WatchlistCatalogResponse {
+            watchlists: store.watchlists.values().cloned().collect(),
+        }),
     )
 }
 
-fn market_brief_actions(theme_id: &str) -> Vec<String> {
-    match theme_id {
-        "pricing" => vec![
-            "compare current packaging against previous snapshot".to_string(),
-            "brief account team on renewal pressure".to_string(),
-            "record monetization deltas in competitor dossier".to_string(),
-        ],
-        "product" => vec![
-            "map launch claims against current product parity".to_string(),
-            "brief product and field teams on positioning impact".to_string(),
-            "capture supporting release-note evidence".to_string(),
-        ],
-        "partnerships" => vec![
-            "update ecosystem map and partner overlap notes".to_string(),
-            "assess channel displacement or expansion risk".to_string(),
-            "brief alliance team with cited evidence".to_string(),
-        ],
-        "hiring" => vec![
-            "track role clusters for territory or segment expansion".to_string(),
-            "update GTM expansion hypothesis with cited evidence".to_string(),
-            "brief field leadership on hiring velocity changes".to_string(),
-        ],
-        _ => vec![
-            "review case evidence and claims".to_string(),
-            "decide whether to escalate or attach a brief".to_string(),
-        ],
+pub(crate) async fn create_watchlist(
+    State(state): State<AppState>,
+    Json(request): Json<CreateWatchlistRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| store.create_watchlist(request)).await;
+    match result {
+        Ok(watchlist) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.watchlist.create",
+                    format!("watchlists/{}", watchlist.id),
+                    serde_json::json!({
+                        "watchlist_id": watchlist.id,
+                        "severity": watchlist.severity,
+                        "min_source_trust": watchlist.min_source_trust,
+                        "enabled": watchlist.enabled,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(WatchlistResponse { watchlist })).into_response()
+        }
+        Err(error) => api_error_response(error),
     }
 }
 
-fn briefing_text(briefing: &MarketIntelCaseBrief) -> String {
-    format!(
-        "{} | key_claims: {} | actions: {}",
-        briefing.summary,
-        if briefing.key_claims.is_empty() {
-            "none".to_string()
-        } else {
-            briefing.key_claims.join("; ")
-        },
-        briefing.recommended_actions.join("; ")
-    )
+pub(crate) async fn create_watchlist_suppression(
+    State(state): State<AppState>,
+    Path(watchlist_id): Path<String>,
+    Json(request): Json<CreateSuppressionRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| {
+        store.add_watchlist_suppression(&watchlist_id, request)
+    })
+    .await;
+    match result {
+        Ok((watchlist, suppression)) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.watchlist.suppression.create",
+                    format!("watchlists/{}", watchlist.id),
+                    serde_json::json!({
+                        "watchlist_id": watchlist.id,
+                        "suppression_id": suppression.id,
+                        "kind": suppression.rule.kind(),
+                        "note": suppression.note,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(WatchlistResponse { watchlist })).into_response()
+        }
+        Err(error) => api_error_response(error),
+    }
 }
 
-async fn load_records<T>(pool: &PgPool, table: &str) -> Result<BTreeMap<String, T>, HelixError>
-where
-    T: DeserializeOwned + HasIntelRecordId,
-{
-    let sql = format!("SELECT record FROM {table} ORDER BY id");
-    let rows = sqlx::query(&sql).fetch_all(pool).await.map_err(db_error)?;
-    let mut records = BTreeMap::new();
-    for row in rows {
-        let record_value: serde_json::Value = row.try_get("record").map_err(db_error)?;
-        let record: T = serde_json::from_value(record_value).map_err(serde_error)?;
-        records.insert(record.record_id().to_string(), record);
+pub(crate) async fn delete_watchlist_suppression(
+    State(state): State<AppState>,
+    Path((watchlist_id, suppression_id)): Path<(String, String)>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| {
+        store.remove_watchlist_suppression(&watchlist_id, &suppression_id)
+    })
+    .await;
+    match result {
+        Ok(watchlist) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.watchlist.suppression.delete",
+                    format!("watchlists/{}", watchlist.id),
+                    serde_json::json!({
+                        "watchlist_id": watchlist.id,
+                        "suppression_id": suppression_id,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::OK, Json(WatchlistResponse { watchlist })).into_response()
+        }
+        Err(error) => api_error_response(error),
     }
-    Ok(records)
-}
-
-trait HasIntelRecordId {
-    fn record_id(&self) -> &str;
 }
 
-impl HasIntelRecordId for SourceDefinition {
-    fn record_id(&self) -> &str {
-        &self.id
+pub(crate) async fn list_suppressed_hits(
+    State(state): State<AppState>,
+    Path(watchlist_id): Path<String>,
+) -> Response {
+    let store = state.intel_desk.read().await;
+    match store.suppressed_hits_for(&watchlist_id) {
+        Ok(hits) => (StatusCode::OK, Json(SuppressedHitCatalogResponse { hits })).into_response(),
+        Err(error) => api_error_response(error),
     }
 }
 
-impl HasIntelRecordId for Watchlist {
-    fn record_id(&self) -> &str {
-        &self.id
-    }
+pub(crate) async fn list_entities(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(EntityCatalogResponse {
+            entities: store.entity_registry.entities().cloned().collect(),
+        }),
+    )
 }
 
-impl HasIntelRecordId for EvidenceItem {
-    fn record_id(&self) -> &str {
-        &self.id
+pub(crate) async fn upsert_entity_handler(
+    State(state): State<AppState>,
+    Json(request): Json<EntityRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| store.upsert_entity(request)).await;
+    match result {
+        Ok(entity) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.entity.upsert",
+                    format!("entities/{}", entity.id),
+                    serde_json::json!({
+                        "entity_id": entity.id,
+                        "kind": entity.kind,
+                        "aliases": entity.aliases,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(EntityResponse { entity })).into_response()
+        }
+        Err(error) => api_error_response(error),
     }
 }
 
-impl HasIntelRecordId for ClaimRecord {
-    fn record_id(&self) -> &str {
-        &self.id
+pub(crate) async fn merge_entity_handler(
+    State(state): State<AppState>,
+    Path(entity_id): Path<String>,
+    Json(request): Json<EntityMergeRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| {
+        store.merge_entity(&entity_id, request.merge_id.trim())
+    })
+    .await;
+    match result {
+        Ok(entity) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.entity.merge",
+                    format!("entities/{entity_id}/merge"),
+                    serde_json::json!({
+                        "entity_id": entity.id,
+                        "merged_id": request.merge_id.trim(),
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::OK, Json(EntityResponse { entity })).into_response()
+        }
+        Err(error) => api_error_response(error),
     }
 }
 
-impl HasIntelRecordId for CaseFile {
-    fn record_id(&self) -> &str {
-        &self.id
+pub(crate) async fn split_entity_handler(
+    State(state): State<AppState>,
+    Path(entity_id): Path<String>,
+    Json(request): Json<EntityRequest>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| store.split_entity(&entity_id, request)).await;
+    match result {
+        Ok(response) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.entity.split",
+                    format!("entities/{entity_id}/split"),
+                    serde_json::json!({
+                        "entity_id": response.entity.id,
+                        "split_id": response.split.id,
+                        "moved_aliases": response.split.aliases,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(response)).into_response()
+        }
+        Err(error) => api_error_response(error),
     }
 }
 
-fn json_string<T: Serialize>(value: &T) -> String {
-    serde_json::to_value(value)
-        .ok()
-        .and_then(|value| value.as_str().map(str::to_string))
-        .unwrap_or_else(|| "unknown".to_string())
-}
-
-fn db_error(error: sqlx::Error) -> HelixError {
-    HelixError::InternalError(format!("intel desk persistence error: {error}"))
-}
-
-fn serde_error(error: serde_json::Error) -> HelixError {
-    HelixError::InternalError(format!("intel desk serialization error: {error}"))
+pub(crate) async fn backtest_watchlist(
+    State(state): State<AppState>,
+    Json(request): Json<WatchlistBacktestRequest>,
+) -> Response {
+    let store = state.intel_desk.read().await;
+    match store.backtest_watchlist(request) {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error) => api_error_response(error),
+    }
 }
 
-async fn mutate_intel_desk<T>(
-    state: &AppState,
-    mutation: impl FnOnce(&mut IntelDeskStore) -> Result<T, HelixError>,
-) -> Result<T, HelixError> {
-    let persistence = state.intel_persistence.clone();
-    let mut store = state.intel_desk.write().await;
-    let rollback = persistence.as_ref().map(|_| store.clone());
-    let result = mutation(&mut store)?;
-
-    if let Some(persistence) = persistence {
-        if let Err(error) = persistence.save(&store).await {
-            if let Some(rollback) = rollback {
-                *store = rollback;
-            }
-            return Err(error);
+pub(crate) async fn list_evidence(
+    State(state): State<AppState>,
+    Query(filters): Query<EvidenceQueueFilterQuery>,
+) -> Response {
+    let store = state.intel_desk.read().await;
+    match store.evidence_queue(&filters) {
+        Ok(evidence) => {
+            (StatusCode::OK, Json(EvidenceCatalogResponse { evidence })).into_response()
         }
+        Err(error) => api_error_response(error),
     }
-
-    Ok(result)
 }
 
-pub(crate) async fn get_intel_overview(State(state): State<AppState>) -> impl IntoResponse {
+pub(crate) async fn list_claims(
+    State(state): State<AppState>,
+    Query(filters): Query<ClaimQueueFilterQuery>,
+) -> Response {
     let store = state.intel_desk.read().await;
-    (StatusCode::OK, Json(store.overview()))
+    match store.claim_queue(&filters) {
+        Ok(claims) => (StatusCode::OK, Json(ClaimCatalogResponse { claims })).into_response(),
+        Err(error) => api_error_response(error),
+    }
 }
 
-pub(crate) async fn get_market_intel_overview(State(state): State<AppState>) -> impl IntoResponse {
+pub(crate) async fn list_claim_graph(
+    State(state): State<AppState>,
+    Query(filters): Query<ClaimGraphQuery>,
+) -> Response {
     let store = state.intel_desk.read().await;
-    (StatusCode::OK, Json(store.market_intelligence_overview()))
+    match store.claim_assertions(&filters) {
+        Ok(assertions) => (StatusCode::OK, Json(ClaimGraphResponse { assertions })).into_response(),
+        Err(error) => api_error_response(error),
+    }
 }
 
-pub(crate) async fn generate_market_intel_brief_handler(
+pub(crate) async fn review_claim_handler(
     State(state): State<AppState>,
-    Path(case_id): Path<String>,
-    Json(request): Json<GenerateMarketIntelBriefRequest>,
+    Path(claim_id): Path<String>,
+    Json(request): Json<ClaimReviewRequest>,
 ) -> Response {
     let result = mutate_intel_desk(&state, |store| {
-        store.generate_market_brief(&case_id, request.attach_to_case)
+        let claim = store.review_claim(&claim_id, request.status)?;
+        let trust_adjustments = store.auto_calibrate_claim_source(&claim);
+        Ok((claim, trust_adjustments))
     })
     .await;
     match result {
-        Ok(response) => {
+        Ok((claim, trust_adjustments)) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.market_brief.generate",
-                    format!("cases/{case_id}/brief"),
+                    "intel.claim.review",
+                    format!("claims/{claim_id}/review"),
                     serde_json::json!({
-                        "case_id": case_id,
-                        "attach_to_case": request.attach_to_case,
-                        "transition": response.transition,
+                        "claim_id": claim.id,
+                        "review_status": claim.review_status,
+                        "evidence_id": claim.evidence_id,
                     }),
                 ),
             )
@@ -3505,42 +8926,53 @@ pub(crate) async fn generate_market_intel_brief_handler(
             {
                 return api_error_response(error);
             }
-            (StatusCode::OK, Json(response)).into_response()
+            if let Err(error) = record_trust_adjustments(&state, &trust_adjustments).await {
+                return api_error_response(error);
+            }
+            (
+                StatusCode::OK,
+                Json(ClaimResponse {
+                    claim,
+                    trust_adjustments,
+                }),
+            )
+                .into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn list_sources(State(state): State<AppState>) -> impl IntoResponse {
+pub(crate) async fn get_claim_votes(
+    State(state): State<AppState>,
+    Path(claim_id): Path<String>,
+) -> Response {
     let store = state.intel_desk.read().await;
-    (
-        StatusCode::OK,
-        Json(SourceCatalogResponse {
-            sources: store.sources.values().cloned().collect(),
-        }),
-    )
+    match store.claim_adjudication(&claim_id) {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error) => api_error_response(error),
+    }
 }
 
-pub(crate) async fn create_source(
+pub(crate) async fn cast_claim_vote_handler(
     State(state): State<AppState>,
-    Json(request): Json<CreateSourceRequest>,
+    Path(claim_id): Path<String>,
+    Json(request): Json<ClaimVoteRequest>,
 ) -> Response {
-    let result = mutate_intel_desk(&state, |store| store.create_source(request)).await;
+    let result = mutate_intel_desk(&state, |store| store.cast_claim_vote(&claim_id, request)).await;
     match result {
-        Ok(source) => {
+        Ok(response) => {
+            let vote = response.adjudication.votes.last();
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.source.create",
-                    format!("sources/{}", source.id),
+                    "intel.claim.vote",
+                    format!("claims/{claim_id}/votes"),
                     serde_json::json!({
-                        "source_id": source.id,
-                        "profile_id": source.profile_id,
-                        "kind": source.kind,
-                        "endpoint_url": source.endpoint_url,
-                        "credential_id": source.credential_id,
-                        "credential_header_name": source.credential_header_name,
-                        "enabled": source.enabled,
+                        "claim_id": response.claim.id,
+                        "reviewer": vote.map(|vote| vote.reviewer.as_str()),
+                        "choice": vote.map(|vote| vote.choice),
+                        "outcome": response.adjudication.outcome,
+                        "review_status": response.claim.review_status,
                     }),
                 ),
             )
@@ -3548,86 +8980,71 @@ pub(crate) async fn create_source(
             {
                 return api_error_response(error);
             }
-            (StatusCode::CREATED, Json(SourceResponse { source })).into_response()
+            if let Err(error) = record_trust_adjustments(&state, &response.trust_adjustments).await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(response)).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn collect_source_handler(
-    State(state): State<AppState>,
-    Path(source_id): Path<String>,
-    Json(request): Json<CollectSourceRequest>,
-) -> Response {
-    let limit = match normalize_collect_limit(request.max_items) {
-        Ok(limit) => limit,
-        Err(error) => return api_error_response(error),
-    };
-    let source = {
-        let store = state.intel_desk.read().await;
-        match store.sources.get(&source_id).cloned() {
-            Some(source) => source,
-            None => {
-                return api_error_response(HelixError::not_found(format!("source {source_id}")))
-            }
-        }
-    };
-    if !source.enabled {
-        return api_error_response(HelixError::validation_error("source", "source is disabled"));
-    }
-    let endpoint_url = match source.endpoint_url.clone() {
-        Some(endpoint_url) => endpoint_url,
-        None => {
-            return api_error_response(HelixError::validation_error(
-                "source.endpoint_url",
-                "source has no endpoint_url",
-            ));
-        }
-    };
-    let fetch_auth = match source_fetch_auth(&state, &source).await {
-        Ok(fetch_auth) => fetch_auth,
-        Err(error) => return api_error_response(error),
-    };
-    let payload = match fetch_source_body(&endpoint_url, fetch_auth.as_ref()).await {
-        Ok(payload) => payload,
-        Err(error) => return api_error_response(error),
-    };
-    let requests =
-        match collect_requests_from_payload(&source, &payload, &request.observed_at, limit) {
-            Ok(requests) => requests,
-            Err(error) => return api_error_response(error),
-        };
-    if requests.is_empty() {
-        return api_error_response(HelixError::validation_error(
-            "source.payload",
-            "source payload produced no evidence",
-        ));
+async fn record_trust_adjustments(
+    state: &AppState,
+    entries: &[TrustHistoryEntry],
+) -> Result<(), HelixError> {
+    for entry in entries {
+        record_audit_event(
+            state,
+            AuditEvent::allow(
+                "intel.source.trust_calibrated",
+                format!("sources/{}/trust-history", entry.source_id),
+                serde_json::json!({
+                    "source_id": entry.source_id,
+                    "previous_trust_score": entry.previous_trust_score,
+                    "trust_score": entry.trust_score,
+                    "origin": entry.origin,
+                    "posterior_bps": entry.posterior_bps,
+                    "history_id": entry.id,
+                }),
+            ),
+        )
+        .await?;
     }
+    Ok(())
+}
 
-    let result = mutate_intel_desk(&state, |store| {
-        requests
-            .into_iter()
-            .map(|request| store.ingest_evidence(request))
-            .collect::<Result<Vec<_>, _>>()
-    })
-    .await;
+pub(crate) async fn get_trust_calibration(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (StatusCode::OK, Json(store.trust_calibration(Utc::now())))
+}
+
+pub(crate) async fn get_trust_calibration_policy(
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(TrustCalibrationPolicyResponse {
+            policy: store.trust_policy,
+        }),
+    )
+}
 
+pub(crate) async fn put_trust_calibration_policy(
+    State(state): State<AppState>,
+    Json(request): Json<TrustCalibrationPolicy>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| store.set_trust_policy(request)).await;
     match result {
-        Ok(results) => {
-            let duplicate_count = results.iter().filter(|result| result.duplicate).count();
-            let collected_count = results.len();
+        Ok(policy) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.source.collect",
-                    format!("sources/{}/collect", source.id),
-                    serde_json::json!({
-                        "source_id": source.id,
-                        "fetched_url": endpoint_url,
-                        "credential_id": fetch_auth.as_ref().map(|auth| auth.credential_id.as_str()),
-                        "collected_count": collected_count,
-                        "duplicate_count": duplicate_count,
-                    }),
+                    "intel.source.trust_policy.update",
+                    "sources/trust-calibration/policy".to_string(),
+                    serde_json::json!({ "policy": policy }),
                 ),
             )
             .await
@@ -3635,14 +9052,8 @@ pub(crate) async fn collect_source_handler(
                 return api_error_response(error);
             }
             (
-                StatusCode::CREATED,
-                Json(CollectSourceResponse {
-                    source,
-                    fetched_url: endpoint_url,
-                    collected_count,
-                    duplicate_count,
-                    results,
-                }),
+                StatusCode::OK,
+                Json(TrustCalibrationPolicyResponse { policy }),
             )
                 .into_response()
         }
@@ -3650,154 +9061,76 @@ pub(crate) async fn collect_source_handler(
     }
 }
 
-pub(crate) async fn collect_due_sources_handler(
+pub(crate) async fn apply_trust_calibration_handler(
     State(state): State<AppState>,
-    Json(request): Json<CollectDueSourcesRequest>,
+    Json(request): Json<ApplyTrustCalibrationRequest>,
 ) -> Response {
-    if request.observed_at.trim().is_empty() {
-        return api_error_response(HelixError::validation_error(
-            "observed_at",
-            "observed_at is required",
-        ));
-    }
-    let limit = match normalize_collect_limit(request.max_items_per_source) {
-        Ok(limit) => limit,
-        Err(error) => return api_error_response(error),
-    };
-
-    let requested_ids = request
-        .source_ids
-        .iter()
-        .map(|source_id| source_id.trim().to_string())
-        .filter(|source_id| !source_id.is_empty())
-        .collect::<BTreeSet<_>>();
-    let sources = {
-        let store = state.intel_desk.read().await;
-        for source_id in &requested_ids {
-            if !store.sources.contains_key(source_id) {
-                return api_error_response(HelixError::not_found(format!("source {source_id}")));
+    let result = mutate_intel_desk(&state, |store| {
+        store.apply_trust_recommendations(
+            request.source_ids.as_deref(),
+            TrustAdjustmentOrigin::Accepted,
+        )
+    })
+    .await;
+    match result {
+        Ok(applied) => {
+            if let Err(error) = record_trust_adjustments(&state, &applied).await {
+                return api_error_response(error);
             }
+            (
+                StatusCode::OK,
+                Json(ApplyTrustCalibrationResponse { applied }),
+            )
+                .into_response()
         }
-        store.sources.values().cloned().collect::<Vec<_>>()
-    };
-
-    let mut skipped_count = 0usize;
-    let mut due_sources = Vec::new();
-    for source in sources {
-        if !requested_ids.is_empty() && !requested_ids.contains(&source.id) {
-            continue;
-        }
-        if !source.enabled
-            || !source_supports_pull_collection(&source)
-            || source.endpoint_url.is_none()
-            || !source_is_due_at_tick(&source, request.tick_minute)
-        {
-            skipped_count += 1;
-            continue;
-        }
-        due_sources.push(source);
+        Err(error) => api_error_response(error),
     }
+}
 
-    let mut pending = Vec::new();
-    for source in due_sources {
-        let endpoint_url = source
-            .endpoint_url
-            .clone()
-            .expect("due pull source has endpoint_url");
-        let fetch_auth = match source_fetch_auth(&state, &source).await {
-            Ok(fetch_auth) => fetch_auth,
-            Err(error) => return api_error_response(error),
-        };
-        let payload = match fetch_source_body(&endpoint_url, fetch_auth.as_ref()).await {
-            Ok(payload) => payload,
-            Err(error) => return api_error_response(error),
-        };
-        let requests =
-            match collect_requests_from_payload(&source, &payload, &request.observed_at, limit) {
-                Ok(requests) if requests.is_empty() => {
-                    return api_error_response(HelixError::validation_error(
-                        "source.payload",
-                        "source payload produced no evidence",
-                    ))
-                }
-                Ok(requests) => requests,
-                Err(error) => return api_error_response(error),
-            };
-        pending.push(PendingScheduledCollection {
-            schedule_phase_minute: source_schedule_phase_minute(&source),
-            source,
-            fetched_url: endpoint_url,
-            requests,
-        });
+pub(crate) async fn get_source_trust_history(
+    State(state): State<AppState>,
+    Path(source_id): Path<String>,
+    Query(query): Query<TrustHistoryQuery>,
+) -> Response {
+    let store = state.intel_desk.read().await;
+    match store.trust_history_report(&source_id, &query) {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error) => api_error_response(error),
     }
+}
 
-    let due_count = pending.len();
-    let result = mutate_intel_desk(&state, |store| {
-        pending
-            .into_iter()
-            .map(|pending| {
-                let results = pending
-                    .requests
-                    .into_iter()
-                    .map(|request| store.ingest_evidence(request))
-                    .collect::<Result<Vec<_>, _>>()?;
-                let duplicate_count = results.iter().filter(|result| result.duplicate).count();
-                Ok(ScheduledSourceCollection {
-                    source: pending.source,
-                    fetched_url: pending.fetched_url,
-                    schedule_phase_minute: pending.schedule_phase_minute,
-                    collected_count: results.len(),
-                    duplicate_count,
-                    results,
-                })
-            })
-            .collect::<Result<Vec<_>, HelixError>>()
-    })
-    .await;
+pub(crate) async fn get_adjudication_config(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(ClaimAdjudicationConfigResponse {
+            config: store.adjudication_config,
+        }),
+    )
+}
 
+pub(crate) async fn put_adjudication_config(
+    State(state): State<AppState>,
+    Json(request): Json<ClaimAdjudicationConfig>,
+) -> Response {
+    let result = mutate_intel_desk(&state, |store| store.set_adjudication_config(request)).await;
     match result {
-        Ok(collections) => {
-            let collected_count = collections
-                .iter()
-                .map(|collection| collection.collected_count)
-                .sum::<usize>();
-            let duplicate_count = collections
-                .iter()
-                .map(|collection| collection.duplicate_count)
-                .sum::<usize>();
+        Ok(config) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.source.collect_due",
-                    "sources/collect-due",
-                    serde_json::json!({
-                        "observed_at": request.observed_at.clone(),
-                        "tick_minute": request.tick_minute,
-                        "due_count": due_count,
-                        "skipped_count": skipped_count,
-                        "collected_count": collected_count,
-                        "duplicate_count": duplicate_count,
-                    }),
+                    "intel.claim.adjudication_config.update",
+                    "claims/adjudication-config".to_string(),
+                    serde_json::json!({ "config": config }),
                 ),
             )
             .await
             {
                 return api_error_response(error);
             }
-            let status = if collections.is_empty() {
-                StatusCode::OK
-            } else {
-                StatusCode::CREATED
-            };
             (
-                status,
-                Json(CollectDueSourcesResponse {
-                    observed_at: request.observed_at.trim().to_string(),
-                    tick_minute: request.tick_minute,
-                    due_count,
-                    skipped_count,
-                    collections,
-                }),
+                StatusCode::OK,
+                Json(ClaimAdjudicationConfigResponse { config }),
             )
                 .into_response()
         }
@@ -3805,59 +9138,25 @@ pub(crate) async fn collect_due_sources_handler(
     }
 }
 
-pub(crate) async fn webhook_ingest_handler(
+pub(crate) async fn ingest_evidence(
     State(state): State<AppState>,
-    Path(source_id): Path<String>,
-    Json(payload): Json<WebhookIngestPayload>,
+    Json(request): Json<IngestEvidenceRequest>,
 ) -> Response {
-    let source = {
-        let store = state.intel_desk.read().await;
-        match store.sources.get(&source_id).cloned() {
-            Some(source) => source,
-            None => {
-                return api_error_response(HelixError::not_found(format!("source {source_id}")))
-            }
-        }
-    };
-    if !source.enabled {
-        return api_error_response(HelixError::validation_error("source", "source is disabled"));
-    }
-    if source.kind != SourceKind::WebhookIngest {
-        return api_error_response(HelixError::validation_error(
-            "source.kind",
-            "source must be webhook_ingest",
-        ));
-    }
-
-    let requests = match webhook_ingest_requests(&source, payload) {
-        Ok(requests) => requests,
-        Err(error) => return api_error_response(error),
-    };
-    let result = mutate_intel_desk(&state, |store| {
-        requests
-            .into_iter()
-            .map(|request| store.ingest_evidence(request))
-            .collect::<Result<Vec<_>, _>>()
-    })
-    .await;
-
+    let result = mutate_intel_desk(&state, |store| store.ingest_evidence(request)).await;
     match result {
-        Ok(results) => {
-            let duplicate_count = results.iter().filter(|result| result.duplicate).count();
-            let accepted_count = results.len();
+        Ok(response) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.source.webhook_ingest",
-                    format!("sources/{}/webhook", source.id),
+                    "intel.evidence.ingest",
+                    format!("evidence/{}", response.evidence.id),
                     serde_json::json!({
-                        "source_id": source.id,
-                        "accepted_count": accepted_count,
-                        "duplicate_count": duplicate_count,
-                        "case_update_count": results
-                            .iter()
-                            .map(|result| result.case_updates.len())
-                            .sum::<usize>(),
+                        "evidence_id": response.evidence.id,
+                        "source_id": response.evidence.source_id,
+                        "duplicate": response.duplicate,
+                        "claim_count": response.claims.len(),
+                        "hit_count": response.hits.len(),
+                        "case_update_count": response.case_updates.len(),
                     }),
                 ),
             )
@@ -3865,65 +9164,102 @@ pub(crate) async fn webhook_ingest_handler(
             {
                 return api_error_response(error);
             }
-            (
-                StatusCode::CREATED,
-                Json(WebhookIngestResponse {
-                    source,
-                    accepted_count,
-                    duplicate_count,
-                    results,
-                }),
-            )
-                .into_response()
+            (StatusCode::CREATED, Json(response)).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn file_import_handler(
+pub(crate) async fn list_cases(
     State(state): State<AppState>,
-    Path(source_id): Path<String>,
-    Json(request): Json<FileImportRequest>,
+    Query(filters): Query<CaseQueueFilterQuery>,
 ) -> Response {
-    let source = {
-        let store = state.intel_desk.read().await;
-        match store.sources.get(&source_id).cloned() {
-            Some(source) => source,
-            None => {
-                return api_error_response(HelixError::not_found(format!("source {source_id}")))
-            }
-        }
-    };
-    if !source.enabled {
-        return api_error_response(HelixError::validation_error("source", "source is disabled"));
+    let store = state.intel_desk.read().await;
+    match store.case_queue(&filters) {
+        Ok(cases) => (StatusCode::OK, Json(CaseCatalogResponse { cases })).into_response(),
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn get_autopilot_review_queue(
+    State(state): State<AppState>,
+    Query(filters): Query<AutopilotReviewQueueQuery>,
+) -> Response {
+    let store = state.intel_desk.read().await;
+    match store.autopilot_review_queue(&filters) {
+        Ok(items) => (StatusCode::OK, Json(AutopilotReviewQueueResponse { items })).into_response(),
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn export_autopilot_review_packet(
+    State(state): State<AppState>,
+    Query(query): Query<AutopilotReviewExportQuery>,
+) -> Response {
+    if query.item_id.trim().is_empty() {
+        return api_error_response(HelixError::validation_error(
+            "item_id",
+            "item_id is required",
+        ));
+    }
+
+    let store = state.intel_desk.read().await;
+    match store.build_review_export_packet(
+        query.review_kind,
+        query.item_id.trim(),
+        query.redact_notes,
+    ) {
+        Ok(packet) => (StatusCode::OK, Json(packet)).into_response(),
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn transition_case_handler(
+    State(state): State<AppState>,
+    Path(case_id): Path<String>,
+    Json(request): Json<CaseTransitionRequest>,
+) -> Response {
+    if request.command.is_system_command() {
+        return api_error_response(HelixError::validation_error(
+            "case.command",
+            "command is issued by the desk and cannot be submitted",
+        ));
     }
-    if source.kind != SourceKind::FileImport {
+    let actor = request
+        .actor
+        .as_deref()
+        .map(str::trim)
+        .filter(|actor| !actor.is_empty())
+        .unwrap_or(DEFAULT_CASE_ACTOR)
+        .to_string();
+    if actor.len() > MAX_CASE_ACTOR_LEN {
         return api_error_response(HelixError::validation_error(
-            "source.kind",
-            "source must be file_import",
+            "case.actor".to_string(),
+            format!("must be at most {MAX_CASE_ACTOR_LEN} characters"),
         ));
     }
-
-    let ingest_request = match file_import_request(&source, request) {
-        Ok(request) => request,
-        Err(error) => return api_error_response(error),
-    };
-    let result = mutate_intel_desk(&state, |store| store.ingest_evidence(ingest_request)).await;
-
+    let result = mutate_intel_desk(&state, |store| {
+        store.operate_case(&case_id, request.command, &actor)
+    })
+    .await;
     match result {
-        Ok(result) => {
+        Ok(response) => {
+            let transition = &response.transition;
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.source.file_import",
-                    format!("sources/{}/import", source.id),
+                    "intel.case.transition",
+                    format!("cases/{case_id}/transition"),
                     serde_json::json!({
-                        "source_id": source.id,
-                        "evidence_id": result.evidence.id,
-                        "duplicate": result.duplicate,
-                        "claim_count": result.claims.len(),
-                        "hit_count": result.hits.len(),
-                        "case_update_count": result.case_updates.len(),
+                        "case_id": transition.case.id,
+                        "actor": actor,
+                        "status": transition.case.status,
+                        "decision": transition.decision,
+                        "related_case_ids": response
+                            .related
+                            .iter()
+                            .map(|related| related.case.id.clone())
+                            .collect::<Vec<_>>(),
                     }),
                 ),
             )
@@ -3931,100 +9267,201 @@ pub(crate) async fn file_import_handler(
             {
                 return api_error_response(error);
             }
-            (
-                StatusCode::CREATED,
-                Json(FileImportResponse { source, result }),
-            )
-                .into_response()
+            (StatusCode::OK, Json(response)).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn list_watchlists(State(state): State<AppState>) -> impl IntoResponse {
+pub(crate) async fn get_case_timeline(
+    State(state): State<AppState>,
+    Path(case_id): Path<String>,
+    Query(query): Query<CaseTimelineQuery>,
+) -> Response {
+    let store = state.intel_desk.read().await;
+    match store.case_timeline(&case_id, &query) {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error) => api_error_response(error),
+    }
+}
+
+pub(crate) async fn get_case_sla_policy(State(state): State<AppState>) -> impl IntoResponse {
     let store = state.intel_desk.read().await;
     (
         StatusCode::OK,
-        Json(WatchlistCatalogResponse {
-            watchlists: store.watchlists.values().cloned().collect(),
+        Json(CaseSlaPolicyResponse {
+            policy: store.case_sla_policy,
         }),
     )
 }
 
-pub(crate) async fn create_watchlist(
+pub(crate) async fn put_case_sla_policy(
     State(state): State<AppState>,
-    Json(request): Json<CreateWatchlistRequest>,
+    Json(request): Json<CaseSlaPolicy>,
 ) -> Response {
-    let result = mutate_intel_desk(&state, |store| store.create_watchlist(request)).await;
+    let result = mutate_intel_desk(&state, |store| store.set_case_sla_policy(request)).await;
     match result {
-        Ok(watchlist) => {
+        Ok(policy) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.watchlist.create",
-                    format!("watchlists/{}", watchlist.id),
-                    serde_json::json!({
-                        "watchlist_id": watchlist.id,
-                        "severity": watchlist.severity,
-                        "min_source_trust": watchlist.min_source_trust,
-                        "enabled": watchlist.enabled,
-                    }),
+                    "intel.case.sla_policy.update",
+                    "cases/sla/policy".to_string(),
+                    serde_json::json!({ "policy": policy }),
                 ),
             )
             .await
             {
                 return api_error_response(error);
             }
-            (StatusCode::CREATED, Json(WatchlistResponse { watchlist })).into_response()
+            (StatusCode::OK, Json(CaseSlaPolicyResponse { policy })).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn list_evidence(
+pub(crate) async fn tick_case_sla_handler(
     State(state): State<AppState>,
-    Query(filters): Query<EvidenceQueueFilterQuery>,
+    Json(request): Json<CaseSlaTickRequest>,
 ) -> Response {
-    let store = state.intel_desk.read().await;
-    match store.evidence_queue(&filters) {
-        Ok(evidence) => {
-            (StatusCode::OK, Json(EvidenceCatalogResponse { evidence })).into_response()
-        }
+    let now = match request.now.as_deref().map(str::trim) {
+        Some(value) => match DateTime::parse_from_rfc3339(value) {
+            Ok(value) => value.with_timezone(&Utc),
+            Err(_) => {
+                return api_error_response(HelixError::validation_error(
+                    "case_sla.now",
+                    "must be an RFC 3339 timestamp",
+                ))
+            }
+        },
+        None => Utc::now(),
+    };
+    match run_case_sla_tick(&state, now).await {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn list_claims(
+/// Advances case SLA windows to `now`, then sends one breach event per newly breached
+/// case through the automation rules and audits it.
+pub(crate) async fn run_case_sla_tick(
+    state: &AppState,
+    now: DateTime<Utc>,
+) -> Result<CaseSlaTickResponse, HelixError> {
+    // Most ticks land inside the current hour; skip the write lock and the save then.
+    let idle_open_count = {
+        let store = state.intel_desk.read().await;
+        (!store.case_slas_due(now)).then(|| store.open_case_sla_count())
+    };
+    let (breaches, open_count) = match idle_open_count {
+        Some(open_count) => (Vec::new(), open_count),
+        None => {
+            mutate_intel_desk_if_changed(state, |store| {
+                let (breaches, open_count, changed) = store.tick_case_slas(now);
+                Ok(((breaches, open_count), changed))
+            })
+            .await?
+        }
+    };
+    let mut events = Vec::with_capacity(breaches.len());
+    let mut trigger_plans = Vec::new();
+    for breach in &breaches {
+        let mut event = Event::new(
+            CASE_SLA_EVENT_SOURCE.to_string(),
+            CASE_SLA_BREACHED_EVENT.to_string(),
+            Some(serde_json::to_value(breach).map_err(serde_error)?),
+        );
+        event.subject = Some(format!("cases/{}", breach.case_id));
+        let evaluation = evaluate_automation_event(state, &event).await?;
+        record_audit_event(
+            state,
+            AuditEvent::allow(
+                "intel.case.sla.breach",
+                format!("cases/{}", breach.case_id),
+                serde_json::json!({
+                    "event_id": event.id,
+                    "severity": breach.severity,
+                    "assignee": breach.assignee,
+                    "watch_group": breach.watch_group,
+                    "deadline_at": breach.deadline_at,
+                    "trigger_plan_count": evaluation.trigger_plans.len(),
+                }),
+            ),
+        )
+        .await?;
+        trigger_plans.extend(evaluation.trigger_plans);
+        events.push(event);
+    }
+    Ok(CaseSlaTickResponse {
+        now: now.to_rfc3339_opts(SecondsFormat::Secs, true),
+        open_count,
+        breaches,
+        events,
+        trigger_plans,
+    })
+}
+
+pub(crate) async fn list_rule_packs(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(RulePackCatalogResponse {
+            rule_packs: store.rule_packs.values().cloned().collect(),
+        }),
+    )
+}
+
+pub(crate) async fn upsert_rule_pack_handler(
     State(state): State<AppState>,
-    Query(filters): Query<ClaimQueueFilterQuery>,
+    Json(request): Json<IntelRulePack>,
 ) -> Response {
-    let store = state.intel_desk.read().await;
-    match store.claim_queue(&filters) {
-        Ok(claims) => (StatusCode::OK, Json(ClaimCatalogResponse { claims })).into_response(),
+    let result = mutate_intel_desk(&state, |store| store.upsert_rule_pack(request)).await;
+    match result {
+        Ok(rule_pack) => {
+            if let Err(error) = record_audit_event(
+                &state,
+                AuditEvent::allow(
+                    "intel.rule_pack.upsert",
+                    format!("rule-packs/{}", rule_pack.id),
+                    serde_json::json!({
+                        "rule_pack_id": rule_pack.id,
+                        "rule_count": rule_pack.rules.len(),
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(RulePackResponse { rule_pack })).into_response()
+        }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn review_claim_handler(
+pub(crate) async fn case_reasoning_handler(
     State(state): State<AppState>,
-    Path(claim_id): Path<String>,
-    Json(request): Json<ClaimReviewRequest>,
+    Path(case_id): Path<String>,
+    Json(request): Json<CaseReasoningRequest>,
 ) -> Response {
     let result = mutate_intel_desk(&state, |store| {
-        store.review_claim(&claim_id, request.status)
+        store.evaluate_case_reasoning(&case_id, request)
     })
     .await;
     match result {
-        Ok(claim) => {
+        Ok(response) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.claim.review",
-                    format!("claims/{claim_id}/review"),
+                    "intel.case.reasoning",
+                    format!("cases/{case_id}/reasoning"),
                     serde_json::json!({
-                        "claim_id": claim.id,
-                        "review_status": claim.review_status,
-                        "evidence_id": claim.evidence_id,
+                        "case_id": response.case.id,
+                        "rule_pack_id": response.gate.rule_pack_id,
+                        "query": response.gate.query,
+                        "verdict": response.gate.decision.verdict,
+                        "claim_ids": response.gate.claim_ids,
+                        "program_fingerprint": response.gate.decision.trace.program_fingerprint,
                     }),
                 ),
             )
@@ -4032,104 +9469,227 @@ pub(crate) async fn review_claim_handler(
             {
                 return api_error_response(error);
             }
-            (StatusCode::OK, Json(ClaimResponse { claim })).into_response()
+            (StatusCode::OK, Json(response)).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn ingest_evidence(
+pub(crate) async fn get_decay_policy(State(state): State<AppState>) -> impl IntoResponse {
+    let store = state.intel_desk.read().await;
+    (
+        StatusCode::OK,
+        Json(DecayPolicyResponse {
+            policy: store.decay_policy.clone(),
+        }),
+    )
+}
+
+pub(crate) async fn put_decay_policy(
     State(state): State<AppState>,
-    Json(request): Json<IngestEvidenceRequest>,
+    Json(request): Json<DecayPolicy>,
 ) -> Response {
-    let result = mutate_intel_desk(&state, |store| store.ingest_evidence(request)).await;
+    let result = mutate_intel_desk(&state, |store| store.set_decay_policy(request)).await;
     match result {
-        Ok(response) => {
+        Ok(policy) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.evidence.ingest",
-                    format!("evidence/{}", response.evidence.id),
-                    serde_json::json!({
-                        "evidence_id": response.evidence.id,
-                        "source_id": response.evidence.source_id,
-                        "duplicate": response.duplicate,
-                        "claim_count": response.claims.len(),
-                        "hit_count": response.hits.len(),
-                        "case_update_count": response.case_updates.len(),
-                    }),
+                    "intel.decay_policy.update",
+                    "decay/policy".to_string(),
+                    serde_json::json!({ "policy": policy }),
                 ),
             )
             .await
             {
                 return api_error_response(error);
             }
-            (StatusCode::CREATED, Json(response)).into_response()
+            (StatusCode::OK, Json(DecayPolicyResponse { policy })).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn list_cases(
+pub(crate) async fn apply_decay_handler(
     State(state): State<AppState>,
-    Query(filters): Query<CaseQueueFilterQuery>,
+    Json(request): Json<DecayApplyRequest>,
 ) -> Response {
-    let store = state.intel_desk.read().await;
-    match store.case_queue(&filters) {
-        Ok(cases) => (StatusCode::OK, Json(CaseCatalogResponse { cases })).into_response(),
-        Err(error) => api_error_response(error),
+    let result = if request.dry_run {
+        state.intel_desk.read().await.decay_report(&request)
+    } else {
+        mutate_intel_desk(&state, |store| store.apply_decay(&request)).await
+    };
+    let report = match result {
+        Ok(report) => report,
+        Err(error) => return api_error_response(error),
+    };
+    if report.dry_run {
+        return (StatusCode::OK, Json(report)).into_response();
+    }
+
+    let claim_events = report.claims.iter().map(|decayed| {
+        AuditEvent::allow(
+            "intel.claim.decay_review",
+            format!("claims/{}/review", decayed.claim_id),
+            serde_json::json!({
+                "claim_id": decayed.claim_id,
+                "evidence_id": decayed.evidence_id,
+                "review_status": ClaimReviewStatus::NeedsReview,
+                "confidence_bps": decayed.confidence_bps,
+                "decayed_confidence_bps": decayed.decayed_confidence_bps,
+                "as_of": report.as_of,
+            }),
+        )
+    });
+    let case_events = report.expired_evidence.iter().flat_map(|expired| {
+        expired.detached_case_ids.iter().map(|case_id| {
+            AuditEvent::allow(
+                "intel.case.evidence_expired",
+                format!("cases/{case_id}/evidence/{}", expired.evidence_id),
+                serde_json::json!({
+                    "case_id": case_id,
+                    "evidence_id": expired.evidence_id,
+                    "relevance_bps": expired.relevance_bps,
+                    "as_of": report.as_of,
+                }),
+            )
+        })
+    });
+    let events = claim_events.chain(case_events).collect::<Vec<_>>();
+    for event in events {
+        if let Err(error) = record_audit_event(&state, event).await {
+            return api_error_response(error);
+        }
     }
+    (StatusCode::OK, Json(report)).into_response()
 }
 
-pub(crate) async fn get_autopilot_review_queue(
+pub(crate) async fn export_market_brief_packet_handler(
     State(state): State<AppState>,
-    Query(filters): Query<AutopilotReviewQueueQuery>,
+    Path(case_id): Path<String>,
+    Query(query): Query<MarketBriefExportQuery>,
 ) -> Response {
     let store = state.intel_desk.read().await;
-    match store.autopilot_review_queue(&filters) {
-        Ok(items) => (StatusCode::OK, Json(AutopilotReviewQueueResponse { items })).into_response(),
+    match store.build_market_brief_export_packet(&case_id, query.redact_notes) {
+        Ok(packet) => (StatusCode::OK, Json(packet)).into_response(),
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn export_autopilot_review_packet(
+pub(crate) async fn export_case_dossier_handler(
     State(state): State<AppState>,
-    Query(query): Query<AutopilotReviewExportQuery>,
+    Path(case_id): Path<String>,
+    Query(query): Query<CaseDossierQuery>,
 ) -> Response {
-    if query.item_id.trim().is_empty() {
-        return api_error_response(HelixError::validation_error(
-            "item_id",
-            "item_id is required",
-        ));
+    let store = state.intel_desk.read().await;
+    let rendered = store
+        .case_dossier(&case_id, query.redact_notes)
+        .and_then(|dossier| dossier.render(query.format));
+    match rendered {
+        Ok(body) => (
+            StatusCode::OK,
+            [
+                (
+                    header::CONTENT_TYPE,
+                    query.format.content_type().to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"{}-dossier.{}\"",
+                        slugify(&case_id),
+                        query.format.extension()
+                    ),
+                ),
+            ],
+            body,
+        )
+            .into_response(),
+        Err(error) => api_error_response(error),
     }
+}
+
+pub(crate) async fn verify_case_dossier_handler(Json(data): Json<DossierData>) -> Response {
+    (StatusCode::OK, Json(verify_dossier(&data))).into_response()
+}
 
+pub(crate) async fn list_notes(
+    State(state): State<AppState>,
+    Query(filters): Query<NoteQueueFilterQuery>,
+) -> Response {
     let store = state.intel_desk.read().await;
-    match store.build_review_export_packet(query.review_kind, query.item_id.trim()) {
-        Ok(packet) => (StatusCode::OK, Json(packet)).into_response(),
+    match store.note_queue(&filters) {
+        Ok(notes) => (StatusCode::OK, Json(NoteCatalogResponse { notes })).into_response(),
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn transition_case_handler(
+pub(crate) async fn list_case_notes(
     State(state): State<AppState>,
     Path(case_id): Path<String>,
-    Json(request): Json<CaseTransitionRequest>,
 ) -> Response {
-    let result = mutate_intel_desk(&state, |store| {
-        store.transition_case(&case_id, request.command)
-    })
-    .await;
+    target_notes_response(&state, NoteTargetKind::Case, &case_id).await
+}
+
+pub(crate) async fn create_case_note(
+    State(state): State<AppState>,
+    Path(case_id): Path<String>,
+    Json(request): Json<CreateNoteRequest>,
+) -> Response {
+    create_note_response(&state, NoteTargetKind::Case, &case_id, request).await
+}
+
+pub(crate) async fn list_evidence_notes(
+    State(state): State<AppState>,
+    Path(evidence_id): Path<String>,
+) -> Response {
+    target_notes_response(&state, NoteTargetKind::Evidence, &evidence_id).await
+}
+
+pub(crate) async fn create_evidence_note(
+    State(state): State<AppState>,
+    Path(evidence_id): Path<String>,
+    Json(request): Json<CreateNoteRequest>,
+) -> Response {
+    create_note_response(&state, NoteTargetKind::Evidence, &evidence_id, request).await
+}
+
+pub(crate) async fn list_claim_notes(
+    State(state): State<AppState>,
+    Path(claim_id): Path<String>,
+) -> Response {
+    target_notes_response(&state, NoteTargetKind::Claim, &claim_id).await
+}
+
+pub(crate) async fn create_claim_note(
+    State(state): State<AppState>,
+    Path(claim_id): Path<String>,
+    Json(request): Json<CreateNoteRequest>,
+) -> Response {
+    create_note_response(&state, NoteTargetKind::Claim, &claim_id, request).await
+}
+
+pub(crate) async fn update_note_handler(
+    State(state): State<AppState>,
+    Path(note_id): Path<String>,
+    Json(request): Json<UpdateNoteRequest>,
+) -> Response {
+    let editor = request.editor.trim().to_string();
+    let result = mutate_intel_desk(&state, |store| store.update_note(&note_id, request)).await;
     match result {
-        Ok(transition) => {
+        Ok(note) => {
             if let Err(error) = record_audit_event(
                 &state,
                 AuditEvent::allow(
-                    "intel.case.transition",
-                    format!("cases/{case_id}/transition"),
+                    "intel.note.update",
+                    format!("notes/{}", note.id),
                     serde_json::json!({
-                        "case_id": transition.case.id,
-                        "status": transition.case.status,
-                        "decision": transition.decision,
+                        "note_id": note.id,
+                        "editor": editor,
+                        "target_kind": note.target_kind,
+                        "target_id": note.target_id,
+                        "revision_count": note.revisions.len(),
+                        "pinned": note.pinned,
                     }),
                 ),
             )
@@ -4137,19 +9697,55 @@ pub(crate) async fn transition_case_handler(
             {
                 return api_error_response(error);
             }
-            (StatusCode::OK, Json(CaseTransitionResponse { transition })).into_response()
+            (StatusCode::OK, Json(note)).into_response()
         }
         Err(error) => api_error_response(error),
     }
 }
 
-pub(crate) async fn export_market_brief_packet_handler(
-    State(state): State<AppState>,
-    Path(case_id): Path<String>,
+async fn target_notes_response(
+    state: &AppState,
+    kind: NoteTargetKind,
+    target_id: &str,
 ) -> Response {
     let store = state.intel_desk.read().await;
-    match store.build_market_brief_export_packet(&case_id) {
-        Ok(packet) => (StatusCode::OK, Json(packet)).into_response(),
+    match store.target_notes(kind, target_id) {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error) => api_error_response(error),
+    }
+}
+
+async fn create_note_response(
+    state: &AppState,
+    kind: NoteTargetKind,
+    target_id: &str,
+    request: CreateNoteRequest,
+) -> Response {
+    let result =
+        mutate_intel_desk(state, |store| store.create_note(kind, target_id, request)).await;
+    match result {
+        Ok(note) => {
+            if let Err(error) = record_audit_event(
+                state,
+                AuditEvent::allow(
+                    "intel.note.create",
+                    format!("notes/{}", note.id),
+                    serde_json::json!({
+                        "note_id": note.id,
+                        "author": note.author,
+                        "target_kind": note.target_kind,
+                        "target_id": note.target_id,
+                        "parent_id": note.parent_id,
+                        "mentions": note.mentions,
+                    }),
+                ),
+            )
+            .await
+            {
+                return api_error_response(error);
+            }
+            (StatusCode::CREATED, Json(note)).into_response()
+        }
         Err(error) => api_error_response(error),
     }
 }
@@ -0,0 +1,716 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Readable case dossiers rendered as a single HTML file or as Markdown.
+//!
+//! Each record shown in a dossier is also carried in the file as canonical JSON, and
+//! hashed into an integrity manifest. Anyone holding the file can recompute the hashes
+//! from the embedded records without access to the desk.
+
+use crate::intel::{CaseQueueEntry, ClaimQueueEntry, EvidenceQueueEntry, MarketIntelCaseBrief};
+use helix_core::intel_case_timeline::CaseEvent;
+use helix_core::intel_desk::{CaseCommand, CaseDecision};
+use helix_core::intel_notes::AnalystNote;
+use helix_core::intel_priority::IntelPriorityBreakdown;
+use helix_core::HelixError;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub(crate) const DOSSIER_HASH_ALGORITHM: &str = "sha256";
+/// Id of the HTML element, and the Markdown heading, that carries the embedded records.
+pub(crate) const DOSSIER_DATA_ID: &str = "helix-dossier-data";
+
+const DOSSIER_STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:960px;margin:2rem auto;padding:0 1rem;color:#1b1f24;line-height:1.5}\
+h1{font-size:1.6rem}h2{border-bottom:1px solid #d0d7de;padding-bottom:.25rem;margin-top:2rem}\
+table{border-collapse:collapse;width:100%;font-size:.9rem}th,td{border:1px solid #d0d7de;padding:.3rem .5rem;text-align:left;vertical-align:top}\
+code{font-family:ui-monospace,monospace;font-size:.85rem;word-break:break-all}\
+.muted{color:#57606a}.pinned{font-weight:600}";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DossierFormat {
+    #[default]
+    Html,
+    Markdown,
+}
+
+impl DossierFormat {
+    pub(crate) fn content_type(self) -> &'static str {
+        match self {
+            Self::Html => "text/html; charset=utf-8",
+            Self::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+/// Everything a dossier shows, collected from the desk at export time.
+#[derive(Debug, Clone)]
+pub(crate) struct CaseDossier {
+    pub(crate) case: CaseQueueEntry,
+    pub(crate) market_brief: Option<MarketIntelCaseBrief>,
+    pub(crate) timeline: Vec<CaseEvent>,
+    /// Ranked, highest priority first.
+    pub(crate) evidence: Vec<EvidenceQueueEntry>,
+    /// Ranked, highest priority first.
+    pub(crate) claims: Vec<ClaimQueueEntry>,
+    pub(crate) notes: Vec<AnalystNote>,
+    pub(crate) notes_redacted: bool,
+    pub(crate) generated_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DossierItem {
+    pub(crate) kind: String,
+    pub(crate) id: String,
+    pub(crate) record: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DossierManifestEntry {
+    pub(crate) kind: String,
+    pub(crate) id: String,
+    pub(crate) sha256: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DossierManifest {
+    pub(crate) algorithm: String,
+    pub(crate) entries: Vec<DossierManifestEntry>,
+    /// Hash over every entry's `kind:id:sha256` line, in order.
+    pub(crate) root_sha256: String,
+}
+
+/// The records embedded in a dossier together with their manifest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DossierData {
+    pub(crate) case_id: String,
+    pub(crate) items: Vec<DossierItem>,
+    pub(crate) manifest: DossierManifest,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DossierVerification {
+    pub(crate) valid: bool,
+    pub(crate) root_sha256: String,
+    pub(crate) computed_root_sha256: String,
+    /// `kind:id` of every item whose hash or position no longer matches the manifest.
+    pub(crate) mismatched_items: Vec<String>,
+}
+
+impl CaseDossier {
+    /// Records in dossier order: the case, the market brief, timeline events, evidence,
+    /// claims, then notes.
+    pub(crate) fn data(&self) -> Result<DossierData, HelixError> {
+        let mut items = vec![dossier_item("case", &self.case.case.id, &self.case)?];
+        if let Some(brief) = &self.market_brief {
+            items.push(dossier_item("market_brief", &brief.case_id, brief)?);
+        }
+        for event in &self.timeline {
+            items.push(dossier_item(
+                "timeline_event",
+                &event.sequence.to_string(),
+                event,
+            )?);
+        }
+        for entry in &self.evidence {
+            items.push(dossier_item("evidence", &entry.evidence.id, entry)?);
+        }
+        for entry in &self.claims {
+            items.push(dossier_item("claim", &entry.claim.id, entry)?);
+        }
+        for note in &self.notes {
+            items.push(dossier_item("note", &note.id, note)?);
+        }
+        let entries = items
+            .iter()
+            .map(|item| DossierManifestEntry {
+                kind: item.kind.clone(),
+                id: item.id.clone(),
+                sha256: record_sha256(&item.record),
+            })
+            .collect::<Vec<_>>();
+        Ok(DossierData {
+            case_id: self.case.case.id.clone(),
+            items,
+            manifest: DossierManifest {
+                algorithm: DOSSIER_HASH_ALGORITHM.to_string(),
+                root_sha256: manifest_root(&entries),
+                entries,
+            },
+        })
+    }
+
+    pub(crate) fn render(&self, format: DossierFormat) -> Result<String, HelixError> {
+        let data = self.data()?;
+        Ok(match format {
+            DossierFormat::Html => self.render_html(&data),
+            DossierFormat::Markdown => self.render_markdown(&data),
+        })
+    }
+
+    fn render_html(&self, data: &DossierData) -> String {
+        let case = &self.case.case;
+        let mut out = String::new();
+        out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        out.push_str(&format!(
+            "<title>Case dossier: {}</title>\n<style>{DOSSIER_STYLE}</style>\n</head>\n<body>\n",
+            html(&case.title)
+        ));
+        out.push_str(&format!(
+            "<h1>Case dossier: {}</h1>\n<table>\n",
+            html(&case.title)
+        ));
+        for (label, value) in self.summary_rows() {
+            out.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                html(label),
+                html(&value)
+            ));
+        }
+        out.push_str("</table>\n");
+
+        out.push_str("<h2>Brief</h2>\n");
+        out.push_str(&format!("<p>{}</p>\n", html(&self.brief_text())));
+        if let Some(brief) = &self.market_brief {
+            out.push_str(&format!(
+                "<p class=\"muted\">Market brief: {}</p>\n",
+                html(&brief.summary)
+            ));
+        }
+
+        out.push_str("<h2>Priority</h2>\n<table>\n");
+        for (label, value) in priority_rows(&self.case.priority) {
+            out.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                html(label),
+                html(&value)
+            ));
+        }
+        out.push_str("</table>\n");
+
+        out.push_str("<h2>Reasoning decisions</h2>\n");
+        let reasoning = self.reasoning_rows();
+        if reasoning.is_empty() {
+            out.push_str("<p class=\"muted\">No reasoning gate has been evaluated.</p>\n");
+        } else {
+            out.push_str("<table>\n<tr><th>Evaluated</th><th>Rule pack</th><th>Query</th><th>Verdict</th><th>Confidence</th><th>Rationale</th></tr>\n");
+            for row in reasoning {
+                out.push_str(&html_row(&row));
+            }
+            out.push_str("</table>\n");
+        }
+
+        out.push_str("<h2>Timeline</h2>\n");
+        out.push_str("<table>\n<tr><th>#</th><th>Recorded</th><th>Actor</th><th>Command</th><th>Decision</th></tr>\n");
+        for event in &self.timeline {
+            out.push_str(&html_row(&timeline_row(event)));
+        }
+        out.push_str("</table>\n");
+
+        out.push_str("<h2>Evidence</h2>\n");
+        out.push_str("<table>\n<tr><th>Rank</th><th>Evidence</th><th>Source</th><th>Observed</th><th>Priority</th><th>Provenance hash</th></tr>\n");
+        for (index, entry) in self.evidence.iter().enumerate() {
+            let evidence = &entry.evidence;
+            let title = match evidence.url.as_deref() {
+                Some(url) if is_web_url(url) => format!(
+                    "<a href=\"{}\">{}</a><br><code>{}</code>",
+                    html(url),
+                    html(&evidence.title),
+                    html(url)
+                ),
+                _ => html(&evidence.title),
+            };
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}<br><span class=\"muted\">{}</span></td><td>{} (trust {})</td><td>{}</td><td>{}</td><td><code>{}</code></td></tr>\n",
+                index + 1,
+                title,
+                html(&evidence.summary),
+                html(&entry.source_name),
+                entry.source_trust_score,
+                html(&evidence.observed_at),
+                entry.priority.total,
+                html(&evidence.provenance_hash)
+            ));
+        }
+        out.push_str("</table>\n");
+
+        out.push_str("<h2>Claims</h2>\n");
+        out.push_str("<table>\n<tr><th>Rank</th><th>Claim</th><th>Review status</th><th>Confidence</th><th>Priority</th><th>Evidence</th></tr>\n");
+        for (index, row) in self.claim_rows().into_iter().enumerate() {
+            let mut cells = vec![(index + 1).to_string()];
+            cells.extend(row);
+            out.push_str(&html_row(&cells));
+        }
+        out.push_str("</table>\n");
+
+        out.push_str("<h2>Notes</h2>\n");
+        if self.notes.is_empty() {
+            out.push_str("<p class=\"muted\">No analyst notes.</p>\n");
+        } else {
+            out.push_str("<ul>\n");
+            for note in &self.notes {
+                out.push_str(&format!(
+                    "<li{}>{}</li>\n",
+                    if note.pinned { " class=\"pinned\"" } else { "" },
+                    html(&note_line(note))
+                ));
+            }
+            out.push_str("</ul>\n");
+        }
+
+        out.push_str("<h2>Integrity manifest</h2>\n");
+        out.push_str(&format!(
+            "<p>Each record below is embedded in <code>#{DOSSIER_DATA_ID}</code> as canonical JSON and hashed with {}. Root hash: <code>{}</code></p>\n",
+            html(&data.manifest.algorithm),
+            html(&data.manifest.root_sha256)
+        ));
+        out.push_str("<table>\n<tr><th>Kind</th><th>Id</th><th>SHA-256</th></tr>\n");
+        for entry in &data.manifest.entries {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td><code>{}</code></td></tr>\n",
+                html(&entry.kind),
+                html(&entry.id),
+                html(&entry.sha256)
+            ));
+        }
+        out.push_str("</table>\n");
+        out.push_str(&format!(
+            "<script type=\"application/json\" id=\"{DOSSIER_DATA_ID}\">{}</script>\n",
+            script_json(data)
+        ));
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    fn render_markdown(&self, data: &DossierData) -> String {
+        let mut out = format!("# Case dossier: {}\n\n", markdown(&self.case.case.title));
+        out.push_str(&markdown_table(
+            &["Field", "Value"],
+            self.summary_rows()
+                .into_iter()
+                .map(|(label, value)| vec![label.to_string(), value])
+                .collect(),
+        ));
+
+        out.push_str("\n## Brief\n\n");
+        out.push_str(&format!("{}\n", markdown(&self.brief_text())));
+        if let Some(brief) = &self.market_brief {
+            out.push_str(&format!("\nMarket brief: {}\n", markdown(&brief.summary)));
+        }
+
+        out.push_str("\n## Priority\n\n");
+        out.push_str(&markdown_table(
+            &["Component", "Value"],
+            priority_rows(&self.case.priority)
+                .into_iter()
+                .map(|(label, value)| vec![label.to_string(), value])
+                .collect(),
+        ));
+
+        out.push_str("\n## Reasoning decisions\n\n");
+        let reasoning = self.reasoning_rows();
+        if reasoning.is_empty() {
+            out.push_str("No reasoning gate has been evaluated.\n");
+        } else {
+            out.push_str(&markdown_table(
+                &[
+                    "Evaluated",
+                    "Rule pack",
+                    "Query",
+                    "Verdict",
+                    "Confidence",
+                    "Rationale",
+                ],
+                reasoning,
+            ));
+        }
+
+        out.push_str("\n## Timeline\n\n");
+        out.push_str(&markdown_table(
+            &["#", "Recorded", "Actor", "Command", "Decision"],
+            self.timeline.iter().map(timeline_row).collect(),
+        ));
+
+        out.push_str("\n## Evidence\n\n");
+        out.push_str(&markdown_table(
+            &[
+                "Rank",
+                "Evidence",
+                "URL",
+                "Source",
+                "Observed",
+                "Priority",
+                "Provenance hash",
+            ],
+            self.evidence
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    vec![
+                        (index + 1).to_string(),
+                        entry.evidence.title.clone(),
+                        entry.evidence.url.clone().unwrap_or_default(),
+                        format!("{} (trust {})", entry.source_name, entry.source_trust_score),
+                        entry.evidence.observed_at.clone(),
+                        entry.priority.total.to_string(),
+                        entry.evidence.provenance_hash.clone(),
+                    ]
+                })
+                .collect(),
+        ));
+
+        out.push_str("\n## Claims\n\n");
+        out.push_str(&markdown_table(
+            &[
+                "Rank",
+                "Claim",
+                "Review status",
+                "Confidence",
+                "Priority",
+                "Evidence",
+            ],
+            self.claim_rows()
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    let mut cells = vec![(index + 1).to_string()];
+                    cells.extend(row);
+                    cells
+                })
+                .collect(),
+        ));
+
+        out.push_str("\n## Notes\n\n");
+        if self.notes.is_empty() {
+            out.push_str("No analyst notes.\n");
+        } else {
+            for note in &self.notes {
+                let line = markdown(&note_line(note));
+                if note.pinned {
+                    out.push_str(&format!("- **{line}**\n"));
+                } else {
+                    out.push_str(&format!("- {line}\n"));
+                }
+            }
+        }
+
+        out.push_str("\n## Integrity manifest\n\n");
+        out.push_str(&format!(
+            "Each record is embedded below as canonical JSON and hashed with {}. Root hash: `{}`\n\n",
+            data.manifest.algorithm, data.manifest.root_sha256
+        ));
+        out.push_str(&markdown_table(
+            &["Kind", "Id", "SHA-256"],
+            data.manifest
+                .entries
+                .iter()
+                .map(|entry| vec![entry.kind.clone(), entry.id.clone(), entry.sha256.clone()])
+                .collect(),
+        ));
+        let json = serde_json::to_string_pretty(data).unwrap_or_default();
+        let fence = "`".repeat(longest_backtick_run(&json).max(2) + 1);
+        out.push_str(&format!(
+            "\n### {DOSSIER_DATA_ID}\n\n{fence}json\n{json}\n{fence}\n"
+        ));
+        out
+    }
+
+    fn summary_rows(&self) -> Vec<(&'static str, String)> {
+        let case = &self.case.case;
+        let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "none".to_string());
+        vec![
+            ("Case id", case.id.clone()),
+            ("Status", snake_label(&case.status)),
+            (
+                "Watchlist",
+                format!("{} ({})", self.case.watchlist_name, case.watchlist_id),
+            ),
+            ("Severity", snake_label(&self.case.severity)),
+            ("Primary entity", optional(&case.primary_entity)),
+            ("Assignee", optional(&case.assignee)),
+            ("Watch group", optional(&case.watch_group)),
+            (
+                "Briefing SLA",
+                self.case
+                    .sla
+                    .as_ref()
+                    .map(|sla| format!("{} by {}", snake_label(&sla.status), sla.deadline_at))
+                    .unwrap_or_else(|| "none".to_string()),
+            ),
+            ("Latest signal", optional(&self.case.latest_signal_at)),
+            ("Latest reason", case.latest_reason.clone()),
+            (
+                "Notes",
+                if self.notes_redacted {
+                    "redacted".to_string()
+                } else {
+                    "included".to_string()
+                },
+            ),
+            ("Generated", self.generated_at.clone()),
+        ]
+    }
+
+    fn brief_text(&self) -> String {
+        self.case
+            .case
+            .briefing_summary
+            .clone()
+            .unwrap_or_else(|| "No brief attached.".to_string())
+    }
+
+    /// Every reasoning gate the case log recorded, oldest first. Cases whose log starts
+    /// after their last gate show that gate from the case itself.
+    fn reasoning_rows(&self) -> Vec<Vec<String>> {
+        let mut gates = self
+            .timeline
+            .iter()
+            .filter_map(|event| match &event.command {
+                CaseCommand::AttachReasoningGate { gate } => Some(gate.as_ref()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if gates.is_empty() {
+            gates.extend(self.case.case.reasoning_gate.as_ref());
+        }
+        gates
+            .into_iter()
+            .map(|gate| {
+                vec![
+                    gate.evaluated_at.clone(),
+                    gate.rule_pack_id.clone(),
+                    gate.query.clone(),
+                    snake_label(&gate.decision.verdict),
+                    format!("{:.2}", gate.decision.confidence),
+                    gate.decision.rationale.clone(),
+                ]
+            })
+            .collect()
+    }
+
+    fn claim_rows(&self) -> Vec<Vec<String>> {
+        self.claims
+            .iter()
+            .map(|entry| {
+                let claim = &entry.claim;
+                vec![
+                    format!("{} {} {}", claim.subject, claim.predicate, claim.object),
+                    if entry.contested {
+                        format!("{} (contested)", snake_label(&claim.review_status))
+                    } else {
+                        snake_label(&claim.review_status)
+                    },
+                    format!("{} bps", claim.confidence_bps),
+                    entry.priority.total.to_string(),
+                    claim.evidence_id.clone(),
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Recomputes every item hash and the root hash of a dossier's embedded records.
+pub(crate) fn verify_dossier(data: &DossierData) -> DossierVerification {
+    let mut mismatched_items = Vec::new();
+    let computed = data
+        .items
+        .iter()
+        .map(|item| DossierManifestEntry {
+            kind: item.kind.clone(),
+            id: item.id.clone(),
+            sha256: record_sha256(&item.record),
+        })
+        .collect::<Vec<_>>();
+    let longest = computed.len().max(data.manifest.entries.len());
+    for index in 0..longest {
+        match (computed.get(index), data.manifest.entries.get(index)) {
+            (Some(item), Some(entry)) if item == entry => {}
+            (Some(item), _) => mismatched_items.push(format!("{}:{}", item.kind, item.id)),
+            (None, Some(entry)) => mismatched_items.push(format!("{}:{}", entry.kind, entry.id)),
+            (None, None) => {}
+        }
+    }
+    let computed_root_sha256 = manifest_root(&computed);
+    DossierVerification {
+        valid: mismatched_items.is_empty()
+            && data.manifest.algorithm == DOSSIER_HASH_ALGORITHM
+            && computed_root_sha256 == data.manifest.root_sha256,
+        root_sha256: data.manifest.root_sha256.clone(),
+        computed_root_sha256,
+        mismatched_items,
+    }
+}
+
+fn dossier_item<T: Serialize>(kind: &str, id: &str, record: &T) -> Result<DossierItem, HelixError> {
+    Ok(DossierItem {
+        kind: kind.to_string(),
+        id: id.to_string(),
+        record: serde_json::to_value(record)
+            .map_err(|error| HelixError::internal_error(format!("dossier record: {error}")))?,
+    })
+}
+
+/// Hash of the compact serialization of `record`. Parsing the embedded JSON back into a
+/// value and serializing it again yields the same bytes.
+fn record_sha256(record: &serde_json::Value) -> String {
+    let bytes = serde_json::to_vec(record).unwrap_or_default();
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+fn manifest_root(entries: &[DossierManifestEntry]) -> String {
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        hasher.update(format!("{}:{}:{}\n", entry.kind, entry.id, entry.sha256).as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn priority_rows(priority: &IntelPriorityBreakdown) -> Vec<(&'static str, String)> {
+    vec![
+        ("Total", priority.total.to_string()),
+        ("Attention tier", priority.attention_tier.to_string()),
+        ("Severity tier", priority.severity_tier.to_string()),
+        (
+            "Corroboration tier",
+            priority.corroboration_tier.to_string(),
+        ),
+        ("Credibility", format!("{} bps", priority.credibility_bps)),
+        ("Freshness tier", priority.freshness_tier.to_string()),
+        ("Trust tier", priority.trust_tier.to_string()),
+        ("Density tier", priority.density_tier.to_string()),
+    ]
+}
+
+fn timeline_row(event: &CaseEvent) -> Vec<String> {
+    let command = serde_json::to_value(&event.command).unwrap_or_default();
+    let mut label = command["type"].as_str().unwrap_or("unknown").to_string();
+    if let Some(detail) = ["reason", "summary"]
+        .iter()
+        .find_map(|field| command[*field].as_str())
+    {
+        label = format!("{label}: {detail}");
+    }
+    let decision = match &event.decision {
+        CaseDecision::Opened => "opened".to_string(),
+        CaseDecision::Updated => "updated".to_string(),
+        CaseDecision::StatusChanged { status } => format!("status -> {}", snake_label(status)),
+        CaseDecision::Denied { reason } => format!("denied: {reason}"),
+    };
+    vec![
+        event.sequence.to_string(),
+        event.recorded_at.clone(),
+        event.actor.clone(),
+        label,
+        decision,
+    ]
+}
+
+fn note_line(note: &AnalystNote) -> String {
+    let reply = note
+        .parent_id
+        .as_deref()
+        .map(|parent| format!(" (reply to {parent})"))
+        .unwrap_or_default();
+    format!(
+        "{} on {} {}, {}{}: {}",
+        note.author,
+        snake_label(&note.target_kind),
+        note.target_id,
+        note.created_at,
+        reply,
+        note.body
+    )
+}
+
+fn snake_label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn is_web_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+fn html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn html_row(cells: &[String]) -> String {
+    let cells = cells
+        .iter()
+        .map(|cell| format!("<td>{}</td>", html(cell)))
+        .collect::<String>();
+    format!("<tr>{cells}</tr>\n")
+}
+
+/// JSON for an inline `<script>` block. `<` only occurs inside strings, where the
+/// `<` escape parses back to the same value.
+fn script_json(data: &DossierData) -> String {
+    serde_json::to_string(data)
+        .unwrap_or_default()
+        .replace('<', "\\u003c")
+}
+
+/// Escapes Markdown punctuation and folds line breaks, so record text renders literally.
+fn markdown(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#' | '!' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '\r' | '\n' => escaped.push(' '),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn markdown_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut out = format!("| {} |\n", headers.join(" | "));
+    out.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+    for row in rows {
+        let cells = row
+            .iter()
+            .map(|cell| markdown(cell))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        out.push_str(&format!("| {cells} |\n"));
+    }
+    out
+}
+
+fn longest_backtick_run(value: &str) -> usize {
+    value.split(|ch| ch != '`').map(str::len).max().unwrap_or(0)
+}
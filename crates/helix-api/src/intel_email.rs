@@ -0,0 +1,882 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RFC 5322 / MIME message parsing for email digest sources, including mbox splitting and
+//! newsletter story extraction.
+
+use crate::intel::decode_xml_entities;
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine as _;
+use helix_core::HelixError;
+
+const MAX_MIME_DEPTH: usize = 8;
+const MIN_DIGEST_STORIES: usize = 2;
+const MAX_DIGEST_STORIES: usize = 20;
+const MIN_STORY_TITLE_LEN: usize = 12;
+const RAW_TEXT_TAGS: &[&str] = &["head", "script", "style", "title"];
+const LINE_BREAK_TAGS: &[&str] = &[
+    "article",
+    "blockquote",
+    "br",
+    "div",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+const HEADING_TAGS: &[&str] = &["b", "h1", "h2", "h3", "h4", "h5", "h6", "strong"];
+const GENERIC_LINK_TEXT: &[&str] = &[
+    "click here",
+    "continue reading",
+    "full story",
+    "here",
+    "learn more",
+    "link",
+    "more",
+    "read",
+    "read more",
+    "read the story",
+    "read the full story",
+];
+const NON_STORY_LINK_MARKERS: &[&str] = &[
+    "forward to a friend",
+    "manage your subscription",
+    "opt-out",
+    "optout",
+    "preferences",
+    "privacy",
+    "unsubscribe",
+    "view in browser",
+    "view online",
+    "view this email",
+];
+const SOCIAL_HOSTS: &[&str] = &[
+    "facebook.com",
+    "instagram.com",
+    "linkedin.com",
+    "twitter.com",
+    "x.com",
+];
+const HTML_TEXT_ENTITIES: &[(&str, &str)] = &[
+    ("&nbsp;", " "),
+    ("&laquo;", "\u{ab}"),
+    ("&raquo;", "\u{bb}"),
+    ("&lsquo;", "\u{2018}"),
+    ("&rsquo;", "\u{2019}"),
+    ("&ldquo;", "\u{201c}"),
+    ("&rdquo;", "\u{201d}"),
+    ("&ndash;", "\u{2013}"),
+    ("&mdash;", "\u{2014}"),
+    ("&hellip;", "\u{2026}"),
+];
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}', '\u{8f}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}', '\u{178}',
+];
+const LENIENT_BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent)
+        .with_decode_allow_trailing_bits(true),
+);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct EmailMessage {
+    pub(crate) message_id: Option<String>,
+    pub(crate) subject: Option<String>,
+    pub(crate) date: Option<String>,
+    pub(crate) sender_domains: Vec<String>,
+    pub(crate) text_body: Option<String>,
+    pub(crate) html_body: Option<String>,
+    pub(crate) newsletter: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EmailStory {
+    pub(crate) title: String,
+    pub(crate) url: String,
+    pub(crate) text: String,
+}
+
+impl EmailMessage {
+    /// Plain-text body, preferring the `text/plain` part over rendered HTML.
+    pub(crate) fn body_text(&self) -> String {
+        if let Some(text) = self.text_body.as_deref() {
+            return text.trim().to_string();
+        }
+        self.html_body
+            .as_deref()
+            .map(|html| {
+                html_body_events(html)
+                    .into_iter()
+                    .map(|event| match event {
+                        BodyEvent::Line { text, .. } | BodyEvent::Link { text, .. } => text,
+                    })
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Linked stories of a newsletter digest, or nothing when the message is not a digest.
+    pub(crate) fn digest_stories(&self) -> Vec<EmailStory> {
+        if !self.newsletter {
+            return Vec::new();
+        }
+        let events = match (self.html_body.as_deref(), self.text_body.as_deref()) {
+            (Some(html), _) => html_body_events(html),
+            (None, Some(text)) => text_body_events(text),
+            (None, None) => return Vec::new(),
+        };
+        let mut stories = stories_from_events(events);
+        if stories.len() < MIN_DIGEST_STORIES {
+            return Vec::new();
+        }
+        stories.truncate(MAX_DIGEST_STORIES);
+        stories
+    }
+}
+
+/// Splits an mbox payload into raw messages; anything else is treated as one message.
+pub(crate) fn split_mailbox(payload: &[u8]) -> Vec<Vec<u8>> {
+    let payload = trim_leading_blank_lines(payload);
+    if !payload.starts_with(b"From ") {
+        return vec![payload.to_vec()];
+    }
+
+    let mut messages = Vec::new();
+    let mut current: Option<Vec<u8>> = None;
+    let mut previous_blank = true;
+    for line in payload.split_inclusive(|byte| *byte == b'\n') {
+        let content = trim_line_end(line);
+        if previous_blank && content.starts_with(b"From ") {
+            messages.extend(current.take());
+            current = Some(Vec::new());
+            previous_blank = false;
+            continue;
+        }
+        previous_blank = content.is_empty();
+        if let Some(message) = current.as_mut() {
+            let quoted = content.iter().take_while(|byte| **byte == b'>').count();
+            if quoted > 0 && content[quoted..].starts_with(b"From ") {
+                message.extend_from_slice(&line[1..]);
+            } else {
+                message.extend_from_slice(line);
+            }
+        }
+    }
+    messages.extend(current);
+    messages
+        .into_iter()
+        .filter(|message| !message.iter().all(u8::is_ascii_whitespace))
+        .collect()
+}
+
+pub(crate) fn parse_email_message(raw: &[u8]) -> Result<EmailMessage, HelixError> {
+    let (header_section, body) = split_header_body(trim_leading_blank_lines(raw));
+    let headers = parse_headers(header_section);
+    if headers.is_empty() {
+        return Err(HelixError::validation_error(
+            "email.content",
+            "message has no RFC 5322 header section",
+        ));
+    }
+
+    let mut bodies = MimeBodies::default();
+    collect_mime_bodies(&headers, body, 0, &mut bodies);
+
+    let mut sender_domains = ["from", "sender"]
+        .into_iter()
+        .filter_map(|name| header(&headers, name))
+        .flat_map(address_domains)
+        .collect::<Vec<_>>();
+    sender_domains.dedup();
+
+    Ok(EmailMessage {
+        message_id: header(&headers, "message-id")
+            .map(|value| value.trim().trim_start_matches('<').trim_end_matches('>'))
+            .filter(|value| !value.is_empty() && !value.contains(char::is_whitespace))
+            .map(str::to_string),
+        subject: header(&headers, "subject")
+            .map(decode_header_words)
+            .map(|subject| subject.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|subject| !subject.is_empty()),
+        date: header(&headers, "date")
+            .map(|date| {
+                date.split('(')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string()
+            })
+            .filter(|date| !date.is_empty()),
+        sender_domains,
+        text_body: bodies.text,
+        html_body: bodies.html,
+        newsletter: header(&headers, "list-id").is_some()
+            || header(&headers, "list-unsubscribe").is_some()
+            || header(&headers, "precedence").is_some_and(|precedence| {
+                matches!(
+                    precedence.trim().to_ascii_lowercase().as_str(),
+                    "bulk" | "list"
+                )
+            }),
+    })
+}
+
+#[derive(Debug, Default)]
+struct MimeBodies {
+    text: Option<String>,
+    html: Option<String>,
+}
+
+fn collect_mime_bodies(
+    headers: &[(String, String)],
+    body: &[u8],
+    depth: usize,
+    bodies: &mut MimeBodies,
+) {
+    let attachment = header(headers, "content-disposition").is_some_and(|disposition| {
+        disposition
+            .trim()
+            .to_ascii_lowercase()
+            .starts_with("attachment")
+    });
+    if attachment {
+        return;
+    }
+
+    let (mime_type, params) = content_type(headers);
+    if mime_type.starts_with("multipart/") {
+        let Some(boundary) = parameter(&params, "boundary") else {
+            return;
+        };
+        if depth >= MAX_MIME_DEPTH {
+            return;
+        }
+        for part in multipart_parts(body, boundary) {
+            let (part_headers, part_body) = split_header_body(part);
+            collect_mime_bodies(&parse_headers(part_headers), part_body, depth + 1, bodies);
+        }
+        return;
+    }
+
+    let slot = match mime_type.as_str() {
+        "text/plain" => &mut bodies.text,
+        "text/html" => &mut bodies.html,
+        _ => return,
+    };
+    if slot.is_some() {
+        return;
+    }
+    let decoded = decode_transfer_encoding(body, header(headers, "content-transfer-encoding"));
+    let text = decode_charset(&decoded, parameter(&params, "charset"));
+    if !text.trim().is_empty() {
+        *slot = Some(text);
+    }
+}
+
+fn split_header_body(raw: &[u8]) -> (&[u8], &[u8]) {
+    let mut offset = 0;
+    for line in raw.split_inclusive(|byte| *byte == b'\n') {
+        if trim_line_end(line).is_empty() {
+            return (&raw[..offset], &raw[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (raw, &[])
+}
+
+fn parse_headers(section: &[u8]) -> Vec<(String, String)> {
+    let text = String::from_utf8_lossy(section);
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        if !name.is_empty() && !name.contains(char::is_whitespace) {
+            headers.push((name.to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(candidate, _)| candidate == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn content_type(headers: &[(String, String)]) -> (String, Vec<(String, String)>) {
+    let value = header(headers, "content-type").unwrap_or("text/plain");
+    let mut segments = split_unquoted(value, ';').into_iter();
+    let mime_type = segments
+        .next()
+        .map(|mime_type| mime_type.trim().to_ascii_lowercase())
+        .filter(|mime_type| !mime_type.is_empty())
+        .unwrap_or_else(|| "text/plain".to_string());
+    let params = segments
+        .filter_map(|segment| {
+            let (name, value) = segment.split_once('=')?;
+            Some((
+                name.trim().to_ascii_lowercase(),
+                value.trim().trim_matches('"').to_string(),
+            ))
+        })
+        .collect();
+    (mime_type, params)
+}
+
+fn parameter<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(candidate, _)| candidate == name)
+        .map(|(_, value)| value.as_str())
+        .filter(|value| !value.is_empty())
+}
+
+fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (index, ch) in value.char_indices() {
+        match ch {
+            '"' => in_quotes = !in_quotes,
+            _ if ch == separator && !in_quotes => {
+                segments.push(&value[start..index]);
+                start = index + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    segments.push(&value[start..]);
+    segments
+}
+
+fn multipart_parts<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{boundary}");
+    let mut parts = Vec::new();
+    let mut part_start = None;
+    let mut offset = 0;
+    for line in body.split_inclusive(|byte| *byte == b'\n') {
+        let content = trim_line_end(line);
+        if let Some(suffix) = content.strip_prefix(delimiter.as_bytes()) {
+            if let Some(start) = part_start.take() {
+                parts.push(trim_trailing_line_break(&body[start..offset]));
+            }
+            if suffix.starts_with(b"--") {
+                return parts;
+            }
+            part_start = Some(offset + line.len());
+        }
+        offset += line.len();
+    }
+    if let Some(start) = part_start {
+        parts.push(&body[start..]);
+    }
+    parts
+}
+
+fn decode_transfer_encoding(body: &[u8], encoding: Option<&str>) -> Vec<u8> {
+    match encoding
+        .map(|encoding| encoding.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("base64") => {
+            let compact = body
+                .iter()
+                .copied()
+                .filter(|byte| !byte.is_ascii_whitespace())
+                .collect::<Vec<_>>();
+            LENIENT_BASE64
+                .decode(compact)
+                .unwrap_or_else(|_| body.to_vec())
+        }
+        Some("quoted-printable") => decode_quoted_printable(body),
+        _ => body.to_vec(),
+    }
+}
+
+fn decode_quoted_printable(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut index = 0;
+    while index < input.len() {
+        let byte = input[index];
+        if byte != b'=' {
+            output.push(byte);
+            index += 1;
+            continue;
+        }
+        let rest = &input[index + 1..];
+        let padding = rest
+            .iter()
+            .take_while(|byte| matches!(byte, b' ' | b'\t'))
+            .count();
+        if rest[padding..].starts_with(b"\r\n") {
+            index += 1 + padding + 2;
+        } else if rest[padding..].starts_with(b"\n") {
+            index += 1 + padding + 1;
+        } else if let Some(value) = rest
+            .get(..2)
+            .and_then(|hex| Some(hex_value(hex[0])? << 4 | hex_value(hex[1])?))
+        {
+            output.push(value);
+            index += 3;
+        } else {
+            output.push(byte);
+            index += 1;
+        }
+    }
+    output
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    char::from(byte)
+        .to_digit(16)
+        .and_then(|digit| u8::try_from(digit).ok())
+}
+
+fn decode_charset(bytes: &[u8], charset: Option<&str>) -> String {
+    let charset = charset.map(|charset| charset.trim().trim_matches('"').to_ascii_lowercase());
+    match charset.as_deref() {
+        Some("iso-8859-1" | "iso8859-1" | "latin1" | "latin-1") => {
+            bytes.iter().map(|byte| char::from(*byte)).collect()
+        }
+        Some("windows-1252" | "cp1252") => bytes
+            .iter()
+            .map(|byte| match byte {
+                0x80..=0x9f => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+                _ => char::from(*byte),
+            })
+            .collect(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Decodes RFC 2047 encoded words, dropping whitespace between adjacent encoded words.
+fn decode_header_words(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    let mut previous_encoded = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match decode_encoded_word(candidate) {
+            Some((decoded, consumed)) => {
+                if !(previous_encoded && before.trim().is_empty()) {
+                    output.push_str(before);
+                }
+                output.push_str(&decoded);
+                rest = &candidate[consumed..];
+                previous_encoded = true;
+            }
+            None => {
+                output.push_str(before);
+                output.push_str("=?");
+                rest = &candidate[2..];
+                previous_encoded = false;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn decode_encoded_word(input: &str) -> Option<(String, usize)> {
+    let body = input.strip_prefix("=?")?;
+    let (charset, body) = body.split_once('?')?;
+    let (encoding, body) = body.split_once('?')?;
+    let end = body.find("?=")?;
+    let text = &body[..end];
+    if charset.is_empty()
+        || charset.contains(char::is_whitespace)
+        || text.contains(char::is_whitespace)
+    {
+        return None;
+    }
+    let bytes = match encoding {
+        "B" | "b" => LENIENT_BASE64.decode(text).ok()?,
+        "Q" | "q" => decode_quoted_printable(text.replace('_', " ").as_bytes()),
+        _ => return None,
+    };
+    let consumed = 2 + charset.len() + 1 + encoding.len() + 1 + end + 2;
+    let charset = charset.split('*').next();
+    Some((decode_charset(&bytes, charset), consumed))
+}
+
+fn address_domains(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .filter_map(|mailbox| {
+            let address = match (mailbox.rfind('<'), mailbox.rfind('>')) {
+                (Some(open), Some(close)) if open < close => &mailbox[open + 1..close],
+                _ => mailbox
+                    .split_whitespace()
+                    .find(|token| token.contains('@'))?,
+            };
+            let domain = address
+                .rsplit_once('@')?
+                .1
+                .trim()
+                .trim_end_matches('.')
+                .to_ascii_lowercase();
+            let valid = domain.contains('.')
+                && domain
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.'));
+            valid.then_some(domain)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BodyEvent {
+    Line { text: String, heading: bool },
+    Link { href: String, text: String },
+}
+
+fn html_body_events(html: &str) -> Vec<BodyEvent> {
+    let mut events = Vec::new();
+    let mut line = String::new();
+    let mut line_heading = false;
+    let mut heading_depth = 0usize;
+    let mut anchor: Option<(String, String)> = None;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        let text = &rest[..text_end];
+        match anchor.as_mut() {
+            Some((_, anchor_text)) => anchor_text.push_str(text),
+            None if !text.trim().is_empty() => {
+                line.push_str(text);
+                line_heading |= heading_depth > 0;
+            }
+            None => line.push(' '),
+        }
+        rest = &rest[text_end..];
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map(|end| &comment[end + 3..])
+                .unwrap_or_default();
+            continue;
+        }
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag_source = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+        if tag_source.starts_with(['!', '?']) {
+            continue;
+        }
+
+        let closing = tag_source.starts_with('/');
+        let tag_source = tag_source.trim_start_matches('/').trim();
+        let name_len = tag_source
+            .find(|ch: char| ch.is_whitespace() || ch == '/')
+            .unwrap_or(tag_source.len());
+        let name = tag_source[..name_len].to_ascii_lowercase();
+
+        if !closing && RAW_TEXT_TAGS.contains(&name.as_str()) {
+            let close = format!("</{name}");
+            rest = find_ascii_case_insensitive(rest, &close)
+                .and_then(|start| rest[start..].find('>').map(|end| &rest[start + end + 1..]))
+                .unwrap_or_default();
+            continue;
+        }
+        if LINE_BREAK_TAGS.contains(&name.as_str()) || HEADING_TAGS.contains(&name.as_str()) {
+            flush_line(&mut line, &mut line_heading, &mut events);
+        }
+        if HEADING_TAGS.contains(&name.as_str()) {
+            heading_depth = if closing {
+                heading_depth.saturating_sub(1)
+            } else {
+                heading_depth + 1
+            };
+        }
+        if name == "a" {
+            if closing {
+                if let Some((href, text)) = anchor.take() {
+                    events.push(BodyEvent::Link {
+                        href,
+                        text: normalize_html_text(&text),
+                    });
+                }
+            } else {
+                flush_line(&mut line, &mut line_heading, &mut events);
+                anchor = html_attribute(&tag_source[name_len..], "href")
+                    .map(|href| (decode_xml_entities(href.trim()), String::new()));
+            }
+        }
+    }
+    flush_line(&mut line, &mut line_heading, &mut events);
+    events
+}
+
+fn flush_line(line: &mut String, heading: &mut bool, events: &mut Vec<BodyEvent>) {
+    let text = normalize_html_text(line);
+    if !text.is_empty() {
+        events.push(BodyEvent::Line {
+            text,
+            heading: *heading,
+        });
+    }
+    line.clear();
+    *heading = false;
+}
+
+fn normalize_html_text(text: &str) -> String {
+    let text = HTML_TEXT_ENTITIES
+        .iter()
+        .fold(text.to_string(), |text, (entity, replacement)| {
+            text.replace(entity, replacement)
+        });
+    decode_xml_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn html_attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes;
+    while let Some(equals) = rest.find('=') {
+        let candidate = rest[..equals].trim();
+        let after_equals = rest[equals + 1..].trim_start();
+        let (value, remainder) = match after_equals.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let body = &after_equals[1..];
+                let end = body.find(quote).unwrap_or(body.len());
+                (&body[..end], body.get(end + 1..).unwrap_or_default())
+            }
+            _ => {
+                let end = after_equals
+                    .find(char::is_whitespace)
+                    .unwrap_or(after_equals.len());
+                (&after_equals[..end], &after_equals[end..])
+            }
+        };
+        let candidate = candidate
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or_default();
+        if candidate.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+        rest = remainder;
+    }
+    None
+}
+
+fn text_body_events(text: &str) -> Vec<BodyEvent> {
+    let mut events = Vec::new();
+    let mut paragraph_start = true;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            paragraph_start = true;
+            continue;
+        }
+        match find_url(line) {
+            Some((start, end)) => {
+                let label = format!("{} {}", &line[..start], &line[end..]);
+                events.push(BodyEvent::Link {
+                    href: line[start..end].to_string(),
+                    text: label
+                        .trim_matches(|ch: char| {
+                            ch.is_whitespace() || "<>()[]:|*-\u{2013}\u{2014}\u{2022}".contains(ch)
+                        })
+                        .to_string(),
+                });
+            }
+            None => events.push(BodyEvent::Line {
+                text: line.to_string(),
+                heading: paragraph_start,
+            }),
+        }
+        paragraph_start = false;
+    }
+    events
+}
+
+fn find_url(line: &str) -> Option<(usize, usize)> {
+    let start = ["https://", "http://"]
+        .into_iter()
+        .filter_map(|scheme| line.find(scheme))
+        .min()?;
+    let length = line[start..]
+        .find(|ch: char| ch.is_whitespace() || matches!(ch, '<' | '>' | '"' | ')' | ']'))
+        .unwrap_or(line.len() - start);
+    let url = line[start..start + length].trim_end_matches(['.', ',', ';']);
+    Some((start, start + url.len()))
+}
+
+/// Groups body events into stories. A descriptive link title opens a story that collects
+/// the lines after it; a generic "read more" link closes a story made of the preceding
+/// heading and its lines. Lines after a second non-story link (share, unsubscribe) are
+/// treated as footer chrome and dropped.
+fn stories_from_events(events: Vec<BodyEvent>) -> Vec<EmailStory> {
+    let mut stories: Vec<EmailStory> = Vec::new();
+    let mut pending: Vec<(String, bool)> = Vec::new();
+    let mut footer_started = false;
+    for event in events {
+        let (href, text) = match event {
+            BodyEvent::Line { text, heading } => {
+                pending.push((text, heading));
+                continue;
+            }
+            BodyEvent::Link { href, text } => (href, text),
+        };
+        if !is_story_link(&href, &text) {
+            if footer_started {
+                pending.clear();
+            } else {
+                append_story_lines(stories.last_mut(), pending.drain(..));
+            }
+            footer_started = !stories.is_empty();
+            continue;
+        }
+        if stories.iter().any(|story| story.url == href) {
+            continue;
+        }
+        footer_started = false;
+
+        if is_story_title(&text) {
+            append_story_lines(stories.last_mut(), pending.drain(..));
+            stories.push(EmailStory {
+                title: text,
+                url: href,
+                text: String::new(),
+            });
+            continue;
+        }
+        if pending.is_empty() {
+            continue;
+        }
+        let title_index = pending
+            .iter()
+            .rposition(|(_, heading)| *heading)
+            .unwrap_or(0);
+        let story_lines = pending.split_off(title_index);
+        append_story_lines(stories.last_mut(), pending.drain(..));
+        let mut story_lines = story_lines.into_iter().map(|(line, _)| line);
+        let title = story_lines.next().unwrap_or_default();
+        stories.push(EmailStory {
+            title,
+            url: href,
+            text: story_lines.collect::<Vec<_>>().join("\n"),
+        });
+    }
+    if !footer_started {
+        append_story_lines(stories.last_mut(), pending.into_iter());
+    }
+    stories
+}
+
+fn append_story_lines(story: Option<&mut EmailStory>, lines: impl Iterator<Item = (String, bool)>) {
+    let Some(story) = story else {
+        return;
+    };
+    for (line, _) in lines {
+        if !story.text.is_empty() {
+            story.text.push('\n');
+        }
+        story.text.push_str(&line);
+    }
+}
+
+fn is_story_link(href: &str, text: &str) -> bool {
+    let href = href.to_ascii_lowercase();
+    let Some(after_scheme) = href
+        .strip_prefix("https://")
+        .or_else(|| href.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = after_scheme
+        .split(['/', '?', '#', ':'])
+        .next()
+        .unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let text = text.to_lowercase();
+    !SOCIAL_HOSTS.contains(&host)
+        && !NON_STORY_LINK_MARKERS
+            .iter()
+            .any(|marker| href.contains(marker) || text.contains(marker))
+}
+
+fn is_story_title(text: &str) -> bool {
+    let normalized = text
+        .trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase();
+    text.chars().count() >= MIN_STORY_TITLE_LEN && !GENERIC_LINK_TEXT.contains(&normalized.as_str())
+}
+
+fn trim_line_end(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+fn trim_trailing_line_break(part: &[u8]) -> &[u8] {
+    part.strip_suffix(b"\r\n")
+        .or_else(|| part.strip_suffix(b"\n"))
+        .unwrap_or(part)
+}
+
+fn trim_leading_blank_lines(payload: &[u8]) -> &[u8] {
+    let payload = payload.strip_prefix(b"\xef\xbb\xbf").unwrap_or(payload);
+    let start = payload
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(payload.len());
+    let line_start = payload[..start]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |index| index + 1);
+    &payload[line_start..]
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    let needle = needle.as_bytes();
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
@@ -0,0 +1,257 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Local mail-drop watcher that ingests Maildir, `.eml`, and mbox files for email digest sources.
+
+use crate::intel::{email_import_requests, ingest_watched_email, record_watched_email_failure};
+use crate::{parse_u16_env, record_audit_event, AppState, AuditEvent};
+use chrono::{DateTime, SecondsFormat, Utc};
+use helix_core::intel_desk::{SourceDefinition, SourceKind};
+use helix_core::HelixError;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior};
+
+const HELIX_EMAIL_WATCH_DIR_ENV: &str = "HELIX_EMAIL_WATCH_DIR";
+const HELIX_EMAIL_WATCH_POLL_SECONDS_ENV: &str = "HELIX_EMAIL_WATCH_POLL_SECONDS";
+const DEFAULT_POLL_SECONDS: u16 = 60;
+const MAX_FILES_PER_SOURCE: usize = 50;
+const MAILDIR_NEW_DIR: &str = "new";
+const MAILDIR_CUR_DIR: &str = "cur";
+const PROCESSED_DIR: &str = "processed";
+const FAILED_DIR: &str = "failed";
+const MAIL_FILE_EXTENSIONS: &[&str] = &["eml", "mbox", "mbx"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EmailWatcherConfig {
+    pub(crate) root: PathBuf,
+    pub(crate) poll_seconds: u16,
+}
+
+impl EmailWatcherConfig {
+    pub(crate) fn from_env() -> Option<Self> {
+        let root = std::env::var_os(HELIX_EMAIL_WATCH_DIR_ENV)
+            .map(PathBuf::from)
+            .filter(|root| !root.as_os_str().is_empty())?;
+        Some(Self {
+            root,
+            poll_seconds: parse_u16_env(HELIX_EMAIL_WATCH_POLL_SECONDS_ENV, DEFAULT_POLL_SECONDS),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct EmailWatchFailure {
+    pub(crate) source_id: String,
+    pub(crate) file_name: String,
+    pub(crate) error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct EmailWatchPassSummary {
+    pub(crate) observed_at: String,
+    pub(crate) file_count: usize,
+    pub(crate) message_count: usize,
+    pub(crate) collected_count: usize,
+    pub(crate) duplicate_count: usize,
+    pub(crate) failures: Vec<EmailWatchFailure>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MailDropFile {
+    path: PathBuf,
+    processed_path: PathBuf,
+    failed_path: PathBuf,
+}
+
+pub(crate) fn spawn_email_watcher(state: AppState) -> Option<JoinHandle<()>> {
+    let config = EmailWatcherConfig::from_env()?;
+    let period = Duration::from_secs(u64::from(config.poll_seconds.max(1)));
+    Some(tokio::spawn(async move {
+        tracing::info!(
+            root = %config.root.display(),
+            poll_seconds = config.poll_seconds,
+            "email watcher started"
+        );
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            if let Err(error) = run_email_watch_pass(&state, &config.root, Utc::now()).await {
+                tracing::warn!(%error, "email watcher pass failed");
+            }
+        }
+    }))
+}
+
+/// Ingests every pending mail file under `<root>/<source_id>/` for enabled email digest
+/// sources, then moves each file out of the drop so it is read once.
+pub(crate) async fn run_email_watch_pass(
+    state: &AppState,
+    root: &Path,
+    now: DateTime<Utc>,
+) -> Result<EmailWatchPassSummary, HelixError> {
+    let observed_at = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let sources = state
+        .intel_desk
+        .read()
+        .await
+        .scheduled_source_candidates()
+        .into_iter()
+        .map(|(source, _)| source)
+        .filter(|source| source.enabled && source.kind == SourceKind::EmailDigest)
+        .collect::<Vec<_>>();
+
+    let mut summary = EmailWatchPassSummary {
+        observed_at: observed_at.clone(),
+        ..EmailWatchPassSummary::default()
+    };
+    for source in sources {
+        let files = mail_drop_files(&root.join(&source.id)).await?;
+        for file in files.into_iter().take(MAX_FILES_PER_SOURCE) {
+            summary.file_count += 1;
+            match ingest_mail_drop_file(state, &source, &file.path, &observed_at).await {
+                Ok((message_count, collected_count, duplicate_count)) => {
+                    summary.message_count += message_count;
+                    summary.collected_count += collected_count;
+                    summary.duplicate_count += duplicate_count;
+                    move_mail_drop_file(&file.path, &file.processed_path).await?;
+                }
+                Err(error) => {
+                    record_watched_email_failure(state, &source.id, &observed_at, &error).await?;
+                    summary.failures.push(EmailWatchFailure {
+                        source_id: source.id.clone(),
+                        file_name: file
+                            .path
+                            .file_name()
+                            .map(|name| name.to_string_lossy().into_owned())
+                            .unwrap_or_default(),
+                        error: error.to_string(),
+                    });
+                    move_mail_drop_file(&file.path, &file.failed_path).await?;
+                }
+            }
+        }
+    }
+
+    if summary.file_count > 0 {
+        record_audit_event(
+            state,
+            AuditEvent::allow(
+                "intel.source.email_watch",
+                "sources/email-watch",
+                serde_json::json!({
+                    "observed_at": summary.observed_at,
+                    "file_count": summary.file_count,
+                    "message_count": summary.message_count,
+                    "collected_count": summary.collected_count,
+                    "duplicate_count": summary.duplicate_count,
+                    "failed_source_ids": summary
+                        .failures
+                        .iter()
+                        .map(|failure| failure.source_id.as_str())
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+        )
+        .await?;
+    }
+    Ok(summary)
+}
+
+async fn ingest_mail_drop_file(
+    state: &AppState,
+    source: &SourceDefinition,
+    path: &Path,
+    observed_at: &str,
+) -> Result<(usize, usize, usize), HelixError> {
+    let payload = tokio::fs::read(path).await.map_err(mail_drop_error)?;
+    let (message_count, requests) = email_import_requests(source, &payload, observed_at, &[])?;
+    let results = ingest_watched_email(state, &source.id, requests, observed_at).await?;
+    let duplicate_count = results.iter().filter(|result| result.duplicate).count();
+    Ok((message_count, results.len(), duplicate_count))
+}
+
+/// Lists Maildir `new/` deliveries and loose mail files in one source drop directory.
+async fn mail_drop_files(directory: &Path) -> Result<Vec<MailDropFile>, HelixError> {
+    let mut files = Vec::new();
+    for path in regular_files(&directory.join(MAILDIR_NEW_DIR)).await? {
+        let Some(name) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+        else {
+            continue;
+        };
+        let seen_name = if name.contains(":2,") {
+            name.clone()
+        } else {
+            format!("{name}:2,S")
+        };
+        files.push(MailDropFile {
+            processed_path: directory.join(MAILDIR_CUR_DIR).join(seen_name),
+            failed_path: directory.join(FAILED_DIR).join(&name),
+            path,
+        });
+    }
+    for path in regular_files(directory).await? {
+        let is_mail_file = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                MAIL_FILE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+            });
+        let Some(name) = path.file_name().map(ToOwned::to_owned) else {
+            continue;
+        };
+        if is_mail_file {
+            files.push(MailDropFile {
+                processed_path: directory.join(PROCESSED_DIR).join(&name),
+                failed_path: directory.join(FAILED_DIR).join(&name),
+                path,
+            });
+        }
+    }
+    Ok(files)
+}
+
+async fn regular_files(directory: &Path) -> Result<Vec<PathBuf>, HelixError> {
+    let mut entries = match tokio::fs::read_dir(directory).await {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(mail_drop_error(error)),
+    };
+    let mut files = Vec::new();
+    while let Some(entry) = entries.next_entry().await.map_err(mail_drop_error)? {
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        let is_file = entry.file_type().await.map_err(mail_drop_error)?.is_file();
+        if is_file && !hidden {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+async fn move_mail_drop_file(from: &Path, to: &Path) -> Result<(), HelixError> {
+    if let Some(parent) = to.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(mail_drop_error)?;
+    }
+    tokio::fs::rename(from, to).await.map_err(mail_drop_error)
+}
+
+fn mail_drop_error(error: std::io::Error) -> HelixError {
+    HelixError::internal_error(format!("email watcher file error: {error}"))
+}
@@ -0,0 +1,664 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Format-detecting parser for RSS 2.0, RSS 1.0 (RDF), Atom, and JSON Feed payloads.
+
+use crate::intel::decode_xml_entities;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use helix_core::HelixError;
+use serde::Deserialize;
+
+const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+const ATOM_03_NS: &str = "http://purl.org/atom/ns#";
+const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const CONTENT_NS: &str = "http://purl.org/rss/1.0/modules/content/";
+const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
+const MEDIA_NS: &str = "http://search.yahoo.com/mrss/";
+const ENCLOSURE_NS: &str = "http://purl.oclc.org/net/rss_2.0/enc#";
+const JSON_FEED_VERSION_PREFIX: &str = "https://jsonfeed.org/version/";
+const MAX_XML_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FeedFormat {
+    Rss2,
+    Rss1,
+    Atom,
+    JsonFeed,
+}
+
+impl FeedFormat {
+    pub(crate) fn tag(self) -> &'static str {
+        match self {
+            FeedFormat::Rss2 | FeedFormat::Rss1 => "rss",
+            FeedFormat::Atom => "atom",
+            FeedFormat::JsonFeed => "json-feed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct FeedEnclosure {
+    pub(crate) url: String,
+    pub(crate) media_type: Option<String>,
+    pub(crate) length: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct FeedEntry {
+    pub(crate) title: Option<String>,
+    pub(crate) link: Option<String>,
+    pub(crate) summary: Option<String>,
+    pub(crate) content: Option<String>,
+    pub(crate) published: Option<String>,
+    pub(crate) authors: Vec<String>,
+    pub(crate) categories: Vec<String>,
+    pub(crate) enclosures: Vec<FeedEnclosure>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedFeed {
+    pub(crate) format: FeedFormat,
+    pub(crate) entries: Vec<FeedEntry>,
+}
+
+pub(crate) fn parse_feed(payload: &str) -> Result<ParsedFeed, HelixError> {
+    let trimmed = payload.trim_start_matches('\u{feff}').trim();
+    if trimmed.starts_with('{') {
+        return parse_json_feed(trimmed);
+    }
+
+    let root = parse_xml_document(trimmed)?;
+    match (root.name.as_str(), root.namespace.as_deref()) {
+        ("rss", None) => {
+            let items = root
+                .child(None, "channel")
+                .map(|channel| channel.children_named(None, "item"))
+                .unwrap_or_default();
+            Ok(ParsedFeed {
+                format: FeedFormat::Rss2,
+                entries: items.into_iter().map(rss_entry).collect(),
+            })
+        }
+        ("RDF", Some(RDF_NS)) => Ok(ParsedFeed {
+            format: FeedFormat::Rss1,
+            entries: root
+                .elements()
+                .filter(|element| element.name == "item")
+                .map(rss_entry)
+                .collect(),
+        }),
+        ("feed", namespace @ (Some(ATOM_NS) | Some(ATOM_03_NS))) => {
+            let feed_authors = atom_authors(&root, namespace);
+            Ok(ParsedFeed {
+                format: FeedFormat::Atom,
+                entries: root
+                    .children_named(namespace, "entry")
+                    .into_iter()
+                    .map(|entry| atom_entry(entry, namespace, &feed_authors))
+                    .collect(),
+            })
+        }
+        _ => Err(HelixError::validation_error(
+            "source.payload",
+            "unrecognized feed format; expected RSS, RDF, Atom, or JSON Feed",
+        )),
+    }
+}
+
+pub(crate) fn canonical_feed_timestamp(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    let parsed = DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_rfc2822(value))
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M%:z"))
+        .or_else(|_| DateTime::parse_from_str(&value.replace('Z', "+00:00"), "%Y-%m-%dT%H:%M%:z"))
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|timestamp| timestamp.and_utc())
+        })?;
+    Some(parsed.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+fn rss_entry(item: &XmlElement) -> FeedEntry {
+    let namespace = item.namespace.as_deref();
+    let link = item.child_text(namespace, "link").or_else(|| {
+        item.child(namespace, "guid")
+            .filter(|guid| guid.attribute("isPermaLink") != Some("false"))
+            .map(XmlElement::text)
+            .filter(|guid| is_http_url(guid))
+    });
+
+    let mut enclosures = Vec::new();
+    for element in item.elements() {
+        let enclosure = match (element.namespace.as_deref(), element.name.as_str()) {
+            (None, "enclosure") => element.attribute("url").map(|url| FeedEnclosure {
+                url: url.to_string(),
+                media_type: element.attribute("type").map(str::to_string),
+                length: element.attribute("length").and_then(parse_length),
+            }),
+            (Some(MEDIA_NS), "content") => element.attribute("url").map(|url| FeedEnclosure {
+                url: url.to_string(),
+                media_type: element.attribute("type").map(str::to_string),
+                length: element.attribute("fileSize").and_then(parse_length),
+            }),
+            (Some(ENCLOSURE_NS), "enclosure") => {
+                element.attribute("resource").map(|url| FeedEnclosure {
+                    url: url.to_string(),
+                    media_type: element.attribute("type").map(str::to_string),
+                    length: element.attribute("length").and_then(parse_length),
+                })
+            }
+            _ => None,
+        };
+        enclosures.extend(enclosure.filter(|enclosure| !enclosure.url.trim().is_empty()));
+    }
+
+    FeedEntry {
+        title: item.child_text(namespace, "title"),
+        link,
+        summary: item.child_text(namespace, "description"),
+        content: item.child_text(Some(CONTENT_NS), "encoded"),
+        published: item
+            .child_text(namespace, "pubDate")
+            .or_else(|| item.child_text(Some(DC_NS), "date")),
+        authors: item
+            .children_text(namespace, "author")
+            .into_iter()
+            .chain(item.children_text(Some(DC_NS), "creator"))
+            .map(|author| rss_author_name(&author))
+            .collect(),
+        categories: item
+            .children_text(namespace, "category")
+            .into_iter()
+            .chain(item.children_text(Some(DC_NS), "subject"))
+            .collect(),
+        enclosures,
+    }
+}
+
+fn rss_author_name(author: &str) -> String {
+    match (author.find('('), author.rfind(')')) {
+        (Some(open), Some(close)) if open < close && author.contains('@') => {
+            author[open + 1..close].trim().to_string()
+        }
+        _ => author.trim().to_string(),
+    }
+}
+
+fn atom_entry(entry: &XmlElement, namespace: Option<&str>, feed_authors: &[String]) -> FeedEntry {
+    let mut link = None;
+    let mut enclosures = Vec::new();
+    for element in entry.children_named(namespace, "link") {
+        let Some(href) = element
+            .attribute("href")
+            .filter(|href| !href.trim().is_empty())
+        else {
+            continue;
+        };
+        match element.attribute("rel").unwrap_or("alternate") {
+            "alternate" if link.is_none() => link = Some(href.to_string()),
+            "enclosure" => enclosures.push(FeedEnclosure {
+                url: href.to_string(),
+                media_type: element.attribute("type").map(str::to_string),
+                length: element.attribute("length").and_then(parse_length),
+            }),
+            _ => {}
+        }
+    }
+    let link = link.or_else(|| {
+        entry
+            .child_text(namespace, "id")
+            .filter(|id| is_http_url(id))
+    });
+
+    let authors = atom_authors(entry, namespace);
+    FeedEntry {
+        title: entry.child_text(namespace, "title"),
+        link,
+        summary: entry.child_text(namespace, "summary"),
+        content: entry
+            .child(namespace, "content")
+            .filter(|content| content.attribute("src").is_none())
+            .map(XmlElement::text)
+            .filter(|content| !content.is_empty()),
+        published: ["published", "updated", "issued", "modified"]
+            .into_iter()
+            .find_map(|name| entry.child_text(namespace, name)),
+        authors: if authors.is_empty() {
+            feed_authors.to_vec()
+        } else {
+            authors
+        },
+        categories: entry
+            .children_named(namespace, "category")
+            .into_iter()
+            .filter_map(|category| {
+                category
+                    .attribute("term")
+                    .or_else(|| category.attribute("label"))
+                    .map(str::to_string)
+            })
+            .collect(),
+        enclosures,
+    }
+}
+
+fn atom_authors(element: &XmlElement, namespace: Option<&str>) -> Vec<String> {
+    element
+        .children_named(namespace, "author")
+        .into_iter()
+        .filter_map(|author| {
+            author
+                .child_text(namespace, "name")
+                .or_else(|| author.child_text(namespace, "email"))
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedDocument {
+    version: String,
+    #[serde(default)]
+    author: Option<JsonFeedAuthor>,
+    #[serde(default)]
+    authors: Vec<JsonFeedAuthor>,
+    #[serde(default)]
+    items: Vec<JsonFeedItem>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct JsonFeedAuthor {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct JsonFeedItem {
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    external_url: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    content_html: Option<String>,
+    #[serde(default)]
+    content_text: Option<String>,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    date_published: Option<String>,
+    #[serde(default)]
+    date_modified: Option<String>,
+    #[serde(default)]
+    author: Option<JsonFeedAuthor>,
+    #[serde(default)]
+    authors: Vec<JsonFeedAuthor>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    attachments: Vec<JsonFeedAttachment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedAttachment {
+    url: String,
+    #[serde(default)]
+    mime_type: Option<String>,
+    #[serde(default)]
+    size_in_bytes: Option<u64>,
+}
+
+fn parse_json_feed(payload: &str) -> Result<ParsedFeed, HelixError> {
+    let document: JsonFeedDocument = serde_json::from_str(payload).map_err(HelixError::from)?;
+    if !document.version.starts_with(JSON_FEED_VERSION_PREFIX) {
+        return Err(HelixError::validation_error(
+            "source.payload",
+            "JSON payload is not a JSON Feed document",
+        ));
+    }
+    let feed_authors = json_feed_author_names(document.authors, document.author);
+    let entries = document
+        .items
+        .into_iter()
+        .map(|item| {
+            let authors = json_feed_author_names(item.authors, item.author);
+            FeedEntry {
+                title: non_empty(item.title),
+                link: non_empty(item.url).or_else(|| non_empty(item.external_url)),
+                summary: non_empty(item.summary),
+                content: non_empty(item.content_html).or_else(|| non_empty(item.content_text)),
+                published: non_empty(item.date_published).or_else(|| non_empty(item.date_modified)),
+                authors: if authors.is_empty() {
+                    feed_authors.clone()
+                } else {
+                    authors
+                },
+                categories: item.tags,
+                enclosures: item
+                    .attachments
+                    .into_iter()
+                    .filter(|attachment| !attachment.url.trim().is_empty())
+                    .map(|attachment| FeedEnclosure {
+                        url: attachment.url,
+                        media_type: attachment.mime_type,
+                        length: attachment.size_in_bytes,
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+    Ok(ParsedFeed {
+        format: FeedFormat::JsonFeed,
+        entries,
+    })
+}
+
+fn json_feed_author_names(
+    authors: Vec<JsonFeedAuthor>,
+    legacy_author: Option<JsonFeedAuthor>,
+) -> Vec<String> {
+    authors
+        .into_iter()
+        .chain(legacy_author)
+        .filter_map(|author| non_empty(author.name))
+        .collect()
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn parse_length(value: &str) -> Option<u64> {
+    value.trim().parse().ok().filter(|length| *length > 0)
+}
+
+fn is_http_url(value: &str) -> bool {
+    value.starts_with("https://") || value.starts_with("http://")
+}
+
+#[derive(Debug, Clone)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Default)]
+struct XmlElement {
+    namespace: Option<String>,
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+    fn elements(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter().filter_map(|node| match node {
+            XmlNode::Element(element) => Some(element),
+            XmlNode::Text(_) => None,
+        })
+    }
+
+    fn children_named(&self, namespace: Option<&str>, name: &str) -> Vec<&XmlElement> {
+        self.elements()
+            .filter(|element| element.name == name && element.namespace.as_deref() == namespace)
+            .collect()
+    }
+
+    fn child(&self, namespace: Option<&str>, name: &str) -> Option<&XmlElement> {
+        self.children_named(namespace, name).into_iter().next()
+    }
+
+    fn child_text(&self, namespace: Option<&str>, name: &str) -> Option<String> {
+        self.children_text(namespace, name).into_iter().next()
+    }
+
+    fn children_text(&self, namespace: Option<&str>, name: &str) -> Vec<String> {
+        self.children_named(namespace, name)
+            .into_iter()
+            .map(XmlElement::text)
+            .filter(|text| !text.is_empty())
+            .collect()
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(candidate, _)| local_name(candidate) == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn text(&self) -> String {
+        let mut output = String::new();
+        self.collect_text(&mut output);
+        output.trim().to_string()
+    }
+
+    fn collect_text(&self, output: &mut String) {
+        for node in &self.children {
+            match node {
+                XmlNode::Text(text) => output.push_str(text),
+                XmlNode::Element(element) => {
+                    element.collect_text(output);
+                    output.push(' ');
+                }
+            }
+        }
+    }
+}
+
+struct OpenElement {
+    qualified_name: String,
+    element: XmlElement,
+    namespaces: Vec<(String, String)>,
+}
+
+fn parse_xml_document(input: &str) -> Result<XmlElement, HelixError> {
+    let invalid = |message: &str| HelixError::validation_error("source.payload", message);
+    let mut stack: Vec<OpenElement> = Vec::new();
+    let mut root = None;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        if let Some(open) = stack.last_mut() {
+            if text_end > 0 {
+                open.element
+                    .children
+                    .push(XmlNode::Text(decode_xml_entities(&rest[..text_end])));
+            }
+        }
+        rest = &rest[text_end..];
+        if rest.is_empty() {
+            break;
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment
+                .find("-->")
+                .ok_or_else(|| invalid("unterminated XML comment"))?;
+            rest = &comment[end + 3..];
+            continue;
+        }
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata
+                .find("]]>")
+                .ok_or_else(|| invalid("unterminated CDATA section"))?;
+            if let Some(open) = stack.last_mut() {
+                open.element
+                    .children
+                    .push(XmlNode::Text(cdata[..end].to_string()));
+            }
+            rest = &cdata[end + 3..];
+            continue;
+        }
+        if let Some(instruction) = rest.strip_prefix("<?") {
+            let end = instruction
+                .find("?>")
+                .ok_or_else(|| invalid("unterminated XML processing instruction"))?;
+            rest = &instruction[end + 2..];
+            continue;
+        }
+        if let Some(declaration) = rest.strip_prefix("<!") {
+            let end = match (declaration.find('['), declaration.find('>')) {
+                (Some(subset), Some(close)) if subset < close => declaration
+                    .find("]>")
+                    .map(|end| end + 2)
+                    .ok_or_else(|| invalid("unterminated XML document type"))?,
+                (_, Some(close)) => close + 1,
+                _ => return Err(invalid("unterminated XML declaration")),
+            };
+            rest = &declaration[end..];
+            continue;
+        }
+
+        let tag_end = find_tag_end(rest).ok_or_else(|| invalid("unterminated XML tag"))?;
+        let tag_source = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        if let Some(closing) = tag_source.strip_prefix('/') {
+            let qualified_name = closing.trim();
+            let position = stack
+                .iter()
+                .rposition(|open| open.qualified_name == qualified_name)
+                .ok_or_else(|| invalid("unexpected XML closing tag"))?;
+            while stack.len() > position {
+                let closed = stack.pop().expect("stack position is in range");
+                match stack.last_mut() {
+                    Some(parent) => parent
+                        .element
+                        .children
+                        .push(XmlNode::Element(closed.element)),
+                    None => root = Some(closed.element),
+                }
+            }
+            if root.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        let self_closing = tag_source.trim_end().ends_with('/');
+        let tag_source = tag_source.trim_end().trim_end_matches('/');
+        let name_len = tag_source
+            .find(char::is_whitespace)
+            .unwrap_or(tag_source.len());
+        let qualified_name = tag_source[..name_len].to_string();
+        if qualified_name.is_empty() {
+            return Err(invalid("XML element name is required"));
+        }
+        let attributes = parse_xml_attributes(&tag_source[name_len..]);
+        let namespaces = attributes
+            .iter()
+            .filter_map(|(name, value)| {
+                if name == "xmlns" {
+                    Some((String::new(), value.clone()))
+                } else {
+                    name.strip_prefix("xmlns:")
+                        .map(|prefix| (prefix.to_string(), value.clone()))
+                }
+            })
+            .collect::<Vec<_>>();
+        let (prefix, name) = match qualified_name.split_once(':') {
+            Some((prefix, name)) => (prefix, name),
+            None => ("", qualified_name.as_str()),
+        };
+        let namespace = namespaces
+            .iter()
+            .rev()
+            .chain(
+                stack
+                    .iter()
+                    .rev()
+                    .flat_map(|open| open.namespaces.iter().rev()),
+            )
+            .find(|(candidate, _)| candidate == prefix)
+            .map(|(_, uri)| uri.clone())
+            .filter(|uri| !uri.is_empty());
+        let element = XmlElement {
+            namespace,
+            name: name.to_string(),
+            attributes,
+            children: Vec::new(),
+        };
+
+        if self_closing {
+            match stack.last_mut() {
+                Some(parent) => parent.element.children.push(XmlNode::Element(element)),
+                None => {
+                    root = Some(element);
+                    break;
+                }
+            }
+        } else {
+            if stack.len() >= MAX_XML_DEPTH {
+                return Err(invalid("XML nesting is too deep"));
+            }
+            stack.push(OpenElement {
+                qualified_name,
+                element,
+                namespaces,
+            });
+        }
+    }
+
+    root.ok_or_else(|| invalid("feed payload is not a complete XML document"))
+}
+
+fn find_tag_end(input: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, ch) in input.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), _) if open == ch => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_xml_attributes(input: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = input.trim_start();
+    while let Some(equals) = rest.find('=') {
+        let name = rest[..equals].trim().to_string();
+        let after_equals = rest[equals + 1..].trim_start();
+        let Some(quote @ ('"' | '\'')) = after_equals.chars().next() else {
+            break;
+        };
+        let body = &after_equals[1..];
+        let Some(end) = body.find(quote) else {
+            break;
+        };
+        if !name.is_empty() {
+            attributes.push((name, decode_xml_entities(&body[..end])));
+        }
+        rest = body[end + 1..].trim_start();
+    }
+    attributes
+}
+
+fn local_name(qualified_name: &str) -> &str {
+    qualified_name
+        .split_once(':')
+        .map(|(_, name)| name)
+        .unwrap_or(qualified_name)
+}
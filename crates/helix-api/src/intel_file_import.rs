@@ -0,0 +1,396 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Row parser for CSV, TSV, and JSON Lines file imports driven by a per-source column mapping.
+
+use helix_core::intel_desk::FileImportMapping;
+use helix_core::HelixError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const DEFAULT_LIST_DELIMITER: char = ';';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FileImportFormat {
+    Text,
+    Csv,
+    Tsv,
+    #[serde(alias = "ndjson")]
+    Jsonl,
+}
+
+impl FileImportFormat {
+    /// Picks a format from the file extension; unknown extensions import as plain text.
+    pub(crate) fn from_file_name(file_name: &str) -> Self {
+        let extension = file_name
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.trim().to_ascii_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "csv" => Self::Csv,
+            "tsv" | "tab" => Self::Tsv,
+            "jsonl" | "ndjson" => Self::Jsonl,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// Evidence fields read from one row before source defaults and validation are applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ImportRow {
+    pub(crate) title: Option<String>,
+    pub(crate) summary: Option<String>,
+    pub(crate) content: Option<String>,
+    pub(crate) url: Option<String>,
+    pub(crate) observed_at: Option<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) entity_labels: Vec<String>,
+}
+
+/// A row and the 1-based line it starts on; row-level problems are kept as `Err` so the
+/// rest of the file still imports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedImportRow {
+    pub(crate) line: usize,
+    pub(crate) row: Result<ImportRow, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Title,
+    Summary,
+    Content,
+    Url,
+    ObservedAt,
+    Tags,
+    EntityLabels,
+}
+
+const FIELDS: [Field; 7] = [
+    Field::Title,
+    Field::Summary,
+    Field::Content,
+    Field::Url,
+    Field::ObservedAt,
+    Field::Tags,
+    Field::EntityLabels,
+];
+
+impl Field {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Summary => "summary",
+            Self::Content => "content",
+            Self::Url => "url",
+            Self::ObservedAt => "observed_at",
+            Self::Tags => "tags",
+            Self::EntityLabels => "entity_labels",
+        }
+    }
+
+    fn is_list(self) -> bool {
+        matches!(self, Self::Tags | Self::EntityLabels)
+    }
+
+    fn mapped_column(self, mapping: &FileImportMapping) -> Option<&str> {
+        match self {
+            Self::Title => mapping.title.as_deref(),
+            Self::Summary => mapping.summary.as_deref(),
+            Self::Content => mapping.content.as_deref(),
+            Self::Url => mapping.url.as_deref(),
+            Self::ObservedAt => mapping.observed_at.as_deref(),
+            Self::Tags => mapping.tags.as_deref(),
+            Self::EntityLabels => mapping.entity_labels.as_deref(),
+        }
+    }
+}
+
+impl ImportRow {
+    fn set_text(&mut self, field: Field, value: String) {
+        let slot = match field {
+            Field::Title => &mut self.title,
+            Field::Summary => &mut self.summary,
+            Field::Content => &mut self.content,
+            Field::Url => &mut self.url,
+            Field::ObservedAt => &mut self.observed_at,
+            Field::Tags | Field::EntityLabels => return,
+        };
+        *slot = Some(value);
+    }
+
+    fn set_list(&mut self, field: Field, values: Vec<String>) {
+        match field {
+            Field::Tags => self.tags = values,
+            Field::EntityLabels => self.entity_labels = values,
+            _ => {}
+        }
+    }
+}
+
+/// Parses a structured import into rows. Errors are returned only for problems that affect
+/// the whole file, such as a CSV header that lacks a mapped column.
+pub(crate) fn parse_import_rows(
+    format: FileImportFormat,
+    content: &str,
+    mapping: &FileImportMapping,
+) -> Result<Vec<ParsedImportRow>, HelixError> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let list_delimiter = mapping
+        .list_delimiter
+        .as_deref()
+        .and_then(|delimiter| delimiter.chars().next())
+        .unwrap_or(DEFAULT_LIST_DELIMITER);
+    match format {
+        FileImportFormat::Csv => parse_delimited(content, ',', mapping, list_delimiter),
+        FileImportFormat::Tsv => parse_delimited(content, '\t', mapping, list_delimiter),
+        FileImportFormat::Jsonl => Ok(parse_json_lines(content, mapping, list_delimiter)),
+        FileImportFormat::Text => Err(HelixError::validation_error(
+            "format",
+            "text imports do not contain rows",
+        )),
+    }
+}
+
+fn parse_delimited(
+    content: &str,
+    delimiter: char,
+    mapping: &FileImportMapping,
+    list_delimiter: char,
+) -> Result<Vec<ParsedImportRow>, HelixError> {
+    let mut records = delimited_records(content, delimiter).into_iter();
+    let header = match records.next() {
+        Some(DelimitedRecord {
+            fields: Ok(fields), ..
+        }) => fields,
+        Some(DelimitedRecord {
+            fields: Err(error), ..
+        }) => {
+            return Err(HelixError::validation_error(
+                "content",
+                &format!("header row is invalid: {error}"),
+            ))
+        }
+        None => {
+            return Err(HelixError::validation_error(
+                "content",
+                "a header row is required",
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    for field in FIELDS {
+        let explicit = field.mapped_column(mapping);
+        let column = explicit.unwrap_or(field.name());
+        let index = header
+            .iter()
+            .position(|name| name.trim().eq_ignore_ascii_case(column));
+        match index {
+            Some(index) => columns.push((field, index)),
+            None if explicit.is_some() || field == Field::Title => {
+                return Err(HelixError::validation_error(
+                    &format!("source.file_import.{}", field.name()),
+                    &format!("column `{column}` is missing from the header row"),
+                ));
+            }
+            None => {}
+        }
+    }
+
+    Ok(records
+        .map(|record| ParsedImportRow {
+            line: record.line,
+            row: record.fields.and_then(|fields| {
+                if fields.len() != header.len() {
+                    return Err(format!(
+                        "row has {} fields; header has {}",
+                        fields.len(),
+                        header.len()
+                    ));
+                }
+                let mut row = ImportRow::default();
+                for &(field, index) in &columns {
+                    let cell = fields[index].trim();
+                    if cell.is_empty() {
+                        continue;
+                    }
+                    if field.is_list() {
+                        row.set_list(field, split_list(cell, list_delimiter));
+                    } else {
+                        row.set_text(field, cell.to_string());
+                    }
+                }
+                Ok(row)
+            }),
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DelimitedRecord {
+    line: usize,
+    fields: Result<Vec<String>, String>,
+}
+
+/// RFC 4180 record splitter: quoted fields may hold delimiters, doubled quotes, and line
+/// breaks. Blank lines are skipped.
+fn delimited_records(content: &str, delimiter: char) -> Vec<DelimitedRecord> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut field_quoted = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push('\n');
+                }
+                '\r' if chars.peek() == Some(&'\n') => {}
+                _ => field.push(ch),
+            }
+            continue;
+        }
+        match ch {
+            '"' if field.is_empty() && !field_quoted => {
+                in_quotes = true;
+                field_quoted = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                line += 1;
+                if !fields.is_empty() || !field.is_empty() || field_quoted {
+                    fields.push(std::mem::take(&mut field));
+                    records.push(DelimitedRecord {
+                        line: record_line,
+                        fields: Ok(std::mem::take(&mut fields)),
+                    });
+                }
+                field_quoted = false;
+                record_line = line;
+            }
+            _ if ch == delimiter => {
+                fields.push(std::mem::take(&mut field));
+                field_quoted = false;
+            }
+            _ => field.push(ch),
+        }
+    }
+
+    if in_quotes {
+        records.push(DelimitedRecord {
+            line: record_line,
+            fields: Err("quoted field is not terminated".to_string()),
+        });
+    } else if !fields.is_empty() || !field.is_empty() || field_quoted {
+        fields.push(field);
+        records.push(DelimitedRecord {
+            line: record_line,
+            fields: Ok(fields),
+        });
+    }
+    records
+}
+
+fn parse_json_lines(
+    content: &str,
+    mapping: &FileImportMapping,
+    list_delimiter: char,
+) -> Vec<ParsedImportRow> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| ParsedImportRow {
+            line: index + 1,
+            row: json_line_row(line, mapping, list_delimiter),
+        })
+        .collect()
+}
+
+fn json_line_row(
+    line: &str,
+    mapping: &FileImportMapping,
+    list_delimiter: char,
+) -> Result<ImportRow, String> {
+    let value = serde_json::from_str::<Value>(line.trim())
+        .map_err(|error| format!("invalid JSON: {error}"))?;
+    let Value::Object(object) = value else {
+        return Err("line must be a JSON object".to_string());
+    };
+
+    let mut row = ImportRow::default();
+    for field in FIELDS {
+        let key = field.mapped_column(mapping).unwrap_or(field.name());
+        let value = object.get(key).or_else(|| {
+            object
+                .iter()
+                .find(|(candidate, _)| candidate.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value)
+        });
+        let Some(value) = value else {
+            continue;
+        };
+        if field.is_list() {
+            let values = match value {
+                Value::Array(items) => items
+                    .iter()
+                    .filter_map(json_scalar_text)
+                    .filter(|item| !item.is_empty())
+                    .collect(),
+                Value::Object(_) => return Err(format!("`{key}` must be a list or string")),
+                _ => json_scalar_text(value)
+                    .map(|text| split_list(&text, list_delimiter))
+                    .unwrap_or_default(),
+            };
+            row.set_list(field, values);
+        } else {
+            if matches!(value, Value::Array(_) | Value::Object(_)) {
+                return Err(format!("`{key}` must be a string"));
+            }
+            if let Some(text) = json_scalar_text(value).filter(|text| !text.is_empty()) {
+                row.set_text(field, text);
+            }
+        }
+    }
+    Ok(row)
+}
+
+fn json_scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.trim().to_string()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+fn split_list(cell: &str, delimiter: char) -> Vec<String> {
+    cell.split(delimiter)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
@@ -0,0 +1,477 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process schedulers driven by the wall clock: pull-source collection, and the
+//! always-on case SLA ticker.
+
+use crate::intel::{
+    fetch_scheduled_collection, ingest_scheduled_collection, normalize_collect_limit,
+    record_scheduled_collection_failure, run_case_sla_tick, set_source_scheduler_paused,
+    source_is_due_between, source_next_due_minute, source_schedule_phase_minute,
+    source_supports_pull_collection, SourceCollectionRun,
+};
+use crate::{
+    api_error_response, parse_bool_env, parse_u16_env, record_audit_event, AppState, AuditEvent,
+};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use helix_core::intel_desk::{SourceDefinition, SourceKind};
+use helix_core::HelixError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior};
+
+const HELIX_SOURCE_SCHEDULER_ENABLED_ENV: &str = "HELIX_SOURCE_SCHEDULER_ENABLED";
+const HELIX_SOURCE_SCHEDULER_TICK_SECONDS_ENV: &str = "HELIX_SOURCE_SCHEDULER_TICK_SECONDS";
+const HELIX_SOURCE_SCHEDULER_MAX_CONCURRENCY_ENV: &str = "HELIX_SOURCE_SCHEDULER_MAX_CONCURRENCY";
+const HELIX_SOURCE_SCHEDULER_MAX_ITEMS_ENV: &str = "HELIX_SOURCE_SCHEDULER_MAX_ITEMS";
+const HELIX_CASE_SLA_TICK_SECONDS_ENV: &str = "HELIX_CASE_SLA_TICK_SECONDS";
+const DEFAULT_TICK_SECONDS: u16 = 60;
+const DEFAULT_MAX_CONCURRENCY: u16 = 4;
+const DEFAULT_MAX_ITEMS_PER_SOURCE: u16 = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SourceSchedulerConfig {
+    pub(crate) enabled: bool,
+    pub(crate) tick_seconds: u16,
+    pub(crate) max_concurrency: u16,
+    pub(crate) max_items_per_source: u16,
+}
+
+impl Default for SourceSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            tick_seconds: DEFAULT_TICK_SECONDS,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_items_per_source: DEFAULT_MAX_ITEMS_PER_SOURCE,
+        }
+    }
+}
+
+impl SourceSchedulerConfig {
+    pub(crate) fn from_env() -> Self {
+        Self {
+            enabled: parse_bool_env(HELIX_SOURCE_SCHEDULER_ENABLED_ENV, false),
+            tick_seconds: parse_u16_env(
+                HELIX_SOURCE_SCHEDULER_TICK_SECONDS_ENV,
+                DEFAULT_TICK_SECONDS,
+            ),
+            max_concurrency: parse_u16_env(
+                HELIX_SOURCE_SCHEDULER_MAX_CONCURRENCY_ENV,
+                DEFAULT_MAX_CONCURRENCY,
+            ),
+            max_items_per_source: parse_u16_env(
+                HELIX_SOURCE_SCHEDULER_MAX_ITEMS_ENV,
+                DEFAULT_MAX_ITEMS_PER_SOURCE,
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SourceSchedulerFailure {
+    pub(crate) source_id: String,
+    pub(crate) error: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SourceSchedulerTickSummary {
+    pub(crate) tick_minute: u64,
+    pub(crate) observed_at: String,
+    pub(crate) due_count: usize,
+    pub(crate) skipped_count: usize,
+    pub(crate) collected_count: usize,
+    pub(crate) duplicate_count: usize,
+    pub(crate) failed_count: usize,
+    pub(crate) failures: Vec<SourceSchedulerFailure>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SourceSchedulerStatus {
+    pub(crate) config: SourceSchedulerConfig,
+    pub(crate) running: bool,
+    /// Mirrors the pause flag persisted on the intel desk, which is what ticks consult.
+    pub(crate) paused: bool,
+    pub(crate) tick_count: u64,
+    pub(crate) restart_count: u64,
+    pub(crate) last_tick_minute: Option<u64>,
+    pub(crate) last_tick: Option<SourceSchedulerTickSummary>,
+}
+
+impl SourceSchedulerStatus {
+    pub(crate) fn new(config: SourceSchedulerConfig) -> Self {
+        Self {
+            config,
+            running: false,
+            paused: false,
+            tick_count: 0,
+            restart_count: 0,
+            last_tick_minute: None,
+            last_tick: None,
+        }
+    }
+}
+
+impl Default for SourceSchedulerStatus {
+    fn default() -> Self {
+        Self::new(SourceSchedulerConfig::default())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ScheduledSourceStatus {
+    pub(crate) source_id: String,
+    pub(crate) name: String,
+    pub(crate) kind: SourceKind,
+    pub(crate) enabled: bool,
+    pub(crate) schedulable: bool,
+    pub(crate) cadence_minutes: u16,
+    pub(crate) schedule_phase_minute: u16,
+    pub(crate) next_due_minute: Option<u64>,
+    pub(crate) run: Option<SourceCollectionRun>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SourceSchedulerResponse {
+    pub(crate) scheduler: SourceSchedulerStatus,
+    pub(crate) tick_minute: u64,
+    pub(crate) sources: Vec<ScheduledSourceStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SourceSchedulerQuery {
+    #[serde(default)]
+    pub(crate) tick_minute: Option<u64>,
+}
+
+pub(crate) fn tick_minute_at(now: DateTime<Utc>) -> u64 {
+    u64::try_from(now.timestamp().max(0)).unwrap_or_default() / 60
+}
+
+fn source_is_schedulable(source: &SourceDefinition) -> bool {
+    source.enabled && source_supports_pull_collection(source) && source.endpoint_url.is_some()
+}
+
+/// Minute after which a source's missed cadence slots are still collected. The persisted
+/// run carries the last collection across restarts, so a slot that came due while the
+/// process was down is picked up on the first tick. The look-back is capped at one cadence,
+/// so however long the outage, a source catches up with a single collection.
+fn source_catch_up_after_minute(
+    source: &SourceDefinition,
+    run: Option<&SourceCollectionRun>,
+    last_tick_minute: Option<u64>,
+    tick_minute: u64,
+) -> u64 {
+    let collected_minute = run.and_then(|run| {
+        let collected_at = run
+            .last_collected_at
+            .as_deref()
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .map(|value| tick_minute_at(value.with_timezone(&Utc)));
+        run.last_tick_minute.max(collected_at)
+    });
+    let after_minute = match collected_minute.max(last_tick_minute) {
+        Some(minute) => minute.min(tick_minute.saturating_sub(1)),
+        None => return tick_minute.saturating_sub(1),
+    };
+    after_minute.max(tick_minute.saturating_sub(u64::from(source.cadence_minutes.max(1))))
+}
+
+pub(crate) fn spawn_source_scheduler(state: AppState) -> Option<JoinHandle<()>> {
+    let config = state
+        .source_scheduler
+        .try_read()
+        .map(|scheduler| scheduler.config.clone())
+        .unwrap_or_default();
+    if !config.enabled {
+        return None;
+    }
+    let period = Duration::from_secs(u64::from(config.tick_seconds.max(1)));
+    Some(tokio::spawn(async move {
+        state.source_scheduler.write().await.running = true;
+        tracing::info!(
+            tick_seconds = config.tick_seconds,
+            max_concurrency = config.max_concurrency,
+            "source scheduler started"
+        );
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            let tick_state = state.clone();
+            let outcome =
+                tokio::spawn(
+                    async move { run_source_scheduler_tick(&tick_state, Utc::now()).await },
+                )
+                .await;
+            match outcome {
+                Ok(Ok(_)) => {}
+                Ok(Err(error)) => tracing::warn!(%error, "source scheduler tick failed"),
+                Err(join_error) => {
+                    state.source_scheduler.write().await.restart_count += 1;
+                    tracing::error!(%join_error, "source scheduler tick aborted; restarting");
+                }
+            }
+        }
+    }))
+}
+
+/// Advances case SLA windows on their own interval. Unlike source collection this runs
+/// whether or not the source scheduler is enabled or paused, so escalated cases breach on
+/// time on every deployment.
+pub(crate) fn spawn_case_sla_ticker(state: AppState) -> JoinHandle<()> {
+    let tick_seconds = parse_u16_env(HELIX_CASE_SLA_TICK_SECONDS_ENV, DEFAULT_TICK_SECONDS);
+    let period = Duration::from_secs(u64::from(tick_seconds));
+    tokio::spawn(async move {
+        tracing::info!(tick_seconds, "case SLA ticker started");
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            if let Err(error) = run_case_sla_tick(&state, Utc::now()).await {
+                tracing::warn!(%error, "case SLA tick failed");
+            }
+        }
+    })
+}
+
+pub(crate) async fn run_source_scheduler_tick(
+    state: &AppState,
+    now: DateTime<Utc>,
+) -> Result<Option<SourceSchedulerTickSummary>, HelixError> {
+    let tick_minute = tick_minute_at(now);
+    let observed_at = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+    if state.intel_desk.read().await.source_scheduler_paused() {
+        return Ok(None);
+    }
+    let (config, last_tick_minute) = {
+        let scheduler = state.source_scheduler.read().await;
+        (scheduler.config.clone(), scheduler.last_tick_minute)
+    };
+    let limit = normalize_collect_limit(Some(usize::from(config.max_items_per_source)))?;
+    let candidates = state.intel_desk.read().await.scheduled_source_candidates();
+
+    let mut skipped_count = 0usize;
+    let mut due_sources = Vec::new();
+    for (source, run) in candidates {
+        let collected_this_tick = run
+            .as_ref()
+            .and_then(|run| run.last_tick_minute)
+            .is_some_and(|last_tick_minute| last_tick_minute >= tick_minute);
+        let after_minute =
+            source_catch_up_after_minute(&source, run.as_ref(), last_tick_minute, tick_minute);
+        if !source_is_schedulable(&source)
+            || collected_this_tick
+            || !source_is_due_between(&source, after_minute, tick_minute)
+        {
+            skipped_count += 1;
+            continue;
+        }
+        due_sources.push(source);
+    }
+
+    let semaphore = Arc::new(Semaphore::new(usize::from(config.max_concurrency.max(1))));
+    let mut fetches = Vec::with_capacity(due_sources.len());
+    for source in due_sources {
+        let source_id = source.id.clone();
+        let fetch_state = state.clone();
+        let semaphore = semaphore.clone();
+        let observed_at = observed_at.clone();
+        fetches.push((
+            source_id,
+            tokio::spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .map_err(|_| HelixError::internal_error("source scheduler semaphore closed"))?;
+                fetch_scheduled_collection(&fetch_state, source, &observed_at, limit).await
+            }),
+        ));
+    }
+
+    let mut due_count = fetches.len();
+    let mut collected_count = 0usize;
+    let mut duplicate_count = 0usize;
+    let mut failures = Vec::new();
+    for (source_id, fetch) in fetches {
+        let fetched = fetch.await.unwrap_or_else(|join_error| {
+            Err(HelixError::internal_error(format!(
+                "source collection task aborted: {join_error}"
+            )))
+        });
+        let collected = match fetched {
+            Ok(Some(pending)) => {
+                ingest_scheduled_collection(state, pending, tick_minute, &observed_at).await
+            }
+            Ok(None) => {
+                // The source circuit is open; it is retried on a later tick.
+                due_count -= 1;
+                skipped_count += 1;
+                continue;
+            }
+            Err(error) => Err(error),
+        };
+        match collected {
+            Ok(collection) => {
+                collected_count += collection.collected_count;
+                duplicate_count += collection.duplicate_count;
+            }
+            Err(error) => {
+                record_scheduled_collection_failure(
+                    state,
+                    &source_id,
+                    tick_minute,
+                    &observed_at,
+                    &error,
+                )
+                .await?;
+                failures.push(SourceSchedulerFailure {
+                    source_id,
+                    error: error.to_string(),
+                });
+            }
+        }
+    }
+
+    let summary = SourceSchedulerTickSummary {
+        tick_minute,
+        observed_at,
+        due_count,
+        skipped_count,
+        collected_count,
+        duplicate_count,
+        failed_count: failures.len(),
+        failures,
+    };
+    {
+        let mut scheduler = state.source_scheduler.write().await;
+        scheduler.tick_count += 1;
+        scheduler.last_tick_minute = Some(tick_minute);
+        scheduler.last_tick = Some(summary.clone());
+    }
+    if summary.due_count > 0 {
+        record_audit_event(
+            state,
+            AuditEvent::allow(
+                "intel.source.scheduler.tick",
+                "sources/scheduler",
+                serde_json::json!({
+                    "tick_minute": summary.tick_minute,
+                    "observed_at": summary.observed_at,
+                    "due_count": summary.due_count,
+                    "skipped_count": summary.skipped_count,
+                    "collected_count": summary.collected_count,
+                    "duplicate_count": summary.duplicate_count,
+                    "failed_source_ids": summary
+                        .failures
+                        .iter()
+                        .map(|failure| failure.source_id.as_str())
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+        )
+        .await?;
+    }
+    Ok(Some(summary))
+}
+
+async fn source_scheduler_response(state: &AppState, tick_minute: u64) -> SourceSchedulerResponse {
+    let mut scheduler = state.source_scheduler.read().await.clone();
+    let desk = state.intel_desk.read().await;
+    scheduler.paused = desk.source_scheduler_paused();
+    let sources = desk
+        .scheduled_source_candidates()
+        .into_iter()
+        .map(|(source, run)| {
+            let schedulable = source_is_schedulable(&source);
+            ScheduledSourceStatus {
+                schedule_phase_minute: source_schedule_phase_minute(&source),
+                next_due_minute: schedulable.then(|| source_next_due_minute(&source, tick_minute)),
+                source_id: source.id,
+                name: source.name,
+                kind: source.kind,
+                enabled: source.enabled,
+                schedulable,
+                cadence_minutes: source.cadence_minutes,
+                run,
+            }
+        })
+        .collect();
+    SourceSchedulerResponse {
+        scheduler,
+        tick_minute,
+        sources,
+    }
+}
+
+pub(crate) async fn get_source_scheduler_handler(
+    State(state): State<AppState>,
+    Query(query): Query<SourceSchedulerQuery>,
+) -> impl IntoResponse {
+    let tick_minute = query
+        .tick_minute
+        .unwrap_or_else(|| tick_minute_at(Utc::now()));
+    (
+        StatusCode::OK,
+        Json(source_scheduler_response(&state, tick_minute).await),
+    )
+}
+
+pub(crate) async fn pause_source_scheduler_handler(State(state): State<AppState>) -> Response {
+    update_source_scheduler_paused(&state, true).await
+}
+
+pub(crate) async fn resume_source_scheduler_handler(State(state): State<AppState>) -> Response {
+    update_source_scheduler_paused(&state, false).await
+}
+
+async fn update_source_scheduler_paused(state: &AppState, paused: bool) -> Response {
+    let previous = match set_source_scheduler_paused(state, paused).await {
+        Ok(previous) => previous,
+        Err(error) => return api_error_response(error),
+    };
+    state.source_scheduler.write().await.paused = paused;
+    let action = if paused {
+        "intel.source.scheduler.pause"
+    } else {
+        "intel.source.scheduler.resume"
+    };
+    if let Err(error) = record_audit_event(
+        state,
+        AuditEvent::allow(
+            action,
+            "sources/scheduler",
+            serde_json::json!({
+                "paused": paused,
+                "previously_paused": previous,
+            }),
+        ),
+    )
+    .await
+    {
+        return api_error_response(error);
+    }
+    let tick_minute = tick_minute_at(Utc::now());
+    (
+        StatusCode::OK,
+        Json(source_scheduler_response(state, tick_minute).await),
+    )
+        .into_response()
+}
@@ -0,0 +1,271 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HMAC signature verification and replay protection for signed webhook deliveries.
+
+use axum::http::HeaderMap;
+use helix_core::deterministic_agents::{DedupDecision, DedupInput, DedupMachine};
+use helix_core::intel_desk::{WebhookSignatureConfig, WebhookSignatureScheme};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SHA256_PREFIX: &str = "sha256=";
+const GITHUB_DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+/// Fingerprints and delivery ids kept per source for deliveries without a timestamp.
+const MAX_UNTIMED_DELIVERIES: usize = 4_096;
+
+/// A delivery whose signature matched. `fingerprint` identifies the signed content for
+/// replay checks; `delivery_id` is a hash of the sender's delivery id header when the
+/// scheme has one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct VerifiedDelivery {
+    pub(crate) fingerprint: u64,
+    pub(crate) timestamp: Option<i64>,
+    pub(crate) delivery_id: Option<u64>,
+}
+
+/// Per-source record of accepted deliveries, stored with the source run so it survives
+/// restarts.
+///
+/// Timestamped deliveries are remembered for twice the tolerance, advanced one tick per
+/// wall-clock second: a delivery dated up to `tolerance` ahead of the clock stays
+/// acceptable for `2 * tolerance` after it arrives. Deliveries without a signed timestamp
+/// never go stale, so their fingerprints and delivery ids are kept in a bounded
+/// most-recent list instead of a time window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct WebhookReplayGuard {
+    dedup: DedupMachine,
+    tolerance_seconds: u32,
+    clock_second: i64,
+    #[serde(default)]
+    untimed: VecDeque<u64>,
+}
+
+impl WebhookReplayGuard {
+    pub(crate) fn new(tolerance_seconds: u32, now: i64) -> Self {
+        Self {
+            dedup: DedupMachine::new(2 * u64::from(tolerance_seconds)),
+            tolerance_seconds,
+            clock_second: now,
+            untimed: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn tolerance_seconds(&self) -> u32 {
+        self.tolerance_seconds
+    }
+
+    /// Restarts the timestamped window for a new tolerance. Untimed deliveries are kept,
+    /// since a tolerance change does not make them safe to accept again.
+    pub(crate) fn retune(&mut self, tolerance_seconds: u32, now: i64) {
+        let untimed = std::mem::take(&mut self.untimed);
+        *self = Self {
+            untimed,
+            ..Self::new(tolerance_seconds, now)
+        };
+    }
+
+    /// Returns `false` when the same delivery was already accepted.
+    pub(crate) fn admit(&mut self, delivery: &VerifiedDelivery, now: i64) -> bool {
+        if delivery.timestamp.is_none() {
+            return self.admit_untimed(delivery);
+        }
+        // Ticks past the window change nothing, so the catch-up loop is bounded by it.
+        let elapsed = now
+            .saturating_sub(self.clock_second)
+            .clamp(0, 2 * i64::from(self.tolerance_seconds) + 1);
+        for _ in 0..elapsed {
+            self.dedup.step(DedupInput::Tick);
+        }
+        self.clock_second = self.clock_second.max(now);
+        self.dedup.step(DedupInput::Observe {
+            fingerprint: delivery.fingerprint,
+        }) == Some(DedupDecision::Emit)
+    }
+
+    fn admit_untimed(&mut self, delivery: &VerifiedDelivery) -> bool {
+        let keys = std::iter::once(delivery.fingerprint)
+            .chain(delivery.delivery_id)
+            .collect::<Vec<_>>();
+        if keys.iter().any(|key| self.untimed.contains(key)) {
+            return false;
+        }
+        self.untimed.extend(keys);
+        while self.untimed.len() > MAX_UNTIMED_DELIVERIES {
+            self.untimed.pop_front();
+        }
+        true
+    }
+}
+
+/// Reads the signature headers a scheme needs without touching the secret, so malformed
+/// deliveries are rejected before the credential vault is consulted.
+pub(crate) fn signature_header_value(
+    config: &WebhookSignatureConfig,
+    headers: &HeaderMap,
+) -> Result<String, String> {
+    let name = configured_signature_header(config);
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| format!("missing `{name}` signature header"))
+}
+
+/// Verifies one delivery against `secret`. `now` is Unix seconds and bounds timestamped
+/// schemes by the configured tolerance.
+pub(crate) fn verify_webhook_signature(
+    config: &WebhookSignatureConfig,
+    secret: &[u8],
+    headers: &HeaderMap,
+    body: &[u8],
+    now: i64,
+) -> Result<VerifiedDelivery, String> {
+    let header = signature_header_value(config, headers)?;
+    let (timestamp, signatures) = match config.scheme {
+        WebhookSignatureScheme::GithubSha256 => {
+            let signature = header
+                .strip_prefix(SHA256_PREFIX)
+                .ok_or_else(|| format!("signature must start with `{SHA256_PREFIX}`"))?;
+            (None, vec![signature.to_string()])
+        }
+        WebhookSignatureScheme::StripeV1 => {
+            let (timestamp, signatures) = parse_stripe_header(&header)?;
+            (Some(timestamp), signatures)
+        }
+        WebhookSignatureScheme::HmacSha256 => {
+            let signature = header.strip_prefix(SHA256_PREFIX).unwrap_or(&header);
+            let timestamp = config
+                .timestamp_header
+                .as_deref()
+                .map(|name| {
+                    headers
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .ok_or_else(|| format!("missing `{name}` timestamp header"))
+                        .and_then(parse_unix_timestamp)
+                })
+                .transpose()?;
+            (timestamp, vec![signature.to_string()])
+        }
+    };
+
+    if let Some(timestamp) = timestamp {
+        let skew = now.abs_diff(timestamp);
+        if skew > u64::from(config.tolerance_seconds) {
+            return Err(format!(
+                "signature timestamp is {skew}s from server time; tolerance is {}s",
+                config.tolerance_seconds
+            ));
+        }
+    }
+
+    let mut mac = HmacSha256::new_from_slice(secret)
+        .map_err(|_| "webhook secret cannot key HMAC-SHA256".to_string())?;
+    if let Some(timestamp) = timestamp {
+        mac.update(format!("{timestamp}.").as_bytes());
+    }
+    mac.update(body);
+    let matched = signatures
+        .iter()
+        .filter_map(|signature| decode_hex(signature))
+        .find(|expected| mac.clone().verify_slice(expected).is_ok())
+        .ok_or_else(|| "signature does not match payload".to_string())?;
+
+    // Fingerprint what was signed, never the header text: the header can be re-encoded,
+    // reordered or padded with extra parts and still verify.
+    let fingerprint = match timestamp {
+        Some(timestamp) => fingerprint_of(&[
+            b"timestamped",
+            timestamp.to_string().as_bytes(),
+            Sha256::digest(body).as_slice(),
+        ]),
+        None => fingerprint_of(&[b"mac", matched.as_slice()]),
+    };
+    let delivery_id = match config.scheme {
+        WebhookSignatureScheme::GithubSha256 => headers
+            .get(GITHUB_DELIVERY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| fingerprint_of(&[b"delivery", value.as_bytes()])),
+        _ => None,
+    };
+    Ok(VerifiedDelivery {
+        fingerprint,
+        timestamp,
+        delivery_id,
+    })
+}
+
+fn fingerprint_of(parts: &[&[u8]]) -> u64 {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+    let digest = hasher.finalize();
+    let mut fingerprint = [0u8; 8];
+    fingerprint.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(fingerprint)
+}
+
+fn configured_signature_header(config: &WebhookSignatureConfig) -> &str {
+    config
+        .signature_header
+        .as_deref()
+        .unwrap_or_else(|| config.scheme.default_signature_header())
+}
+
+/// Splits `t=<unix>,v1=<hex>[,v1=<hex>...]`; other schemes such as `v0` are ignored.
+fn parse_stripe_header(header: &str) -> Result<(i64, Vec<String>), String> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = Some(parse_unix_timestamp(value)?),
+            Some(("v1", value)) => signatures.push(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    let timestamp =
+        timestamp.ok_or_else(|| "signature header has no `t=` timestamp".to_string())?;
+    if signatures.is_empty() {
+        return Err("signature header has no `v1=` signature".to_string());
+    }
+    Ok((timestamp, signatures))
+}
+
+fn parse_unix_timestamp(value: &str) -> Result<i64, String> {
+    value
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| "signature timestamp must be Unix seconds".to_string())
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    let value = value.trim();
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(value.get(index..index + 2)?, 16).ok())
+        .collect()
+}
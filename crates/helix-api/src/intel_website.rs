@@ -0,0 +1,344 @@
+// Copyright 2026 DarkLightX
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Block-level HTML snapshot capture for website diff sources.
+
+use crate::intel::decode_xml_entities;
+use helix_core::intel_desk::{WebsiteDiffConfig, MAX_SNAPSHOT_BLOCKS};
+
+const MAX_SNAPSHOT_BLOCK_LEN: usize = 2_048;
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+const RAW_TEXT_TAGS: &[&str] = &["script", "style", "noscript", "template", "svg"];
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "dd",
+    "details",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+const IMPLICITLY_CLOSED_TAGS: &[&str] = &["dd", "dt", "li", "p", "td", "th", "tr"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WebsiteCapture {
+    pub(crate) title: Option<String>,
+    pub(crate) blocks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct HtmlElement {
+    tag: String,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CompoundSelector {
+    tag: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl CompoundSelector {
+    fn matches(&self, element: &HtmlElement) -> bool {
+        self.tag.as_ref().is_none_or(|tag| tag == &element.tag)
+            && self
+                .id
+                .as_ref()
+                .is_none_or(|id| element.id.as_ref() == Some(id))
+            && self
+                .classes
+                .iter()
+                .all(|class| element.classes.iter().any(|candidate| candidate == class))
+    }
+}
+
+fn parse_selector(selector: &str) -> Vec<CompoundSelector> {
+    selector
+        .split_whitespace()
+        .map(|compound| {
+            let mut parsed = CompoundSelector::default();
+            let tag_len = compound
+                .bytes()
+                .take_while(|byte| byte.is_ascii_alphanumeric())
+                .count();
+            if tag_len > 0 {
+                parsed.tag = Some(compound[..tag_len].to_ascii_lowercase());
+            }
+            let mut rest = &compound[tag_len..];
+            while let Some(marker) = rest.chars().next() {
+                let ident_len = rest[1..].find(['.', '#']).unwrap_or(rest.len() - 1);
+                let ident = rest[1..1 + ident_len].to_string();
+                if marker == '#' {
+                    parsed.id = Some(ident);
+                } else {
+                    parsed.classes.push(ident);
+                }
+                rest = &rest[1 + ident_len..];
+            }
+            parsed
+        })
+        .collect()
+}
+
+fn selector_matches(selector: &[CompoundSelector], stack: &[HtmlElement]) -> bool {
+    let Some((subject, ancestors)) = selector.split_last() else {
+        return false;
+    };
+    stack.iter().enumerate().any(|(index, element)| {
+        subject.matches(element) && ancestors_match(ancestors, &stack[..index])
+    })
+}
+
+fn ancestors_match(selectors: &[CompoundSelector], stack: &[HtmlElement]) -> bool {
+    let mut remaining = stack;
+    for selector in selectors.iter().rev() {
+        match remaining
+            .iter()
+            .rposition(|element| selector.matches(element))
+        {
+            Some(position) => remaining = &remaining[..position],
+            None => return false,
+        }
+    }
+    true
+}
+
+pub(crate) fn capture_website_blocks(
+    payload: &str,
+    config: Option<&WebsiteDiffConfig>,
+) -> WebsiteCapture {
+    let scope = config
+        .and_then(|config| config.content_selector.as_deref())
+        .map(parse_selector);
+    let ignored = config
+        .map(|config| {
+            config
+                .ignore_selectors
+                .iter()
+                .map(|selector| parse_selector(selector))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let captures = |stack: &[HtmlElement]| {
+        scope
+            .as_ref()
+            .is_none_or(|scope| selector_matches(scope, stack))
+            && !ignored
+                .iter()
+                .any(|selector| selector_matches(selector, stack))
+    };
+
+    let mut title = None;
+    let mut in_title = false;
+    let mut stack: Vec<HtmlElement> = Vec::new();
+    let mut blocks = Vec::new();
+    let mut current = String::new();
+    let mut rest = payload;
+    while !rest.is_empty() {
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        let text = &rest[..text_end];
+        if in_title {
+            title = Some(normalize_block_text(text)).filter(|title| !title.is_empty());
+        } else if !text.trim().is_empty() && captures(&stack) {
+            current.push_str(text);
+            current.push(' ');
+        }
+        rest = &rest[text_end..];
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map(|end| &comment[end + 3..])
+                .unwrap_or_default();
+            continue;
+        }
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag_source = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+        if tag_source.starts_with(['!', '?']) {
+            continue;
+        }
+
+        if let Some(closing) = tag_source.strip_prefix('/') {
+            let name = closing.trim().to_ascii_lowercase();
+            if name == "title" {
+                in_title = false;
+            }
+            if BLOCK_TAGS.contains(&name.as_str()) {
+                flush_block(&mut current, &mut blocks);
+            }
+            if let Some(position) = stack.iter().rposition(|element| element.tag == name) {
+                stack.truncate(position);
+            }
+            continue;
+        }
+
+        let element = parse_element(tag_source);
+        if element.tag.is_empty() {
+            continue;
+        }
+        if RAW_TEXT_TAGS.contains(&element.tag.as_str()) {
+            let close = format!("</{}", element.tag);
+            rest = find_ascii_case_insensitive(rest, &close)
+                .and_then(|start| rest[start..].find('>').map(|end| &rest[start + end + 1..]))
+                .unwrap_or_default();
+            continue;
+        }
+        if element.tag == "title" {
+            in_title = true;
+            continue;
+        }
+        if BLOCK_TAGS.contains(&element.tag.as_str()) {
+            flush_block(&mut current, &mut blocks);
+            if IMPLICITLY_CLOSED_TAGS.contains(&element.tag.as_str())
+                && stack.last().map(|open| open.tag.as_str()) == Some(element.tag.as_str())
+            {
+                stack.pop();
+            }
+        }
+        if element.tag == "br" {
+            current.push(' ');
+        }
+        if !VOID_TAGS.contains(&element.tag.as_str()) && !tag_source.trim_end().ends_with('/') {
+            stack.push(element);
+        }
+    }
+    flush_block(&mut current, &mut blocks);
+    blocks.truncate(MAX_SNAPSHOT_BLOCKS);
+
+    WebsiteCapture { title, blocks }
+}
+
+fn flush_block(current: &mut String, blocks: &mut Vec<String>) {
+    let block = normalize_block_text(current);
+    current.clear();
+    if block.is_empty() || blocks.len() >= MAX_SNAPSHOT_BLOCKS {
+        return;
+    }
+    let block = match block.char_indices().nth(MAX_SNAPSHOT_BLOCK_LEN) {
+        Some((end, _)) => block[..end].to_string(),
+        None => block,
+    };
+    blocks.push(block);
+}
+
+fn normalize_block_text(text: &str) -> String {
+    decode_xml_entities(&text.replace("&nbsp;", " "))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_element(tag_source: &str) -> HtmlElement {
+    let tag_source = tag_source.trim().trim_end_matches('/');
+    let name_len = tag_source
+        .find(|ch: char| ch.is_whitespace() || ch == '/')
+        .unwrap_or(tag_source.len());
+    let mut element = HtmlElement {
+        tag: tag_source[..name_len].to_ascii_lowercase(),
+        ..HtmlElement::default()
+    };
+    for (name, value) in parse_attributes(&tag_source[name_len..]) {
+        match name.as_str() {
+            "id" if !value.is_empty() => element.id = Some(value),
+            "class" => {
+                element.classes = value.split_whitespace().map(str::to_string).collect();
+            }
+            _ => {}
+        }
+    }
+    element
+}
+
+fn parse_attributes(input: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = input.trim_start();
+    while !rest.is_empty() {
+        let name_len = rest
+            .find(|ch: char| ch.is_whitespace() || ch == '=' || ch == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = rest[name_len..].trim_start();
+        let mut value = String::new();
+        if let Some(after_equals) = rest.strip_prefix('=') {
+            let after_equals = after_equals.trim_start();
+            match after_equals.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after_equals[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    value = body[..end].to_string();
+                    rest = body.get(end + 1..).unwrap_or_default();
+                }
+                _ => {
+                    let end = after_equals
+                        .find(char::is_whitespace)
+                        .unwrap_or(after_equals.len());
+                    value = after_equals[..end].to_string();
+                    rest = &after_equals[end..];
+                }
+            }
+        } else if name.is_empty() {
+            rest = rest.get(1..).unwrap_or_default();
+        }
+        if !name.is_empty() {
+            attributes.push((name, decode_xml_entities(&value)));
+        }
+        rest = rest.trim_start();
+    }
+    attributes
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    let needle = needle.as_bytes();
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
@@ -15,22 +15,45 @@
 
 mod evm_rpc;
 mod intel;
+mod intel_dossier;
+mod intel_email;
+mod intel_email_watcher;
+mod intel_feed;
+mod intel_file_import;
+mod intel_scheduler;
+mod intel_webhook;
+mod intel_website;
 
 use crate::intel::{
-    collect_due_sources_handler, collect_source_handler, create_source, create_watchlist,
-    export_autopilot_review_packet, export_market_brief_packet_handler, file_import_handler,
-    generate_market_intel_brief_handler, get_autopilot_review_queue, get_intel_overview,
-    get_market_intel_overview, ingest_evidence, list_cases, list_claims, list_evidence,
-    list_sources, list_watchlists, review_claim_handler, transition_case_handler,
-    webhook_ingest_handler, AutopilotReviewKind, AutopilotReviewQueueEntry, IntelDeskPostgresStore,
-    IntelDeskStore,
+    apply_decay_handler, apply_trust_calibration_handler, backtest_watchlist,
+    case_reasoning_handler, cast_claim_vote_handler, collect_due_sources_handler,
+    collect_source_handler, create_case_note, create_claim_note, create_evidence_note,
+    create_source, create_watchlist, create_watchlist_suppression, delete_watchlist_suppression,
+    email_import_handler, export_autopilot_review_packet, export_case_dossier_handler,
+    export_market_brief_packet_handler, file_import_handler, generate_market_intel_brief_handler,
+    get_adjudication_config, get_autopilot_review_queue, get_case_sla_policy, get_case_timeline,
+    get_claim_votes, get_decay_policy, get_intel_overview, get_market_intel_overview,
+    get_source_trust_history, get_trust_calibration, get_trust_calibration_policy, ingest_evidence,
+    list_case_notes, list_cases, list_claim_graph, list_claim_notes, list_claims, list_entities,
+    list_evidence, list_evidence_notes, list_notes, list_rule_packs, list_sources,
+    list_suppressed_hits, list_watchlists, merge_entity_handler, preview_json_mapping_handler,
+    put_adjudication_config, put_case_sla_policy, put_decay_policy, put_trust_calibration_policy,
+    review_claim_handler, split_entity_handler, tick_case_sla_handler, transition_case_handler,
+    update_note_handler, upsert_entity_handler, upsert_rule_pack_handler,
+    verify_case_dossier_handler, webhook_ingest_handler, AutopilotReviewKind,
+    AutopilotReviewQueueEntry, IntelDeskPostgresStore, IntelDeskStore,
+};
+use crate::intel_email_watcher::spawn_email_watcher;
+use crate::intel_scheduler::{
+    get_source_scheduler_handler, pause_source_scheduler_handler, resume_source_scheduler_handler,
+    spawn_case_sla_ticker, spawn_source_scheduler, SourceSchedulerConfig, SourceSchedulerStatus,
 };
 use axum::{
     extract::{Path, Query, Request, State},
-    http::{header::AUTHORIZATION, Method, StatusCode},
+    http::{header::AUTHORIZATION, HeaderMap, Method, StatusCode},
     middleware::{self, Next},
     response::{IntoResponse, Json, Response},
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
     Router,
 };
 use helix_agent_sdk::{AgentContext, EventPublisher, SdkAgent, SdkError};
@@ -95,6 +118,7 @@ pub(crate) struct AppState {
     autopilot_guard: Arc<RwLock<AutopilotGuardMachine>>,
     intel_desk: Arc<RwLock<IntelDeskStore>>,
     intel_persistence: Option<Arc<IntelDeskPostgresStore>>,
+    source_scheduler: Arc<RwLock<SourceSchedulerStatus>>,
     state_persistence: Option<Arc<AppPostgresStore>>,
     automation_rules: Arc<RwLock<Vec<Rule>>>,
     recipes: Arc<RwLock<Vec<Recipe>>>,
@@ -789,11 +813,15 @@ async fn main() {
         None => Vec::new(),
     };
 
+    let mut source_scheduler = SourceSchedulerStatus::new(SourceSchedulerConfig::from_env());
+    source_scheduler.paused = intel_desk.source_scheduler_paused();
+
     let state = AppState {
         policy_config: Arc::new(RwLock::new(policy_config)),
         autopilot_guard: Arc::new(RwLock::new(autopilot_guard)),
         intel_desk: Arc::new(RwLock::new(intel_desk)),
         intel_persistence,
+        source_scheduler: Arc::new(RwLock::new(source_scheduler)),
         state_persistence,
         automation_rules: Arc::new(RwLock::new(automation_rules)),
         recipes: Arc::new(RwLock::new(recipes)),
@@ -804,6 +832,9 @@ async fn main() {
         llm_model,
         auth_service: Arc::new(api_auth_from_env()),
     };
+    let _source_scheduler = spawn_source_scheduler(state.clone());
+    let _case_sla_ticker = spawn_case_sla_ticker(state.clone());
+    let _email_watcher = spawn_email_watcher(state.clone());
     let app = app_with_optional_static_ui(state);
 
     let addr = api_addr_from_env();
@@ -1043,8 +1074,8 @@ struct OnchainReceiptResponse {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct ApiErrorResponse {
-    error: String,
+pub(crate) struct ApiErrorResponse {
+    pub(crate) error: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1772,43 +1803,50 @@ async fn evaluate_automation_rules(
     State(state): State<AppState>,
     Json(req): Json<AutomationRuleEvaluateRequest>,
 ) -> Response {
+    match evaluate_automation_event(&state, &req.event).await {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error) => api_error_response(error),
+    }
+}
+
+/// Plans recipe triggers for one event against the loaded automation rules, storing
+/// the evaluation when persistence is enabled.
+pub(crate) async fn evaluate_automation_event(
+    state: &AppState,
+    event: &Event,
+) -> Result<AutomationRuleEvaluateResponse, HelixError> {
     let rules = state.automation_rules.read().await.clone();
     let listener = RuleEngineEventListener::new(rules.clone());
-    let trigger_plans = listener.handle_event(&req.event);
+    let trigger_plans = listener.handle_event(event);
     let evaluation = match state.state_persistence.as_ref() {
-        Some(persistence) => match persistence
-            .insert_automation_rule_evaluation(&req.event, rules.len(), &trigger_plans)
-            .await
-        {
-            Ok(evaluation) => Some(evaluation),
-            Err(error) => return api_error_response(error),
-        },
+        Some(persistence) => Some(
+            persistence
+                .insert_automation_rule_evaluation(event, rules.len(), &trigger_plans)
+                .await?,
+        ),
         None => None,
     };
-    let event = AuditEvent::allow(
-        "automation.rules.evaluate",
-        "automation/rules",
-        serde_json::json!({
-            "event_id": req.event.id,
-            "event_type": req.event.r#type,
-            "rule_count": rules.len(),
-            "trigger_plan_count": trigger_plans.len(),
-            "evaluation_id": evaluation.as_ref().map(|entry| entry.id)
-        }),
-    );
-    if let Err(error) = record_audit_event(&state, event).await {
-        return api_error_response(error);
-    }
-
-    (
-        StatusCode::OK,
-        Json(AutomationRuleEvaluateResponse {
-            rule_count: rules.len(),
-            trigger_plans,
-            evaluation,
-        }),
+    record_audit_event(
+        state,
+        AuditEvent::allow(
+            "automation.rules.evaluate",
+            "automation/rules",
+            serde_json::json!({
+                "event_id": event.id,
+                "event_type": event.r#type,
+                "rule_count": rules.len(),
+                "trigger_plan_count": trigger_plans.len(),
+                "evaluation_id": evaluation.as_ref().map(|entry| entry.id)
+            }),
+        ),
     )
-        .into_response()
+    .await?;
+
+    Ok(AutomationRuleEvaluateResponse {
+        rule_count: rules.len(),
+        trigger_plans,
+        evaluation,
+    })
 }
 
 async fn post_apply_agent_template(
@@ -3195,8 +3233,14 @@ fn app(state: AppState) -> Router {
         require_api_auth,
     ));
 
+    // Webhook deliveries authenticate per source: signed sources verify the sender's
+    // HMAC, unsigned ones fall back to the bearer check inside the handler.
     Router::new()
         .route("/health", get(health_check))
+        .route(
+            "/api/v1/sources/:source_id/webhook",
+            post(webhook_ingest_handler),
+        )
         .merge(api_routes)
         .with_state(state)
         .layer(
@@ -3269,34 +3313,141 @@ fn api_router() -> Router<AppState> {
             post(collect_due_sources_handler),
         )
         .route(
-            "/api/v1/sources/:source_id/collect",
-            post(collect_source_handler),
+            "/api/v1/sources/json-mapping/preview",
+            post(preview_json_mapping_handler),
         )
         .route(
-            "/api/v1/sources/:source_id/webhook",
-            post(webhook_ingest_handler),
+            "/api/v1/sources/scheduler",
+            get(get_source_scheduler_handler),
+        )
+        .route(
+            "/api/v1/sources/scheduler/pause",
+            post(pause_source_scheduler_handler),
+        )
+        .route(
+            "/api/v1/sources/scheduler/resume",
+            post(resume_source_scheduler_handler),
+        )
+        .route(
+            "/api/v1/sources/trust-calibration",
+            get(get_trust_calibration),
+        )
+        .route(
+            "/api/v1/sources/trust-calibration/policy",
+            get(get_trust_calibration_policy).put(put_trust_calibration_policy),
+        )
+        .route(
+            "/api/v1/sources/trust-calibration/apply",
+            post(apply_trust_calibration_handler),
+        )
+        .route(
+            "/api/v1/sources/:source_id/trust-history",
+            get(get_source_trust_history),
+        )
+        .route(
+            "/api/v1/sources/:source_id/collect",
+            post(collect_source_handler),
         )
         .route(
             "/api/v1/sources/:source_id/import",
             post(file_import_handler),
         )
+        .route(
+            "/api/v1/sources/:source_id/email",
+            post(email_import_handler),
+        )
         .route(
             "/api/v1/watchlists",
             get(list_watchlists).post(create_watchlist),
         )
+        .route("/api/v1/watchlists/backtest", post(backtest_watchlist))
+        .route(
+            "/api/v1/watchlists/:watchlist_id/suppressions",
+            post(create_watchlist_suppression),
+        )
+        .route(
+            "/api/v1/watchlists/:watchlist_id/suppressions/:suppression_id",
+            delete(delete_watchlist_suppression),
+        )
+        .route(
+            "/api/v1/watchlists/:watchlist_id/suppressed-hits",
+            get(list_suppressed_hits),
+        )
+        .route(
+            "/api/v1/entities",
+            get(list_entities).post(upsert_entity_handler),
+        )
+        .route(
+            "/api/v1/entities/:entity_id/merge",
+            post(merge_entity_handler),
+        )
+        .route(
+            "/api/v1/entities/:entity_id/split",
+            post(split_entity_handler),
+        )
         .route("/api/v1/evidence", get(list_evidence))
         .route("/api/v1/evidence/ingest", post(ingest_evidence))
+        .route(
+            "/api/v1/evidence/:evidence_id/notes",
+            get(list_evidence_notes).post(create_evidence_note),
+        )
         .route("/api/v1/claims", get(list_claims))
+        .route("/api/v1/claims/graph", get(list_claim_graph))
         .route(
             "/api/v1/claims/:claim_id/review",
             post(review_claim_handler),
         )
+        .route(
+            "/api/v1/claims/:claim_id/votes",
+            get(get_claim_votes).post(cast_claim_vote_handler),
+        )
+        .route(
+            "/api/v1/claims/:claim_id/notes",
+            get(list_claim_notes).post(create_claim_note),
+        )
+        .route(
+            "/api/v1/claims/adjudication-config",
+            get(get_adjudication_config).put(put_adjudication_config),
+        )
         .route("/api/v1/cases", get(list_cases))
+        .route(
+            "/api/v1/cases/sla/policy",
+            get(get_case_sla_policy).put(put_case_sla_policy),
+        )
+        .route("/api/v1/cases/sla/tick", post(tick_case_sla_handler))
+        .route(
+            "/api/v1/cases/dossier/verify",
+            post(verify_case_dossier_handler),
+        )
         .route(
             "/api/v1/cases/:case_id/transition",
             post(transition_case_handler),
         )
+        .route("/api/v1/cases/:case_id/timeline", get(get_case_timeline))
+        .route(
+            "/api/v1/cases/:case_id/dossier",
+            get(export_case_dossier_handler),
+        )
+        .route(
+            "/api/v1/cases/:case_id/notes",
+            get(list_case_notes).post(create_case_note),
+        )
+        .route("/api/v1/notes", get(list_notes))
+        .route("/api/v1/notes/:note_id", put(update_note_handler))
+        .route(
+            "/api/v1/cases/:case_id/reasoning",
+            post(case_reasoning_handler),
+        )
+        .route(
+            "/api/v1/decay/policy",
+            get(get_decay_policy).put(put_decay_policy),
+        )
+        .route("/api/v1/decay/apply", post(apply_decay_handler))
         .route("/api/v1/reasoning/evaluate", post(post_reasoning_evaluate))
+        .route(
+            "/api/v1/reasoning/rule-packs",
+            get(list_rule_packs).post(upsert_rule_pack_handler),
+        )
         .route("/api/v1/autopilot/status", get(get_autopilot_status))
         .route(
             "/api/v1/autopilot/config",
@@ -3325,20 +3476,28 @@ async fn require_api_auth(State(state): State<AppState>, req: Request, next: Nex
         return next.run(req).await;
     }
 
-    let authorization = req
-        .headers()
+    match bearer_auth_rejection(&state, req.headers()) {
+        Some(rejection) => rejection,
+        None => next.run(req).await,
+    }
+}
+
+pub(crate) fn bearer_auth_rejection(state: &AppState, headers: &HeaderMap) -> Option<Response> {
+    let authorization = headers
         .get(AUTHORIZATION)
         .and_then(|value| value.to_str().ok());
 
     match state.auth_service.evaluate_bearer_header(authorization) {
-        AuthDecision::Allow { .. } => next.run(req).await,
-        AuthDecision::Deny { reason } => (
-            StatusCode::UNAUTHORIZED,
-            Json(ApiErrorResponse {
-                error: reason.as_str().to_string(),
-            }),
-        )
-            .into_response(),
+        AuthDecision::Allow { .. } => None,
+        AuthDecision::Deny { reason } => Some(
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(ApiErrorResponse {
+                    error: reason.as_str().to_string(),
+                }),
+            )
+                .into_response(),
+        ),
     }
 }
 
@@ -3349,12 +3508,16 @@ mod tests {
         AutopilotReviewExportPacketResponse, AutopilotReviewQueueResponse, CaseCatalogResponse,
         CaseTransitionRequest, CaseTransitionResponse, ClaimCatalogResponse, ClaimResponse,
         ClaimReviewRequest, CollectDueSourcesResponse, CollectSourceResponse, CreateSourceRequest,
-        CreateWatchlistRequest, FileImportResponse, GenerateMarketIntelBriefRequest,
-        GenerateMarketIntelBriefResponse, IngestEvidenceRequest, IngestEvidenceResponse,
-        IntelDeskOverviewResponse, MarketIntelBriefExportPacketResponse,
-        MarketIntelOverviewResponse, SourceCatalogResponse, SourceResponse, WatchlistResponse,
+        CreateWatchlistRequest, EmailImportResponse, FileImportResponse, FileImportRowStatus,
+        FileRowImportResponse, GenerateMarketIntelBriefRequest, GenerateMarketIntelBriefResponse,
+        IngestEvidenceRequest, IngestEvidenceResponse, IntelDeskOverviewResponse,
+        JsonMappingPreviewResponse, MarketIntelBriefExportPacketResponse,
+        MarketIntelOverviewResponse, SourceCatalogResponse, SourceResponse,
+        WatchlistBacktestRequest, WatchlistBacktestResponse, WatchlistResponse,
         WebhookIngestResponse,
     };
+    use crate::intel_email_watcher::run_email_watch_pass;
+    use crate::intel_scheduler::{run_source_scheduler_tick, SourceSchedulerResponse};
     use async_trait::async_trait;
     use axum::{
         body::{to_bytes, Body},
@@ -3375,6 +3538,7 @@ mod tests {
             autopilot_guard: Arc::new(RwLock::new(AutopilotGuardMachine::default())),
             intel_desk: Arc::new(RwLock::new(IntelDeskStore::default())),
             intel_persistence: None,
+            source_scheduler: Arc::new(RwLock::new(SourceSchedulerStatus::default())),
             state_persistence: None,
             automation_rules: Arc::new(RwLock::new(Vec::new())),
             recipes: Arc::new(RwLock::new(Vec::new())),
@@ -3413,6 +3577,27 @@ mod tests {
         format!("http://{addr}{path}")
     }
 
+    async fn spawn_sequence_server(path: &'static str, bodies: Vec<&'static str>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let app = Router::new().route(
+            path,
+            get(move || {
+                let calls = calls.clone();
+                let bodies = bodies.clone();
+                async move {
+                    let call = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    bodies[call.min(bodies.len() - 1)]
+                }
+            }),
+        );
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}{path}")
+    }
+
     async fn create_test_source(app: Router, name: &str) -> SourceResponse {
         let request = CreateSourceRequest {
             profile_id: None,
@@ -3427,6 +3612,10 @@ mod tests {
             trust_score: 91,
             enabled: true,
             tags: vec!["test".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         };
 
         let response = app
@@ -4676,6 +4865,9 @@ mod tests {
                                 description: "tie break regression".to_string(),
                                 keywords: vec![keyword.to_string()],
                                 entities: vec![entity.to_string()],
+                                aliases: BTreeMap::new(),
+                                query: None,
+                                trigger: None,
                                 min_source_trust: 40,
                                 severity: helix_core::intel_desk::WatchlistSeverity::Medium,
                                 enabled: true,
@@ -5280,6 +5472,10 @@ mod tests {
             trust_score: 73,
             enabled: true,
             tags: vec!["manual".to_string(), "notes".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         };
 
         let create_response = app
@@ -5374,6 +5570,10 @@ mod tests {
             trust_score: 90,
             enabled: true,
             tags: vec!["market-intel".to_string(), "pricing".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         };
 
         let create_response = app
@@ -5440,48 +5640,59 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn source_collect_due_endpoint_collects_due_pull_sources() {
-        let feed_url = spawn_text_server(
-            "/scheduled-pricing.json",
-            r#"{
-              "items": [
-                {
-                  "title": "Boreal Cloud scheduled pricing bundle changed",
-                  "summary": "Scheduled collector saw Boreal Cloud add an enterprise bundle discount.",
-                  "content": "Boreal Cloud pricing changed with a scheduled enterprise bundle discount signal.",
-                  "url": "https://example.org/boreal/scheduled-pricing",
-                  "tags": ["pricing"],
-                  "entity_labels": ["boreal cloud"],
-                  "proposed_claims": [
-                    {
-                      "subject": "boreal cloud",
-                      "predicate": "discounted",
-                      "object": "enterprise bundle",
-                      "confidence_bps": 8900,
-                      "rationale": "The scheduled feed states a bundle discount."
-                    }
-                  ]
-                }
-              ]
-            }"#,
+    async fn source_collect_endpoint_ingests_only_changed_website_blocks() {
+        let page_url = spawn_sequence_server(
+            "/orion/pricing.html",
+            vec![
+                r#"<html><head><title>Orion Dynamics Pricing</title></head><body>
+                <div class="ad">Buy now</div>
+                <main>
+                  <p>Orion Dynamics enterprise plan costs $40 per seat.</p>
+                  <p class="timestamp">Updated 09:00</p>
+                  <p>Support includes business hours coverage.</p>
+                </main></body></html>"#,
+                r#"<html><head><title>Orion Dynamics Pricing</title></head><body>
+                <div class="ad">Limited offer</div>
+                <main>
+                  <p>Orion Dynamics enterprise plan costs $55 per seat.</p>
+                  <p class="timestamp">Updated 10:00</p>
+                  <p>Support includes business hours coverage.</p>
+                  <ul><li>New sovereign cloud region</li></ul>
+                </main></body></html>"#,
+                r#"<html><head><title>Orion Dynamics Pricing</title></head><body>
+                <div class="ad">Another offer</div>
+                <main>
+                  <p>Orion Dynamics enterprise plan costs $55 per seat.</p>
+                  <p class="timestamp">Updated 11:00</p>
+                  <p>Support includes business hours coverage.</p>
+                  <ul><li>New sovereign cloud region</li></ul>
+                </main></body></html>"#,
+            ],
         )
         .await;
         let app = test_app();
         let create = CreateSourceRequest {
             profile_id: None,
-            name: "Boreal Scheduled Pricing Feed".to_string(),
-            description: "Scheduled JSON pricing changes".to_string(),
-            kind: helix_core::intel_desk::SourceKind::JsonApi,
-            endpoint_url: Some(feed_url),
+            name: "Orion Pricing Page".to_string(),
+            description: "Pricing page diff".to_string(),
+            kind: helix_core::intel_desk::SourceKind::WebsiteDiff,
+            endpoint_url: Some(page_url),
             credential_id: None,
             credential_header_name: None,
             credential_header_prefix: None,
-            cadence_minutes: 1,
-            trust_score: 90,
+            cadence_minutes: 60,
+            trust_score: 80,
             enabled: true,
-            tags: vec!["market-intel".to_string(), "scheduled".to_string()],
+            tags: vec!["pricing".to_string()],
+            website_diff: Some(helix_core::intel_desk::WebsiteDiffConfig {
+                content_selector: Some("main".to_string()),
+                ignore_selectors: vec![".timestamp".to_string()],
+            }),
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         };
-        let create_response = app
+        let response = app
             .clone()
             .oneshot(
                 Request::builder()
@@ -5493,203 +5704,248 @@ mod tests {
             )
             .await
             .unwrap();
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = to_bytes(create_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
         let created: SourceResponse = serde_json::from_slice(&body).unwrap();
 
-        let collect_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/v1/sources/collect-due")
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        serde_json::to_vec(&serde_json::json!({
-                            "observed_at": "2026-04-04T10:00:00Z",
-                            "tick_minute": 42,
-                            "max_items_per_source": 5,
-                            "source_ids": [created.source.id]
-                        }))
+        let mut collections = Vec::new();
+        for observed_at in [
+            "2026-04-01T09:00:00Z",
+            "2026-04-01T10:00:00Z",
+            "2026-04-01T11:00:00Z",
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("/api/v1/sources/{}/collect", created.source.id))
+                        .header("content-type", "application/json")
+                        .body(Body::from(
+                            serde_json::to_vec(&serde_json::json!({ "observed_at": observed_at }))
+                                .unwrap(),
+                        ))
                         .unwrap(),
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(collect_response.status(), StatusCode::CREATED);
-        let body = to_bytes(collect_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let payload: CollectDueSourcesResponse = serde_json::from_slice(&body).unwrap();
-        assert_eq!(payload.due_count, 1);
-        assert_eq!(payload.skipped_count, 0);
-        assert_eq!(payload.collections.len(), 1);
-        assert_eq!(payload.collections[0].collected_count, 1);
-        assert_eq!(payload.collections[0].duplicate_count, 0);
-        assert_eq!(payload.collections[0].schedule_phase_minute, 0);
-        assert!(!payload.collections[0].results[0].hits.is_empty());
-        assert!(!payload.collections[0].results[0].case_updates.is_empty());
+                )
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: CollectSourceResponse = serde_json::from_slice(&body).unwrap();
+            collections.push((status, payload));
+        }
+
+        let (status, baseline) = &collections[0];
+        assert_eq!(*status, StatusCode::CREATED);
+        let diff = baseline.website_diff.as_ref().unwrap();
+        assert!(diff.baseline);
+        assert_eq!(diff.block_count, 2);
+        let evidence = &baseline.results[0].evidence;
+        assert_eq!(evidence.title, "Orion Dynamics Pricing");
+        assert!(evidence.content.contains("$40 per seat"));
+        assert!(!evidence.content.contains("Updated 09:00"));
+        assert!(!evidence.content.contains("Buy now"));
+
+        let (status, changed) = &collections[1];
+        assert_eq!(*status, StatusCode::CREATED);
+        let diff = changed.website_diff.as_ref().unwrap();
+        assert!(!diff.baseline);
+        assert_eq!(
+            diff.previous_observed_at.as_deref(),
+            Some("2026-04-01T09:00:00Z")
+        );
+        assert_eq!(diff.changed_blocks, 1);
+        assert_eq!(diff.added_blocks, 1);
+        assert_eq!(diff.unchanged_blocks, 1);
+        let evidence = &changed.results[0].evidence;
+        assert_eq!(evidence.title, "Orion Dynamics Pricing changed");
+        assert!(evidence.summary.contains("1 added, 1 changed"));
+        assert!(evidence
+            .content
+            .contains("~ Orion Dynamics enterprise plan costs $55"));
+        assert!(evidence.content.contains("+ New sovereign cloud region"));
+        assert!(!evidence.content.contains("business hours"));
+        assert!(evidence.tags.contains(&"website-change".to_string()));
+
+        let (status, unchanged) = &collections[2];
+        assert_eq!(*status, StatusCode::OK);
+        assert_eq!(unchanged.collected_count, 0);
+        let diff = unchanged.website_diff.as_ref().unwrap();
+        assert_eq!(diff.unchanged_blocks, 3);
+        assert_eq!(
+            diff.previous_content_hash.as_deref(),
+            Some(diff.content_hash.as_str())
+        );
     }
 
-    #[tokio::test]
-    async fn source_collect_due_endpoint_rejects_boundaries() {
-        let zero_limit_response = test_app()
+    async fn collect_feed_fixture(path: &'static str, body: &'static str) -> CollectSourceResponse {
+        let feed_url = spawn_text_server(path, body).await;
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: format!("Feed fixture {path}"),
+            description: "Feed format fixture".to_string(),
+            kind: helix_core::intel_desk::SourceKind::RssFeed,
+            endpoint_url: Some(feed_url),
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 80,
+            enabled: true,
+            tags: vec!["fixture".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+        let response = app
+            .clone()
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/v1/sources/collect-due")
+                    .uri("/api/v1/sources")
                     .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{"observed_at":"2026-04-04T10:00:00Z","tick_minute":42,"max_items_per_source":0}"#,
-                    ))
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(zero_limit_response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
 
-        let missing_source_response = test_app()
+        let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/v1/sources/collect-due")
+                    .uri(format!("/api/v1/sources/{}/collect", created.source.id))
                     .header("content-type", "application/json")
                     .body(Body::from(
-                        r#"{"observed_at":"2026-04-04T10:00:00Z","tick_minute":42,"source_ids":["missing_source"]}"#,
+                        serde_json::to_vec(&serde_json::json!({
+                            "observed_at": "2026-04-05T00:00:00Z"
+                        }))
+                        .unwrap(),
                     ))
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(missing_source_response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn funding_round_mapping() -> serde_json::Value {
+        serde_json::json!({
+            "items_path": "$.data.results",
+            "title": "$.headline",
+            "summary": "{{ company.name }} raised {{ round.stage }} funding",
+            "content": "$.body.text",
+            "url": "$.links[0].href",
+            "observed_at": "$.published",
+            "tags": ["$.topics", "funding-round"],
+            "entity_labels": ["$.company.name", "$.investors[*].name"],
+            "proposed_claims": [{
+                "subject": "$.company.name",
+                "predicate": "raised",
+                "object": "{{ round.stage }} of {{ round.amount_usd }} USD",
+                "confidence_bps": 7000
+            }]
+        })
     }
 
     #[tokio::test]
-    async fn source_webhook_endpoint_ingests_payload_and_opens_case() {
+    async fn source_json_mapping_preview_reports_mapped_items_and_errors() {
+        let mut payload: serde_json::Value = serde_json::from_str(include_str!(
+            "../tests/fixtures/json_api/funding_rounds.json"
+        ))
+        .unwrap();
+        payload["data"]["results"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({"company": {"name": "Untitled Co"}}));
+        let preview = |mapping: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/sources/json-mapping/preview")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&serde_json::json!({
+                        "mapping": mapping,
+                        "payload": payload.clone()
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap()
+        };
+
         let app = test_app();
-        let create = CreateSourceRequest {
-            profile_id: None,
-            name: "Boreal Webhook".to_string(),
-            description: "Inbound partner intelligence webhook".to_string(),
-            kind: helix_core::intel_desk::SourceKind::WebhookIngest,
-            endpoint_url: None,
-            credential_id: None,
-            credential_header_name: None,
-            credential_header_prefix: None,
-            cadence_minutes: 15,
-            trust_score: 88,
-            enabled: true,
-            tags: vec!["market-intel".to_string(), "webhook".to_string()],
-        };
-
-        let create_response = app
+        let response = app
             .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/v1/sources")
-                    .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
-                    .unwrap(),
-            )
+            .oneshot(preview(funding_round_mapping()))
             .await
             .unwrap();
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = to_bytes(create_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let preview_response: JsonMappingPreviewResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(preview_response.item_count, 3);
+        assert_eq!(preview_response.mapped_count, 2);
 
-        let webhook_response = app
-            .clone()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(format!("/api/v1/sources/{}/webhook", created.source.id))
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{
-                          "observed_at": "2026-04-02T10:00:00Z",
-                          "items": [
-                            {
-                              "title": "Boreal Cloud webhook reports a bundle discount",
-                              "summary": "Inbound partner webhook says Boreal Cloud is discounting an enterprise bundle.",
-                              "content": "Boreal Cloud pricing changed after a new enterprise bundle discount was pushed to partners.",
-                              "url": "https://example.org/webhook/boreal-bundle",
-                              "tags": ["pricing"],
-                              "entity_labels": ["boreal cloud"],
-                              "proposed_claims": [
-                                {
-                                  "subject": "boreal cloud",
-                                  "predicate": "discounted",
-                                  "object": "enterprise bundle",
-                                  "confidence_bps": 9000,
-                                  "rationale": "The webhook payload states the partner discount."
-                                }
-                              ]
-                            }
-                          ]
-                        }"#,
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(webhook_response.status(), StatusCode::CREATED);
-        let body = to_bytes(webhook_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let payload: WebhookIngestResponse = serde_json::from_slice(&body).unwrap();
-        assert_eq!(payload.accepted_count, 1);
-        assert_eq!(payload.duplicate_count, 0);
-        assert_eq!(payload.results[0].claims.len(), 1);
-        assert!(!payload.results[0].hits.is_empty());
-        assert!(!payload.results[0].case_updates.is_empty());
-        assert!(payload.results[0]
-            .evidence
-            .tags
-            .contains(&"webhook".to_string()));
-    }
+        let orion = preview_response.items[0].item.as_ref().unwrap();
+        assert_eq!(orion.title, "Orion Dynamics closes $42M Series C");
+        assert_eq!(
+            orion.summary.as_deref(),
+            Some("Orion Dynamics raised Series C funding")
+        );
+        assert_eq!(orion.observed_at.as_deref(), Some("2026-04-03T08:00:00Z"));
+        assert_eq!(
+            orion.url.as_deref(),
+            Some("https://example.com/rounds/rnd-1")
+        );
+        assert_eq!(
+            orion.entity_labels,
+            vec!["Orion Dynamics", "Kestrel Ventures"]
+        );
+        assert_eq!(orion.proposed_claims.len(), 1);
+        assert_eq!(orion.proposed_claims[0].object, "Series C of 42000000 USD");
+
+        let vega = preview_response.items[1].item.as_ref().unwrap();
+        assert_eq!(vega.observed_at.as_deref(), Some("2026-04-02T09:00:00Z"));
+        assert_eq!(vega.tags, vec!["logistics", "funding-round"]);
+        assert!(vega.proposed_claims.is_empty());
+        assert!(preview_response.items[2]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("title did not resolve"));
 
-    #[tokio::test]
-    async fn source_webhook_endpoint_rejects_non_webhook_source() {
-        let response = test_app()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/v1/sources/rss_national_security/webhook")
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{"observed_at":"2026-04-02T10:00:00Z","items":[{"title":"Denied","summary":"Denied","content":"Denied"}]}"#,
-                    ))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let mut invalid = funding_round_mapping();
+        invalid["title"] = serde_json::json!("$.headline[");
+        let response = app.oneshot(preview(invalid)).await.unwrap();
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn source_webhook_endpoint_rejects_payload_boundaries() {
+    async fn source_collect_endpoint_applies_json_api_mapping() {
+        let endpoint_url = spawn_text_server(
+            "/fixtures/funding_rounds.json",
+            include_str!("../tests/fixtures/json_api/funding_rounds.json"),
+        )
+        .await;
         let app = test_app();
-        let create = CreateSourceRequest {
-            profile_id: None,
-            name: "Boundary Webhook".to_string(),
-            description: "Webhook boundary source".to_string(),
-            kind: helix_core::intel_desk::SourceKind::WebhookIngest,
-            endpoint_url: None,
-            credential_id: None,
-            credential_header_name: None,
-            credential_header_prefix: None,
-            cadence_minutes: 15,
-            trust_score: 88,
-            enabled: true,
-            tags: vec!["boundary".to_string()],
-        };
-        let create_response = app
+        let create = serde_json::json!({
+            "name": "Funding Rounds API",
+            "description": "Nested funding round feed",
+            "kind": "json_api",
+            "endpoint_url": endpoint_url,
+            "cadence_minutes": 60,
+            "trust_score": 80,
+            "enabled": true,
+            "tags": ["funding"],
+            "json_api": funding_round_mapping()
+        });
+        let response = app
             .clone()
             .oneshot(
                 Request::builder()
@@ -5701,72 +5957,123 @@ mod tests {
             )
             .await
             .unwrap();
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = to_bytes(create_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
         let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            created
+                .source
+                .json_api
+                .as_ref()
+                .unwrap()
+                .items_path
+                .as_deref(),
+            Some("$.data.results")
+        );
 
-        let empty_response = app
-            .clone()
+        let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(format!("/api/v1/sources/{}/webhook", created.source.id))
+                    .uri(format!("/api/v1/sources/{}/collect", created.source.id))
                     .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{"observed_at":"2026-04-02T10:00:00Z","items":[]}"#,
-                    ))
+                    .body(Body::from(r#"{"observed_at":"2026-04-05T00:00:00Z"}"#))
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(empty_response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let collected: CollectSourceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(collected.collected_count, 2);
+        let orion = &collected.results[0];
+        assert_eq!(orion.evidence.observed_at, "2026-04-03T08:00:00Z");
+        assert!(orion.evidence.content.contains("Lakeside plant"));
+        assert!(orion.evidence.tags.contains(&"robotics".to_string()));
+        assert_eq!(orion.claims.len(), 1);
+        let vega = &collected.results[1].evidence;
+        assert_eq!(vega.content, "Vega Freight raised Seed funding");
+        assert_eq!(
+            vega.url.as_deref(),
+            Some("https://example.com/rounds/rnd-2")
+        );
+    }
 
-        let too_many_items = serde_json::json!({
-            "observed_at": "2026-04-02T10:00:00Z",
-            "items": (0..=50)
-                .map(|index| serde_json::json!({
-                    "title": format!("Webhook item {index}"),
-                    "summary": "summary",
-                    "content": "content"
-                }))
-                .collect::<Vec<_>>()
+    const CONDITIONAL_FETCH_BODY: &str = r#"{
+      "items": [
+        {
+          "title": "Boreal Cloud opened a Lisbon region",
+          "summary": "Boreal Cloud announced a new hosting region.",
+          "content": "Boreal Cloud opened a Lisbon region for EU customers.",
+          "url": "https://example.org/boreal/lisbon",
+          "observed_at": "2026-04-02T09:00:00Z"
+        }
+      ]
+    }"#;
+
+    /// Serves `CONDITIONAL_FETCH_BODY` with an ETag and answers a matching `If-None-Match`
+    /// with 304. The first `failures` requests get HTTP 500. Returns the URL and a request counter.
+    async fn spawn_conditional_server(
+        path: &'static str,
+        failures: usize,
+    ) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
+        let app = Router::new().route(
+            path,
+            get(move |headers: axum::http::HeaderMap| {
+                let calls = calls.clone();
+                async move {
+                    let call = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    if call < failures {
+                        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+                    }
+                    let etag = "\"lisbon-v1\"";
+                    let cache_headers = [
+                        ("etag", etag),
+                        ("last-modified", "Thu, 02 Apr 2026 09:00:00 GMT"),
+                    ];
+                    if headers
+                        .get("if-none-match")
+                        .is_some_and(|value| value == etag)
+                    {
+                        return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
+                    }
+                    (StatusCode::OK, cache_headers, CONDITIONAL_FETCH_BODY).into_response()
+                }
+            }),
+        );
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
         });
-        let too_many_response = app
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(format!("/api/v1/sources/{}/webhook", created.source.id))
-                    .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_vec(&too_many_items).unwrap()))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(too_many_response.status(), StatusCode::BAD_REQUEST);
+        (format!("http://{addr}{path}"), counter)
     }
 
-    #[tokio::test]
-    async fn source_file_import_endpoint_ingests_file_and_opens_case() {
-        let app = test_app();
+    async fn create_pull_source(
+        app: Router,
+        endpoint_url: String,
+    ) -> helix_core::intel_desk::SourceDefinition {
         let create = CreateSourceRequest {
             profile_id: None,
-            name: "Boreal File Drop".to_string(),
-            description: "Operator-uploaded competitive intelligence files".to_string(),
-            kind: helix_core::intel_desk::SourceKind::FileImport,
-            endpoint_url: None,
+            name: "Conditional fetch source".to_string(),
+            description: "Pull source used by fetch health tests".to_string(),
+            kind: helix_core::intel_desk::SourceKind::JsonApi,
+            endpoint_url: Some(endpoint_url),
             credential_id: None,
             credential_header_name: None,
             credential_header_prefix: None,
             cadence_minutes: 60,
-            trust_score: 86,
+            trust_score: 80,
             enabled: true,
-            tags: vec!["market-intel".to_string(), "file".to_string()],
+            tags: vec!["fetch".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         };
-
-        let create_response = app
-            .clone()
+        let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
@@ -5777,84 +6084,329 @@ mod tests {
             )
             .await
             .unwrap();
-        assert_eq!(create_response.status(), StatusCode::CREATED);
-        let body = to_bytes(create_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        serde_json::from_slice::<SourceResponse>(&body)
+            .unwrap()
+            .source
+    }
 
-        let import_response = app
-            .clone()
+    async fn collect_source_now(app: Router, source_id: &str) -> (StatusCode, Value) {
+        let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(format!("/api/v1/sources/{}/import", created.source.id))
+                    .uri(format!("/api/v1/sources/{source_id}/collect"))
                     .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{
-                          "file_name": "boreal-pricing-note.md",
-                          "title": "Boreal Cloud file note reports a bundle discount",
-                          "content": "Boreal Cloud pricing changed after an enterprise bundle discount was distributed in a partner note.",
-                          "observed_at": "2026-04-03T10:00:00Z",
-                          "tags": ["pricing"],
-                          "entity_labels": ["boreal cloud"],
-                          "proposed_claims": [
-                            {
-                              "subject": "boreal cloud",
-                              "predicate": "discounted",
-                              "object": "enterprise bundle",
-                              "confidence_bps": 8700,
-                              "rationale": "The imported note states the bundle discount."
-                            }
-                          ]
-                        }"#,
-                    ))
+                    .body(Body::from(r#"{"observed_at":"2026-04-05T00:00:00Z"}"#))
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(import_response.status(), StatusCode::CREATED);
-        let body = to_bytes(import_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let payload: FileImportResponse = serde_json::from_slice(&body).unwrap();
-        assert_eq!(payload.result.claims.len(), 1);
-        assert!(!payload.result.hits.is_empty());
-        assert!(!payload.result.case_updates.is_empty());
-        assert!(payload.result.evidence.tags.contains(&"file".to_string()));
+        let status = response.status();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
     }
 
-    #[tokio::test]
-    async fn source_file_import_endpoint_rejects_kind_and_content_boundaries() {
-        let wrong_kind_response = test_app()
+    async fn source_health(app: Router, source_id: &str) -> Value {
+        let response = app
             .oneshot(
                 Request::builder()
-                    .method("POST")
-                    .uri("/api/v1/sources/rss_national_security/import")
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{"file_name":"note.txt","content":"content","observed_at":"2026-04-03T10:00:00Z"}"#,
-                    ))
+                    .uri("/api/v1/sources")
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(wrong_kind_response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let catalog: Value = serde_json::from_slice(&body).unwrap();
+        catalog["health"][source_id].clone()
+    }
 
-        let app = test_app();
-        let create = CreateSourceRequest {
-            profile_id: None,
-            name: "Boundary File Drop".to_string(),
-            description: "File boundary source".to_string(),
-            kind: helix_core::intel_desk::SourceKind::FileImport,
-            endpoint_url: None,
+    #[tokio::test]
+    async fn source_collect_endpoint_revalidates_with_etag() {
+        let (endpoint_url, calls) = spawn_conditional_server("/regions.json", 0).await;
+        let state = default_app_state(None, None);
+        let app = app(state.clone());
+        let source = create_pull_source(app.clone(), endpoint_url).await;
+        let health = source_health(app.clone(), &source.id).await;
+        assert_eq!(health["status"], "unknown");
+
+        let (status, first) = collect_source_now(app.clone(), &source.id).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(first["collected_count"], 1);
+        assert_eq!(first["not_modified"], false);
+
+        let (status, second) = collect_source_now(app.clone(), &source.id).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(second["collected_count"], 0);
+        assert_eq!(second["not_modified"], true);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        let health = source_health(app.clone(), &source.id).await;
+        assert_eq!(health["status"], "healthy");
+        assert_eq!(health["breaker"], "Closed");
+        assert_eq!(health["last_status"], 304);
+        assert_eq!(health["last_bytes_fetched"], 0);
+        assert_eq!(
+            health["bytes_fetched_total"],
+            CONDITIONAL_FETCH_BODY.len() as u64
+        );
+        assert_eq!(health["last_success_at"], "2026-04-05T00:00:00Z");
+
+        let run = state
+            .intel_desk
+            .read()
+            .await
+            .scheduled_source_candidates()
+            .into_iter()
+            .find_map(|(candidate, run)| (candidate.id == source.id).then_some(run))
+            .flatten()
+            .unwrap();
+        let fetch = &run.fetch;
+        assert_eq!(fetch.etag.as_deref(), Some("\"lisbon-v1\""));
+        assert_eq!(
+            fetch.last_modified.as_deref(),
+            Some("Thu, 02 Apr 2026 09:00:00 GMT")
+        );
+    }
+
+    #[tokio::test]
+    async fn source_collect_endpoint_opens_circuit_after_repeated_failures() {
+        let (endpoint_url, calls) = spawn_conditional_server("/flaky.json", 3).await;
+        let app = test_app();
+        let source = create_pull_source(app.clone(), endpoint_url).await;
+
+        for _ in 0..3 {
+            let (status, _) = collect_source_now(app.clone(), &source.id).await;
+            assert_eq!(status, StatusCode::BAD_GATEWAY);
+        }
+        let health = source_health(app.clone(), &source.id).await;
+        assert_eq!(health["status"], "circuit_open");
+        assert_eq!(health["breaker"], "Open");
+        assert_eq!(health["consecutive_failures"], 3);
+        assert_eq!(health["last_status"], 500);
+        assert!(health["last_error"].as_str().unwrap().contains("HTTP 500"));
+
+        for _ in 0..2 {
+            let (status, body) = collect_source_now(app.clone(), &source.id).await;
+            assert_eq!(status, StatusCode::BAD_GATEWAY);
+            assert!(body.to_string().contains("circuit is open"));
+        }
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        let (status, probe) = collect_source_now(app.clone(), &source.id).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(probe["collected_count"], 1);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 4);
+        let health = source_health(app, &source.id).await;
+        assert_eq!(health["status"], "healthy");
+        assert_eq!(health["breaker"], "Closed");
+        assert_eq!(health["consecutive_failures"], 0);
+        assert_eq!(health["last_failure_at"], "2026-04-05T00:00:00Z");
+    }
+
+    #[tokio::test]
+    async fn source_collect_endpoint_parses_rss2_fixture() {
+        let collected = collect_feed_fixture(
+            "/fixtures/rss2.xml",
+            include_str!("../tests/fixtures/feeds/rss2.xml"),
+        )
+        .await;
+        assert_eq!(collected.collected_count, 2);
+
+        let partnership = &collected.results[0].evidence;
+        assert_eq!(
+            partnership.title,
+            "Boreal Cloud & Orion Dynamics expand partnership"
+        );
+        assert_eq!(partnership.observed_at, "2026-04-01T01:30:00Z");
+        assert_eq!(
+            partnership.url.as_deref(),
+            Some("https://example.org/boreal/partnership")
+        );
+        assert_eq!(
+            partnership.summary,
+            "Boreal Cloud signed a reseller agreement."
+        );
+        assert!(partnership
+            .content
+            .contains("reseller agreement with Orion Dynamics"));
+        assert!(partnership.content.contains("Authors: Dana Reyes"));
+        assert!(partnership.content.contains(
+            "Enclosure: https://example.org/boreal/partnership.mp3 (audio/mpeg, 48213 bytes)"
+        ));
+        assert_eq!(
+            partnership.tags,
+            vec!["fixture", "go-to-market", "partnerships", "rss"]
+        );
+
+        let lisbon = &collected.results[1].evidence;
+        assert_eq!(
+            lisbon.url.as_deref(),
+            Some("https://example.org/boreal/lisbon")
+        );
+        assert_eq!(lisbon.observed_at, "2026-03-30T07:15:00Z");
+        assert!(lisbon.content.contains("Authors: Sam Okafor"));
+        assert!(lisbon
+            .content
+            .contains("Enclosure: https://example.org/boreal/lisbon.jpg (image/jpeg, 2048 bytes)"));
+        assert!(lisbon.tags.contains(&"hiring".to_string()));
+    }
+
+    #[tokio::test]
+    async fn source_collect_endpoint_parses_rss1_fixture() {
+        let collected = collect_feed_fixture(
+            "/fixtures/rss1.rdf",
+            include_str!("../tests/fixtures/feeds/rss1.rdf"),
+        )
+        .await;
+        assert_eq!(collected.collected_count, 1);
+
+        let filing = &collected.results[0].evidence;
+        assert_eq!(
+            filing.title,
+            "Orion Dynamics files 8-K on leadership change"
+        );
+        assert_eq!(filing.observed_at, "2026-04-02T14:05:00Z");
+        assert_eq!(
+            filing.url.as_deref(),
+            Some("https://example.org/orion/filings/8k")
+        );
+        assert!(filing
+            .summary
+            .contains("new chief financial officer \u{2014} effective immediately"));
+        assert!(filing.content.contains("Authors: Filings Desk"));
+        assert!(filing.content.contains(
+            "Enclosure: https://example.org/orion/filings/8k.pdf (application/pdf, 90210 bytes)"
+        ));
+        assert_eq!(filing.tags, vec!["fixture", "regulatory-filings", "rss"]);
+    }
+
+    #[tokio::test]
+    async fn source_collect_endpoint_parses_atom_fixture() {
+        let collected = collect_feed_fixture(
+            "/fixtures/atom.xml",
+            include_str!("../tests/fixtures/feeds/atom.xml"),
+        )
+        .await;
+        assert_eq!(collected.collected_count, 2);
+
+        let advisory = &collected.results[0].evidence;
+        assert_eq!(advisory.title, "Northwind gateway patch for CVE-2026-1234");
+        assert_eq!(advisory.observed_at, "2026-04-03T07:30:00Z");
+        assert_eq!(
+            advisory.url.as_deref(),
+            Some("https://example.org/northwind/advisories/2026-01")
+        );
+        assert_eq!(
+            advisory.summary,
+            "Patch released for a gateway authentication bypass."
+        );
+        assert!(advisory
+            .content
+            .starts_with("Northwind released firmware 4.2.1 fixing an authentication bypass."));
+        assert!(advisory.content.contains("Authors: Northwind PSIRT"));
+        assert!(advisory.content.contains(
+            "Enclosure: https://example.org/northwind/advisories/2026-01.json (application/json, 1337 bytes)"
+        ));
+        assert_eq!(
+            advisory.tags,
+            vec!["atom", "firmware-updates", "fixture", "security"]
+        );
+
+        let retirement = &collected.results[1].evidence;
+        assert_eq!(
+            retirement.url.as_deref(),
+            Some("https://example.org/northwind/advisories/2026-02")
+        );
+        assert_eq!(retirement.observed_at, "2026-04-01T00:00:00Z");
+        assert!(retirement
+            .content
+            .starts_with("The legacy VPN client reaches end of life in June."));
+        assert!(retirement.content.contains("Authors: Avery Lin"));
+    }
+
+    #[tokio::test]
+    async fn source_collect_endpoint_parses_json_feed_fixture() {
+        let collected = collect_feed_fixture(
+            "/fixtures/feed.json",
+            include_str!("../tests/fixtures/feeds/json_feed.json"),
+        )
+        .await;
+        assert_eq!(collected.collected_count, 2);
+
+        let funding = &collected.results[0].evidence;
+        assert_eq!(funding.title, "Helios Robotics closes Series C");
+        assert_eq!(funding.observed_at, "2026-04-04T23:45:00Z");
+        assert_eq!(funding.summary, "Helios Robotics raised new funding.");
+        assert!(funding.content.contains("Authors: Helios Communications"));
+        assert!(funding.content.contains(
+            "Enclosure: https://example.org/helios/series-c.pdf (application/pdf, 4096 bytes)"
+        ));
+        assert_eq!(
+            funding.tags,
+            vec!["fixture", "funding", "json-feed", "robotics"]
+        );
+
+        let pilot = &collected.results[1].evidence;
+        assert!(pilot
+            .title
+            .starts_with("Helios Robotics began a warehouse automation pilot"));
+        assert_eq!(
+            pilot.url.as_deref(),
+            Some("https://news.example.net/helios-warehouse")
+        );
+        assert_eq!(pilot.observed_at, "2026-04-05T00:00:00Z");
+        assert!(pilot.content.contains("Authors: Priya Natarajan"));
+    }
+
+    #[tokio::test]
+    async fn source_collect_due_endpoint_collects_due_pull_sources() {
+        let feed_url = spawn_text_server(
+            "/scheduled-pricing.json",
+            r#"{
+              "items": [
+                {
+                  "title": "Boreal Cloud scheduled pricing bundle changed",
+                  "summary": "Scheduled collector saw Boreal Cloud add an enterprise bundle discount.",
+                  "content": "Boreal Cloud pricing changed with a scheduled enterprise bundle discount signal.",
+                  "url": "https://example.org/boreal/scheduled-pricing",
+                  "tags": ["pricing"],
+                  "entity_labels": ["boreal cloud"],
+                  "proposed_claims": [
+                    {
+                      "subject": "boreal cloud",
+                      "predicate": "discounted",
+                      "object": "enterprise bundle",
+                      "confidence_bps": 8900,
+                      "rationale": "The scheduled feed states a bundle discount."
+                    }
+                  ]
+                }
+              ]
+            }"#,
+        )
+        .await;
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Boreal Scheduled Pricing Feed".to_string(),
+            description: "Scheduled JSON pricing changes".to_string(),
+            kind: helix_core::intel_desk::SourceKind::JsonApi,
+            endpoint_url: Some(feed_url),
             credential_id: None,
             credential_header_name: None,
             credential_header_prefix: None,
-            cadence_minutes: 60,
-            trust_score: 86,
+            cadence_minutes: 1,
+            trust_score: 90,
             enabled: true,
-            tags: vec!["boundary".to_string()],
+            tags: vec!["market-intel".to_string(), "scheduled".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         };
         let create_response = app
             .clone()
@@ -5874,111 +6426,4327 @@ mod tests {
             .unwrap();
         let created: SourceResponse = serde_json::from_slice(&body).unwrap();
 
-        let empty_response = app
+        let collect_response = app
             .clone()
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(format!("/api/v1/sources/{}/import", created.source.id))
+                    .uri("/api/v1/sources/collect-due")
                     .header("content-type", "application/json")
                     .body(Body::from(
-                        r#"{"file_name":"empty.txt","content":"","observed_at":"2026-04-03T10:00:00Z"}"#,
+                        serde_json::to_vec(&serde_json::json!({
+                            "observed_at": "2026-04-04T10:00:00Z",
+                            "tick_minute": 42,
+                            "max_items_per_source": 5,
+                            "source_ids": [created.source.id]
+                        }))
+                        .unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(collect_response.status(), StatusCode::CREATED);
+        let body = to_bytes(collect_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let payload: CollectDueSourcesResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.due_count, 1);
+        assert_eq!(payload.skipped_count, 0);
+        assert_eq!(payload.collections.len(), 1);
+        assert_eq!(payload.collections[0].collected_count, 1);
+        assert_eq!(payload.collections[0].duplicate_count, 0);
+        assert_eq!(payload.collections[0].schedule_phase_minute, 0);
+        assert!(!payload.collections[0].results[0].hits.is_empty());
+        assert!(!payload.collections[0].results[0].case_updates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn source_scheduler_tick_collects_due_sources_and_records_runs() {
+        let feed_url = spawn_text_server(
+            "/scheduler-pricing.json",
+            r#"{
+              "items": [
+                {
+                  "title": "Boreal Cloud scheduler pricing bundle changed",
+                  "summary": "The in-process scheduler saw a Boreal Cloud enterprise bundle discount.",
+                  "content": "Boreal Cloud pricing changed with a scheduler-driven enterprise bundle discount.",
+                  "url": "https://example.org/boreal/scheduler-pricing",
+                  "tags": ["pricing"],
+                  "entity_labels": ["boreal cloud"]
+                }
+              ]
+            }"#,
+        )
+        .await;
+        let state = default_app_state(None, None);
+        let app = app(state.clone());
+        for (name, endpoint_url) in [
+            ("Boreal Scheduler Feed", feed_url),
+            (
+                "Broken Scheduler Feed",
+                "http://127.0.0.1:9/unreachable.json".to_string(),
+            ),
+        ] {
+            let create = CreateSourceRequest {
+                profile_id: None,
+                name: name.to_string(),
+                description: "Scheduler-driven JSON source".to_string(),
+                kind: helix_core::intel_desk::SourceKind::JsonApi,
+                endpoint_url: Some(endpoint_url),
+                credential_id: None,
+                credential_header_name: None,
+                credential_header_prefix: None,
+                cadence_minutes: 1,
+                trust_score: 90,
+                enabled: true,
+                tags: vec!["market-intel".to_string()],
+                website_diff: None,
+                file_import: None,
+                json_api: None,
+                webhook_signature: None,
+            };
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/api/v1/sources")
+                        .header("content-type", "application/json")
+                        .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let now = chrono::DateTime::parse_from_rfc3339("2026-04-04T10:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let summary = run_source_scheduler_tick(&state, now)
+            .await
+            .unwrap()
+            .expect("scheduler is not paused");
+        assert_eq!(summary.due_count, 2);
+        assert_eq!(summary.collected_count, 1);
+        assert_eq!(summary.failed_count, 1);
+        assert_eq!(summary.failures[0].source_id, "broken-scheduler-feed");
+
+        let repeat = run_source_scheduler_tick(&state, now)
+            .await
+            .unwrap()
+            .expect("scheduler is not paused");
+        assert_eq!(repeat.due_count, 0);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/api/v1/sources/scheduler?tick_minute={}",
+                        summary.tick_minute
                     ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: SourceSchedulerResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.scheduler.tick_count, 2);
+        assert_eq!(
+            payload.scheduler.last_tick_minute,
+            Some(summary.tick_minute)
+        );
+        let healthy = payload
+            .sources
+            .iter()
+            .find(|status| status.source_id == "boreal-scheduler-feed")
+            .unwrap();
+        assert!(healthy.schedulable);
+        assert_eq!(healthy.next_due_minute, Some(summary.tick_minute));
+        let run = healthy.run.as_ref().unwrap();
+        assert_eq!(
+            run.last_collected_at.as_deref(),
+            Some("2026-04-04T10:00:00Z")
+        );
+        assert_eq!(run.collected_total, 1);
+        let broken = payload
+            .sources
+            .iter()
+            .find(|status| status.source_id == "broken-scheduler-feed")
+            .unwrap();
+        let run = broken.run.as_ref().unwrap();
+        assert_eq!(run.last_collected_at, None);
+        assert_eq!(run.consecutive_failures, 1);
+        assert!(run.last_error.is_some());
+        assert!(payload
+            .sources
+            .iter()
+            .any(|status| status.source_id == "rss_national_security" && !status.schedulable));
+    }
+
+    #[tokio::test]
+    async fn source_scheduler_catches_up_slots_missed_while_restarting() {
+        let feed_url = spawn_text_server(
+            "/scheduler-catch-up.json",
+            r#"{"items": [{"title": "Boreal Cloud hourly digest", "summary": "Hourly digest", "content": "Boreal Cloud hourly digest content.", "url": "https://example.org/boreal/hourly", "tags": ["pricing"], "entity_labels": ["boreal cloud"]}]}"#,
+        )
+        .await;
+        let state = default_app_state(None, None);
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Boreal Hourly Feed".to_string(),
+            description: "Hourly JSON source".to_string(),
+            kind: helix_core::intel_desk::SourceKind::JsonApi,
+            endpoint_url: Some(feed_url),
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 90,
+            enabled: true,
+            tags: vec!["market-intel".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+        let response = app(state.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(empty_response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let source = state
+            .intel_desk
+            .read()
+            .await
+            .scheduled_source_candidates()
+            .into_iter()
+            .map(|(source, _)| source)
+            .find(|source| source.id == "boreal-hourly-feed")
+            .unwrap();
+        let base = chrono::DateTime::parse_from_rfc3339("2026-04-04T10:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let due_minute = crate::intel::source_next_due_minute(
+            &source,
+            crate::intel_scheduler::tick_minute_at(base),
+        );
+        let at_minute = |minute: u64| {
+            chrono::DateTime::from_timestamp(i64::try_from(minute * 60).unwrap(), 0).unwrap()
+        };
+
+        let first = run_source_scheduler_tick(&state, at_minute(due_minute))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.due_count, 1);
+
+        // A restart forgets the in-process tick; the next slot passes while the process
+        // is down, and the first tick after it must still collect from the persisted run.
+        state.source_scheduler.write().await.last_tick_minute = None;
+        let after_restart = run_source_scheduler_tick(&state, at_minute(due_minute + 65))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(after_restart.due_count, 1);
+        let settled = run_source_scheduler_tick(&state, at_minute(due_minute + 66))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(settled.due_count, 0);
+
+        // A long outage still catches up with one collection, not one per missed slot.
+        state.source_scheduler.write().await.last_tick_minute = None;
+        let after_outage =
+            run_source_scheduler_tick(&state, at_minute(due_minute + 60 * 24 * 7 + 30))
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(after_outage.due_count, 1);
+    }
+
+    #[tokio::test]
+    async fn source_scheduler_pause_endpoint_suspends_ticks_across_restarts() {
+        let state = default_app_state(None, None);
+        let app = app(state.clone());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/scheduler/pause")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: SourceSchedulerResponse = serde_json::from_slice(&body).unwrap();
+        assert!(payload.scheduler.paused);
+        assert!(run_source_scheduler_tick(&state, chrono::Utc::now())
+            .await
+            .unwrap()
+            .is_none());
+
+        // The pause lives on the desk, so a process restarted from the stored desk
+        // (with fresh in-memory scheduler status) stays paused.
+        let restarted = default_app_state(None, None);
+        *restarted.intel_desk.write().await = state.intel_desk.read().await.clone();
+        assert!(!restarted.source_scheduler.read().await.paused);
+        assert!(run_source_scheduler_tick(&restarted, chrono::Utc::now())
+            .await
+            .unwrap()
+            .is_none());
+        let response = crate::app(restarted)
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/sources/scheduler")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: SourceSchedulerResponse = serde_json::from_slice(&body).unwrap();
+        assert!(payload.scheduler.paused);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/scheduler/resume")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: SourceSchedulerResponse = serde_json::from_slice(&body).unwrap();
+        assert!(!payload.scheduler.paused);
+        assert!(run_source_scheduler_tick(&state, chrono::Utc::now())
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn source_collect_due_endpoint_rejects_boundaries() {
+        let zero_limit_response = test_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/collect-due")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"observed_at":"2026-04-04T10:00:00Z","tick_minute":42,"max_items_per_source":0}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(zero_limit_response.status(), StatusCode::BAD_REQUEST);
+
+        let missing_source_response = test_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/collect-due")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"observed_at":"2026-04-04T10:00:00Z","tick_minute":42,"source_ids":["missing_source"]}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(missing_source_response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn source_webhook_endpoint_ingests_payload_and_opens_case() {
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Boreal Webhook".to_string(),
+            description: "Inbound partner intelligence webhook".to_string(),
+            kind: helix_core::intel_desk::SourceKind::WebhookIngest,
+            endpoint_url: None,
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 15,
+            trust_score: 88,
+            enabled: true,
+            tags: vec!["market-intel".to_string(), "webhook".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = to_bytes(create_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+
+        let webhook_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{}/webhook", created.source.id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{
+                          "observed_at": "2026-04-02T10:00:00Z",
+                          "items": [
+                            {
+                              "title": "Boreal Cloud webhook reports a bundle discount",
+                              "summary": "Inbound partner webhook says Boreal Cloud is discounting an enterprise bundle.",
+                              "content": "Boreal Cloud pricing changed after a new enterprise bundle discount was pushed to partners.",
+                              "url": "https://example.org/webhook/boreal-bundle",
+                              "tags": ["pricing"],
+                              "entity_labels": ["boreal cloud"],
+                              "proposed_claims": [
+                                {
+                                  "subject": "boreal cloud",
+                                  "predicate": "discounted",
+                                  "object": "enterprise bundle",
+                                  "confidence_bps": 9000,
+                                  "rationale": "The webhook payload states the partner discount."
+                                }
+                              ]
+                            }
+                          ]
+                        }"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(webhook_response.status(), StatusCode::CREATED);
+        let body = to_bytes(webhook_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let payload: WebhookIngestResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.accepted_count, 1);
+        assert_eq!(payload.duplicate_count, 0);
+        assert_eq!(payload.results[0].claims.len(), 1);
+        assert!(!payload.results[0].hits.is_empty());
+        assert!(!payload.results[0].case_updates.is_empty());
+        assert!(payload.results[0]
+            .evidence
+            .tags
+            .contains(&"webhook".to_string()));
+    }
+
+    fn webhook_hmac_hex(secret: &str, signed: &[u8]) -> String {
+        use hmac::Mac;
+        let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(signed);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn webhook_signature_config(
+        scheme: helix_core::intel_desk::WebhookSignatureScheme,
+        timestamp_header: Option<&str>,
+    ) -> helix_core::intel_desk::WebhookSignatureConfig {
+        helix_core::intel_desk::WebhookSignatureConfig {
+            scheme,
+            signature_header: None,
+            timestamp_header: timestamp_header.map(ToOwned::to_owned),
+            tolerance_seconds: 300,
+        }
+    }
+
+    #[test]
+    fn webhook_signature_verification_covers_supported_schemes() {
+        use crate::intel_webhook::{verify_webhook_signature, WebhookReplayGuard};
+        use axum::http::HeaderMap;
+        use helix_core::intel_desk::WebhookSignatureScheme;
+
+        let secret = "whsec_partner";
+        let body = br#"{"title":"Boreal Cloud signed delivery"}"#;
+        let now = 1_775_000_000_i64;
+
+        let github = webhook_signature_config(WebhookSignatureScheme::GithubSha256, None);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-hub-signature-256",
+            format!("sha256={}", webhook_hmac_hex(secret, body))
+                .parse()
+                .unwrap(),
+        );
+        let delivery =
+            verify_webhook_signature(&github, secret.as_bytes(), &headers, body, now).unwrap();
+        assert_eq!(delivery.timestamp, None);
+        let mut recased = headers.clone();
+        recased.insert(
+            "x-hub-signature-256",
+            format!("sha256={}", webhook_hmac_hex(secret, body).to_uppercase())
+                .parse()
+                .unwrap(),
+        );
+        recased.insert("x-github-delivery", "delivery-1".parse().unwrap());
+        let recased =
+            verify_webhook_signature(&github, secret.as_bytes(), &recased, body, now).unwrap();
+        assert_eq!(recased.fingerprint, delivery.fingerprint);
+        assert!(recased.delivery_id.is_some());
+        let mut github_guard = WebhookReplayGuard::new(300, now);
+        assert!(github_guard.admit(&recased, now));
+        assert!(!github_guard.admit(&delivery, now + 86_400));
+        let tampered = verify_webhook_signature(
+            &github,
+            secret.as_bytes(),
+            &headers,
+            br#"{"title":"tampered"}"#,
+            now,
+        );
+        assert_eq!(tampered.unwrap_err(), "signature does not match payload");
+        assert!(
+            verify_webhook_signature(&github, secret.as_bytes(), &HeaderMap::new(), body, now)
+                .unwrap_err()
+                .contains("missing `X-Hub-Signature-256`")
+        );
+
+        let stripe = webhook_signature_config(WebhookSignatureScheme::StripeV1, None);
+        let signed = [format!("{now}.").as_bytes(), body.as_slice()].concat();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "stripe-signature",
+            format!(
+                "t={now},v1={},v1=00,v0=ignored",
+                webhook_hmac_hex(secret, &signed)
+            )
+            .parse()
+            .unwrap(),
+        );
+        let delivery =
+            verify_webhook_signature(&stripe, secret.as_bytes(), &headers, body, now + 120)
+                .unwrap();
+        assert_eq!(delivery.timestamp, Some(now));
+        let mut padded = HeaderMap::new();
+        padded.insert(
+            "stripe-signature",
+            format!("v0=dummy,v1={},t={now}", webhook_hmac_hex(secret, &signed))
+                .parse()
+                .unwrap(),
+        );
+        let padded =
+            verify_webhook_signature(&stripe, secret.as_bytes(), &padded, body, now).unwrap();
+        assert_eq!(padded.fingerprint, delivery.fingerprint);
+        assert!(
+            verify_webhook_signature(&stripe, secret.as_bytes(), &headers, body, now + 301)
+                .unwrap_err()
+                .contains("tolerance is 300s")
+        );
+
+        let generic =
+            webhook_signature_config(WebhookSignatureScheme::HmacSha256, Some("X-Timestamp"));
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-signature-256",
+            webhook_hmac_hex(secret, &signed).parse().unwrap(),
+        );
+        assert!(
+            verify_webhook_signature(&generic, secret.as_bytes(), &headers, body, now)
+                .unwrap_err()
+                .contains("missing `X-Timestamp`")
+        );
+        headers.insert("x-timestamp", now.to_string().parse().unwrap());
+        let delivery =
+            verify_webhook_signature(&generic, secret.as_bytes(), &headers, body, now).unwrap();
+        assert!(verify_webhook_signature(&generic, b"wrong", &headers, body, now).is_err());
+
+        // Dated at the edge of tolerance ahead of the clock, the delivery verifies until
+        // `now + 600`, so the guard must remember it that long.
+        let mut guard = WebhookReplayGuard::new(300, now - 300);
+        assert!(guard.admit(&delivery, now - 300));
+        assert!(!guard.admit(&delivery, now + 299));
+        assert!(guard.admit(&delivery, now + 301));
+    }
+
+    #[tokio::test]
+    async fn webhook_delivery_is_admitted_only_when_its_body_ingests() {
+        let state = default_app_state(None, None);
+        let create = serde_json::json!({
+            "name": "Retrying Partner Webhook",
+            "description": "Partner deliveries retried with the same delivery id",
+            "kind": "webhook_ingest",
+            "cadence_minutes": 60,
+            "trust_score": 80,
+            "enabled": true,
+            "tags": ["partner"]
+        });
+        let response = app(state.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let source = serde_json::from_slice::<SourceResponse>(&body)
+            .unwrap()
+            .source;
+
+        let config = webhook_signature_config(
+            helix_core::intel_desk::WebhookSignatureScheme::GithubSha256,
+            None,
+        );
+        let now = 1_767_225_600;
+        let delivery = |fingerprint| crate::intel_webhook::VerifiedDelivery {
+            fingerprint,
+            timestamp: None,
+            delivery_id: Some(7),
+        };
+        let ingest = |fingerprint, body: &'static str| {
+            crate::intel::ingest_webhook_delivery(
+                &state,
+                source.clone(),
+                Some((&config, delivery(fingerprint), now)),
+                body.as_bytes(),
+            )
+        };
+
+        // Neither a malformed body nor one the desk rejects spends the delivery id.
+        let response = ingest(1, r#"{"title":"#).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = ingest(
+            2,
+            r#"{"title":"Partner outage","observed_at":"2026-01-01T00:00:00Z","proposed_claims":[{"subject":"Partner","predicate":"reports","object":"outage","confidence_bps":20000}]}"#,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let corrected = r#"{"title":"Partner outage","summary":"Partner API is down","observed_at":"2026-01-01T00:00:00Z"}"#;
+        let response = ingest(3, corrected).await;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: WebhookIngestResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.accepted_count, 1);
+        assert_eq!(payload.duplicate_count, 0);
+
+        let response = ingest(3, corrected).await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn source_webhook_endpoint_authenticates_signed_sources_without_bearer_token() {
+        let app = test_app_with_required_auth();
+        let create = serde_json::json!({
+            "name": "Signed Partner Webhook",
+            "description": "Partner deliveries signed with a shared secret",
+            "kind": "webhook_ingest",
+            "credential_id": "50000000-0000-0000-0000-000000000011",
+            "cadence_minutes": 60,
+            "trust_score": 80,
+            "enabled": true,
+            "tags": ["partner"],
+            "webhook_signature": { "scheme": "github_sha256" }
+        });
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("authorization", "Bearer test-token-12345")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            created
+                .source
+                .webhook_signature
+                .as_ref()
+                .unwrap()
+                .signature_header
+                .as_deref(),
+            Some("X-Hub-Signature-256")
+        );
+
+        let deliver = |uri: String, signature: Option<&'static str>| {
+            let mut request = Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json");
+            if let Some(signature) = signature {
+                request = request.header("x-hub-signature-256", signature);
+            }
+            request
+                .body(Body::from(r#"{"title":"Unsigned delivery"}"#))
+                .unwrap()
+        };
+        let signed_uri = format!("/api/v1/sources/{}/webhook", created.source.id);
+
+        let response = app
+            .clone()
+            .oneshot(deliver(signed_uri.clone(), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            payload["error"],
+            "missing `X-Hub-Signature-256` signature header"
+        );
+
+        // A present signature reaches the credential vault, which needs DATABASE_URL here.
+        let response = app
+            .clone()
+            .oneshot(deliver(signed_uri, Some("sha256=00")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let response = app
+            .oneshot(deliver(
+                "/api/v1/sources/rss_national_security/webhook".to_string(),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn source_webhook_endpoint_rejects_non_webhook_source() {
+        let response = test_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/rss_national_security/webhook")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"observed_at":"2026-04-02T10:00:00Z","items":[{"title":"Denied","summary":"Denied","content":"Denied"}]}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn source_webhook_endpoint_rejects_payload_boundaries() {
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Boundary Webhook".to_string(),
+            description: "Webhook boundary source".to_string(),
+            kind: helix_core::intel_desk::SourceKind::WebhookIngest,
+            endpoint_url: None,
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 15,
+            trust_score: 88,
+            enabled: true,
+            tags: vec!["boundary".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = to_bytes(create_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+
+        let empty_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{}/webhook", created.source.id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"observed_at":"2026-04-02T10:00:00Z","items":[]}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(empty_response.status(), StatusCode::BAD_REQUEST);
+
+        let too_many_items = serde_json::json!({
+            "observed_at": "2026-04-02T10:00:00Z",
+            "items": (0..=50)
+                .map(|index| serde_json::json!({
+                    "title": format!("Webhook item {index}"),
+                    "summary": "summary",
+                    "content": "content"
+                }))
+                .collect::<Vec<_>>()
+        });
+        let too_many_response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{}/webhook", created.source.id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&too_many_items).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(too_many_response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn source_file_import_endpoint_ingests_file_and_opens_case() {
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Boreal File Drop".to_string(),
+            description: "Operator-uploaded competitive intelligence files".to_string(),
+            kind: helix_core::intel_desk::SourceKind::FileImport,
+            endpoint_url: None,
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 86,
+            enabled: true,
+            tags: vec!["market-intel".to_string(), "file".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = to_bytes(create_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+
+        let import_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{}/import", created.source.id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{
+                          "file_name": "boreal-pricing-note.md",
+                          "title": "Boreal Cloud file note reports a bundle discount",
+                          "content": "Boreal Cloud pricing changed after an enterprise bundle discount was distributed in a partner note.",
+                          "observed_at": "2026-04-03T10:00:00Z",
+                          "tags": ["pricing"],
+                          "entity_labels": ["boreal cloud"],
+                          "proposed_claims": [
+                            {
+                              "subject": "boreal cloud",
+                              "predicate": "discounted",
+                              "object": "enterprise bundle",
+                              "confidence_bps": 8700,
+                              "rationale": "The imported note states the bundle discount."
+                            }
+                          ]
+                        }"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(import_response.status(), StatusCode::CREATED);
+        let body = to_bytes(import_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let payload: FileImportResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.result.claims.len(), 1);
+        assert!(!payload.result.hits.is_empty());
+        assert!(!payload.result.case_updates.is_empty());
+        assert!(payload.result.evidence.tags.contains(&"file".to_string()));
+    }
+
+    #[tokio::test]
+    async fn source_file_import_endpoint_rejects_kind_and_content_boundaries() {
+        let wrong_kind_response = test_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/rss_national_security/import")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"file_name":"note.txt","content":"content","observed_at":"2026-04-03T10:00:00Z"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(wrong_kind_response.status(), StatusCode::BAD_REQUEST);
+
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Boundary File Drop".to_string(),
+            description: "File boundary source".to_string(),
+            kind: helix_core::intel_desk::SourceKind::FileImport,
+            endpoint_url: None,
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 86,
+            enabled: true,
+            tags: vec!["boundary".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+        let body = to_bytes(create_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let created: SourceResponse = serde_json::from_slice(&body).unwrap();
+
+        let empty_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{}/import", created.source.id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"file_name":"empty.txt","content":"","observed_at":"2026-04-03T10:00:00Z"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(empty_response.status(), StatusCode::BAD_REQUEST);
+
+        let oversized = serde_json::json!({
+            "file_name": "large.txt",
+            "content": "x".repeat(16_385),
+            "observed_at": "2026-04-03T10:00:00Z"
+        });
+        let oversized_response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{}/import", created.source.id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&oversized).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(oversized_response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn create_file_import_source(
+        app: Router,
+        mapping: Option<helix_core::intel_desk::FileImportMapping>,
+    ) -> SourceResponse {
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Analyst Spreadsheet Drop".to_string(),
+            description: "Structured exports from analyst trackers".to_string(),
+            kind: helix_core::intel_desk::SourceKind::FileImport,
+            endpoint_url: None,
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 80,
+            enabled: true,
+            tags: vec!["tracker".to_string()],
+            website_diff: None,
+            file_import: mapping,
+            json_api: None,
+            webhook_signature: None,
+        };
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    async fn post_file_row_import(
+        app: Router,
+        source_id: &str,
+        request: serde_json::Value,
+    ) -> (StatusCode, Option<FileRowImportResponse>) {
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{source_id}/import"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), 4 * 1024 * 1024)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).ok())
+    }
+
+    #[tokio::test]
+    async fn source_file_import_maps_csv_rows_with_preview_and_row_errors() {
+        let app = test_app();
+        let created = create_file_import_source(
+            app.clone(),
+            Some(helix_core::intel_desk::FileImportMapping {
+                title: Some("Headline".to_string()),
+                summary: Some("Body".to_string()),
+                url: Some("Link".to_string()),
+                observed_at: Some("Published".to_string()),
+                tags: Some("Labels".to_string()),
+                entity_labels: Some("Companies".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await;
+        let request = |dry_run: bool| {
+            serde_json::json!({
+                "file_name": "analyst_notes.csv",
+                "content": include_str!("../tests/fixtures/imports/analyst_notes.csv"),
+                "observed_at": "2026-04-05T00:00:00Z",
+                "tags": ["q2-review"],
+                "dry_run": dry_run
+            })
+        };
+
+        let (status, preview) =
+            post_file_row_import(app.clone(), &created.source.id, request(true)).await;
+        assert_eq!(status, StatusCode::OK);
+        let preview = preview.unwrap();
+        assert!(preview.dry_run);
+        assert_eq!(preview.row_count, 6);
+        assert_eq!(preview.accepted_count, 2);
+        assert_eq!(preview.duplicate_count, 1);
+        assert_eq!(preview.error_count, 3);
+
+        let (status, imported) =
+            post_file_row_import(app.clone(), &created.source.id, request(false)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let imported = imported.unwrap();
+        assert!(!imported.dry_run);
+        assert_eq!(imported.accepted_count, 2, "dry run must not store rows");
+        let lines = imported
+            .rows
+            .iter()
+            .map(|row| (row.line, row.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                (2, FileImportRowStatus::Accepted),
+                (3, FileImportRowStatus::Accepted),
+                (5, FileImportRowStatus::Invalid),
+                (6, FileImportRowStatus::Invalid),
+                (7, FileImportRowStatus::Duplicate),
+                (8, FileImportRowStatus::Invalid),
+            ]
+        );
+        assert_eq!(
+            imported.rows[4].provenance_hash,
+            imported.rows[0].provenance_hash
+        );
+        assert!(imported.rows[2].error.as_deref().unwrap().contains("title"));
+        assert!(imported.rows[3]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("not a date"));
+        assert!(imported.rows[5]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("header has 6"));
+
+        let orion = &imported.rows[1].result.as_ref().unwrap().evidence;
+        assert_eq!(orion.title, "Orion Dynamics opens \"Lakeside\" plant");
+        assert!(orion.summary.contains("second plant.\nHiring starts"));
+        assert_eq!(orion.observed_at, "2026-04-03T09:30:00Z");
+        assert_eq!(orion.entity_labels, vec!["lakeside", "orion dynamics"]);
+        let boreal = &imported.rows[0].result.as_ref().unwrap().evidence;
+        assert_eq!(boreal.observed_at, "2026-04-03T00:00:00Z");
+        assert_eq!(
+            boreal.url.as_deref(),
+            Some("https://example.com/boreal-bundle")
+        );
+        for tag in ["tracker", "q2-review", "pricing", "partner"] {
+            assert!(boreal.tags.contains(&tag.to_string()), "missing {tag}");
+        }
+
+        let (status, repeated) =
+            post_file_row_import(app, &created.source.id, request(false)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let repeated = repeated.unwrap();
+        assert_eq!(repeated.accepted_count, 0);
+        assert_eq!(repeated.duplicate_count, 3);
+    }
+
+    #[tokio::test]
+    async fn source_file_import_reads_json_lines_and_tsv_rows() {
+        let app = test_app();
+        let created = create_file_import_source(app.clone(), None).await;
+
+        let (status, jsonl) = post_file_row_import(
+            app.clone(),
+            &created.source.id,
+            serde_json::json!({
+                "file_name": "signals.ndjson",
+                "content": include_str!("../tests/fixtures/imports/signals.ndjson"),
+                "observed_at": "2026-04-05T00:00:00Z"
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let jsonl = jsonl.unwrap();
+        assert_eq!(
+            serde_json::to_value(jsonl.format).unwrap(),
+            serde_json::json!("jsonl")
+        );
+        assert_eq!(jsonl.row_count, 5);
+        assert_eq!(jsonl.accepted_count, 2);
+        assert_eq!(jsonl.error_count, 3);
+        let vega = &jsonl.rows[0].result.as_ref().unwrap().evidence;
+        assert_eq!(vega.entity_labels, vec!["rotterdam", "vega freight"]);
+        assert!(vega.tags.contains(&"europe".to_string()));
+        let helio = &jsonl.rows[1].result.as_ref().unwrap().evidence;
+        assert_eq!(jsonl.rows[1].line, 3);
+        assert_eq!(helio.observed_at, "2026-04-04T00:00:00Z");
+        assert!(helio.summary.starts_with("Helio Grid won"));
+        assert!(jsonl.rows[2]
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("invalid JSON"));
+        assert_eq!(
+            jsonl.rows[3].error.as_deref(),
+            Some("line must be a JSON object")
+        );
+
+        let (status, tsv) = post_file_row_import(
+            app,
+            &created.source.id,
+            serde_json::json!({
+                "file_name": "export.txt",
+                "format": "tsv",
+                "content": "title\tsummary\ttags\nCobalt Air grounds fleet\tRegulator grounded two aircraft.\taviation;safety\n",
+                "observed_at": "2026-04-05T00:00:00Z"
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let tsv = tsv.unwrap();
+        assert_eq!(tsv.accepted_count, 1);
+        let cobalt = &tsv.rows[0].result.as_ref().unwrap().evidence;
+        assert_eq!(cobalt.observed_at, "2026-04-05T00:00:00Z");
+        assert!(cobalt.tags.contains(&"safety".to_string()));
+    }
+
+    #[tokio::test]
+    async fn source_file_import_dedups_on_stored_provenance_and_previews_without_storing() {
+        let app = test_app();
+        let created = create_file_import_source(app.clone(), None).await;
+        let import = |content: &str, dry_run: bool| {
+            serde_json::json!({
+                "file_name": "signals.jsonl",
+                "content": content,
+                "observed_at": "2026-04-05T00:00:00Z",
+                "dry_run": dry_run
+            })
+        };
+        let original = r#"{"title":"Kestrel Labs recalls sensors","summary":"Kestrel Labs recalled a sensor line.","content":"First report."}"#;
+        let reworded = r#"{"title":"Kestrel Labs recalls sensors","summary":"Kestrel Labs recalled a sensor line.","content":"Updated report with more detail."}"#;
+
+        let (status, preview) = post_file_row_import(
+            app.clone(),
+            &created.source.id,
+            import(&format!("{original}\n{original}\n"), true),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let preview = preview.unwrap();
+        let statuses = preview
+            .rows
+            .iter()
+            .map(|row| row.status)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                FileImportRowStatus::Accepted,
+                FileImportRowStatus::Duplicate
+            ]
+        );
+        let previewed = preview.rows[0].result.as_ref().unwrap();
+        assert!(previewed.case_updates.is_empty());
+        assert_eq!(
+            preview.rows[1].provenance_hash,
+            preview.rows[0].provenance_hash
+        );
+
+        let (status, imported) =
+            post_file_row_import(app.clone(), &created.source.id, import(original, false)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let imported = imported.unwrap();
+        assert_eq!(imported.accepted_count, 1, "dry run must not store rows");
+        let stored = &imported.rows[0];
+        assert_eq!(
+            stored.provenance_hash.as_deref(),
+            Some(
+                stored
+                    .result
+                    .as_ref()
+                    .unwrap()
+                    .evidence
+                    .provenance_hash
+                    .as_str()
+            )
+        );
+        assert_eq!(stored.provenance_hash, preview.rows[0].provenance_hash);
+
+        // Same evidence id with other content: the stored item and its hash are reported.
+        for dry_run in [true, false] {
+            let (_, repeated) =
+                post_file_row_import(app.clone(), &created.source.id, import(reworded, dry_run))
+                    .await;
+            let row = &repeated.unwrap().rows[0];
+            assert_eq!(row.status, FileImportRowStatus::Duplicate);
+            assert_eq!(row.provenance_hash, stored.provenance_hash);
+            assert_eq!(
+                row.result.as_ref().unwrap().evidence.content,
+                "First report."
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn source_file_import_rejects_structured_boundaries() {
+        let app = test_app();
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: "Mapped RSS".to_string(),
+            description: "Wrong kind for a column mapping".to_string(),
+            kind: helix_core::intel_desk::SourceKind::RssFeed,
+            endpoint_url: Some("https://example.com/feed.xml".to_string()),
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 80,
+            enabled: true,
+            tags: Vec::new(),
+            website_diff: None,
+            file_import: Some(helix_core::intel_desk::FileImportMapping {
+                title: Some("Headline".to_string()),
+                ..Default::default()
+            }),
+            json_api: None,
+            webhook_signature: None,
+        };
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let created = create_file_import_source(
+            app.clone(),
+            Some(helix_core::intel_desk::FileImportMapping {
+                url: Some("Link".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await;
+        let rejected = [
+            serde_json::json!({
+                "file_name": "missing_link.csv",
+                "content": "title,summary\nRow,Summary\n",
+                "observed_at": "2026-04-05T00:00:00Z"
+            }),
+            serde_json::json!({
+                "file_name": "header_only.csv",
+                "content": "title,summary,link\n",
+                "observed_at": "2026-04-05T00:00:00Z"
+            }),
+            serde_json::json!({
+                "file_name": "note.txt",
+                "content": "plain text",
+                "observed_at": "2026-04-05T00:00:00Z",
+                "dry_run": true
+            }),
+        ];
+        for request in rejected {
+            let (status, _) = post_file_row_import(app.clone(), &created.source.id, request).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    async fn create_email_source(app: Router, name: &str) -> SourceResponse {
+        let create = CreateSourceRequest {
+            profile_id: None,
+            name: name.to_string(),
+            description: "Forwarded newsletters and analyst mail".to_string(),
+            kind: helix_core::intel_desk::SourceKind::EmailDigest,
+            endpoint_url: None,
+            credential_id: None,
+            credential_header_name: None,
+            credential_header_prefix: None,
+            cadence_minutes: 60,
+            trust_score: 75,
+            enabled: true,
+            tags: vec!["inbox".to_string()],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
+        };
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&create).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    async fn post_email_import(
+        app: Router,
+        source_id: &str,
+        content: &str,
+    ) -> (StatusCode, Option<EmailImportResponse>) {
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/v1/sources/{source_id}/email"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_vec(&serde_json::json!({
+                            "content": content,
+                            "observed_at": "2026-04-05T00:00:00Z"
+                        }))
+                        .unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        (status, serde_json::from_slice(&body).ok())
+    }
+
+    #[tokio::test]
+    async fn source_email_import_endpoint_parses_multipart_message() {
+        let app = test_app();
+        let created = create_email_source(app.clone(), "Investor relations inbox").await;
+        let fixture = include_str!("../tests/fixtures/email/multipart_alternative.eml");
+
+        let (status, imported) = post_email_import(app.clone(), &created.source.id, fixture).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let imported = imported.unwrap();
+        assert_eq!(imported.message_count, 1);
+        assert_eq!(imported.accepted_count, 1);
+        assert_eq!(imported.duplicate_count, 0);
+
+        let evidence = &imported.results[0].evidence;
+        assert_eq!(
+            evidence.title,
+            "Orion Dynamics names new CFO \u{2014} effective today"
+        );
+        assert_eq!(evidence.observed_at, "2026-04-02T13:05:00Z");
+        assert_eq!(
+            evidence.url.as_deref(),
+            Some("mid:20260402.cfo@orion-dynamics.com")
+        );
+        assert!(evidence.content.starts_with(
+            "Orion Dynamics appointed Mara Chen as chief financial officer, effective immediately."
+        ));
+        assert!(evidence
+            .content
+            .contains("The company\u{2019}s previous CFO"));
+        assert!(!evidence.content.contains("JVBERi0"));
+        assert_eq!(evidence.entity_labels, vec!["news.orion-dynamics.com"]);
+        assert_eq!(evidence.tags, vec!["email", "inbox"]);
+
+        let (status, reimported) = post_email_import(app, &created.source.id, fixture).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let reimported = reimported.unwrap();
+        assert_eq!(reimported.duplicate_count, 1);
+        assert_eq!(reimported.results[0].evidence.id, evidence.id);
+    }
+
+    #[tokio::test]
+    async fn source_email_import_endpoint_splits_newsletter_digest() {
+        let app = test_app();
+        let created = create_email_source(app.clone(), "Newsletter inbox").await;
+        let (status, imported) = post_email_import(
+            app,
+            &created.source.id,
+            include_str!("../tests/fixtures/email/newsletter_digest.eml"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let imported = imported.unwrap();
+        assert_eq!(imported.message_count, 1);
+        assert_eq!(imported.accepted_count, 3);
+
+        let stories = imported
+            .results
+            .iter()
+            .map(|result| &result.evidence)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stories
+                .iter()
+                .map(|story| (story.title.as_str(), story.url.as_deref().unwrap()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "Helios Robotics closes Series C",
+                    "https://example.org/helios/series-c"
+                ),
+                (
+                    "Boreal Cloud opens Lisbon office",
+                    "https://example.org/boreal/lisbon"
+                ),
+                (
+                    "Northwind patches gateway authentication bypass",
+                    "https://example.org/northwind/advisories/2026-01"
+                ),
+            ]
+        );
+        assert!(stories[0]
+            .content
+            .starts_with("Helios Robotics raised $80M to scale warehouse automation pilots."));
+        assert!(stories[0].content.contains(
+            "Digest: Market Brief: robotics funding, Lisbon expansion, gateway patch (mid:brief-2026-04-04@digest.example.com)"
+        ));
+        assert!(stories[1]
+            .content
+            .starts_with("Boreal Cloud is hiring forty engineers for its new Lisbon hub."));
+        assert!(stories[2]
+            .content
+            .starts_with("Firmware 4.2.1 fixes CVE-2026-1234 & tightens session checks."));
+        assert!(!stories[2].content.contains("You are receiving this email"));
+        for story in &stories {
+            assert_eq!(story.observed_at, "2026-04-04T06:00:00Z");
+            assert_eq!(story.entity_labels, vec!["digest.example.com"]);
+            assert!(story.tags.contains(&"email-digest".to_string()));
+        }
+    }
+
+    #[tokio::test]
+    async fn source_email_import_endpoint_reads_mbox_messages() {
+        let app = test_app();
+        let created = create_email_source(app.clone(), "Archived mailbox").await;
+        let (status, imported) = post_email_import(
+            app,
+            &created.source.id,
+            include_str!("../tests/fixtures/email/mailbox.mbox"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let imported = imported.unwrap();
+        assert_eq!(imported.message_count, 2);
+        assert_eq!(imported.accepted_count, 2);
+
+        let filing = &imported.results[0].evidence;
+        assert_eq!(filing.title, "Orion Dynamics files 8-K");
+        assert_eq!(filing.observed_at, "2026-04-03T08:00:00Z");
+        assert_eq!(
+            filing.content,
+            "Orion Dynamics filed an 8-K describing a leadership change.\nFrom the filing: the new CFO starts immediately."
+        );
+        assert_eq!(filing.entity_labels, vec!["sec-watch.example.net"]);
+
+        let notes = &imported.results[1].evidence;
+        assert_eq!(notes.title, "Caf\u{e9} notes on Boreal Cloud");
+        assert_eq!(notes.observed_at, "2026-04-05T00:00:00Z");
+        assert_eq!(
+            notes.url.as_deref(),
+            Some("mid:cafe-notes@research.example.net")
+        );
+        assert!(notes.content.contains("caf\u{e9} partnership"));
+        assert!(notes
+            .content
+            .contains("\nFrom the field: attendance doubled"));
+        assert_eq!(
+            notes.entity_labels,
+            vec!["lists.example.org", "research.example.net"]
+        );
+    }
+
+    #[tokio::test]
+    async fn source_email_import_endpoint_rejects_kind_and_content_boundaries() {
+        let app = test_app();
+        let (status, _) = post_email_import(
+            app.clone(),
+            "rss_national_security",
+            include_str!("../tests/fixtures/email/multipart_alternative.eml"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let created = create_email_source(app.clone(), "Boundary inbox").await;
+        let (status, _) = post_email_import(app.clone(), &created.source.id, "  \n").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) =
+            post_email_import(app, &created.source.id, "no header section here").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn email_watch_pass_ingests_maildir_and_mail_files() {
+        let state = default_app_state(None, None);
+        let created = create_email_source(app(state.clone()), "Watched mail drop").await;
+        let root = std::env::temp_dir().join(format!(
+            "helix-email-watch-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let drop_dir = root.join(&created.source.id);
+        std::fs::create_dir_all(drop_dir.join("new")).unwrap();
+        std::fs::write(
+            drop_dir.join("new").join("1775120700.M1P1.desk"),
+            include_str!("../tests/fixtures/email/multipart_alternative.eml"),
+        )
+        .unwrap();
+        std::fs::write(
+            drop_dir.join("mailbox.mbox"),
+            include_str!("../tests/fixtures/email/mailbox.mbox"),
+        )
+        .unwrap();
+        std::fs::write(drop_dir.join("broken.eml"), "not an email").unwrap();
+        std::fs::write(drop_dir.join("notes.txt"), "ignored").unwrap();
+
+        let now = chrono::DateTime::parse_from_rfc3339("2026-04-06T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let summary = run_email_watch_pass(&state, &root, now).await.unwrap();
+        assert_eq!(summary.file_count, 3);
+        assert_eq!(summary.message_count, 3);
+        assert_eq!(summary.collected_count, 3);
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!(summary.failures[0].file_name, "broken.eml");
+
+        assert!(drop_dir
+            .join("cur")
+            .join("1775120700.M1P1.desk:2,S")
+            .is_file());
+        assert!(drop_dir.join("processed").join("mailbox.mbox").is_file());
+        assert!(drop_dir.join("failed").join("broken.eml").is_file());
+        assert!(drop_dir.join("notes.txt").is_file());
+        assert!(!drop_dir.join("mailbox.mbox").exists());
+
+        let run = state
+            .intel_desk
+            .read()
+            .await
+            .scheduled_source_candidates()
+            .into_iter()
+            .find(|(source, _)| source.id == created.source.id)
+            .and_then(|(_, run)| run)
+            .unwrap();
+        assert_eq!(run.collected_total, 3);
+        assert_eq!(
+            run.last_collected_at.as_deref(),
+            Some("2026-04-06T12:00:00Z")
+        );
+        assert_eq!(run.consecutive_failures, 0);
+
+        let second = run_email_watch_pass(&state, &root, now).await.unwrap();
+        assert_eq!(second.file_count, 0);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn source_collect_endpoint_rejects_zero_limit() {
+        let response = test_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/sources/rss_national_security/collect")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"observed_at":"2026-04-01T10:05:00Z","max_items":0}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn watchlists_endpoint_creates_watchlist() {
+        let request = CreateWatchlistRequest {
+            name: "Launch Monitor".to_string(),
+            description: "Track launches and facilities".to_string(),
+            keywords: vec!["launch".to_string()],
+            entities: vec!["orion dynamics".to_string()],
+            aliases: BTreeMap::from([(
+                "Orion Dynamics".to_string(),
+                vec!["ORDY".to_string(), "orion dynamics".to_string()],
+            )]),
+            query: None,
+            trigger: None,
+            min_source_trust: 55,
+            severity: helix_core::intel_desk::WatchlistSeverity::Medium,
+            enabled: true,
+        };
+
+        let response = test_app()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/watchlists")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: WatchlistResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.watchlist.min_source_trust, 55);
+        assert_eq!(
+            payload.watchlist.aliases,
+            BTreeMap::from([("orion dynamics".to_string(), vec!["ordy".to_string()])])
+        );
+    }
+
+    #[tokio::test]
+    async fn watchlist_query_matches_whole_words_and_rejects_invalid_syntax() {
+        let app = test_app();
+        let create_watchlist = |query: &str| {
+            let request = CreateWatchlistRequest {
+                name: "Ransomware Incidents".to_string(),
+                description: "Ransomware attacks, not vendor news".to_string(),
+                keywords: Vec::new(),
+                entities: Vec::new(),
+                aliases: BTreeMap::new(),
+                query: Some(query.to_string()),
+                trigger: None,
+                min_source_trust: 40,
+                severity: helix_core::intel_desk::WatchlistSeverity::High,
+                enabled: true,
+            };
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/watchlists")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(create_watchlist("ransomware AND (title:attack OR NOT"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(create_watchlist(
+                "ransomware AND (attack* OR encrypted) NOT \"tabletop exercise\"",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let ingest = |title: &str, content: &str| {
+            let request = IngestEvidenceRequest {
+                source_id: "rss_national_security".to_string(),
+                title: title.to_string(),
+                summary: String::new(),
+                content: content.to_string(),
+                url: None,
+                observed_at: "2026-03-06T13:00:00Z".to_string(),
+                tags: Vec::new(),
+                entity_labels: Vec::new(),
+                proposed_claims: Vec::new(),
+            };
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/evidence/ingest")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                .unwrap()
+        };
+        let query_hits = |body: &[u8]| {
+            let payload: IngestEvidenceResponse = serde_json::from_slice(body).unwrap();
+            payload
+                .hits
+                .into_iter()
+                .filter(|hit| hit.watchlist_id == "ransomware-incidents")
+                .collect::<Vec<_>>()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(ingest(
+                "Port operator hit by ransomware",
+                "The ransomware attackers encrypted scheduling servers overnight.",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let hits = query_hits(&body);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].reason, "query_match");
+        assert_eq!(
+            hits[0].matched_keywords,
+            vec![
+                "attack*".to_string(),
+                "encrypted".to_string(),
+                "ransomware".to_string()
+            ]
+        );
+
+        for (title, content) in [
+            (
+                "Anti-ransomware vendor raises funding",
+                "The anti-ransomware startup said attackers are its focus.",
+            ),
+            (
+                "Ransomware tabletop exercise",
+                "The ransomware tabletop exercise simulated an attack on payroll.",
+            ),
+        ] {
+            let response = app.clone().oneshot(ingest(title, content)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            assert!(query_hits(&body).is_empty(), "{title} should not match");
+        }
+    }
+
+    #[tokio::test]
+    async fn entity_registry_resolves_aliases_across_evidence_watchlists_and_cases() {
+        let app = test_app();
+        let post = |uri: &str, body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let ingest = |title: &str, entity_labels: &[&str]| {
+            post(
+                "/api/v1/evidence/ingest",
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": title,
+                    "summary": format!("{title}, per a regional filing."),
+                    "content": format!("{title}. Details remain limited."),
+                    "url": null,
+                    "observed_at": "2026-03-06T15:00:00Z",
+                    "tags": [],
+                    "entity_labels": entity_labels,
+                    "proposed_claims": [],
+                }),
+            )
+        };
+
+        // Evidence ingested before registration is relabeled when the alias is registered.
+        let response = app
+            .clone()
+            .oneshot(ingest("Regional grid filing names HLCN", &["HLCN"]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let early: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(early.evidence.entity_labels, vec!["hlcn"]);
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/evidence/ingest",
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "HLCN supplies transformer parts",
+                    "summary": "HLCN supplies transformer parts to the regional grid.",
+                    "content": "HLCN supplies transformer parts to the regional grid.",
+                    "url": null,
+                    "observed_at": "2026-03-06T15:00:00Z",
+                    "tags": [],
+                    "entity_labels": [],
+                    "proposed_claims": [{
+                        "subject": "HLCN",
+                        "predicate": "supplies",
+                        "object": "regional grid",
+                        "confidence_bps": 7000,
+                        "rationale": null,
+                    }],
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let supplier: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let supplier_claim_id = supplier.claims[0].id.clone();
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/entities",
+                serde_json::json!({
+                    "name": "Halcyon Labs",
+                    "kind": "org",
+                    "aliases": ["HLCN", "Halcyon Labs Inc."],
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["entity"]["id"], "halcyon labs");
+        assert_eq!(
+            payload["entity"]["aliases"],
+            serde_json::json!(["halcyon labs inc", "hlcn"])
+        );
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/evidence?entity=halcyon%20labs")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(payload["evidence"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|entry| entry["evidence"]["id"] == early.evidence.id.as_str()));
+
+        // Relabeled claims keep the id derived from the label they were extracted with.
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/claims?predicate=supplies")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: ClaimCatalogResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.claims.len(), 1);
+        assert_eq!(payload.claims[0].claim.id, supplier_claim_id);
+        assert_eq!(payload.claims[0].claim.subject, "halcyon labs");
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/watchlists",
+                serde_json::json!({
+                    "name": "Halcyon Watch",
+                    "description": "Grid supplier exposure",
+                    "keywords": [],
+                    "entities": ["Halcyon Labs Inc."],
+                    "min_source_trust": 40,
+                    "severity": "medium",
+                    "enabled": true,
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: WatchlistResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.watchlist.entities, vec!["halcyon labs"]);
+
+        let mut case_ids = Vec::new();
+        for (title, labels) in [
+            (
+                "Utility audit flags supplier delays",
+                &["Halcyon Labs Inc."][..],
+            ),
+            ("Auditors say HLCN missed two deliveries", &[][..]),
+        ] {
+            let response = app.clone().oneshot(ingest(title, labels)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+            let hit = payload
+                .hits
+                .iter()
+                .find(|hit| hit.watchlist_id == "halcyon-watch")
+                .unwrap();
+            assert_eq!(hit.matched_entities, vec!["halcyon labs"]);
+            let case = payload
+                .case_updates
+                .iter()
+                .find(|transition| transition.case.watchlist_id == "halcyon-watch")
+                .unwrap();
+            assert_eq!(case.case.primary_entity.as_deref(), Some("halcyon labs"));
+            case_ids.push(case.case.id.clone());
+        }
+        assert_eq!(case_ids[0], case_ids[1]);
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/entities",
+                serde_json::json!({"name": "Halcyon Grid", "kind": "org", "aliases": ["hgrid"]}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/entities/halcyon%20labs/merge",
+                serde_json::json!({"merge_id": "halcyon grid"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            payload["entity"]["aliases"],
+            serde_json::json!(["halcyon grid", "halcyon labs inc", "hgrid", "hlcn"])
+        );
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/entities/halcyon%20labs/split",
+                serde_json::json!({"name": "Halcyon Grid", "kind": "org", "aliases": ["hgrid"]}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["split"]["id"], "halcyon grid");
+        assert_eq!(
+            payload["entity"]["aliases"],
+            serde_json::json!(["halcyon labs inc", "hlcn"])
+        );
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/entities/halcyon%20labs/split",
+                serde_json::json!({"name": "Other", "kind": "org", "aliases": ["hgrid"]}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/entities/missing/merge",
+                serde_json::json!({"merge_id": "halcyon labs"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn watchlist_backtest_compares_draft_with_baseline_without_mutating_desk() {
+        let app = test_app();
+        let backtest = |watchlist_id: Option<&str>, observed_to: Option<&str>| {
+            let request = WatchlistBacktestRequest {
+                watchlist_id: watchlist_id.map(str::to_string),
+                watchlist: CreateWatchlistRequest {
+                    name: "Pricing Moves".to_string(),
+                    description: "Retargeted at launch pricing".to_string(),
+                    keywords: vec!["launch".to_string()],
+                    entities: vec!["vector works".to_string()],
+                    aliases: BTreeMap::new(),
+                    query: None,
+                    trigger: None,
+                    min_source_trust: 65,
+                    severity: helix_core::intel_desk::WatchlistSeverity::Critical,
+                    enabled: false,
+                },
+                observed_from: Some("2026-03-06T00:00:00Z".to_string()),
+                observed_to: observed_to.map(str::to_string),
+            };
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/watchlists/backtest")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(backtest(Some("market_pricing_moves"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: WatchlistBacktestResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.watchlist.id, "market_pricing_moves");
+        assert_eq!(payload.hit_count, 1);
+        assert_eq!(payload.hits[0].matched_entities, vec!["vector works"]);
+        assert_eq!(
+            payload.newly_matched,
+            vec![payload.hits[0].evidence_id.clone()]
+        );
+        assert_eq!(payload.no_longer_matched.len(), payload.baseline_hit_count);
+        assert!(payload.baseline_hit_count >= 1);
+        assert_eq!(
+            payload.severity_distribution,
+            BTreeMap::from([("critical".to_string(), 1)])
+        );
+        assert_eq!(
+            payload.baseline_severity_distribution,
+            BTreeMap::from([("high".to_string(), payload.baseline_hit_count)])
+        );
+        assert_eq!(payload.cases.len(), 1);
+        assert_eq!(
+            payload.cases[0].primary_entity.as_deref(),
+            Some("vector works")
+        );
+        assert!(payload.cases[0].escalated);
+        assert!(!payload.cases[0].existing);
+
+        let response = app
+            .clone()
+            .oneshot(backtest(
+                Some("market_pricing_moves"),
+                Some("2026-03-06T09:00:00Z"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: WatchlistBacktestResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.hit_count, 0);
+        assert!(payload.cases.is_empty());
+
+        let response = app
+            .clone()
+            .oneshot(backtest(
+                Some("market_pricing_moves"),
+                Some("2026-03-05T00:00:00Z"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(backtest(Some("missing_watchlist"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/watchlists")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let pricing = payload["watchlists"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|watchlist| watchlist["id"] == "market_pricing_moves")
+            .unwrap();
+        assert_eq!(pricing["severity"], "high");
+        assert!(pricing["entities"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("boreal cloud")));
+    }
+
+    #[tokio::test]
+    async fn watchlist_suppression_records_hits_without_updating_cases() {
+        let app = test_app();
+        let post = |uri: &str, body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/watchlists/watch_exec_moves/suppressions",
+                serde_json::json!({
+                    "kind": "entity",
+                    "entity": "Alice North",
+                    "note": "Known benign coverage of a retired executive.",
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let suppression = &payload["watchlist"]["suppressions"][0];
+        assert_eq!(suppression["kind"], "entity");
+        assert_eq!(suppression["entity"], "alice north");
+        let suppression_id = suppression["id"].as_str().unwrap().to_string();
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/evidence/ingest",
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North resigned from Orion Dynamics",
+                    "summary": "Leadership change at Orion",
+                    "content": "Alice North resigned after a brief detention, according to the report.",
+                    "url": "https://example.org/report",
+                    "observed_at": "2026-03-06T12:00:00Z",
+                    "tags": ["leadership"],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": [],
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let hit = payload
+            .hits
+            .iter()
+            .find(|hit| hit.watchlist_id == "watch_exec_moves")
+            .unwrap();
+        assert_eq!(
+            hit.suppressed_by.as_deref(),
+            Some(format!("entity:{suppression_id}").as_str())
+        );
+        assert!(payload
+            .case_updates
+            .iter()
+            .all(|update| update.case.watchlist_id != "watch_exec_moves"));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/watchlists/watch_exec_moves/suppressed-hits")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["hits"].as_array().unwrap().len(), 1);
+        assert_eq!(payload["hits"][0]["observed_at"], "2026-03-06T12:00:00Z");
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/watchlists/watch_exec_moves/suppressions",
+                serde_json::json!({"kind": "url_domain", "domain": "not a domain"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/watchlists/missing/suppressions",
+                serde_json::json!({"kind": "source", "source_id": "rss_national_security"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let delete = |suppression_id: &str| {
+            Request::builder()
+                .method("DELETE")
+                .uri(format!(
+                    "/api/v1/watchlists/watch_exec_moves/suppressions/{suppression_id}"
+                ))
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = app.clone().oneshot(delete(&suppression_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(payload["watchlist"]["suppressions"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+        let response = app.oneshot(delete(&suppression_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn watchlist_trigger_opens_case_once_distinct_sources_repeat_within_window() {
+        let app = test_app();
+        let create_watchlist = |window_hours: u32| {
+            let request = CreateWatchlistRequest {
+                name: "Kestrel Chatter".to_string(),
+                description: "Repeated reporting on Kestrel Freight".to_string(),
+                keywords: Vec::new(),
+                entities: vec!["kestrel freight".to_string()],
+                aliases: BTreeMap::new(),
+                query: None,
+                trigger: Some(
+                    helix_core::intel_watchlist_window::WatchlistTrigger::DistinctSources {
+                        min_sources: 3,
+                        window_hours,
+                    },
+                ),
+                min_source_trust: 40,
+                severity: helix_core::intel_desk::WatchlistSeverity::High,
+                enabled: true,
+            };
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/watchlists")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(create_watchlist(0)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app.clone().oneshot(create_watchlist(24)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let ingest = |source_id: &str, title: &str, observed_at: &str| {
+            let request = IngestEvidenceRequest {
+                source_id: source_id.to_string(),
+                title: title.to_string(),
+                summary: format!("{title}, according to {source_id}."),
+                content: format!("{title}. Reported by {source_id} at {observed_at}."),
+                url: None,
+                observed_at: observed_at.to_string(),
+                tags: Vec::new(),
+                entity_labels: vec!["kestrel freight".to_string()],
+                proposed_claims: Vec::new(),
+            };
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/evidence/ingest")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                .unwrap()
+        };
+        let mut evidence_ids = Vec::new();
+        let mut hit_counts = Vec::new();
+        let mut last = None;
+        for (source_id, title, observed_at) in [
+            (
+                "rss_national_security",
+                "Kestrel Freight delays port filings",
+                "2026-03-06T08:00:00Z",
+            ),
+            (
+                "rss_partner_ecosystem",
+                "Kestrel Freight partner pauses routes",
+                "2026-03-06T14:00:00Z",
+            ),
+            (
+                "rss_gtm_hiring_tracker",
+                "Kestrel Freight freezes hiring",
+                "2026-03-07T02:00:00Z",
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(ingest(source_id, title, observed_at))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+            evidence_ids.push(payload.evidence.id.clone());
+            hit_counts.push(
+                payload
+                    .hits
+                    .iter()
+                    .filter(|hit| hit.watchlist_id == "kestrel-chatter")
+                    .count(),
+            );
+            last = Some(payload);
+        }
+        assert_eq!(hit_counts, vec![0, 0, 1]);
+        let payload = last.unwrap();
+        let hits = payload
+            .hits
+            .iter()
+            .filter(|hit| hit.watchlist_id == "kestrel-chatter")
+            .collect::<Vec<_>>();
+        assert_eq!(hits[0].reason, "aggregate_distinct_sources");
+        let aggregate = hits[0].aggregate.as_ref().unwrap();
+        assert_eq!(aggregate.distinct_source_count, 3);
+        assert_eq!(aggregate.evidence_ids, evidence_ids);
+
+        let case = payload
+            .case_updates
+            .iter()
+            .find(|transition| transition.case.watchlist_id == "kestrel-chatter")
+            .unwrap();
+        assert_eq!(
+            case.case.status,
+            helix_core::intel_desk::CaseStatus::Escalated
+        );
+        let mut case_evidence = case.case.evidence_ids.clone();
+        case_evidence.sort();
+        evidence_ids.sort();
+        assert_eq!(case_evidence, evidence_ids);
+    }
+
+    #[tokio::test]
+    async fn evidence_ingest_creates_claims_hits_and_case() {
+        let app = test_app();
+        let request = IngestEvidenceRequest {
+            source_id: "rss_national_security".to_string(),
+            title: "Alice North resigned from Orion Dynamics".to_string(),
+            summary: "Leadership change at Orion".to_string(),
+            content: "Alice North resigned after a brief detention, according to the report."
+                .to_string(),
+            url: Some("https://example.org/report".to_string()),
+            observed_at: "2026-03-06T12:00:00Z".to_string(),
+            tags: vec!["leadership".to_string(), "security".to_string()],
+            entity_labels: vec!["alice north".to_string(), "orion dynamics".to_string()],
+            proposed_claims: vec![helix_core::intel_desk::ProposedClaim {
+                subject: "alice north".to_string(),
+                predicate: "resigned_from".to_string(),
+                object: "orion dynamics".to_string(),
+                confidence_bps: 9100,
+                rationale: Some("explicitly stated in the report".to_string()),
+            }],
+        };
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/evidence/ingest")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.claims.len(), 1);
+        assert!(!payload.hits.is_empty());
+        assert!(!payload.case_updates.is_empty());
+        assert_eq!(
+            payload.case_updates[0].case.status,
+            helix_core::intel_desk::CaseStatus::Escalated
+        );
+
+        let cases_response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/cases")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(cases_response.status(), StatusCode::OK);
+        let body = to_bytes(cases_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let cases: CaseCatalogResponse = serde_json::from_slice(&body).unwrap();
+        assert!(cases
+            .cases
+            .iter()
+            .any(|entry| entry.case.id == payload.case_updates[0].case.id));
+    }
+
+    #[tokio::test]
+    async fn claim_graph_merges_triples_across_evidence_and_flags_contradictions() {
+        let app = test_app();
+        let ingest = |title: &str, object: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/evidence/ingest")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::to_vec(&serde_json::json!({
+                        "source_id": "rss_national_security",
+                        "title": title,
+                        "summary": format!("{title}, per a company filing."),
+                        "content": format!("{title}. The filing names a new chief executive."),
+                        "url": null,
+                        "observed_at": "2026-03-06T15:00:00Z",
+                        "tags": [],
+                        "entity_labels": ["alice north"],
+                        "proposed_claims": [{
+                            "subject": "Alice North",
+                            "predicate": "ceo_of",
+                            "object": object,
+                            "confidence_bps": 8000,
+                            "rationale": null,
+                        }],
+                    }))
+                    .unwrap(),
+                ))
+                .unwrap()
+        };
+        for (title, object) in [
+            ("Alice North named chief executive", "Orion Dynamics"),
+            ("Board confirms Alice North as CEO", "orion dynamics"),
+            ("Alice North to lead Vector Works", "Vector Works"),
+        ] {
+            let response = app.clone().oneshot(ingest(title, object)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/claims?predicate=ceo_of&contested=true"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: ClaimCatalogResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.claims.len(), 3);
+        let orion = payload
+            .claims
+            .iter()
+            .filter(|entry| entry.claim.object == "orion dynamics")
+            .collect::<Vec<_>>();
+        assert_eq!(orion.len(), 2);
+        assert_eq!(orion[0].assertion_id, orion[1].assertion_id);
+        assert_eq!(orion[0].supporting_evidence_ids.len(), 2);
+        assert_eq!(
+            orion[0].contradictions[0].kind,
+            helix_core::intel_claim_graph::ClaimContradictionKind::ConflictingObject
+        );
+
+        let response = app
+            .clone()
+            .oneshot(get(
+                "/api/v1/claims/graph?contested=true&subject=Alice%20North",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let assertions = payload["assertions"].as_array().unwrap();
+        assert_eq!(assertions.len(), 2);
+        assert_eq!(assertions[0]["object"], "orion dynamics");
+        assert_eq!(assertions[0]["claim_ids"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            assertions[0]["contradictions"][0]["assertion_id"],
+            assertions[1]["id"]
+        );
+
+        let response = app
+            .oneshot(get("/api/v1/claims/graph?limit=0"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn evidence_ingest_clusters_syndicated_copies_and_counts_independent_sources() {
+        let app = test_app();
+        let story = |source_id: &str, title: &str, content: &str, observed_at: &str| {
+            IngestEvidenceRequest {
+                source_id: source_id.to_string(),
+                title: title.to_string(),
+                summary: String::new(),
+                content: content.to_string(),
+                url: None,
+                observed_at: observed_at.to_string(),
+                tags: vec!["pricing".to_string()],
+                entity_labels: vec!["halvard cloud".to_string()],
+                proposed_claims: Vec::new(),
+            }
+        };
+        let evidence_entries = |app: Router| async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri("/api/v1/evidence?limit=100")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: crate::intel::EvidenceCatalogResponse =
+                serde_json::from_slice(&body).unwrap();
+            payload
+                .evidence
+                .into_iter()
+                .filter(|entry| entry.evidence.entity_labels == ["halvard cloud"])
+                .collect::<Vec<_>>()
+        };
+
+        ingest_test_evidence(
+            app.clone(),
+            story(
+                "json_api_cloud_pricing",
+                "Halvard Cloud raises enterprise seat prices",
+                "Halvard Cloud will raise enterprise seat prices by 12 percent from May, \
+                 according to a customer notice reviewed by analysts, citing higher data \
+                 center power costs across Europe.",
+                "2026-03-06T08:00:00Z",
+            ),
+        )
+        .await;
+        let single = evidence_entries(app.clone()).await;
+        assert_eq!(single.len(), 1);
+        let original_id = single[0].evidence.id.clone();
+        assert_eq!(
+            single[0].evidence.story_cluster_id.as_deref(),
+            Some(original_id.as_str())
+        );
+
+        ingest_test_evidence(
+            app.clone(),
+            story(
+                "rss_partner_ecosystem",
+                "Halvard Cloud raises enterprise seat prices!",
+                "Halvard Cloud will raise enterprise seat prices by 12 percent from May, \
+                 according to a customer notice reviewed by analysts on Tuesday, citing \
+                 higher data centre power costs across Europe.",
+                "2026-03-06T09:30:00Z",
+            ),
+        )
+        .await;
+        ingest_test_evidence(
+            app.clone(),
+            story(
+                "rss_national_security",
+                "Halvard Cloud opens a Lisbon region",
+                "Halvard Cloud will open a hosting region in Lisbon from May for enterprise \
+                 customers, adding capacity for data residency across Europe.",
+                "2026-03-06T10:00:00Z",
+            ),
+        )
+        .await;
+
+        let entries = evidence_entries(app).await;
+        assert_eq!(entries.len(), 3);
+        let clustered = entries
+            .iter()
+            .filter(|entry| entry.evidence.story_cluster_id.as_deref() == Some(&original_id))
+            .collect::<Vec<_>>();
+        assert_eq!(clustered.len(), 2);
+        assert!(
+            clustered
+                .iter()
+                .all(|entry| entry.priority.corroboration_tier
+                    > single[0].priority.corroboration_tier)
+        );
+        let unrelated = entries
+            .iter()
+            .find(|entry| entry.evidence.title == "Halvard Cloud opens a Lisbon region")
+            .unwrap();
+        assert_eq!(
+            unrelated.evidence.story_cluster_id.as_deref(),
+            Some(unrelated.evidence.id.as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn evidence_ingest_extracts_entities_that_drive_watchlist_hits() {
+        let app = test_app();
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/watchlists")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_vec(&CreateWatchlistRequest {
+                            name: "Kestrel Exposure".to_string(),
+                            description: "Track Kestrel Freight incidents".to_string(),
+                            keywords: Vec::new(),
+                            entities: vec!["kestrel freight".to_string()],
+                            aliases: BTreeMap::from([(
+                                "kestrel freight".to_string(),
+                                vec!["KSTL".to_string()],
+                            )]),
+                            query: None,
+                            trigger: None,
+                            min_source_trust: 40,
+                            severity: helix_core::intel_desk::WatchlistSeverity::High,
+                            enabled: true,
+                        })
+                        .unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let content = "KSTL confirmed CVE-2026-1042 was exploited from 203.0.113.7.";
+        let request = IngestEvidenceRequest {
+            source_id: "rss_national_security".to_string(),
+            title: "Freight operator discloses breach".to_string(),
+            summary: String::new(),
+            content: content.to_string(),
+            url: None,
+            observed_at: "2026-03-06T12:45:00Z".to_string(),
+            tags: Vec::new(),
+            entity_labels: Vec::new(),
+            proposed_claims: Vec::new(),
+        };
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/evidence/ingest")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        assert!(payload.evidence.entity_labels.is_empty());
+        let content_field = helix_core::intel_entity_extraction::EvidenceTextField::Content;
+        let extracted = payload
+            .evidence
+            .extracted_entities
+            .iter()
+            .map(|entity| {
+                (
+                    entity.label.as_str(),
+                    &content[entity.start..entity.end],
+                    entity.field,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            extracted,
+            vec![
+                ("kestrel freight", "KSTL", content_field),
+                ("cve-2026-1042", "CVE-2026-1042", content_field),
+                ("203.0.113.7", "203.0.113.7", content_field),
+            ]
+        );
+        let hit = payload
+            .hits
+            .iter()
+            .find(|hit| hit.watchlist_id == "kestrel-exposure")
+            .expect("extracted alias should match the watchlist entity");
+        assert_eq!(hit.matched_entities, vec!["kestrel freight".to_string()]);
+        assert_eq!(hit.reason, "entity_match");
+    }
+
+    #[tokio::test]
+    async fn case_reasoning_gate_blocks_escalation_until_claims_are_corroborated() {
+        let app = test_app();
+        let post = |uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/reasoning/rule-packs".to_string(),
+                serde_json::json!({
+                    "id": "exec_appointments",
+                    "name": "Executive appointments",
+                    "rules": [{
+                        "id": "corroborated_appointment",
+                        "antecedents": [
+                            "corroborated({case},appointed_to)",
+                            "severity({case},high)",
+                        ],
+                        "consequent": "escalate({case})",
+                    }],
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/reasoning/rule-packs".to_string(),
+                serde_json::json!({"id": "Bad Id", "name": "Bad", "rules": []}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                "/api/v1/evidence/ingest".to_string(),
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North appointed chair of Orion Dynamics",
+                    "summary": "Board appointment at Orion Dynamics",
+                    "content": "Alice North was appointed to the Orion Dynamics board as chair.",
+                    "url": null,
+                    "observed_at": "2026-03-06T12:30:00Z",
+                    "tags": ["leadership"],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": [{
+                        "subject": "alice north",
+                        "predicate": "appointed_to",
+                        "object": "orion dynamics board",
+                        "confidence_bps": 8700,
+                        "rationale": "board notice",
+                    }],
+                }),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let ingest: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let case_id = ingest
+            .case_updates
+            .iter()
+            .find(|update| update.case.watchlist_id == "watch_exec_moves")
+            .unwrap()
+            .case
+            .id
+            .clone();
+        let claim_id = ingest.claims[0].id.clone();
+
+        let reason = |include_needs_review: bool| {
+            post(
+                format!("/api/v1/cases/{case_id}/reasoning"),
+                serde_json::json!({
+                    "rule_pack_id": "exec_appointments",
+                    "include_needs_review": include_needs_review,
+                }),
+            )
+        };
+        let escalate = || {
+            post(
+                format!("/api/v1/cases/{case_id}/transition"),
+                serde_json::json!({"command": {"type": "escalate", "reason": "analyst review"}}),
+            )
+        };
+
+        let response = app.clone().oneshot(reason(true)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["gate"]["query"], format!("escalate({case_id})"));
+        assert_ne!(payload["gate"]["decision"]["verdict"], "allow");
+        assert!(payload["request"]["facts"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!(
+                "reported_appointed_to(alice_north,orion_dynamics_board)"
+            )));
+        assert_eq!(payload["case"]["reasoning_gate"], payload["gate"]);
+
+        let response = app.clone().oneshot(escalate()).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            payload["transition"]["decision"],
+            serde_json::json!({"kind": "denied", "reason": "reasoning_gate"})
+        );
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                format!("/api/v1/claims/{claim_id}/review"),
+                serde_json::json!({"status": "corroborated"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app.clone().oneshot(reason(false)).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["gate"]["decision"]["verdict"], "allow");
+        assert!(payload["gate"]["decision"]["trace"]["support_graph"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|node| node["rule_id"] == "corroborated_appointment"));
+
+        let response = app.clone().oneshot(escalate()).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["transition"]["case"]["status"], "escalated");
+
+        let response = app
+            .oneshot(post(
+                format!("/api/v1/cases/{case_id}/reasoning"),
+                serde_json::json!({"rule_pack_id": "missing_pack"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn decay_apply_returns_stale_claims_to_review_and_detaches_expired_evidence() {
+        let app = test_app();
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/decay/policy")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["policy"]["default_half_life_hours"], 0);
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/decay/policy".to_string(),
+                serde_json::json!({
+                    "default_half_life_hours": 0,
+                    "claim_review_threshold_bps": 12000,
+                    "evidence_expiry_bps": 1000,
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/decay/policy".to_string(),
+                serde_json::json!({
+                    "default_half_life_hours": 0,
+                    "tag_half_life_hours": {"Rumor": 24},
+                    "claim_review_threshold_bps": 5000,
+                    "evidence_expiry_bps": 2000,
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["policy"]["tag_half_life_hours"]["rumor"], 24);
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/evidence/ingest".to_string(),
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North rumored to join Orion Dynamics board",
+                    "summary": "Unconfirmed board move",
+                    "content": "Alice North may join the Orion Dynamics board.",
+                    "url": null,
+                    "observed_at": "2026-03-05T12:00:00Z",
+                    "tags": ["rumor"],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": [{
+                        "subject": "alice north",
+                        "predicate": "joining_board_of",
+                        "object": "orion dynamics",
+                        "confidence_bps": 8000,
+                        "rationale": "trade press rumor",
+                    }],
+                }),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let ingest: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let evidence_id = ingest.evidence.id.clone();
+        let claim_id = ingest.claims[0].id.clone();
+        let case_id = ingest
+            .case_updates
+            .iter()
+            .find(|update| update.case.watchlist_id == "watch_exec_moves")
+            .unwrap()
+            .case
+            .id
+            .clone();
+        // A confirmed report keeps the case holding unexpired evidence once the rumor decays.
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/evidence/ingest".to_string(),
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North confirmed for Orion Dynamics board",
+                    "summary": "Board appointment confirmed",
+                    "content": "Orion Dynamics confirmed Alice North joins its board.",
+                    "url": null,
+                    "observed_at": "2026-03-05T13:00:00Z",
+                    "tags": [],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": [],
+                }),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let confirmed: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let confirmed_id = confirmed.evidence.id.clone();
+        assert!(confirmed
+            .case_updates
+            .iter()
+            .any(|update| update.case.id == case_id));
+        for (uri, body) in [
+            (
+                format!("/api/v1/claims/{claim_id}/review"),
+                serde_json::json!({"status": "corroborated"}),
+            ),
+            (
+                format!("/api/v1/cases/{case_id}/transition"),
+                serde_json::json!({"command": {"type": "mark_monitoring"}}),
+            ),
+        ] {
+            let response = app.clone().oneshot(send("POST", uri, body)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        // Three 24h half-lives: relevance 1250 bps, confidence 8000 -> 1000 bps.
+        let apply = |dry_run: bool| {
+            send(
+                "POST",
+                "/api/v1/decay/apply".to_string(),
+                serde_json::json!({"as_of": "2026-03-08T12:00:00Z", "dry_run": dry_run}),
+            )
+        };
+        for dry_run in [true, false] {
+            let response = app.clone().oneshot(apply(dry_run)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(payload["as_of"], "2026-03-08T12:00:00Z");
+            let decayed = payload["claims"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|claim| claim["claim_id"] == claim_id.as_str())
+                .unwrap();
+            assert_eq!(decayed["relevance_bps"], 1250);
+            assert_eq!(decayed["decayed_confidence_bps"], 1000);
+            let expired = payload["expired_evidence"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|item| item["evidence_id"] == evidence_id.as_str())
+                .unwrap();
+            assert_eq!(expired["detached_case_ids"], serde_json::json!([case_id]));
+        }
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/cases")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let case = payload["cases"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| &entry["case"])
+            .find(|case| case["id"] == case_id.as_str())
+            .unwrap();
+        assert_eq!(case["evidence_ids"], serde_json::json!([confirmed_id]));
+        assert!(!case["claim_ids"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!(claim_id)));
+
+        let response = app.clone().oneshot(apply(false)).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(payload["claims"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|claim| claim["claim_id"] != claim_id.as_str()));
+
+        let response = app
+            .oneshot(send(
+                "POST",
+                "/api/v1/decay/apply".to_string(),
+                serde_json::json!({"as_of": "yesterday"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn claim_votes_reach_quorum_before_changing_review_status() {
+        let app = test_app();
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/claims/adjudication-config".to_string(),
+                serde_json::json!({"min_severity": "high", "quorum": 4, "reviewers": 3}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/claims/adjudication-config".to_string(),
+                serde_json::json!({"min_severity": "high", "quorum": 2, "reviewers": 3}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/evidence/ingest".to_string(),
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North named Orion Dynamics chief strategist",
+                    "summary": "Leadership change at Orion Dynamics",
+                    "content": "Alice North was named chief strategist at Orion Dynamics.",
+                    "url": null,
+                    "observed_at": "2026-03-06T12:30:00Z",
+                    "tags": ["leadership"],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": [{
+                        "subject": "alice north",
+                        "predicate": "chief_strategist_of",
+                        "object": "orion dynamics",
+                        "confidence_bps": 8200,
+                        "rationale": "press release",
+                    }],
+                }),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let ingest: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let claim_id = ingest.claims[0].id.clone();
+        let case_id = ingest
+            .case_updates
+            .iter()
+            .find(|update| update.case.watchlist_id == "watch_exec_moves")
+            .unwrap()
+            .case
+            .id
+            .clone();
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("/api/v1/claims/{claim_id}/review"),
+                serde_json::json!({"status": "corroborated"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let vote = |reviewer: &str, choice: &str| {
+            send(
+                "POST",
+                format!("/api/v1/claims/{claim_id}/votes"),
+                serde_json::json!({
+                    "reviewer": reviewer,
+                    "choice": choice,
+                    "rationale": format!("{reviewer} checked the release"),
+                }),
+            )
+        };
+        for (reviewer, choice, status, outcome) in [
+            ("ana", "corroborate", StatusCode::CREATED, "pending"),
+            ("ben", "reject", StatusCode::CREATED, "contested"),
+        ] {
+            let response = app.clone().oneshot(vote(reviewer, choice)).await.unwrap();
+            assert_eq!(response.status(), status);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(payload["adjudication"]["outcome"], outcome);
+            assert_eq!(payload["claim"]["review_status"], "needs_review");
+        }
+        let response = app
+            .clone()
+            .oneshot(vote("Ana", "corroborate"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/claims?contested=true")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: ClaimCatalogResponse = serde_json::from_slice(&body).unwrap();
+        assert!(payload
+            .claims
+            .iter()
+            .any(|entry| entry.claim.id == claim_id));
+
+        let response = app
+            .clone()
+            .oneshot(vote("cy", "corroborate"))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["adjudication"]["outcome"], "corroborated");
+        assert_eq!(payload["claim"]["review_status"], "corroborated");
+        let response = app.clone().oneshot(vote("dee", "reject")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/v1/claims/{claim_id}/votes"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let reviewers = payload["adjudication"]["votes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|vote| vote["reviewer"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(reviewers, vec!["ana", "ben", "cy"]);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/api/v1/autopilot/review-queue/export?review_kind=case&item_id={case_id}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let packet: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let exported = packet["supporting_claims"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["claim"]["id"] == claim_id.as_str())
+            .unwrap();
+        assert_eq!(
+            exported["adjudication"]["votes"].as_array().unwrap().len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn decay_reopens_adjudicated_claims_for_a_fresh_vote() {
+        let app = test_app();
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let json = |response: axum::response::Response| async move {
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+        };
+        // Two of two can split one-one and never reach quorum.
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/claims/adjudication-config".to_string(),
+                serde_json::json!({"min_severity": "high", "quorum": 2, "reviewers": 2}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        for (uri, body) in [
+            (
+                "/api/v1/claims/adjudication-config",
+                serde_json::json!({"min_severity": "high", "quorum": 2, "reviewers": 3}),
+            ),
+            (
+                "/api/v1/decay/policy",
+                serde_json::json!({
+                    "default_half_life_hours": 0,
+                    "tag_half_life_hours": {"rumor": 24},
+                    "claim_review_threshold_bps": 5000,
+                    "evidence_expiry_bps": 0,
+                }),
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(send("PUT", uri.to_string(), body))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/evidence/ingest".to_string(),
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North rumored to chair Orion Dynamics",
+                    "summary": "Unconfirmed chair appointment",
+                    "content": "Alice North may chair the Orion Dynamics board.",
+                    "url": null,
+                    "observed_at": "2026-03-05T12:00:00Z",
+                    "tags": ["rumor"],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": [{
+                        "subject": "alice north",
+                        "predicate": "chair_of",
+                        "object": "orion dynamics",
+                        "confidence_bps": 8000,
+                        "rationale": "trade press rumor",
+                    }],
+                }),
+            ))
+            .await
+            .unwrap();
+        let ingest: IngestEvidenceResponse = serde_json::from_value(json(response).await).unwrap();
+        let claim_id = ingest.claims[0].id.clone();
+        let vote = |reviewer: &str| {
+            send(
+                "POST",
+                format!("/api/v1/claims/{claim_id}/votes"),
+                serde_json::json!({
+                    "reviewer": reviewer,
+                    "choice": "corroborate",
+                    "rationale": format!("{reviewer} checked the filing"),
+                }),
+            )
+        };
+        for reviewer in ["ana", "ben"] {
+            let response = app.clone().oneshot(vote(reviewer)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/decay/apply".to_string(),
+                serde_json::json!({"as_of": "2026-03-08T12:00:00Z"}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let payload = json(response).await;
+        assert!(payload["claims"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|claim| claim["claim_id"] == claim_id.as_str()));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/v1/claims/{claim_id}/votes"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let payload = json(response).await;
+        assert_eq!(payload["claim"]["review_status"], "needs_review");
+        assert_eq!(payload["adjudication"]["outcome"], "pending");
+        assert_eq!(payload["adjudication"]["votes"], serde_json::json!([]));
+        let closed = &payload["adjudication"]["closed_rounds"][0];
+        assert_eq!(closed["outcome"], "corroborated");
+        assert_eq!(closed["closed_reason"], "decay");
+        assert_eq!(closed["closed_at"], "2026-03-08T12:00:00Z");
+
+        for (reviewer, outcome) in [("ana", "pending"), ("cy", "corroborated")] {
+            let response = app.clone().oneshot(vote(reviewer)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+            let payload = json(response).await;
+            assert_eq!(payload["adjudication"]["outcome"], outcome);
+        }
+    }
+
+    #[tokio::test]
+    async fn trust_calibration_recommends_applies_and_records_history() {
+        let app = test_app();
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let get = |uri: String| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let policy = |auto_apply: bool, max_step: u8| {
+            serde_json::json!({
+                "prior_weight": 2,
+                "min_reviewed_claims": 2,
+                "max_step_per_period": max_step,
+                "period_hours": 168,
+                "min_trust_score": 10,
+                "max_trust_score": 95,
+                "auto_apply": auto_apply,
+            })
+        };
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/sources/trust-calibration/policy".to_string(),
+                serde_json::json!({
+                    "prior_weight": 2,
+                    "min_reviewed_claims": 2,
+                    "max_step_per_period": 5,
+                    "period_hours": 168,
+                    "min_trust_score": 60,
+                    "max_trust_score": 50,
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/sources/trust-calibration/policy".to_string(),
+                policy(false, 5),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let proposed_claims = ["ceo_of", "board_member_of", "advisor_to", "investor_in"]
+            .iter()
+            .map(|predicate| {
+                serde_json::json!({
+                    "subject": "alice north",
+                    "predicate": predicate,
+                    "object": "vector works",
+                    "confidence_bps": 7000,
+                    "rationale": "unverified tip",
+                })
+            })
+            .collect::<Vec<_>>();
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/evidence/ingest".to_string(),
+                serde_json::json!({
+                    "source_id": "rss_national_security",
+                    "title": "Alice North tipped for Vector Works roles",
+                    "summary": "Unverified roles",
+                    "content": "A tip links Alice North to several Vector Works roles.",
+                    "url": null,
+                    "observed_at": "2026-03-06T12:30:00Z",
+                    "tags": ["leadership"],
+                    "entity_labels": ["alice north"],
+                    "proposed_claims": proposed_claims,
+                }),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let ingest: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let claim_ids = ingest
+            .claims
+            .iter()
+            .map(|claim| claim.id.clone())
+            .collect::<Vec<_>>();
+        for claim_id in &claim_ids[..3] {
+            let response = app
+                .clone()
+                .oneshot(send(
+                    "POST",
+                    format!("/api/v1/claims/{claim_id}/review"),
+                    serde_json::json!({"status": "rejected"}),
+                ))
+                .await
+                .unwrap();
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(payload["trust_adjustments"], serde_json::json!([]));
+        }
+
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/sources/trust-calibration".to_string()))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let recommendation = payload["recommendations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|item| item["source_id"] == "rss_national_security")
+            .unwrap()
+            .clone();
+        let original = recommendation["current_trust_score"].as_u64().unwrap();
+        let proposed = recommendation["proposed_trust_score"].as_u64().unwrap();
+        assert_eq!(original - proposed, 5);
+        assert_eq!(recommendation["limited_by_period"], true);
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/sources/trust-calibration/apply".to_string(),
+                serde_json::json!({"source_ids": ["missing_source"]}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/v1/sources/trust-calibration/apply".to_string(),
+                serde_json::json!({"source_ids": ["rss_national_security"]}),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["applied"].as_array().unwrap().len(), 1);
+        assert_eq!(payload["applied"][0]["origin"], "accepted");
+        assert_eq!(payload["applied"][0]["trust_score"], proposed);
+
+        // The period budget is spent, so the source has no further recommendation.
+        let response = app
+            .clone()
+            .oneshot(get("/api/v1/sources/trust-calibration".to_string()))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(payload["recommendations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|item| item["source_id"] != "rss_national_security"));
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/sources/trust-calibration/policy".to_string(),
+                policy(true, 10),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("/api/v1/claims/{}/review", claim_ids[3]),
+                serde_json::json!({"status": "rejected"}),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["trust_adjustments"][0]["origin"], "auto");
+        assert_eq!(
+            payload["trust_adjustments"][0]["trust_score"],
+            original - 10
+        );
+
+        let response = app
+            .clone()
+            .oneshot(get(
+                "/api/v1/sources/rss_national_security/trust-history?at=2000-01-01T00:00:00Z"
+                    .to_string(),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["entries"].as_array().unwrap().len(), 2);
+        assert_eq!(payload["trust_score"], original - 10);
+        assert_eq!(payload["trust_score_at"], original);
+
+        // Queues rank with the trust in effect at `trust_as_of` when asked to.
+        for (query, expected) in [
+            ("", original - 10),
+            ("&trust_as_of=2000-01-01T00:00:00Z", original),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(get(format!(
+                    "/api/v1/evidence?source_id=rss_national_security{query}"
+                )))
+                .await
+                .unwrap();
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert!(payload["evidence"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .all(|entry| entry["source_trust_score"] == expected));
+        }
+        let response = app
+            .oneshot(get("/api/v1/evidence?trust_as_of=last-week".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn recreating_a_source_records_a_manual_trust_change() {
+        let app = test_app();
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+        let get = |uri: String| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let source = |trust_score: u8| {
+            serde_json::json!({
+                "name": "Harbor Wire",
+                "description": "Port logistics wire.",
+                "kind": "rss_feed",
+                "cadence_minutes": 60,
+                "trust_score": trust_score,
+                "enabled": true,
+                "tags": ["logistics"],
+            })
+        };
+        for trust_score in [70, 70, 55] {
+            let response = app
+                .clone()
+                .oneshot(send(
+                    "POST",
+                    "/api/v1/sources".to_string(),
+                    source(trust_score),
+                ))
+                .await
+                .unwrap();
+            assert!(response.status().is_success());
+        }
+
+        let response = app
+            .oneshot(get(
+                "/api/v1/sources/harbor-wire/trust-history?at=2000-01-01T00:00:00Z".to_string(),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let entries = payload["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["origin"], "manual");
+        assert_eq!(entries[0]["previous_trust_score"], 70);
+        assert_eq!(entries[0]["trust_score"], 55);
+        assert_eq!(payload["trust_score_at"], 70);
+    }
+
+    #[tokio::test]
+    async fn case_transition_endpoint_updates_status() {
+        let app = test_app();
+        let ingest = IngestEvidenceRequest {
+            source_id: "rss_national_security".to_string(),
+            title: "Alice North appointed at Orion Dynamics".to_string(),
+            summary: "Leadership change".to_string(),
+            content: "Alice North was appointed to a new role at Orion Dynamics.".to_string(),
+            url: None,
+            observed_at: "2026-03-06T12:30:00Z".to_string(),
+            tags: vec!["leadership".to_string()],
+            entity_labels: vec!["alice north".to_string()],
+            proposed_claims: vec![helix_core::intel_desk::ProposedClaim {
+                subject: "alice north".to_string(),
+                predicate: "appointed_to".to_string(),
+                object: "orion dynamics".to_string(),
+                confidence_bps: 8700,
+                rationale: Some("appointment notice".to_string()),
+            }],
+        };
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/evidence/ingest")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&ingest).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let ingest_payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let case_id = ingest_payload.case_updates[0].case.id.clone();
+
+        let transition_response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(&format!("/api/v1/cases/{case_id}/transition"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::to_vec(&CaseTransitionRequest {
+                            command: helix_core::intel_desk::CaseCommand::Close,
+                            actor: None,
+                        })
+                        .unwrap(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(transition_response.status(), StatusCode::OK);
+
+        let body = to_bytes(transition_response.into_body(), 1024 * 1024)
+            .await
+            .unwrap();
+        let payload: CaseTransitionResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            payload.transition.case.status,
+            helix_core::intel_desk::CaseStatus::Closed
+        );
+    }
+
+    #[test]
+    fn trust_adjustment_ids_continue_from_the_largest_stored_id() {
+        let entry = |id: &str| {
+            (
+                id.to_string(),
+                helix_core::intel_source_calibration::TrustHistoryEntry {
+                    id: id.to_string(),
+                    source_id: "rss_national_security".to_string(),
+                    previous_trust_score: 70,
+                    trust_score: 75,
+                    origin: helix_core::intel_source_calibration::TrustAdjustmentOrigin::Manual,
+                    posterior_bps: 0,
+                    corroborated_claim_count: 0,
+                    rejected_claim_count: 0,
+                    recorded_at: "2026-03-06T12:00:00Z".to_string(),
+                },
+            )
+        };
+        assert_eq!(
+            crate::intel::next_trust_adjustment_id(&BTreeMap::new()),
+            "trust_adjustment_00000001"
+        );
+        // A gap in the loaded history must not hand out an id that sorts before stored ones.
+        let history = BTreeMap::from([
+            entry("trust_adjustment_00000001"),
+            entry("trust_adjustment_00000005"),
+        ]);
+        assert_eq!(
+            crate::intel::next_trust_adjustment_id(&history),
+            "trust_adjustment_00000006"
+        );
+    }
+
+    #[test]
+    fn case_event_rows_join_legacy_headers_by_sequence() {
+        use helix_core::intel_case_timeline::CaseTimeline;
+        use helix_core::intel_desk::{new_case, transition_case, CaseCommand, CaseStatus};
+
+        let open = CaseCommand::Open {
+            case_id: "case_1".to_string(),
+            title: "Executive moves: acme".to_string(),
+            watchlist_id: "watch_exec_moves".to_string(),
+            primary_entity: Some("acme".to_string()),
+            evidence_id: "ev_1".to_string(),
+            claim_ids: vec!["claim_1".to_string()],
+            reason: "matched acme".to_string(),
+        };
+        let escalate = CaseCommand::Escalate {
+            reason: "board exit".to_string(),
+        };
+        let opened = new_case(open.clone()).unwrap();
+        let escalated = transition_case(&opened.case, escalate.clone()).unwrap();
+        let mut legacy = CaseTimeline::new("case_1");
+        legacy.append("operator", "2026-03-01T00:00:00Z", open, opened.decision);
+        let mut full = legacy.clone();
+        full.append(
+            "operator",
+            "2026-03-02T00:00:00Z",
+            escalate,
+            escalated.decision,
+        );
+
+        // The legacy header still embeds event 1; both events also have their own rows.
+        let rows = full
+            .events
+            .iter()
+            .map(|event| ("case_1".to_string(), event.clone()))
+            .collect();
+        let merged = crate::intel::merge_case_event_rows(
+            BTreeMap::from([("case_1".to_string(), legacy)]),
+            rows,
+        );
+        assert_eq!(merged["case_1"], full);
+        assert_eq!(
+            merged["case_1"].replay().unwrap().unwrap().status,
+            CaseStatus::Escalated
+        );
+    }
+
+    #[tokio::test]
+    async fn case_timeline_replays_the_event_log_and_answers_as_of() {
+        let app = test_app();
+        let ingest = IngestEvidenceRequest {
+            source_id: "rss_national_security".to_string(),
+            title: "Bram Cole appointed at Orion Dynamics".to_string(),
+            summary: "Leadership change".to_string(),
+            content: "Bram Cole was appointed to a new role at Orion Dynamics.".to_string(),
+            url: None,
+            observed_at: "2026-03-06T12:45:00Z".to_string(),
+            tags: vec!["leadership".to_string()],
+            entity_labels: vec!["bram cole".to_string()],
+            proposed_claims: Vec::new(),
+        };
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/evidence/ingest")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&ingest).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let ingest_payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let opened = ingest_payload.case_updates[0].case.clone();
+
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let before_close = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+
+        let transition = |command: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/v1/cases/{}/transition", opened.id))
+                .header("content-type", "application/json")
+                .body(Body::from(command.to_string()))
+                .unwrap()
+        };
+        for (command, status) in [
+            (
+                serde_json::json!({"command": {"type": "close"}, "actor": "ana"}),
+                StatusCode::OK,
+            ),
+            (
+                serde_json::json!({"command": {"type": "reopen", "reason": "new filing"}}),
+                StatusCode::OK,
+            ),
+            (
+                serde_json::json!({"command": {"type": "reopen", "reason": "again"}}),
+                StatusCode::OK,
+            ),
+            (
+                serde_json::json!({"command": {
+                    "type": "relabel_primary_entity",
+                    "primary_entity": "someone else"
+                }}),
+                StatusCode::BAD_REQUEST,
+            ),
+        ] {
+            let response = app.clone().oneshot(transition(command)).await.unwrap();
+            assert_eq!(response.status(), status);
+        }
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/api/v1/cases/{}/timeline?as_of={before_close}",
+                        opened.id
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["replay_matches"], true);
+        assert_eq!(payload["case"]["status"], "open");
+        assert_eq!(payload["case"]["latest_reason"], "new filing");
+        assert_eq!(
+            payload["case_as_of"]["status"],
+            serde_json::json!(opened.status)
+        );
+
+        let events = payload["events"].as_array().unwrap();
+        assert_eq!(events[0]["command"]["type"], "open");
+        assert_eq!(
+            events[0]["actor"],
+            format!("watchlist:{}", opened.watchlist_id)
+        );
+        let tail = &events[events.len() - 3..];
+        assert_eq!(tail[0]["actor"], "ana");
+        assert_eq!(tail[0]["decision"]["status"], "closed");
+        assert_eq!(tail[1]["actor"], "operator");
+        assert_eq!(tail[2]["decision"]["kind"], "denied");
+        assert_eq!(tail[2]["sequence"].as_u64().unwrap(), events.len() as u64);
+    }
+
+    #[tokio::test]
+    async fn case_merge_redirects_hits_and_split_moves_evidence() {
+        let app = test_app();
+        let ingest = |title: &str, entity: &str, observed_at: &str| {
+            let request = IngestEvidenceRequest {
+                source_id: "rss_national_security".to_string(),
+                title: title.to_string(),
+                summary: "Leadership change".to_string(),
+                content: format!("{title}."),
+                url: None,
+                observed_at: observed_at.to_string(),
+                tags: vec!["leadership".to_string()],
+                entity_labels: vec![entity.to_string()],
+                proposed_claims: Vec::new(),
+            };
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/evidence/ingest")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&request).unwrap()))
+                .unwrap()
+        };
+        let exec_case = |payload: &IngestEvidenceResponse| {
+            payload
+                .case_updates
+                .iter()
+                .find(|update| update.case.watchlist_id == "watch_exec_moves")
+                .unwrap()
+                .case
+                .clone()
+        };
+        let mut opened = Vec::new();
+        for (title, entity, observed_at) in [
+            (
+                "Alice North resigned from the board",
+                "alice north",
+                "2026-03-06T13:00:00Z",
+            ),
+            (
+                "Orion Dynamics appointed a finance chief",
+                "orion dynamics",
+                "2026-03-06T13:05:00Z",
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(ingest(title, entity, observed_at))
+                .await
+                .unwrap();
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+            opened.push((exec_case(&payload), payload.evidence.id));
+        }
+        let (target, target_evidence) = opened[0].clone();
+        let (absorbed, absorbed_evidence) = opened[1].clone();
+
+        let transition = |case_id: &str, command: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/v1/cases/{case_id}/transition"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "command": command }).to_string(),
+                ))
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(transition(
+                &absorbed.id,
+                serde_json::json!({"type": "merge", "into_case_id": target.id}),
+            ))
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        assert_eq!(status, StatusCode::OK, "{}", String::from_utf8_lossy(&body));
+        let merged: CaseTransitionResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            merged.transition.case.status,
+            helix_core::intel_desk::CaseStatus::Merged
+        );
+        assert_eq!(
+            merged.transition.case.merged_into.as_deref(),
+            Some(target.id.as_str())
+        );
+        let merged_target = &merged.related[0].case;
+        assert_eq!(merged_target.id, target.id);
+        assert!(merged_target.evidence_ids.contains(&target_evidence));
+        assert!(merged_target.evidence_ids.contains(&absorbed_evidence));
+        assert_eq!(
+            merged_target.status,
+            helix_core::intel_desk::CaseStatus::Escalated
+        );
+
+        let response = app
+            .clone()
+            .oneshot(ingest(
+                "Orion Dynamics appointed a general counsel",
+                "orion dynamics",
+                "2026-03-06T13:10:00Z",
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(exec_case(&payload).id, target.id);
+        let redirected_evidence = payload.evidence.id;
 
-        let oversized = serde_json::json!({
-            "file_name": "large.txt",
-            "content": "x".repeat(16_385),
-            "observed_at": "2026-04-03T10:00:00Z"
-        });
-        let oversized_response = app
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri(format!("/api/v1/sources/{}/import", created.source.id))
-                    .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_vec(&oversized).unwrap()))
-                    .unwrap(),
-            )
+        let response = app
+            .clone()
+            .oneshot(transition(
+                &target.id,
+                serde_json::json!({
+                    "type": "split",
+                    "evidence_ids": [absorbed_evidence, redirected_evidence],
+                    "new_case_id": "case_orion_split"
+                }),
+            ))
             .await
             .unwrap();
-        assert_eq!(oversized_response.status(), StatusCode::BAD_REQUEST);
-    }
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let split: CaseTransitionResponse = serde_json::from_slice(&body).unwrap();
+        assert!(split
+            .transition
+            .case
+            .evidence_ids
+            .contains(&target_evidence));
+        assert!(!split
+            .transition
+            .case
+            .evidence_ids
+            .contains(&absorbed_evidence));
+        let opened_split = &split.related[0].case;
+        assert_eq!(opened_split.id, "case_orion_split");
+        assert_eq!(
+            opened_split.evidence_ids,
+            vec![absorbed_evidence, redirected_evidence]
+        );
 
-    #[tokio::test]
-    async fn source_collect_endpoint_rejects_zero_limit() {
-        let response = test_app()
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/v1/sources/rss_national_security/collect")
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        r#"{"observed_at":"2026-04-01T10:05:00Z","max_items":0}"#,
-                    ))
-                    .unwrap(),
-            )
+        for case_id in [absorbed.id.as_str(), target.id.as_str(), "case_orion_split"] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri(format!("/api/v1/cases/{case_id}/timeline"))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+            let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(payload["replay_matches"], true);
+        }
+
+        let response = app
+            .oneshot(transition(
+                &target.id,
+                serde_json::json!({"type": "merge", "into_case_id": absorbed.id}),
+            ))
             .await
             .unwrap();
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn watchlists_endpoint_creates_watchlist() {
-        let request = CreateWatchlistRequest {
-            name: "Launch Monitor".to_string(),
-            description: "Track launches and facilities".to_string(),
-            keywords: vec!["launch".to_string()],
-            entities: vec!["orion dynamics".to_string()],
-            min_source_trust: 55,
-            severity: helix_core::intel_desk::WatchlistSeverity::Medium,
-            enabled: true,
+    async fn case_sla_breaches_unbriefed_escalations_and_filters_the_queue() {
+        let app = test_app();
+        let request = IngestEvidenceRequest {
+            source_id: "rss_national_security".to_string(),
+            title: "Alice North resigned from the board".to_string(),
+            summary: "Leadership change".to_string(),
+            content: "Alice North resigned from the board.".to_string(),
+            url: None,
+            observed_at: "2026-03-06T13:00:00Z".to_string(),
+            tags: vec!["leadership".to_string()],
+            entity_labels: vec!["alice north".to_string()],
+            proposed_claims: Vec::new(),
         };
-
-        let response = test_app()
+        let response = app
+            .clone()
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/v1/watchlists")
+                    .uri("/api/v1/evidence/ingest")
                     .header("content-type", "application/json")
                     .body(Body::from(serde_json::to_vec(&request).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let case_id = payload
+            .case_updates
+            .iter()
+            .find(|update| update.case.watchlist_id == "watch_exec_moves")
+            .unwrap()
+            .case
+            .id
+            .clone();
+
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("/api/v1/cases/{case_id}/transition"),
+                serde_json::json!({"command": {
+                    "type": "assign",
+                    "assignee": "dana",
+                    "watch_group": "exec-desk"
+                }}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let queue = |query: &str| {
+            let app = app.clone();
+            let uri = format!("/api/v1/cases?{query}");
+            async move {
+                let response = app
+                    .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                let status = response.status();
+                let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+                (
+                    status,
+                    serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+                )
+            }
+        };
+        let (status, mine) = queue("view=mine&assignee=dana").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(mine["cases"].as_array().unwrap().len(), 1);
+        let sla = mine["cases"][0]["sla"].clone();
+        assert_eq!(sla["status"], "pending");
+        assert_eq!(sla["deadline_hours"], 4);
+        assert_eq!(queue("view=mine").await.0, StatusCode::BAD_REQUEST);
+        let (_, unassigned) = queue("view=unassigned").await;
+        assert!(unassigned["cases"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|entry| entry["case"]["id"] != case_id.as_str()));
+
+        let started_at = chrono::DateTime::parse_from_rfc3339(sla["started_at"].as_str().unwrap())
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let tick = |hours: i64| {
+            send(
+                "POST",
+                "/api/v1/cases/sla/tick".to_string(),
+                serde_json::json!({
+                    "now": (started_at + chrono::Duration::hours(hours)).to_rfc3339()
+                }),
+            )
+        };
+        let breached_ids = |body: &serde_json::Value| {
+            body["breaches"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|breach| breach["case_id"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        let response = app.clone().oneshot(tick(3)).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let early: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(!breached_ids(&early).contains(&case_id));
 
+        let response = app.clone().oneshot(tick(5)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
         let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
-        let payload: WatchlistResponse = serde_json::from_slice(&body).unwrap();
-        assert_eq!(payload.watchlist.min_source_trust, 55);
+        let late: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(breached_ids(&late).contains(&case_id));
+        let event = late["events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|event| event["subject"] == format!("cases/{case_id}").as_str())
+            .unwrap();
+        assert_eq!(event["type"], "helix.intel.case.sla_breached");
+        assert_eq!(event["data"]["assignee"], "dana");
+        assert_eq!(event["data"]["watch_group"], "exec-desk");
+
+        let response = app.clone().oneshot(tick(6)).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let repeat: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(!breached_ids(&repeat).contains(&case_id));
+        // Nothing is due again within the same hour, so the tick leaves the store alone.
+        let response = app.clone().oneshot(tick(6)).await.unwrap();
+        let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+        let idle: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(idle["breaches"].as_array().unwrap().is_empty());
+        assert_eq!(idle["open_count"], repeat["open_count"]);
+        let (_, breaching) = queue("view=breaching&watch_group=exec-desk").await;
+        assert_eq!(breaching["cases"][0]["case"]["id"], case_id.as_str());
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("/api/v1/cases/{case_id}/transition"),
+                serde_json::json!({"command": {"type": "attach_brief", "summary": "Board exit confirmed."}}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let (_, breaching) = queue("view=breaching&watch_group=exec-desk").await;
+        assert!(breaching["cases"].as_array().unwrap().is_empty());
+        let (_, mine) = queue("view=mine&assignee=dana").await;
+        assert_eq!(mine["cases"][0]["sla"]["status"], "met_late");
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                "/api/v1/cases/sla/policy".to_string(),
+                serde_json::json!({
+                    "critical_hours": 0,
+                    "high_hours": 4,
+                    "medium_hours": 12,
+                    "low_hours": 24
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
     #[tokio::test]
-    async fn evidence_ingest_creates_claims_hits_and_case() {
+    async fn analyst_notes_thread_search_and_redact_in_export_packets() {
         let app = test_app();
         let request = IngestEvidenceRequest {
             source_id: "rss_national_security".to_string(),
-            title: "Alice North resigned from Orion Dynamics".to_string(),
-            summary: "Leadership change at Orion".to_string(),
-            content: "Alice North resigned after a brief detention, according to the report."
-                .to_string(),
-            url: Some("https://example.org/report".to_string()),
-            observed_at: "2026-03-06T12:00:00Z".to_string(),
-            tags: vec!["leadership".to_string(), "security".to_string()],
-            entity_labels: vec!["alice north".to_string(), "orion dynamics".to_string()],
-            proposed_claims: vec![helix_core::intel_desk::ProposedClaim {
-                subject: "alice north".to_string(),
-                predicate: "resigned_from".to_string(),
-                object: "orion dynamics".to_string(),
-                confidence_bps: 9100,
-                rationale: Some("explicitly stated in the report".to_string()),
-            }],
+            title: "Alice North resigned from the board".to_string(),
+            summary: "Leadership change".to_string(),
+            content: "Alice North resigned from the board.".to_string(),
+            url: None,
+            observed_at: "2026-03-06T13:00:00Z".to_string(),
+            tags: vec!["leadership".to_string()],
+            entity_labels: vec!["alice north".to_string()],
+            proposed_claims: Vec::new(),
         };
-
         let response = app
             .clone()
             .oneshot(
@@ -5991,59 +10759,155 @@ mod tests {
             )
             .await
             .unwrap();
-        assert_eq!(response.status(), StatusCode::CREATED);
-
         let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
         let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
-        assert_eq!(payload.claims.len(), 1);
-        assert!(!payload.hits.is_empty());
-        assert!(!payload.case_updates.is_empty());
+        let evidence_id = payload.evidence.id.clone();
+        let case_id = payload
+            .case_updates
+            .iter()
+            .find(|update| update.case.watchlist_id == "watch_exec_moves")
+            .unwrap()
+            .case
+            .id
+            .clone();
+
+        let call = |method: &str, uri: String, body: Option<serde_json::Value>| {
+            let app = app.clone();
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+                .unwrap();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+                (
+                    status,
+                    serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+                )
+            }
+        };
+
+        let (status, root) = call(
+            "POST",
+            format!("/api/v1/cases/{case_id}/notes"),
+            Some(serde_json::json!({
+                "author": "dana",
+                "body": "Board exit needs a filing check, @Eli."
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(root["mentions"], serde_json::json!(["eli"]));
+        let root_id = root["id"].as_str().unwrap().to_string();
+
+        let (status, reply) = call(
+            "POST",
+            format!("/api/v1/cases/{case_id}/notes"),
+            Some(serde_json::json!({
+                "author": "eli",
+                "body": "Filing found, resignation effective Friday.",
+                "parent_id": root_id
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(reply["parent_id"], root_id.as_str());
+        let (status, _) = call(
+            "POST",
+            format!("/api/v1/evidence/{evidence_id}/notes"),
+            Some(serde_json::json!({"author": "eli", "body": "Source article is paywalled."})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, _) = call(
+            "POST",
+            "/api/v1/cases/case_missing/notes".to_string(),
+            Some(serde_json::json!({"author": "eli", "body": "orphan"})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = call(
+            "PUT",
+            format!("/api/v1/notes/{root_id}"),
+            Some(serde_json::json!({"editor": "eli", "body": "rewritten"})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, edited) = call(
+            "PUT",
+            format!("/api/v1/notes/{root_id}"),
+            Some(serde_json::json!({
+                "editor": "dana",
+                "body": "Board exit confirmed by filing, thanks @Eli.",
+                "pinned": true
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(edited["pinned"], true);
+        assert_eq!(edited["revisions"].as_array().unwrap().len(), 1);
+
+        let (status, threads) = call("GET", format!("/api/v1/cases/{case_id}/notes"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(threads["threads"].as_array().unwrap().len(), 1);
+        assert_eq!(threads["threads"][0]["root"]["id"], root_id.as_str());
         assert_eq!(
-            payload.case_updates[0].case.status,
-            helix_core::intel_desk::CaseStatus::Escalated
+            threads["threads"][0]["replies"].as_array().unwrap().len(),
+            1
         );
 
-        let cases_response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/api/v1/cases")
-                    .body(Body::empty())
-                    .unwrap(),
+        let (_, mentioned) = call("GET", "/api/v1/notes?mention=@eli".to_string(), None).await;
+        assert_eq!(mentioned["notes"].as_array().unwrap().len(), 1);
+        let (_, searched) = call("GET", "/api/v1/notes?q=PAYWALLED".to_string(), None).await;
+        assert_eq!(searched["notes"][0]["target_kind"], "evidence");
+        let (status, _) = call("GET", "/api/v1/notes?limit=0".to_string(), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let export = |redact: bool| {
+            format!(
+                "/api/v1/autopilot/review-queue/export?review_kind=case&item_id={case_id}&redact_notes={redact}"
             )
-            .await
-            .unwrap();
-        assert_eq!(cases_response.status(), StatusCode::OK);
-        let body = to_bytes(cases_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let cases: CaseCatalogResponse = serde_json::from_slice(&body).unwrap();
-        assert!(cases
-            .cases
+        };
+        let (status, packet) = call("GET", export(false), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(packet["notes"].as_array().unwrap().len(), 3);
+        assert_eq!(packet["notes_redacted"], false);
+        assert!(packet["notes"]
+            .as_array()
+            .unwrap()
             .iter()
-            .any(|entry| entry.case.id == payload.case_updates[0].case.id));
+            .any(|note| note["body"] == "Source article is paywalled."));
+        let (_, redacted) = call("GET", export(true), None).await;
+        assert_eq!(redacted["notes_redacted"], true);
+        assert!(redacted["notes"].as_array().unwrap().iter().all(|note| {
+            note["body"] == "[redacted]" && note["mentions"].as_array().unwrap().is_empty()
+        }));
     }
 
     #[tokio::test]
-    async fn case_transition_endpoint_updates_status() {
+    async fn case_dossier_renders_html_and_markdown_with_a_verifiable_manifest() {
         let app = test_app();
-        let ingest = IngestEvidenceRequest {
+        let request = IngestEvidenceRequest {
             source_id: "rss_national_security".to_string(),
-            title: "Alice North appointed at Orion Dynamics".to_string(),
+            title: "Alice North resigned from the board".to_string(),
             summary: "Leadership change".to_string(),
-            content: "Alice North was appointed to a new role at Orion Dynamics.".to_string(),
-            url: None,
-            observed_at: "2026-03-06T12:30:00Z".to_string(),
+            content: "Alice North resigned from the board.".to_string(),
+            url: Some("https://example.org/north-resigns".to_string()),
+            observed_at: "2026-03-06T13:00:00Z".to_string(),
             tags: vec!["leadership".to_string()],
             entity_labels: vec!["alice north".to_string()],
             proposed_claims: vec![helix_core::intel_desk::ProposedClaim {
                 subject: "alice north".to_string(),
-                predicate: "appointed_to".to_string(),
-                object: "orion dynamics".to_string(),
-                confidence_bps: 8700,
-                rationale: Some("appointment notice".to_string()),
+                predicate: "resigned_from".to_string(),
+                object: "board".to_string(),
+                confidence_bps: 9000,
+                rationale: Some("board notice".to_string()),
             }],
         };
-
         let response = app
             .clone()
             .oneshot(
@@ -6051,41 +10915,149 @@ mod tests {
                     .method("POST")
                     .uri("/api/v1/evidence/ingest")
                     .header("content-type", "application/json")
-                    .body(Body::from(serde_json::to_vec(&ingest).unwrap()))
+                    .body(Body::from(serde_json::to_vec(&request).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
         let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
-        let ingest_payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
-        let case_id = ingest_payload.case_updates[0].case.id.clone();
+        let payload: IngestEvidenceResponse = serde_json::from_slice(&body).unwrap();
+        let evidence = payload.evidence.clone();
+        let case_id = payload
+            .case_updates
+            .iter()
+            .find(|update| update.case.watchlist_id == "watch_exec_moves")
+            .unwrap()
+            .case
+            .id
+            .clone();
+
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        for (uri, body) in [
+            (
+                format!("/api/v1/cases/{case_id}/transition"),
+                serde_json::json!({"command": {
+                    "type": "attach_brief",
+                    "summary": "North exit <b>confirmed</b> by filing."
+                }}),
+            ),
+            (
+                format!("/api/v1/cases/{case_id}/notes"),
+                serde_json::json!({"author": "dana", "body": "Check the successor, @eli."}),
+            ),
+        ] {
+            let response = app.clone().oneshot(send("POST", uri, body)).await.unwrap();
+            assert!(response.status().is_success());
+        }
 
-        let transition_response = app
+        let dossier = |query: &str| {
+            let app = app.clone();
+            let uri = format!("/api/v1/cases/{case_id}/dossier{query}");
+            async move {
+                let response = app
+                    .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                let status = response.status();
+                let content_type = response
+                    .headers()
+                    .get("content-type")
+                    .map(|value| value.to_str().unwrap().to_string())
+                    .unwrap_or_default();
+                let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+                (
+                    status,
+                    content_type,
+                    String::from_utf8(body.to_vec()).unwrap(),
+                )
+            }
+        };
+        let verify = |data: serde_json::Value| {
+            let app = app.clone();
+            async move {
+                let response = app
+                    .oneshot(send(
+                        "POST",
+                        "/api/v1/cases/dossier/verify".to_string(),
+                        data,
+                    ))
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                let body = to_bytes(response.into_body(), 1024 * 1024).await.unwrap();
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+            }
+        };
+
+        let (status, content_type, html) = dossier("").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(content_type.starts_with("text/html"));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("North exit &lt;b&gt;confirmed&lt;/b&gt; by filing."));
+        assert!(!html.contains("<b>confirmed"));
+        assert!(html.contains(&evidence.provenance_hash));
+        assert!(html.contains("https://example.org/north-resigns"));
+        assert!(html.contains("alice north resigned_from board"));
+        assert!(html.contains("needs_review"));
+        assert!(html.contains("Check the successor, @eli."));
+        let start = html
+            .find("<script type=\"application/json\" id=\"helix-dossier-data\">")
+            .unwrap();
+        let data_start = html[start..].find('>').unwrap() + start + 1;
+        let data_end = html[data_start..].find("</script>").unwrap() + data_start;
+        let mut data: serde_json::Value =
+            serde_json::from_str(&html[data_start..data_end]).unwrap();
+        assert_eq!(data["items"][0]["kind"], "case");
+        assert!(html.contains(data["manifest"]["root_sha256"].as_str().unwrap()));
+        let verified = verify(data.clone()).await;
+        assert_eq!(verified["valid"], true);
+
+        let evidence_index = data["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .position(|item| item["kind"] == "evidence")
+            .unwrap();
+        data["items"][evidence_index]["record"]["evidence"]["title"] =
+            serde_json::json!("Alice North stays on the board");
+        let tampered = verify(data).await;
+        assert_eq!(tampered["valid"], false);
+        assert_eq!(
+            tampered["mismatched_items"],
+            serde_json::json!([format!("evidence:{}", evidence.id)])
+        );
+
+        let (status, content_type, markdown) = dossier("?format=markdown&redact_notes=true").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(content_type.starts_with("text/markdown"));
+        assert!(markdown.contains("## Integrity manifest"));
+        assert!(!markdown.contains("Check the successor"));
+        let fence = "### helix-dossier-data\n\n```json\n";
+        let data_start = markdown.find(fence).unwrap() + fence.len();
+        let data_end = markdown[data_start..].find("\n```").unwrap() + data_start;
+        let data: serde_json::Value =
+            serde_json::from_str(&markdown[data_start..data_end]).unwrap();
+        assert_eq!(verify(data).await["valid"], true);
+
+        assert_eq!(dossier("?format=pdf").await.0, StatusCode::BAD_REQUEST);
+        let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .method("POST")
-                    .uri(&format!("/api/v1/cases/{case_id}/transition"))
-                    .header("content-type", "application/json")
-                    .body(Body::from(
-                        serde_json::to_vec(&CaseTransitionRequest {
-                            command: helix_core::intel_desk::CaseCommand::Close,
-                        })
-                        .unwrap(),
-                    ))
+                    .uri("/api/v1/cases/case_missing/dossier")
+                    .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(transition_response.status(), StatusCode::OK);
-
-        let body = to_bytes(transition_response.into_body(), 1024 * 1024)
-            .await
-            .unwrap();
-        let payload: CaseTransitionResponse = serde_json::from_slice(&body).unwrap();
-        assert_eq!(
-            payload.transition.case.status,
-            helix_core::intel_desk::CaseStatus::Closed
-        );
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
@@ -0,0 +1,426 @@
+//! Bridge from intel desk claims to the symbolic reasoning kernel.
+//!
+//! A case's claims become facts for a `KrrSymbolic` request. Corroborated claims are
+//! triples such as `resigned_from(alice_north,orion_dynamics)`, claims with a negated
+//! predicate become `not` facts, and needs-review claims, when included, become weaker
+//! `reported_`-prefixed facts that rules must opt into. Rule packs are ground rules
+//! written against a `{case}` placeholder, which is replaced by the case's term.
+//!
+//! The decision is stored on the case as a [`CaseReasoningGate`]; once a case carries a
+//! gate, escalation is only allowed when the gate's verdict is `allow`.
+
+use crate::intel_claim_graph::split_claim_negation;
+use crate::intel_desk::{CaseFile, ClaimRecord, ClaimReviewStatus, WatchlistSeverity};
+use crate::reasoning::{
+    KrrTriple, ReasoningConsistencyScope, ReasoningDecision, ReasoningEvaluationRequest,
+    ReasoningVerdict, SymbolicRule,
+};
+use crate::HelixError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+pub const CASE_PLACEHOLDER: &str = "{case}";
+pub const DEFAULT_CASE_REASONING_QUERY: &str = "escalate({case})";
+pub const MAX_RULE_PACK_RULES: usize = 128;
+const MAX_RULE_ANTECEDENTS: usize = 16;
+const MAX_RULE_PACK_DESCRIPTION_LEN: usize = 512;
+
+/// Stored set of ground rules evaluated against a case.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntelRulePack {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub rules: Vec<SymbolicRule>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseReasoningOptions {
+    /// Query to prove; defaults to `escalate({case})`.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Adds needs-review claims as `reported_` facts.
+    #[serde(default)]
+    pub include_needs_review: bool,
+    #[serde(default)]
+    pub consistency_scope: Option<ReasoningConsistencyScope>,
+    #[serde(default)]
+    pub max_rounds: Option<u8>,
+}
+
+/// Reasoning decision attached to a case, kept for audit and checked before escalation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseReasoningGate {
+    pub rule_pack_id: String,
+    /// Query after `{case}` substitution.
+    pub query: String,
+    pub include_needs_review: bool,
+    /// Claims that contributed facts.
+    pub claim_ids: Vec<String>,
+    pub evaluated_at: String,
+    pub decision: ReasoningDecision,
+}
+
+impl CaseReasoningGate {
+    pub fn allows_escalation(&self) -> bool {
+        self.decision.verdict == ReasoningVerdict::Allow
+    }
+}
+
+pub fn canonicalize_rule_pack(pack: IntelRulePack) -> Result<IntelRulePack, HelixError> {
+    let id = pack.id.trim().to_string();
+    if id.is_empty()
+        || !id
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_')
+    {
+        return Err(HelixError::validation_error(
+            "rule_pack.id",
+            "must use lowercase ascii, digits, or '_'",
+        ));
+    }
+    let name = pack.name.trim().to_string();
+    if name.is_empty() {
+        return Err(HelixError::validation_error(
+            "rule_pack.name",
+            "must not be empty",
+        ));
+    }
+    let description = pack.description.trim().to_string();
+    if description.len() > MAX_RULE_PACK_DESCRIPTION_LEN {
+        return Err(HelixError::validation_error(
+            "rule_pack.description".to_string(),
+            format!("must be at most {MAX_RULE_PACK_DESCRIPTION_LEN} bytes"),
+        ));
+    }
+    if pack.rules.is_empty() || pack.rules.len() > MAX_RULE_PACK_RULES {
+        return Err(HelixError::validation_error(
+            "rule_pack.rules".to_string(),
+            format!("must hold 1 to {MAX_RULE_PACK_RULES} rules"),
+        ));
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut rules = Vec::with_capacity(pack.rules.len());
+    for rule in pack.rules {
+        let rule_id = rule.id.trim().to_string();
+        if rule_id.is_empty() || !seen.insert(rule_id.clone()) {
+            return Err(HelixError::validation_error(
+                "rule_pack.rules.id",
+                "rule ids must be non-empty and unique",
+            ));
+        }
+        if rule.antecedents.is_empty() || rule.antecedents.len() > MAX_RULE_ANTECEDENTS {
+            return Err(HelixError::validation_error(
+                "rule_pack.rules.antecedents".to_string(),
+                format!("rule `{rule_id}` needs 1 to {MAX_RULE_ANTECEDENTS} antecedents"),
+            ));
+        }
+        let antecedents = rule
+            .antecedents
+            .iter()
+            .map(|antecedent| antecedent.trim().to_string())
+            .collect::<Vec<_>>();
+        let consequent = rule.consequent.trim().to_string();
+        if consequent.is_empty() || antecedents.iter().any(String::is_empty) {
+            return Err(HelixError::validation_error(
+                "rule_pack.rules".to_string(),
+                format!("rule `{rule_id}` has an empty fact"),
+            ));
+        }
+        rules.push(SymbolicRule {
+            id: rule_id,
+            antecedents,
+            consequent,
+        });
+    }
+
+    Ok(IntelRulePack {
+        id,
+        name,
+        description,
+        rules,
+    })
+}
+
+/// Lowercases a label and collapses everything but letters and digits into `_`, so
+/// labels with spaces or commas are safe as fact arguments.
+pub fn reasoning_term(label: &str) -> String {
+    let mut term = String::with_capacity(label.len());
+    for ch in label.trim().chars().flat_map(char::to_lowercase) {
+        if ch.is_alphanumeric() {
+            term.push(ch);
+        } else if !term.is_empty() && !term.ends_with('_') {
+            term.push('_');
+        }
+    }
+    term.trim_end_matches('_').to_string()
+}
+
+/// The claim as a reasoning triple. The predicate keeps any negation prefix; use
+/// [`claim_fact`] when negation should become a `not` literal.
+pub fn claim_triple(claim: &ClaimRecord) -> KrrTriple {
+    KrrTriple {
+        subject: reasoning_term(&claim.subject),
+        predicate: reasoning_term(&claim.predicate),
+        object: reasoning_term(&claim.object),
+    }
+}
+
+/// The claim as a fact string, `predicate(subject,object)` or `not predicate(...)` when
+/// the predicate is negated. `prefix` is prepended to the predicate name.
+pub fn claim_fact(claim: &ClaimRecord, prefix: &str) -> String {
+    let (predicate, negated) = split_claim_negation(&claim.predicate);
+    let atom = format!(
+        "{prefix}{}({},{})",
+        reasoning_term(predicate),
+        reasoning_term(&claim.subject),
+        reasoning_term(&claim.object)
+    );
+    if negated {
+        format!("not {atom}")
+    } else {
+        atom
+    }
+}
+
+/// Builds the symbolic request for `case` from its claims and a rule pack. Rejected
+/// claims are ignored. Returns the request with the substituted query and the ids of
+/// the claims that contributed facts.
+pub fn build_case_reasoning_request(
+    case: &CaseFile,
+    severity: WatchlistSeverity,
+    claims: &[ClaimRecord],
+    pack: &IntelRulePack,
+    options: &CaseReasoningOptions,
+) -> Result<(ReasoningEvaluationRequest, Vec<String>), HelixError> {
+    let case_term = reasoning_term(&case.id);
+    let substitute = |value: &str| value.replace(CASE_PLACEHOLDER, &case_term);
+    let query = substitute(
+        options
+            .query
+            .as_deref()
+            .map(str::trim)
+            .filter(|query| !query.is_empty())
+            .unwrap_or(DEFAULT_CASE_REASONING_QUERY),
+    );
+
+    let context = |predicate: &str, object: &str| KrrTriple {
+        subject: case_term.clone(),
+        predicate: predicate.to_string(),
+        object: reasoning_term(object),
+    };
+    let mut triples = vec![
+        context("severity", &json_label(&severity)),
+        context("status", &json_label(&case.status)),
+        context("watchlist", &case.watchlist_id),
+    ];
+    if let Some(entity) = &case.primary_entity {
+        triples.push(context("primary_entity", entity));
+    }
+
+    let mut facts = BTreeSet::new();
+    let mut claim_ids = Vec::new();
+    for claim in claims {
+        let (predicate, negated) = split_claim_negation(&claim.predicate);
+        let prefix = match claim.review_status {
+            ClaimReviewStatus::Corroborated => "",
+            ClaimReviewStatus::NeedsReview if options.include_needs_review => "reported_",
+            _ => continue,
+        };
+        claim_ids.push(claim.id.clone());
+        if negated {
+            facts.insert(claim_fact(claim, prefix));
+            continue;
+        }
+        if prefix.is_empty() {
+            triples.push(claim_triple(claim));
+            triples.push(context("corroborated", predicate));
+            facts.insert(format!("has_corroborated_claim({case_term})"));
+        } else {
+            facts.insert(claim_fact(claim, prefix));
+            triples.push(context("reported", predicate));
+            facts.insert(format!("has_reported_claim({case_term})"));
+        }
+    }
+    triples.sort_by(|left, right| {
+        (&left.subject, &left.predicate, &left.object).cmp(&(
+            &right.subject,
+            &right.predicate,
+            &right.object,
+        ))
+    });
+    triples.dedup();
+    claim_ids.sort();
+
+    let rules = pack
+        .rules
+        .iter()
+        .map(|rule| SymbolicRule {
+            id: rule.id.clone(),
+            antecedents: rule
+                .antecedents
+                .iter()
+                .map(|antecedent| substitute(antecedent))
+                .collect(),
+            consequent: substitute(&rule.consequent),
+        })
+        .collect();
+
+    Ok((
+        ReasoningEvaluationRequest::KrrSymbolic {
+            query,
+            facts: facts.into_iter().collect(),
+            rules,
+            triples,
+            consistency_scope: options.consistency_scope,
+            max_rounds: options.max_rounds,
+        },
+        claim_ids,
+    ))
+}
+
+fn json_label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intel_desk::CaseStatus;
+    use crate::reasoning::evaluate_reasoning;
+
+    fn claim(id: &str, predicate: &str, review_status: ClaimReviewStatus) -> ClaimRecord {
+        ClaimRecord {
+            id: id.to_string(),
+            evidence_id: "evidence_1".to_string(),
+            subject: "Alice North".to_string(),
+            predicate: predicate.to_string(),
+            object: "Orion Dynamics, Inc.".to_string(),
+            confidence_bps: 8_000,
+            review_status,
+            rationale: String::new(),
+        }
+    }
+
+    fn case() -> CaseFile {
+        CaseFile {
+            id: "case_alice".to_string(),
+            title: "Executive Movements: alice north".to_string(),
+            watchlist_id: "watch_exec_moves".to_string(),
+            status: CaseStatus::Open,
+            primary_entity: Some("alice north".to_string()),
+            evidence_ids: vec!["evidence_1".to_string()],
+            claim_ids: Vec::new(),
+            latest_reason: "seed".to_string(),
+            briefing_summary: None,
+            reasoning_gate: None,
+            merged_into: None,
+            assignee: None,
+            watch_group: None,
+        }
+    }
+
+    fn pack() -> IntelRulePack {
+        canonicalize_rule_pack(IntelRulePack {
+            id: "exec_moves".to_string(),
+            name: "Executive moves".to_string(),
+            description: String::new(),
+            rules: vec![SymbolicRule {
+                id: "corroborated_resignation".to_string(),
+                antecedents: vec![
+                    "corroborated({case},resigned_from)".to_string(),
+                    "severity({case},high)".to_string(),
+                ],
+                consequent: "escalate({case})".to_string(),
+            }],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn corroborated_claims_prove_the_escalation_query() {
+        let claims = [
+            claim("c1", "resigned_from", ClaimReviewStatus::Corroborated),
+            claim("c2", "detained_by", ClaimReviewStatus::NeedsReview),
+            claim("c3", "appointed_to", ClaimReviewStatus::Rejected),
+        ];
+        let (request, claim_ids) = build_case_reasoning_request(
+            &case(),
+            WatchlistSeverity::High,
+            &claims,
+            &pack(),
+            &CaseReasoningOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(claim_ids, vec!["c1"]);
+        let ReasoningEvaluationRequest::KrrSymbolic { query, triples, .. } = &request else {
+            panic!("expected a symbolic request");
+        };
+        assert_eq!(query, "escalate(case_alice)");
+        assert!(triples.contains(&KrrTriple {
+            subject: "alice_north".to_string(),
+            predicate: "resigned_from".to_string(),
+            object: "orion_dynamics_inc".to_string(),
+        }));
+
+        let decision = evaluate_reasoning(request).unwrap();
+        assert_eq!(decision.verdict, ReasoningVerdict::Allow);
+        assert!(decision
+            .trace
+            .support_graph
+            .iter()
+            .any(|node| node.rule_id.as_deref() == Some("corroborated_resignation")));
+    }
+
+    #[test]
+    fn needs_review_claims_are_weaker_facts_and_negations_contradict() {
+        let claims = [
+            claim("c1", "resigned_from", ClaimReviewStatus::NeedsReview),
+            claim("c2", "not_resigned_from", ClaimReviewStatus::NeedsReview),
+        ];
+        let options = CaseReasoningOptions {
+            include_needs_review: true,
+            ..CaseReasoningOptions::default()
+        };
+        let (request, claim_ids) = build_case_reasoning_request(
+            &case(),
+            WatchlistSeverity::High,
+            &claims,
+            &pack(),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(claim_ids, vec!["c1", "c2"]);
+        let ReasoningEvaluationRequest::KrrSymbolic { facts, .. } = &request else {
+            panic!("expected a symbolic request");
+        };
+        assert!(
+            facts.contains(&"reported_resigned_from(alice_north,orion_dynamics_inc)".to_string())
+        );
+        assert!(facts
+            .contains(&"not reported_resigned_from(alice_north,orion_dynamics_inc)".to_string()));
+
+        let decision = evaluate_reasoning(request).unwrap();
+        assert_ne!(decision.verdict, ReasoningVerdict::Allow);
+        assert!(!decision.trace.contradictions.is_empty());
+    }
+
+    #[test]
+    fn rule_packs_reject_duplicate_rule_ids_and_bad_ids() {
+        let mut invalid = pack();
+        invalid.rules.push(invalid.rules[0].clone());
+        assert!(canonicalize_rule_pack(invalid).is_err());
+        let mut invalid = pack();
+        invalid.id = "Exec Moves".to_string();
+        assert!(canonicalize_rule_pack(invalid).is_err());
+        assert_eq!(
+            reasoning_term(" Orion Dynamics, Inc. "),
+            "orion_dynamics_inc"
+        );
+    }
+}
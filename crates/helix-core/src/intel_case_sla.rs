@@ -0,0 +1,264 @@
+//! Briefing SLA for escalated cases.
+//!
+//! Escalating a case starts a window on the deterministic `SlaDeadlineMachine`, sized
+//! in hours by the watchlist severity. The machine advances one tick per elapsed
+//! wall-clock hour, so a case breaches when it has gone unbriefed for the full window.
+//! Attaching a brief completes the window; closing or merging the case cancels it.
+
+use crate::deterministic_agents::{SlaDeadlineMachine, SlaDecision, SlaInput};
+use crate::intel_desk::WatchlistSeverity;
+use crate::HelixError;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+const MAX_SLA_HOURS: u16 = 24 * 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseSlaPolicy {
+    /// Hours an escalated case may go without a brief, by watchlist severity.
+    pub critical_hours: u16,
+    pub high_hours: u16,
+    pub medium_hours: u16,
+    pub low_hours: u16,
+}
+
+impl Default for CaseSlaPolicy {
+    fn default() -> Self {
+        Self {
+            critical_hours: 1,
+            high_hours: 4,
+            medium_hours: 12,
+            low_hours: 24,
+        }
+    }
+}
+
+impl CaseSlaPolicy {
+    pub fn brief_hours(&self, severity: WatchlistSeverity) -> u16 {
+        match severity {
+            WatchlistSeverity::Critical => self.critical_hours,
+            WatchlistSeverity::High => self.high_hours,
+            WatchlistSeverity::Medium => self.medium_hours,
+            WatchlistSeverity::Low => self.low_hours,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseSlaStatus {
+    Pending,
+    Breached,
+    MetOnTime,
+    MetLate,
+    /// The case was closed or merged before it was briefed.
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseSla {
+    pub case_id: String,
+    pub severity: WatchlistSeverity,
+    pub deadline_hours: u16,
+    pub status: CaseSlaStatus,
+    pub started_at: String,
+    pub deadline_at: String,
+    /// Wall-clock time up to which whole-hour ticks have been applied.
+    pub ticked_through: String,
+    #[serde(default)]
+    pub breached_at: Option<String>,
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    machine: SlaDeadlineMachine,
+}
+
+impl CaseSla {
+    /// Opens a window for an escalated case at `started_at`.
+    pub fn start(
+        case_id: &str,
+        severity: WatchlistSeverity,
+        policy: &CaseSlaPolicy,
+        started_at: DateTime<Utc>,
+    ) -> Self {
+        let deadline_hours = policy.brief_hours(severity).max(1);
+        let mut machine = SlaDeadlineMachine::new(deadline_hours);
+        machine.step(SlaInput::StartWindow);
+        Self {
+            case_id: case_id.to_string(),
+            severity,
+            deadline_hours,
+            status: CaseSlaStatus::Pending,
+            started_at: timestamp(started_at),
+            deadline_at: timestamp(started_at + Duration::hours(i64::from(deadline_hours))),
+            ticked_through: timestamp(started_at),
+            breached_at: None,
+            completed_at: None,
+            machine,
+        }
+    }
+
+    /// True while the window runs: pending, or breached and still unbriefed.
+    pub fn is_open(&self) -> bool {
+        self.machine.active()
+    }
+
+    /// Hours left in the window, as the machine counts them.
+    pub fn remaining_hours(&self) -> u16 {
+        self.machine.remaining_ticks()
+    }
+
+    /// True when `tick(now)` would apply at least one whole-hour tick.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.machine.active()
+            && parse_time(&self.ticked_through)
+                .is_some_and(|ticked_through| (now - ticked_through).num_hours() >= 1)
+    }
+
+    /// Feeds the machine one tick per whole hour between `ticked_through` and `now`.
+    /// Returns true when this call moved the case into breach.
+    pub fn tick(&mut self, now: DateTime<Utc>) -> bool {
+        let Some(ticked_through) = parse_time(&self.ticked_through) else {
+            return false;
+        };
+        if !self.machine.active() || now <= ticked_through {
+            return false;
+        }
+        // Ticks past the deadline only repeat `Expired`, so the loop is bounded by the
+        // window rather than by how long the desk was idle.
+        let hours = (now - ticked_through).num_hours().max(0);
+        let ticks = hours.min(i64::from(self.deadline_hours) + 1);
+        let mut breached = false;
+        for _ in 0..ticks {
+            if self.machine.step(SlaInput::Tick) == SlaDecision::Expired
+                && self.status == CaseSlaStatus::Pending
+            {
+                self.status = CaseSlaStatus::Breached;
+                self.breached_at = Some(self.deadline_at.clone());
+                breached = true;
+            }
+        }
+        self.ticked_through = timestamp(ticked_through + Duration::hours(hours));
+        breached
+    }
+
+    /// Closes the window because the case was briefed.
+    pub fn complete(&mut self, at: DateTime<Utc>) {
+        self.status = match self.machine.step(SlaInput::Complete) {
+            SlaDecision::CompletedOnTime => CaseSlaStatus::MetOnTime,
+            SlaDecision::CompletedLate => CaseSlaStatus::MetLate,
+            _ => return,
+        };
+        self.completed_at = Some(timestamp(at));
+    }
+
+    /// Drops the window because the case left escalation without a brief. A breach
+    /// already recorded stays on the record.
+    pub fn cancel(&mut self, at: DateTime<Utc>) {
+        if !self.machine.active() {
+            return;
+        }
+        self.machine.step(SlaInput::Reset);
+        if self.status == CaseSlaStatus::Pending {
+            self.status = CaseSlaStatus::Cancelled;
+        }
+        self.completed_at = Some(timestamp(at));
+    }
+}
+
+pub fn canonicalize_case_sla_policy(policy: CaseSlaPolicy) -> Result<CaseSlaPolicy, HelixError> {
+    for (field, hours) in [
+        ("case_sla.critical_hours", policy.critical_hours),
+        ("case_sla.high_hours", policy.high_hours),
+        ("case_sla.medium_hours", policy.medium_hours),
+        ("case_sla.low_hours", policy.low_hours),
+    ] {
+        if hours == 0 || hours > MAX_SLA_HOURS {
+            return Err(HelixError::validation_error(
+                field.to_string(),
+                format!("must be between 1 and {MAX_SLA_HOURS}"),
+            ));
+        }
+    }
+    Ok(policy)
+}
+
+fn timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|value| value.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        parse_time(value).unwrap()
+    }
+
+    fn started() -> CaseSla {
+        CaseSla::start(
+            "case_1",
+            WatchlistSeverity::High,
+            &CaseSlaPolicy::default(),
+            at("2026-03-01T00:00:00Z"),
+        )
+    }
+
+    #[test]
+    fn unbriefed_cases_breach_once_the_window_elapses() {
+        let mut sla = started();
+        assert_eq!(sla.deadline_at, "2026-03-01T04:00:00Z");
+        assert!(!sla.is_due(at("2026-03-01T00:59:59Z")));
+        assert!(sla.is_due(at("2026-03-01T03:59:59Z")));
+        assert!(!sla.tick(at("2026-03-01T03:59:59Z")));
+        assert!(!sla.is_due(at("2026-03-01T03:59:59Z")));
+        assert_eq!(sla.remaining_hours(), 1);
+        assert_eq!(sla.ticked_through, "2026-03-01T03:00:00Z");
+        assert!(sla.tick(at("2026-03-01T04:10:00Z")));
+        assert_eq!(sla.status, CaseSlaStatus::Breached);
+        assert_eq!(sla.breached_at.as_deref(), Some("2026-03-01T04:00:00Z"));
+        assert!(!sla.tick(at("2026-03-01T09:00:00Z")));
+
+        sla.complete(at("2026-03-01T09:30:00Z"));
+        assert_eq!(sla.status, CaseSlaStatus::MetLate);
+    }
+
+    #[test]
+    fn briefs_and_closures_end_the_window() {
+        let mut sla = started();
+        sla.tick(at("2026-03-01T02:00:00Z"));
+        sla.complete(at("2026-03-01T02:30:00Z"));
+        assert_eq!(sla.status, CaseSlaStatus::MetOnTime);
+        assert!(!sla.tick(at("2026-03-02T00:00:00Z")));
+        assert_eq!(sla.status, CaseSlaStatus::MetOnTime);
+
+        let mut sla = started();
+        sla.cancel(at("2026-03-01T01:00:00Z"));
+        assert_eq!(sla.status, CaseSlaStatus::Cancelled);
+        assert!(!sla.is_due(at("2026-03-02T00:00:00Z")));
+        assert!(!sla.tick(at("2026-03-02T00:00:00Z")));
+    }
+
+    #[test]
+    fn long_idle_gaps_breach_without_replaying_every_hour() {
+        let mut sla = CaseSla::start(
+            "case_1",
+            WatchlistSeverity::Critical,
+            &CaseSlaPolicy::default(),
+            at("2026-03-01T00:00:00Z"),
+        );
+        assert_eq!(sla.deadline_hours, 1);
+        assert!(sla.tick(at("2027-03-01T00:00:00Z")));
+        assert_eq!(sla.ticked_through, "2027-03-01T00:00:00Z");
+        assert!(canonicalize_case_sla_policy(CaseSlaPolicy {
+            low_hours: 0,
+            ..CaseSlaPolicy::default()
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! Append-only case event log.
+//!
+//! Every command applied to a case is recorded with the decision the kernel returned,
+//! the actor that issued it and when. Folding the log through `new_case` and
+//! `transition_case` reproduces the stored case, and folding a prefix of it answers
+//! what the case looked like at an earlier time. Cases that existed before the log was
+//! kept start from a baseline snapshot instead of an open command.
+
+use crate::intel_desk::{new_case, transition_case, CaseCommand, CaseDecision, CaseFile};
+use crate::HelixError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseEvent {
+    /// Position in the case log, starting at 1.
+    pub sequence: u64,
+    pub actor: String,
+    pub recorded_at: String,
+    pub command: CaseCommand,
+    pub decision: CaseDecision,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseTimeline {
+    pub case_id: String,
+    /// Case state when the log started, for cases opened before events were recorded.
+    #[serde(default)]
+    pub baseline: Option<CaseFile>,
+    #[serde(default)]
+    pub baseline_recorded_at: Option<String>,
+    #[serde(default)]
+    pub events: Vec<CaseEvent>,
+}
+
+impl CaseTimeline {
+    pub fn new(case_id: &str) -> Self {
+        Self {
+            case_id: case_id.to_string(),
+            baseline: None,
+            baseline_recorded_at: None,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn from_baseline(case: CaseFile, recorded_at: &str) -> Self {
+        Self {
+            case_id: case.id.clone(),
+            baseline: Some(case),
+            baseline_recorded_at: Some(recorded_at.to_string()),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn append(
+        &mut self,
+        actor: &str,
+        recorded_at: &str,
+        command: CaseCommand,
+        decision: CaseDecision,
+    ) -> &CaseEvent {
+        self.events.push(CaseEvent {
+            sequence: self.events.len() as u64 + 1,
+            actor: actor.to_string(),
+            recorded_at: recorded_at.to_string(),
+            command,
+            decision,
+        });
+        &self.events[self.events.len() - 1]
+    }
+
+    /// Current case derived from the whole log.
+    pub fn replay(&self) -> Result<Option<CaseFile>, HelixError> {
+        fold_case_events(self.baseline.clone(), &self.events)
+    }
+
+    /// Case as it stood at `at`: the baseline, if it was recorded by then, folded with
+    /// the events recorded at or before `at`. `None` when the case did not exist yet.
+    pub fn case_as_of(&self, at: DateTime<Utc>) -> Result<Option<CaseFile>, HelixError> {
+        let baseline = match &self.baseline_recorded_at {
+            Some(recorded_at) if !recorded_before(recorded_at, at) => return Ok(None),
+            _ => self.baseline.clone(),
+        };
+        let recorded = self
+            .events
+            .iter()
+            .take_while(|event| recorded_before(&event.recorded_at, at))
+            .count();
+        fold_case_events(baseline, &self.events[..recorded])
+    }
+}
+
+/// Folds events over an optional starting case. Without a baseline the first event
+/// must open the case. Each replayed decision must match the recorded one, so a log
+/// that no longer reproduces under the current kernel is reported instead of silently
+/// producing a different case.
+pub fn fold_case_events(
+    baseline: Option<CaseFile>,
+    events: &[CaseEvent],
+) -> Result<Option<CaseFile>, HelixError> {
+    let mut state = baseline;
+    for event in events {
+        let transition = match &state {
+            Some(case) => transition_case(case, event.command.clone())?,
+            None => new_case(event.command.clone())?,
+        };
+        if transition.decision != event.decision {
+            return Err(HelixError::internal_error(format!(
+                "case {} replay diverged at event {}",
+                transition.case.id, event.sequence
+            )));
+        }
+        state = Some(transition.case);
+    }
+    Ok(state)
+}
+
+fn recorded_before(recorded_at: &str, at: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(recorded_at.trim())
+        .is_ok_and(|recorded| recorded.with_timezone(&Utc) <= at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intel_desk::CaseStatus;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn open() -> CaseCommand {
+        CaseCommand::Open {
+            case_id: "case_1".to_string(),
+            title: "Executive moves: acme".to_string(),
+            watchlist_id: "watch_exec_moves".to_string(),
+            primary_entity: Some("acme".to_string()),
+            evidence_id: "ev_1".to_string(),
+            claim_ids: vec!["claim_1".to_string()],
+            reason: "matched acme".to_string(),
+        }
+    }
+
+    fn record(timeline: &mut CaseTimeline, recorded_at: &str, command: CaseCommand) {
+        let current = timeline.replay().unwrap();
+        let transition = match &current {
+            Some(case) => transition_case(case, command.clone()).unwrap(),
+            None => new_case(command.clone()).unwrap(),
+        };
+        timeline.append("operator", recorded_at, command, transition.decision);
+    }
+
+    #[test]
+    fn folding_the_log_reproduces_each_point_in_time() {
+        let mut timeline = CaseTimeline::new("case_1");
+        record(&mut timeline, "2026-03-01T00:00:00Z", open());
+        record(
+            &mut timeline,
+            "2026-03-02T00:00:00Z",
+            CaseCommand::Escalate {
+                reason: "board exit".to_string(),
+            },
+        );
+        record(&mut timeline, "2026-03-03T00:00:00Z", CaseCommand::Close);
+        record(
+            &mut timeline,
+            "2026-03-04T00:00:00Z",
+            CaseCommand::Reopen {
+                reason: "x".to_string(),
+            },
+        );
+        record(&mut timeline, "2026-03-05T00:00:00Z", CaseCommand::Close);
+        assert_eq!(timeline.events.len(), 5);
+        assert_eq!(timeline.events[4].sequence, 5);
+
+        let status_at = |value: &str| {
+            timeline
+                .case_as_of(at(value))
+                .unwrap()
+                .map(|case| case.status)
+        };
+        assert_eq!(status_at("2026-02-28T00:00:00Z"), None);
+        assert_eq!(status_at("2026-03-01T12:00:00Z"), Some(CaseStatus::Open));
+        assert_eq!(
+            status_at("2026-03-02T00:00:00Z"),
+            Some(CaseStatus::Escalated)
+        );
+        assert_eq!(
+            timeline.replay().unwrap().unwrap().latest_reason,
+            "x".to_string()
+        );
+    }
+
+    #[test]
+    fn denied_events_are_kept_without_changing_the_case() {
+        let mut timeline = CaseTimeline::new("case_1");
+        record(&mut timeline, "2026-03-01T00:00:00Z", open());
+        record(
+            &mut timeline,
+            "2026-03-02T00:00:00Z",
+            CaseCommand::Reopen {
+                reason: "not closed".to_string(),
+            },
+        );
+        assert!(matches!(
+            timeline.events[1].decision,
+            CaseDecision::Denied { .. }
+        ));
+        let opened = new_case(open()).unwrap().case;
+        assert_eq!(timeline.replay().unwrap(), Some(opened));
+
+        let mut tampered = timeline.clone();
+        tampered.events[1].decision = CaseDecision::Updated;
+        assert!(tampered.replay().is_err());
+    }
+
+    #[test]
+    fn baseline_cases_replay_from_their_snapshot() {
+        let opened = new_case(open()).unwrap().case;
+        let mut timeline = CaseTimeline::from_baseline(opened.clone(), "2026-03-01T00:00:00Z");
+        record(
+            &mut timeline,
+            "2026-03-02T00:00:00Z",
+            CaseCommand::MarkMonitoring,
+        );
+        assert_eq!(
+            timeline
+                .case_as_of(at("2026-03-01T06:00:00Z"))
+                .unwrap()
+                .as_ref(),
+            Some(&opened)
+        );
+        assert_eq!(
+            timeline.case_as_of(at("2026-02-01T00:00:00Z")).unwrap(),
+            None
+        );
+        assert_eq!(
+            timeline.replay().unwrap().map(|case| case.status),
+            Some(CaseStatus::Monitoring)
+        );
+
+        let mut without_open = CaseTimeline::new("case_1");
+        without_open.append(
+            "operator",
+            "2026-03-01T00:00:00Z",
+            CaseCommand::Close,
+            CaseDecision::StatusChanged {
+                status: CaseStatus::Closed,
+            },
+        );
+        assert!(without_open.replay().is_err());
+    }
+}
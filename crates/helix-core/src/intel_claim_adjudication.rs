@@ -0,0 +1,377 @@
+//! Multi-reviewer claim adjudication on the approval gate quorum model.
+//!
+//! Each reviewer casts one vote with a rationale. Votes are replayed through two
+//! `ApprovalGateMachine`s, one counting corroborate votes and one counting reject votes,
+//! so a claim changes status only when one side reaches quorum. Split votes without a
+//! quorum leave the claim contested; the configuration must leave enough reviewers that
+//! a fully cast vote always decides. A decided claim that is re-opened, for example by
+//! decay, keeps its earlier votes as a closed round and starts a new one.
+
+use crate::deterministic_agents::{ApprovalDecision, ApprovalGateMachine, ApprovalInput};
+use crate::intel_desk::{ClaimReviewStatus, WatchlistSeverity};
+use crate::HelixError;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_ADJUDICATION_REVIEWERS: u16 = 16;
+const MAX_REVIEWER_LEN: usize = 128;
+const MAX_RATIONALE_LEN: usize = 2_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimVoteChoice {
+    Corroborate,
+    Reject,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimVote {
+    pub reviewer: String,
+    pub choice: ClaimVoteChoice,
+    pub rationale: String,
+    pub cast_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimAdjudicationOutcome {
+    Pending,
+    /// Votes disagree and neither side has reached quorum.
+    Contested,
+    Corroborated,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimAdjudicationConfig {
+    /// Claims linked to a case at or above this severity can only be reviewed by vote.
+    /// `None` keeps single-reviewer review for every claim.
+    #[serde(default)]
+    pub min_severity: Option<WatchlistSeverity>,
+    pub quorum: u16,
+    pub reviewers: u16,
+}
+
+impl Default for ClaimAdjudicationConfig {
+    fn default() -> Self {
+        Self {
+            min_severity: None,
+            quorum: 2,
+            reviewers: 3,
+        }
+    }
+}
+
+impl ClaimAdjudicationConfig {
+    pub fn requires_adjudication(&self, severity: Option<WatchlistSeverity>) -> bool {
+        match (self.min_severity, severity) {
+            (Some(min), Some(severity)) => severity.weight() >= min.weight(),
+            _ => false,
+        }
+    }
+}
+
+/// Vote history for one claim. Quorum and reviewer count are fixed when the first vote
+/// is cast, so later configuration changes do not re-decide open adjudications.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimAdjudication {
+    pub claim_id: String,
+    pub quorum: u16,
+    pub reviewers: u16,
+    pub votes: Vec<ClaimVote>,
+    pub outcome: ClaimAdjudicationOutcome,
+    /// Earlier rounds, oldest first.
+    #[serde(default)]
+    pub closed_rounds: Vec<ClaimAdjudicationRound>,
+}
+
+/// A finished round of votes, kept when the claim was re-opened for review.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimAdjudicationRound {
+    pub quorum: u16,
+    pub reviewers: u16,
+    pub votes: Vec<ClaimVote>,
+    pub outcome: ClaimAdjudicationOutcome,
+    pub closed_at: String,
+    pub closed_reason: String,
+}
+
+impl ClaimAdjudication {
+    pub fn new(claim_id: &str, config: &ClaimAdjudicationConfig) -> Self {
+        Self {
+            claim_id: claim_id.to_string(),
+            quorum: config.quorum,
+            reviewers: config.reviewers,
+            votes: Vec::new(),
+            outcome: ClaimAdjudicationOutcome::Pending,
+            closed_rounds: Vec::new(),
+        }
+    }
+
+    /// True when every reviewer has voted and neither side reached quorum. Only
+    /// configurations stored before quorum was checked against the reviewer count can
+    /// get here.
+    pub fn is_deadlocked(&self) -> bool {
+        self.outcome == ClaimAdjudicationOutcome::Contested
+            && self.votes.len() >= usize::from(self.reviewers)
+    }
+
+    /// Closes the current round and starts an empty one under `config`.
+    pub fn reopen(&mut self, config: &ClaimAdjudicationConfig, reason: &str, at: &str) {
+        let fresh = Self::new(&self.claim_id, config);
+        let closed = std::mem::replace(self, fresh);
+        self.closed_rounds = closed.closed_rounds;
+        self.closed_rounds.push(ClaimAdjudicationRound {
+            quorum: closed.quorum,
+            reviewers: closed.reviewers,
+            votes: closed.votes,
+            outcome: closed.outcome,
+            closed_at: at.to_string(),
+            closed_reason: reason.to_string(),
+        });
+    }
+
+    /// Records a vote and returns the new outcome.
+    pub fn cast(&mut self, vote: ClaimVote) -> Result<ClaimAdjudicationOutcome, HelixError> {
+        let vote = canonicalize_vote(vote)?;
+        if matches!(
+            self.outcome,
+            ClaimAdjudicationOutcome::Corroborated | ClaimAdjudicationOutcome::Rejected
+        ) {
+            return Err(HelixError::validation_error(
+                "vote",
+                "claim adjudication is already decided",
+            ));
+        }
+        if self.votes.len() >= usize::from(self.reviewers) {
+            return Err(HelixError::validation_error(
+                "vote",
+                "every reviewer has already voted",
+            ));
+        }
+        if self
+            .votes
+            .iter()
+            .any(|existing| existing.reviewer.eq_ignore_ascii_case(&vote.reviewer))
+        {
+            return Err(HelixError::validation_error(
+                "vote.reviewer".to_string(),
+                format!("{} has already voted on this claim", vote.reviewer),
+            ));
+        }
+        self.votes.push(vote);
+        self.outcome = adjudicate(self.quorum, self.reviewers, &self.votes);
+        Ok(self.outcome)
+    }
+
+    /// Review status the claim should take, once the outcome is decided.
+    pub fn review_status(&self) -> Option<ClaimReviewStatus> {
+        match self.outcome {
+            ClaimAdjudicationOutcome::Corroborated => Some(ClaimReviewStatus::Corroborated),
+            ClaimAdjudicationOutcome::Rejected => Some(ClaimReviewStatus::Rejected),
+            ClaimAdjudicationOutcome::Pending | ClaimAdjudicationOutcome::Contested => None,
+        }
+    }
+}
+
+/// Replays votes through a corroborate gate and a mirrored reject gate.
+pub fn adjudicate(quorum: u16, reviewers: u16, votes: &[ClaimVote]) -> ClaimAdjudicationOutcome {
+    let mut corroborate = ApprovalGateMachine::new(quorum, reviewers);
+    let mut reject = ApprovalGateMachine::new(quorum, reviewers);
+    let mut decisions = (ApprovalDecision::Pending, ApprovalDecision::Pending);
+    for vote in votes {
+        let (for_corroborate, for_reject) = match vote.choice {
+            ClaimVoteChoice::Corroborate => (ApprovalInput::Approve, ApprovalInput::Reject),
+            ClaimVoteChoice::Reject => (ApprovalInput::Reject, ApprovalInput::Approve),
+        };
+        decisions = (corroborate.step(for_corroborate), reject.step(for_reject));
+    }
+    let split = votes.iter().any(|vote| vote.choice != votes[0].choice);
+    match decisions {
+        (ApprovalDecision::Approved, _) => ClaimAdjudicationOutcome::Corroborated,
+        (_, ApprovalDecision::Approved) => ClaimAdjudicationOutcome::Rejected,
+        _ if split => ClaimAdjudicationOutcome::Contested,
+        _ => ClaimAdjudicationOutcome::Pending,
+    }
+}
+
+pub fn canonicalize_adjudication_config(
+    config: ClaimAdjudicationConfig,
+) -> Result<ClaimAdjudicationConfig, HelixError> {
+    if config.reviewers == 0 || config.reviewers > MAX_ADJUDICATION_REVIEWERS {
+        return Err(HelixError::validation_error(
+            "adjudication.reviewers".to_string(),
+            format!("must be between 1 and {MAX_ADJUDICATION_REVIEWERS}"),
+        ));
+    }
+    if config.quorum == 0 || config.quorum > config.reviewers {
+        return Err(HelixError::validation_error(
+            "adjudication.quorum",
+            "must be between 1 and reviewers",
+        ));
+    }
+    // With fewer reviewers, an even split leaves both sides short of quorum for good.
+    if config.reviewers < 2 * config.quorum - 1 {
+        return Err(HelixError::validation_error(
+            "adjudication.reviewers".to_string(),
+            format!(
+                "must be at least {} so a full vote reaches quorum {}",
+                2 * config.quorum - 1,
+                config.quorum
+            ),
+        ));
+    }
+    Ok(config)
+}
+
+fn canonicalize_vote(vote: ClaimVote) -> Result<ClaimVote, HelixError> {
+    let reviewer = vote.reviewer.trim().to_string();
+    if reviewer.is_empty() || reviewer.len() > MAX_REVIEWER_LEN {
+        return Err(HelixError::validation_error(
+            "vote.reviewer".to_string(),
+            format!("must be 1 to {MAX_REVIEWER_LEN} characters"),
+        ));
+    }
+    let rationale = vote.rationale.trim().to_string();
+    if rationale.is_empty() || rationale.len() > MAX_RATIONALE_LEN {
+        return Err(HelixError::validation_error(
+            "vote.rationale".to_string(),
+            format!("must be 1 to {MAX_RATIONALE_LEN} characters"),
+        ));
+    }
+    Ok(ClaimVote {
+        reviewer,
+        rationale,
+        ..vote
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vote(reviewer: &str, choice: ClaimVoteChoice) -> ClaimVote {
+        ClaimVote {
+            reviewer: reviewer.to_string(),
+            choice,
+            rationale: "checked the filing".to_string(),
+            cast_at: "2026-03-06T12:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn quorum_decides_the_claim() {
+        let mut adjudication = ClaimAdjudication::new("c1", &ClaimAdjudicationConfig::default());
+        assert_eq!(
+            adjudication
+                .cast(vote("ana", ClaimVoteChoice::Corroborate))
+                .unwrap(),
+            ClaimAdjudicationOutcome::Pending
+        );
+        assert_eq!(adjudication.review_status(), None);
+        assert_eq!(
+            adjudication
+                .cast(vote("ben", ClaimVoteChoice::Corroborate))
+                .unwrap(),
+            ClaimAdjudicationOutcome::Corroborated
+        );
+        assert_eq!(
+            adjudication.review_status(),
+            Some(ClaimReviewStatus::Corroborated)
+        );
+        assert!(adjudication
+            .cast(vote("cy", ClaimVoteChoice::Reject))
+            .is_err());
+
+        adjudication.reopen(
+            &ClaimAdjudicationConfig::default(),
+            "decay",
+            "2026-03-09T00:00:00Z",
+        );
+        assert_eq!(adjudication.outcome, ClaimAdjudicationOutcome::Pending);
+        assert_eq!(adjudication.closed_rounds.len(), 1);
+        assert_eq!(adjudication.closed_rounds[0].votes.len(), 2);
+        assert_eq!(
+            adjudication
+                .cast(vote("ana", ClaimVoteChoice::Reject))
+                .unwrap(),
+            ClaimAdjudicationOutcome::Pending
+        );
+    }
+
+    #[test]
+    fn split_votes_stay_contested_until_a_side_reaches_quorum() {
+        let mut adjudication = ClaimAdjudication::new("c1", &ClaimAdjudicationConfig::default());
+        adjudication
+            .cast(vote("ana", ClaimVoteChoice::Corroborate))
+            .unwrap();
+        assert_eq!(
+            adjudication
+                .cast(vote("ben", ClaimVoteChoice::Reject))
+                .unwrap(),
+            ClaimAdjudicationOutcome::Contested
+        );
+        assert!(adjudication
+            .cast(vote("Ben", ClaimVoteChoice::Reject))
+            .is_err());
+        assert_eq!(
+            adjudication
+                .cast(vote("cy", ClaimVoteChoice::Reject))
+                .unwrap(),
+            ClaimAdjudicationOutcome::Rejected
+        );
+        assert_eq!(adjudication.votes.len(), 3);
+
+        let config = ClaimAdjudicationConfig {
+            quorum: 3,
+            reviewers: 5,
+            ..ClaimAdjudicationConfig::default()
+        };
+        let votes = [
+            vote("ana", ClaimVoteChoice::Corroborate),
+            vote("ben", ClaimVoteChoice::Reject),
+            vote("cy", ClaimVoteChoice::Corroborate),
+        ];
+        assert_eq!(
+            adjudicate(config.quorum, config.reviewers, &votes),
+            ClaimAdjudicationOutcome::Contested
+        );
+    }
+
+    #[test]
+    fn config_and_votes_are_validated() {
+        let config = ClaimAdjudicationConfig {
+            min_severity: Some(WatchlistSeverity::High),
+            ..ClaimAdjudicationConfig::default()
+        };
+        assert!(config.requires_adjudication(Some(WatchlistSeverity::Critical)));
+        assert!(!config.requires_adjudication(Some(WatchlistSeverity::Medium)));
+        assert!(!ClaimAdjudicationConfig::default()
+            .requires_adjudication(Some(WatchlistSeverity::Critical)));
+        assert!(canonicalize_adjudication_config(ClaimAdjudicationConfig {
+            quorum: 4,
+            ..config
+        })
+        .is_err());
+        assert!(canonicalize_adjudication_config(ClaimAdjudicationConfig {
+            reviewers: 0,
+            quorum: 0,
+            ..config
+        })
+        .is_err());
+        for (quorum, reviewers) in [(2, 2), (3, 4)] {
+            assert!(canonicalize_adjudication_config(ClaimAdjudicationConfig {
+                quorum,
+                reviewers,
+                ..config
+            })
+            .is_err());
+        }
+        let mut adjudication = ClaimAdjudication::new("c1", &config);
+        assert!(adjudication
+            .cast(ClaimVote {
+                rationale: " ".to_string(),
+                ..vote("ana", ClaimVoteChoice::Reject)
+            })
+            .is_err());
+    }
+}
@@ -0,0 +1,369 @@
+//! Claim graph: one canonical assertion per distinct triple across evidence.
+//!
+//! Claims are stored per evidence item, so the same subject/predicate/object reported by
+//! three outlets is three rows. The graph folds them into one assertion that lists its
+//! supporting claims and evidence, and links assertions that contradict each other.
+//! The graph is derived from the stored claims on demand and is never persisted.
+
+use crate::intel_desk::{ClaimRecord, ClaimReviewStatus};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// Predicates that may hold several objects at once, so different objects for the same
+/// subject do not conflict.
+pub const MULTI_VALUED_PREDICATES: &[&str] = &[
+    "mentioned_in_source",
+    "mentions",
+    "owns",
+    "partnered_with",
+    "partners_with",
+    "competes_with",
+    "launched",
+    "launched_beta",
+    "hiring_for",
+    "raised",
+];
+
+/// Predicate prefixes that negate the rest of the predicate, such as `not_resigned_from`.
+const NEGATION_PREFIXES: &[&str] = &[
+    "not_",
+    "not ",
+    "did_not_",
+    "did not ",
+    "never_",
+    "never ",
+    "no_longer_",
+    "no longer ",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimContradictionKind {
+    /// Same subject and single-valued predicate with a different object.
+    ConflictingObject,
+    /// The same triple asserted and negated.
+    Negation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimContradiction {
+    pub assertion_id: String,
+    pub kind: ClaimContradictionKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimAssertion {
+    pub id: String,
+    pub subject: String,
+    /// Predicate with any negation prefix removed.
+    pub predicate: String,
+    pub object: String,
+    pub negated: bool,
+    pub claim_ids: Vec<String>,
+    /// Distinct evidence items that carry a claim for this assertion.
+    pub evidence_ids: Vec<String>,
+    pub max_confidence_bps: u16,
+    pub corroborated_claim_count: usize,
+    pub rejected_claim_count: usize,
+    pub contradictions: Vec<ClaimContradiction>,
+}
+
+impl ClaimAssertion {
+    pub fn contested(&self) -> bool {
+        !self.contradictions.is_empty()
+    }
+
+    /// An assertion whose every claim was rejected no longer contradicts anything.
+    fn active(&self) -> bool {
+        self.rejected_claim_count < self.claim_ids.len()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClaimGraph {
+    assertions: BTreeMap<String, ClaimAssertion>,
+    /// Claim id to assertion id.
+    by_claim: BTreeMap<String, String>,
+}
+
+impl ClaimGraph {
+    pub fn build<'a, I>(claims: I) -> Self
+    where
+        I: IntoIterator<Item = &'a ClaimRecord>,
+    {
+        let mut graph = Self::default();
+        for claim in claims {
+            let (predicate, negated) = split_claim_negation(&claim.predicate);
+            let id = assertion_id(&claim.subject, predicate, &claim.object, negated);
+            let assertion = graph
+                .assertions
+                .entry(id.clone())
+                .or_insert_with(|| ClaimAssertion {
+                    id: id.clone(),
+                    subject: claim.subject.clone(),
+                    predicate: predicate.to_string(),
+                    object: claim.object.clone(),
+                    negated,
+                    claim_ids: Vec::new(),
+                    evidence_ids: Vec::new(),
+                    max_confidence_bps: 0,
+                    corroborated_claim_count: 0,
+                    rejected_claim_count: 0,
+                    contradictions: Vec::new(),
+                });
+            assertion.claim_ids.push(claim.id.clone());
+            if !assertion.evidence_ids.contains(&claim.evidence_id) {
+                assertion.evidence_ids.push(claim.evidence_id.clone());
+            }
+            assertion.max_confidence_bps = assertion.max_confidence_bps.max(claim.confidence_bps);
+            match claim.review_status {
+                ClaimReviewStatus::Corroborated => assertion.corroborated_claim_count += 1,
+                ClaimReviewStatus::Rejected => assertion.rejected_claim_count += 1,
+                ClaimReviewStatus::NeedsReview => {}
+            }
+            graph.by_claim.insert(claim.id.clone(), id);
+        }
+        for assertion in graph.assertions.values_mut() {
+            assertion.claim_ids.sort();
+            assertion.evidence_ids.sort();
+        }
+        graph.link_contradictions();
+        graph
+    }
+
+    pub fn assertions(&self) -> impl Iterator<Item = &ClaimAssertion> {
+        self.assertions.values()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ClaimAssertion> {
+        self.assertions.get(id)
+    }
+
+    pub fn assertion_for_claim(&self, claim_id: &str) -> Option<&ClaimAssertion> {
+        self.by_claim
+            .get(claim_id)
+            .and_then(|id| self.assertions.get(id))
+    }
+
+    /// Assertions that contradict `assertion`, in id order.
+    pub fn contradicting(&self, assertion: &ClaimAssertion) -> Vec<&ClaimAssertion> {
+        assertion
+            .contradictions
+            .iter()
+            .filter_map(|contradiction| self.assertions.get(&contradiction.assertion_id))
+            .collect()
+    }
+
+    fn link_contradictions(&mut self) {
+        let mut groups = BTreeMap::<(&str, &str), Vec<&ClaimAssertion>>::new();
+        for assertion in self.assertions.values().filter(|item| item.active()) {
+            groups
+                .entry((assertion.subject.as_str(), assertion.predicate.as_str()))
+                .or_default()
+                .push(assertion);
+        }
+
+        let mut links = Vec::new();
+        for group in groups.values() {
+            for (index, left) in group.iter().enumerate() {
+                for right in &group[index + 1..] {
+                    if let Some(kind) = contradiction_kind(left, right) {
+                        links.push((left.id.clone(), right.id.clone(), kind));
+                    }
+                }
+            }
+        }
+        for (left, right, kind) in links {
+            for (from, to) in [(&left, &right), (&right, &left)] {
+                if let Some(assertion) = self.assertions.get_mut(from) {
+                    assertion.contradictions.push(ClaimContradiction {
+                        assertion_id: to.clone(),
+                        kind,
+                    });
+                }
+            }
+        }
+        for assertion in self.assertions.values_mut() {
+            assertion
+                .contradictions
+                .sort_by(|left, right| left.assertion_id.cmp(&right.assertion_id));
+        }
+    }
+}
+
+/// Splits a canonical predicate into its base and whether it carried a negation prefix.
+pub fn split_claim_negation(predicate: &str) -> (&str, bool) {
+    NEGATION_PREFIXES
+        .iter()
+        .find_map(|prefix| predicate.strip_prefix(prefix))
+        .filter(|base| !base.trim().is_empty())
+        .map_or((predicate, false), |base| (base.trim(), true))
+}
+
+fn contradiction_kind(
+    left: &ClaimAssertion,
+    right: &ClaimAssertion,
+) -> Option<ClaimContradictionKind> {
+    if left.negated != right.negated {
+        return (left.object == right.object).then_some(ClaimContradictionKind::Negation);
+    }
+    let conflicting = !left.negated
+        && left.object != right.object
+        && !MULTI_VALUED_PREDICATES.contains(&left.predicate.as_str());
+    conflicting.then_some(ClaimContradictionKind::ConflictingObject)
+}
+
+fn assertion_id(subject: &str, predicate: &str, object: &str, negated: bool) -> String {
+    let mut hasher = Sha256::new();
+    for part in [subject, predicate, object, if negated { "not" } else { "" }] {
+        hasher.update(part.as_bytes());
+        hasher.update([0x1f]);
+    }
+    format!("assertion_{}", &format!("{:x}", hasher.finalize())[..12])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claim(
+        id: &str,
+        evidence_id: &str,
+        predicate: &str,
+        object: &str,
+        review_status: ClaimReviewStatus,
+    ) -> ClaimRecord {
+        ClaimRecord {
+            id: id.to_string(),
+            evidence_id: evidence_id.to_string(),
+            subject: "alice north".to_string(),
+            predicate: predicate.to_string(),
+            object: object.to_string(),
+            confidence_bps: 8_000,
+            review_status,
+            rationale: String::new(),
+        }
+    }
+
+    #[test]
+    fn identical_triples_merge_across_evidence() {
+        let claims = [
+            claim(
+                "c1",
+                "e1",
+                "ceo_of",
+                "orion dynamics",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c2",
+                "e2",
+                "ceo_of",
+                "orion dynamics",
+                ClaimReviewStatus::Corroborated,
+            ),
+            claim(
+                "c3",
+                "e2",
+                "mentioned_in_source",
+                "a",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c4",
+                "e3",
+                "mentioned_in_source",
+                "b",
+                ClaimReviewStatus::NeedsReview,
+            ),
+        ];
+        let graph = ClaimGraph::build(&claims);
+        let assertion = graph.assertion_for_claim("c1").unwrap();
+        assert_eq!(assertion.claim_ids, vec!["c1", "c2"]);
+        assert_eq!(assertion.evidence_ids, vec!["e1", "e2"]);
+        assert_eq!(assertion.corroborated_claim_count, 1);
+        assert!(!assertion.contested());
+        assert_eq!(graph.assertions().count(), 3);
+        assert!(graph.assertions().all(|assertion| !assertion.contested()));
+    }
+
+    #[test]
+    fn conflicting_objects_and_negations_contradict() {
+        let claims = [
+            claim(
+                "c1",
+                "e1",
+                "ceo_of",
+                "orion dynamics",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c2",
+                "e2",
+                "ceo_of",
+                "vector works",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c3",
+                "e3",
+                "resigned_from",
+                "orion dynamics",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c4",
+                "e4",
+                "not_resigned_from",
+                "orion dynamics",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c5",
+                "e5",
+                "not_resigned_from",
+                "vector works",
+                ClaimReviewStatus::NeedsReview,
+            ),
+            claim(
+                "c6",
+                "e6",
+                "ceo_of",
+                "boreal cloud",
+                ClaimReviewStatus::Rejected,
+            ),
+        ];
+        let graph = ClaimGraph::build(&claims);
+
+        let ceo = graph.assertion_for_claim("c1").unwrap();
+        assert_eq!(
+            ceo.contradictions,
+            vec![ClaimContradiction {
+                assertion_id: graph.assertion_for_claim("c2").unwrap().id.clone(),
+                kind: ClaimContradictionKind::ConflictingObject,
+            }]
+        );
+        let negated = graph.assertion_for_claim("c4").unwrap();
+        assert!(negated.negated);
+        assert_eq!(negated.predicate, "resigned_from");
+        assert_eq!(
+            graph
+                .contradicting(graph.assertion_for_claim("c3").unwrap())
+                .iter()
+                .map(|assertion| (assertion.id.as_str(), assertion.negated))
+                .collect::<Vec<_>>(),
+            vec![(negated.id.as_str(), true)]
+        );
+        assert!(!graph.assertion_for_claim("c5").unwrap().contested());
+        assert!(!graph.assertion_for_claim("c6").unwrap().contested());
+    }
+
+    #[test]
+    fn negation_prefixes_are_split_from_the_predicate() {
+        assert_eq!(split_claim_negation("not_acquired"), ("acquired", true));
+        assert_eq!(split_claim_negation("no longer ceo_of"), ("ceo_of", true));
+        assert_eq!(split_claim_negation("notified"), ("notified", false));
+        assert_eq!(split_claim_negation("not_"), ("not_", false));
+    }
+}
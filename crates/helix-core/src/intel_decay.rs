@@ -0,0 +1,220 @@
+//! Half-life decay of claim confidence and evidence relevance.
+//!
+//! Decay is always evaluated at an explicit `as_of` time rather than the wall clock, so
+//! a decay run can be replayed and gives the same answer. A half-life is chosen per
+//! evidence: the shortest half-life among its tags wins, then its source kind, then the
+//! policy default. A half-life of zero means the evidence does not decay.
+
+use crate::intel_desk::SourceKind;
+use crate::HelixError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const MAX_HALF_LIFE_HOURS: u32 = 24 * 365 * 10;
+const MAX_DECAY_TAGS: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecayPolicy {
+    /// Half-life used when no source kind or tag override applies; 0 disables decay.
+    pub default_half_life_hours: u32,
+    #[serde(default)]
+    pub source_kind_half_life_hours: BTreeMap<SourceKind, u32>,
+    /// Lowercased evidence tag to half-life.
+    #[serde(default)]
+    pub tag_half_life_hours: BTreeMap<String, u32>,
+    /// Corroborated claims whose decayed confidence falls below this return to review.
+    pub claim_review_threshold_bps: u16,
+    /// Evidence whose decayed relevance falls below this is expired.
+    pub evidence_expiry_bps: u16,
+}
+
+impl Default for DecayPolicy {
+    /// Decay is off by default; thresholds only matter once a half-life is set.
+    fn default() -> Self {
+        Self {
+            default_half_life_hours: 0,
+            source_kind_half_life_hours: BTreeMap::new(),
+            tag_half_life_hours: BTreeMap::new(),
+            claim_review_threshold_bps: 5_000,
+            evidence_expiry_bps: 1_000,
+        }
+    }
+}
+
+impl DecayPolicy {
+    /// Unknown sources fall through to the default half-life.
+    pub fn half_life_hours(&self, source_kind: Option<SourceKind>, tags: &[String]) -> u32 {
+        tags.iter()
+            .filter_map(|tag| self.tag_half_life_hours.get(&tag.trim().to_lowercase()))
+            .copied()
+            .filter(|hours| *hours > 0)
+            .min()
+            .or_else(|| {
+                source_kind.and_then(|kind| self.source_kind_half_life_hours.get(&kind).copied())
+            })
+            .unwrap_or(self.default_half_life_hours)
+    }
+
+    /// Relevance of evidence observed at `observed_at`, in basis points of its original
+    /// weight. Unparseable timestamps and evidence from the future do not decay.
+    pub fn relevance_bps(
+        &self,
+        source_kind: Option<SourceKind>,
+        tags: &[String],
+        observed_at: &str,
+        as_of: DateTime<Utc>,
+    ) -> u16 {
+        let half_life_hours = self.half_life_hours(source_kind, tags);
+        let Ok(observed_at) = DateTime::parse_from_rfc3339(observed_at.trim()) else {
+            return 10_000;
+        };
+        let age_seconds = as_of
+            .signed_duration_since(observed_at.with_timezone(&Utc))
+            .num_seconds();
+        decay_factor_bps(half_life_hours, age_seconds)
+    }
+}
+
+/// `10_000 * 0.5^(age / half_life)`, rounded. A zero half-life or non-positive age
+/// returns 10_000.
+pub fn decay_factor_bps(half_life_hours: u32, age_seconds: i64) -> u16 {
+    if half_life_hours == 0 || age_seconds <= 0 {
+        return 10_000;
+    }
+    let half_lives = age_seconds as f64 / (f64::from(half_life_hours) * 3_600.0);
+    (10_000.0 * (-half_lives).exp2()).round() as u16
+}
+
+/// Scales `confidence_bps` by a relevance factor in basis points.
+pub fn decayed_confidence_bps(confidence_bps: u16, relevance_bps: u16) -> u16 {
+    ((u32::from(confidence_bps) * u32::from(relevance_bps) + 5_000) / 10_000) as u16
+}
+
+pub fn canonicalize_decay_policy(policy: DecayPolicy) -> Result<DecayPolicy, HelixError> {
+    let check_hours = |context: &str, hours: u32| {
+        if hours > MAX_HALF_LIFE_HOURS {
+            return Err(HelixError::validation_error(
+                context.to_string(),
+                format!("half-life must be at most {MAX_HALF_LIFE_HOURS} hours"),
+            ));
+        }
+        Ok(())
+    };
+    check_hours(
+        "decay.default_half_life_hours",
+        policy.default_half_life_hours,
+    )?;
+    for hours in policy.source_kind_half_life_hours.values() {
+        check_hours("decay.source_kind_half_life_hours", *hours)?;
+    }
+    if policy.tag_half_life_hours.len() > MAX_DECAY_TAGS {
+        return Err(HelixError::validation_error(
+            "decay.tag_half_life_hours".to_string(),
+            format!("too many items; max is {MAX_DECAY_TAGS}"),
+        ));
+    }
+    let mut tag_half_life_hours = BTreeMap::new();
+    for (tag, hours) in policy.tag_half_life_hours {
+        check_hours("decay.tag_half_life_hours", hours)?;
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            return Err(HelixError::validation_error(
+                "decay.tag_half_life_hours",
+                "tags must not be empty",
+            ));
+        }
+        tag_half_life_hours.insert(tag, hours);
+    }
+    for (context, bps) in [
+        (
+            "decay.claim_review_threshold_bps",
+            policy.claim_review_threshold_bps,
+        ),
+        ("decay.evidence_expiry_bps", policy.evidence_expiry_bps),
+    ] {
+        if bps > 10_000 {
+            return Err(HelixError::validation_error(context, "must be <= 10000"));
+        }
+    }
+    Ok(DecayPolicy {
+        tag_half_life_hours,
+        ..policy
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn as_of(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn relevance_halves_every_half_life() {
+        assert_eq!(decay_factor_bps(24, 0), 10_000);
+        assert_eq!(decay_factor_bps(24, 24 * 3_600), 5_000);
+        assert_eq!(decay_factor_bps(24, 48 * 3_600), 2_500);
+        assert_eq!(decay_factor_bps(0, 48 * 3_600), 10_000);
+        assert_eq!(decay_factor_bps(24, -3_600), 10_000);
+        assert_eq!(decayed_confidence_bps(8_000, 5_000), 4_000);
+    }
+
+    #[test]
+    fn tags_override_source_kind_and_default() {
+        let policy = canonicalize_decay_policy(DecayPolicy {
+            default_half_life_hours: 720,
+            source_kind_half_life_hours: BTreeMap::from([(SourceKind::RssFeed, 168)]),
+            tag_half_life_hours: BTreeMap::from([
+                ("Pricing".to_string(), 24),
+                ("launch".to_string(), 72),
+            ]),
+            ..DecayPolicy::default()
+        })
+        .unwrap();
+        let tags = vec!["pricing".to_string(), "launch".to_string()];
+        assert_eq!(policy.half_life_hours(Some(SourceKind::RssFeed), &tags), 24);
+        assert_eq!(policy.half_life_hours(Some(SourceKind::RssFeed), &[]), 168);
+        assert_eq!(policy.half_life_hours(None, &[]), 720);
+        assert_eq!(
+            policy.relevance_bps(
+                Some(SourceKind::JsonApi),
+                &tags,
+                "2026-03-05T12:00:00Z",
+                as_of("2026-03-06T12:00:00Z"),
+            ),
+            5_000
+        );
+        assert_eq!(
+            policy.relevance_bps(
+                Some(SourceKind::JsonApi),
+                &tags,
+                "not a time",
+                as_of("2026-03-06T12:00:00Z"),
+            ),
+            10_000
+        );
+    }
+
+    #[test]
+    fn invalid_policies_are_rejected() {
+        assert!(canonicalize_decay_policy(DecayPolicy {
+            claim_review_threshold_bps: 10_001,
+            ..DecayPolicy::default()
+        })
+        .is_err());
+        assert!(canonicalize_decay_policy(DecayPolicy {
+            tag_half_life_hours: BTreeMap::from([(" ".to_string(), 24)]),
+            ..DecayPolicy::default()
+        })
+        .is_err());
+        assert!(canonicalize_decay_policy(DecayPolicy {
+            default_half_life_hours: u32::MAX,
+            ..DecayPolicy::default()
+        })
+        .is_err());
+    }
+}
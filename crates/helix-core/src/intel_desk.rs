@@ -1,16 +1,56 @@
+use crate::intel_case_reasoning::CaseReasoningGate;
+use crate::intel_entity_extraction::ExtractedEntity;
+use crate::intel_json_mapping::{canonicalize_json_api_mapping, JsonApiMapping};
+use crate::intel_near_duplicate::MinHashSignature;
+use crate::intel_watchlist_query::{QueryDocument, WatchlistQuery};
+use crate::intel_watchlist_suppression::{WatchlistSuppression, MAX_WATCHLIST_SUPPRESSIONS};
+use crate::intel_watchlist_window::{
+    canonicalize_watchlist_trigger, WatchlistAggregate, WatchlistTrigger,
+};
 use crate::HelixError;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 const MAX_TAGS: usize = 16;
 const MAX_ENTITIES: usize = 16;
 const MAX_KEYWORDS: usize = 16;
+const MAX_ALIASES_PER_ENTITY: usize = 8;
 const MAX_TEXT_LEN: usize = 16_384;
 const DEFAULT_PROFILE_ID: &str = "50000000-0000-0000-0000-000000000010";
 const DEFAULT_SOURCE_CREDENTIAL_HEADER_NAME: &str = "Authorization";
 const DEFAULT_SOURCE_CREDENTIAL_HEADER_PREFIX: &str = "Bearer";
+const MAX_WEBSITE_IGNORE_SELECTORS: usize = 16;
+const MAX_WEBSITE_SELECTOR_LEN: usize = 128;
+const MAX_FILE_IMPORT_COLUMN_LEN: usize = 128;
+const DEFAULT_WEBHOOK_TOLERANCE_SECONDS: u32 = 300;
+const MAX_WEBHOOK_TOLERANCE_SECONDS: u32 = 3_600;
+pub const MAX_SNAPSHOT_BLOCKS: usize = 512;
+
+/// Column (CSV/TSV header) or key (JSON Lines) names that feed each evidence field when a
+/// file import source receives structured rows. Unset fields fall back to the field name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileImportMapping {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub observed_at: Option<String>,
+    #[serde(default)]
+    pub tags: Option<String>,
+    #[serde(default)]
+    pub entity_labels: Option<String>,
+    /// Separator used to split tag and entity label cells; defaults to `;`.
+    #[serde(default)]
+    pub list_delimiter: Option<String>,
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SourceKind {
     RssFeed,
@@ -42,6 +82,94 @@ pub struct SourceDefinition {
     pub enabled: bool,
     #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub website_diff: Option<WebsiteDiffConfig>,
+    #[serde(default)]
+    pub file_import: Option<FileImportMapping>,
+    #[serde(default)]
+    pub json_api: Option<JsonApiMapping>,
+    #[serde(default)]
+    pub webhook_signature: Option<WebhookSignatureConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebsiteDiffConfig {
+    #[serde(default)]
+    pub content_selector: Option<String>,
+    #[serde(default)]
+    pub ignore_selectors: Vec<String>,
+}
+
+/// Signature scheme a third-party sender uses to sign webhook deliveries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSignatureScheme {
+    /// `X-Hub-Signature-256: sha256=<hex>` over the raw body.
+    GithubSha256,
+    /// `Stripe-Signature: t=<unix>,v1=<hex>` over `<t>.<body>`.
+    StripeV1,
+    /// Hex HMAC-SHA256 in a configurable header, optionally over `<timestamp>.<body>`.
+    HmacSha256,
+}
+
+impl WebhookSignatureScheme {
+    pub fn default_signature_header(self) -> &'static str {
+        match self {
+            Self::GithubSha256 => "X-Hub-Signature-256",
+            Self::StripeV1 => "Stripe-Signature",
+            Self::HmacSha256 => "X-Signature-256",
+        }
+    }
+}
+
+/// Webhook delivery verification. The HMAC secret is the vaulted credential named by the
+/// source's `credential_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookSignatureConfig {
+    pub scheme: WebhookSignatureScheme,
+    #[serde(default)]
+    pub signature_header: Option<String>,
+    /// Header carrying the signed Unix timestamp; only used by `hmac_sha256`.
+    #[serde(default)]
+    pub timestamp_header: Option<String>,
+    #[serde(default = "default_webhook_tolerance_seconds")]
+    pub tolerance_seconds: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockChangeKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockChange {
+    pub kind: BlockChangeKind,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotBlockDiff {
+    pub changes: Vec<BlockChange>,
+    pub unchanged_count: usize,
+}
+
+impl SnapshotBlockDiff {
+    pub fn count(&self, kind: BlockChangeKind) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .count()
+    }
+
+    pub fn has_new_content(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.kind != BlockChangeKind::Removed)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -72,6 +200,17 @@ pub struct EvidenceItem {
     pub tags: Vec<String>,
     pub entity_labels: Vec<String>,
     pub provenance_hash: String,
+    /// Hex MinHash signature of the title, summary, and content; see `intel_near_duplicate`.
+    #[serde(default)]
+    pub minhash: Option<String>,
+    /// Id of the evidence item that represents this story cluster: the first item ingested
+    /// for the story, whose own `story_cluster_id` is its `id`.
+    #[serde(default)]
+    pub story_cluster_id: Option<String>,
+    /// Spans found by the offline extractor at ingest, kept apart from the
+    /// operator-supplied `entity_labels`.
+    #[serde(default)]
+    pub extracted_entities: Vec<ExtractedEntity>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -132,6 +271,20 @@ pub struct Watchlist {
     pub keywords: Vec<String>,
     #[serde(default)]
     pub entities: Vec<String>,
+    /// Alternate names per entity that the extractor resolves to that entity.
+    #[serde(default)]
+    pub aliases: BTreeMap<String, Vec<String>>,
+    /// Boolean query (see `intel_watchlist_query`); when set it decides matches in place
+    /// of the flat `keywords` and `entities` lists.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// When set, matches accumulate in a window and only a satisfied trigger raises a hit
+    /// (see `intel_watchlist_window`).
+    #[serde(default)]
+    pub trigger: Option<WatchlistTrigger>,
+    /// Rules that mark known-benign hits as suppressed (see `intel_watchlist_suppression`).
+    #[serde(default)]
+    pub suppressions: Vec<WatchlistSuppression>,
     pub min_source_trust: u8,
     pub severity: WatchlistSeverity,
     pub enabled: bool,
@@ -146,6 +299,13 @@ pub struct WatchlistHit {
     pub matched_keywords: Vec<String>,
     pub matched_entities: Vec<String>,
     pub reason: String,
+    /// Window figures when a watchlist trigger raised this hit.
+    #[serde(default)]
+    pub aggregate: Option<WatchlistAggregate>,
+    /// `<kind>:<rule id>` of the suppression rule that matched; suppressed hits do not
+    /// feed triggers or cases.
+    #[serde(default)]
+    pub suppressed_by: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -156,9 +316,30 @@ pub enum CaseStatus {
     BriefReady,
     Escalated,
     Closed,
+    /// Absorbed into another case; the tombstone's `merged_into` names it.
+    Merged,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl CaseStatus {
+    /// False for closed cases and merge tombstones, which take no new evidence.
+    pub fn is_live(self) -> bool {
+        !matches!(self, Self::Closed | Self::Merged)
+    }
+
+    /// Order used to keep the higher status on a merge; it follows the priority
+    /// attention tiers.
+    fn merge_rank(self) -> u8 {
+        match self {
+            Self::Closed | Self::Merged => 0,
+            Self::Monitoring => 1,
+            Self::Open => 2,
+            Self::BriefReady => 3,
+            Self::Escalated => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaseFile {
     pub id: String,
     pub title: String,
@@ -169,9 +350,21 @@ pub struct CaseFile {
     pub claim_ids: Vec<String>,
     pub latest_reason: String,
     pub briefing_summary: Option<String>,
+    /// Latest symbolic reasoning decision; escalation requires it to allow when set.
+    #[serde(default)]
+    pub reasoning_gate: Option<CaseReasoningGate>,
+    /// Case this one was merged into; set on merge tombstones only.
+    #[serde(default)]
+    pub merged_into: Option<String>,
+    /// Operator who owns the case.
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Team that follows the case alongside the assignee.
+    #[serde(default)]
+    pub watch_group: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CaseCommand {
     Open {
@@ -199,6 +392,69 @@ pub enum CaseCommand {
     Reopen {
         reason: String,
     },
+    /// Sets the case owner and watch group; `None` clears either.
+    Assign {
+        assignee: Option<String>,
+        watch_group: Option<String>,
+    },
+    /// Records the latest symbolic reasoning decision. Issued by the desk only.
+    AttachReasoningGate {
+        gate: Box<CaseReasoningGate>,
+    },
+    /// Drops expired evidence and the claims it supported; a case keeps its last
+    /// evidence item. Issued by the desk only.
+    DetachEvidence {
+        evidence_id: String,
+        claim_ids: Vec<String>,
+        reason: String,
+    },
+    /// Follows an entity registry relabel. Issued by the desk only.
+    RelabelPrimaryEntity {
+        primary_entity: String,
+    },
+    /// Folds this case into `into_case_id` and leaves a tombstone redirecting to it.
+    Merge {
+        into_case_id: String,
+    },
+    /// Moves `evidence_ids`, and the claims they support, into a new case.
+    Split {
+        evidence_ids: Vec<String>,
+        new_case_id: String,
+        /// Claims that move with the evidence; the desk fills these from claim links.
+        #[serde(default)]
+        claim_ids: Vec<String>,
+    },
+    /// Takes in a merged case's evidence and claims. Issued by the desk only.
+    Absorb {
+        case_id: String,
+        evidence_ids: Vec<String>,
+        claim_ids: Vec<String>,
+        status: CaseStatus,
+    },
+    /// Opens a case split off another one. Issued by the desk only.
+    SplitFrom {
+        case_id: String,
+        source_case_id: String,
+        title: String,
+        watchlist_id: String,
+        primary_entity: Option<String>,
+        evidence_ids: Vec<String>,
+        claim_ids: Vec<String>,
+    },
+}
+
+impl CaseCommand {
+    /// Commands the desk issues itself; operators cannot submit them as transitions.
+    pub fn is_system_command(&self) -> bool {
+        matches!(
+            self,
+            Self::AttachReasoningGate { .. }
+                | Self::DetachEvidence { .. }
+                | Self::RelabelPrimaryEntity { .. }
+                | Self::Absorb { .. }
+                | Self::SplitFrom { .. }
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -210,12 +466,19 @@ pub enum CaseDecision {
     Denied { reason: String },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CaseTransition {
     pub case: CaseFile,
     pub decision: CaseDecision,
 }
 
+/// One command of a multi-case operation and the transition it produced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseStep {
+    pub command: CaseCommand,
+    pub transition: CaseTransition,
+}
+
 pub fn canonicalize_source(source: SourceDefinition) -> Result<SourceDefinition, HelixError> {
     validate_identifier("source.id", &source.id)?;
     let profile_id = normalize_uuid_string("source.profile_id", &source.profile_id)?;
@@ -229,6 +492,14 @@ pub fn canonicalize_source(source: SourceDefinition) -> Result<SourceDefinition,
     let credential_header_prefix =
         normalize_source_credential_header_prefix(source.credential_header_prefix)?;
     let tags = normalize_list(source.tags, MAX_TAGS, true, "source.tags")?;
+    let website_diff = normalize_website_diff_config(source.kind, source.website_diff)?;
+    let file_import = normalize_file_import_mapping(source.kind, source.file_import)?;
+    let json_api = normalize_json_api_mapping(source.kind, source.json_api)?;
+    let webhook_signature = normalize_webhook_signature_config(
+        source.kind,
+        credential_id.as_deref(),
+        source.webhook_signature,
+    )?;
     if source.cadence_minutes == 0 {
         return Err(HelixError::validation_error(
             "source.cadence_minutes",
@@ -256,6 +527,10 @@ pub fn canonicalize_source(source: SourceDefinition) -> Result<SourceDefinition,
         trust_score: source.trust_score,
         enabled: source.enabled,
         tags,
+        website_diff,
+        file_import,
+        json_api,
+        webhook_signature,
     })
 }
 
@@ -263,6 +538,10 @@ fn default_source_profile_id() -> String {
     DEFAULT_PROFILE_ID.to_string()
 }
 
+fn default_webhook_tolerance_seconds() -> u32 {
+    DEFAULT_WEBHOOK_TOLERANCE_SECONDS
+}
+
 fn default_source_credential_header_name() -> String {
     DEFAULT_SOURCE_CREDENTIAL_HEADER_NAME.to_string()
 }
@@ -384,17 +663,325 @@ fn normalize_source_endpoint(value: Option<String>) -> Result<Option<String>, He
     }
 }
 
+fn normalize_website_diff_config(
+    kind: SourceKind,
+    config: Option<WebsiteDiffConfig>,
+) -> Result<Option<WebsiteDiffConfig>, HelixError> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+    let content_selector = config
+        .content_selector
+        .map(|selector| normalize_css_selector("source.website_diff.content_selector", &selector))
+        .transpose()?
+        .filter(|selector| !selector.is_empty());
+    if config.ignore_selectors.len() > MAX_WEBSITE_IGNORE_SELECTORS {
+        return Err(HelixError::validation_error(
+            "source.website_diff.ignore_selectors",
+            &format!("too many items; max is {MAX_WEBSITE_IGNORE_SELECTORS}"),
+        ));
+    }
+    let mut ignore_selectors = Vec::new();
+    for selector in config.ignore_selectors {
+        let selector = normalize_css_selector("source.website_diff.ignore_selectors", &selector)?;
+        if !selector.is_empty() {
+            push_unique(&mut ignore_selectors, selector);
+        }
+    }
+    if content_selector.is_none() && ignore_selectors.is_empty() {
+        return Ok(None);
+    }
+    if kind != SourceKind::WebsiteDiff {
+        return Err(HelixError::validation_error(
+            "source.website_diff",
+            "only website_diff sources accept website_diff settings",
+        ));
+    }
+    Ok(Some(WebsiteDiffConfig {
+        content_selector,
+        ignore_selectors,
+    }))
+}
+
+fn normalize_file_import_mapping(
+    kind: SourceKind,
+    mapping: Option<FileImportMapping>,
+) -> Result<Option<FileImportMapping>, HelixError> {
+    let Some(mapping) = mapping else {
+        return Ok(None);
+    };
+    let column = |context: &str, value: Option<String>| {
+        value
+            .map(|value| {
+                let normalized = value.trim().to_string();
+                if normalized.len() > MAX_FILE_IMPORT_COLUMN_LEN {
+                    return Err(HelixError::validation_error(
+                        context,
+                        &format!("must be <= {MAX_FILE_IMPORT_COLUMN_LEN} characters"),
+                    ));
+                }
+                Ok(normalized)
+            })
+            .transpose()
+            .map(|value| value.filter(|value| !value.is_empty()))
+    };
+    let list_delimiter = mapping
+        .list_delimiter
+        .filter(|delimiter| !delimiter.is_empty())
+        .map(|delimiter| {
+            let mut chars = delimiter.chars();
+            match (chars.next(), chars.next()) {
+                (Some(separator), None) if !separator.is_alphanumeric() => Ok(delimiter),
+                _ => Err(HelixError::validation_error(
+                    "source.file_import.list_delimiter",
+                    "must be a single non-alphanumeric character",
+                )),
+            }
+        })
+        .transpose()?;
+    let normalized = FileImportMapping {
+        title: column("source.file_import.title", mapping.title)?,
+        summary: column("source.file_import.summary", mapping.summary)?,
+        content: column("source.file_import.content", mapping.content)?,
+        url: column("source.file_import.url", mapping.url)?,
+        observed_at: column("source.file_import.observed_at", mapping.observed_at)?,
+        tags: column("source.file_import.tags", mapping.tags)?,
+        entity_labels: column("source.file_import.entity_labels", mapping.entity_labels)?,
+        list_delimiter,
+    };
+    if normalized == FileImportMapping::default() {
+        return Ok(None);
+    }
+    if kind != SourceKind::FileImport {
+        return Err(HelixError::validation_error(
+            "source.file_import",
+            "only file_import sources accept file_import settings",
+        ));
+    }
+    Ok(Some(normalized))
+}
+
+fn normalize_webhook_signature_config(
+    kind: SourceKind,
+    credential_id: Option<&str>,
+    config: Option<WebhookSignatureConfig>,
+) -> Result<Option<WebhookSignatureConfig>, HelixError> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+    if kind != SourceKind::WebhookIngest {
+        return Err(HelixError::validation_error(
+            "source.webhook_signature",
+            "only webhook_ingest sources accept webhook_signature settings",
+        ));
+    }
+    if credential_id.is_none() {
+        return Err(HelixError::validation_error(
+            "source.credential_id",
+            "webhook signature verification requires a credential_id holding the secret",
+        ));
+    }
+    let header = |context: &str, value: Option<String>| -> Result<Option<String>, HelixError> {
+        let Some(value) = value.map(|value| value.trim().to_string()) else {
+            return Ok(None);
+        };
+        if value.is_empty() {
+            return Ok(None);
+        }
+        if value.len() > 64 || !value.bytes().all(is_http_header_name_byte) {
+            return Err(HelixError::validation_error(
+                context,
+                "must be a valid HTTP header name of at most 64 characters",
+            ));
+        }
+        Ok(Some(value))
+    };
+    let signature_header = header(
+        "source.webhook_signature.signature_header",
+        config.signature_header,
+    )?
+    .unwrap_or_else(|| config.scheme.default_signature_header().to_string());
+    let timestamp_header = header(
+        "source.webhook_signature.timestamp_header",
+        config.timestamp_header,
+    )?;
+    if timestamp_header.is_some() && config.scheme != WebhookSignatureScheme::HmacSha256 {
+        return Err(HelixError::validation_error(
+            "source.webhook_signature.timestamp_header",
+            "only hmac_sha256 signatures take a timestamp header",
+        ));
+    }
+    if config.tolerance_seconds == 0 || config.tolerance_seconds > MAX_WEBHOOK_TOLERANCE_SECONDS {
+        return Err(HelixError::validation_error(
+            "source.webhook_signature.tolerance_seconds",
+            &format!("must be between 1 and {MAX_WEBHOOK_TOLERANCE_SECONDS}"),
+        ));
+    }
+    Ok(Some(WebhookSignatureConfig {
+        scheme: config.scheme,
+        signature_header: Some(signature_header),
+        timestamp_header,
+        tolerance_seconds: config.tolerance_seconds,
+    }))
+}
+
+fn normalize_json_api_mapping(
+    kind: SourceKind,
+    mapping: Option<JsonApiMapping>,
+) -> Result<Option<JsonApiMapping>, HelixError> {
+    let Some(mapping) = mapping else {
+        return Ok(None);
+    };
+    if kind != SourceKind::JsonApi {
+        return Err(HelixError::validation_error(
+            "source.json_api",
+            "only json_api sources accept json_api mappings",
+        ));
+    }
+    canonicalize_json_api_mapping(mapping).map(Some)
+}
+
+fn normalize_css_selector(context: &str, value: &str) -> Result<String, HelixError> {
+    let normalized = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.len() > MAX_WEBSITE_SELECTOR_LEN {
+        return Err(HelixError::validation_error(
+            context,
+            &format!("must be <= {MAX_WEBSITE_SELECTOR_LEN} characters"),
+        ));
+    }
+    for compound in normalized
+        .split(' ')
+        .filter(|compound| !compound.is_empty())
+    {
+        if !is_simple_css_compound(compound) {
+            return Err(HelixError::validation_error(
+                context,
+                "must use tag, .class, and #id selectors joined by spaces",
+            ));
+        }
+    }
+    Ok(normalized)
+}
+
+fn is_simple_css_compound(compound: &str) -> bool {
+    let is_ident_byte = |byte: u8| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_');
+    let tag_len = compound
+        .bytes()
+        .take_while(|byte| byte.is_ascii_alphanumeric())
+        .count();
+    let mut rest = &compound[tag_len..];
+    while let Some(marker) = rest.bytes().next() {
+        if !matches!(marker, b'.' | b'#') {
+            return false;
+        }
+        let ident_len = rest[1..]
+            .bytes()
+            .take_while(|byte| is_ident_byte(*byte))
+            .count();
+        if ident_len == 0 {
+            return false;
+        }
+        rest = &rest[1 + ident_len..];
+    }
+    !compound.is_empty()
+}
+
+pub fn diff_snapshot_blocks(previous: &[String], current: &[String]) -> SnapshotBlockDiff {
+    let previous = &previous[..previous.len().min(MAX_SNAPSHOT_BLOCKS)];
+    let current = &current[..current.len().min(MAX_SNAPSHOT_BLOCKS)];
+    let rows = previous.len() + 1;
+    let columns = current.len() + 1;
+    let mut lcs = vec![0u16; rows * columns];
+    for i in (0..previous.len()).rev() {
+        for j in (0..current.len()).rev() {
+            lcs[i * columns + j] = if previous[i] == current[j] {
+                lcs[(i + 1) * columns + j + 1] + 1
+            } else {
+                lcs[(i + 1) * columns + j].max(lcs[i * columns + j + 1])
+            };
+        }
+    }
+
+    let mut diff = SnapshotBlockDiff::default();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < previous.len() || j < current.len() {
+        if i < previous.len() && j < current.len() && previous[i] == current[j] {
+            flush_block_changes(&mut diff, &mut removed, &mut added);
+            diff.unchanged_count += 1;
+            i += 1;
+            j += 1;
+        } else if j < current.len()
+            && (i == previous.len() || lcs[i * columns + j + 1] >= lcs[(i + 1) * columns + j])
+        {
+            added.push(current[j].clone());
+            j += 1;
+        } else {
+            removed.push(previous[i].clone());
+            i += 1;
+        }
+    }
+    flush_block_changes(&mut diff, &mut removed, &mut added);
+    diff
+}
+
+fn flush_block_changes(
+    diff: &mut SnapshotBlockDiff,
+    removed: &mut Vec<String>,
+    added: &mut Vec<String>,
+) {
+    let paired = removed.len().min(added.len());
+    let mut removed = std::mem::take(removed).into_iter();
+    let mut added = std::mem::take(added).into_iter();
+    for _ in 0..paired {
+        diff.changes.push(BlockChange {
+            kind: BlockChangeKind::Changed,
+            previous: removed.next(),
+            current: added.next(),
+        });
+    }
+    diff.changes.extend(added.map(|block| BlockChange {
+        kind: BlockChangeKind::Added,
+        previous: None,
+        current: Some(block),
+    }));
+    diff.changes.extend(removed.map(|block| BlockChange {
+        kind: BlockChangeKind::Removed,
+        previous: Some(block),
+        current: None,
+    }));
+}
+
 pub fn canonicalize_watchlist(watchlist: Watchlist) -> Result<Watchlist, HelixError> {
     validate_identifier("watchlist.id", &watchlist.id)?;
     validate_non_empty("watchlist.name", &watchlist.name)?;
     let description = clamp_trimmed_text("watchlist.description", &watchlist.description, 512)?;
     let keywords = normalize_list(watchlist.keywords, MAX_KEYWORDS, true, "watchlist.keywords")?;
     let entities = normalize_list(watchlist.entities, MAX_ENTITIES, true, "watchlist.entities")?;
+    let aliases = normalize_watchlist_aliases(watchlist.aliases, &entities)?;
+    let query = watchlist
+        .query
+        .map(|query| query.trim().to_string())
+        .filter(|query| !query.is_empty());
+    if let Some(query) = &query {
+        WatchlistQuery::parse(query)?;
+    }
+    let trigger = watchlist
+        .trigger
+        .map(canonicalize_watchlist_trigger)
+        .transpose()?;
+    if watchlist.suppressions.len() > MAX_WATCHLIST_SUPPRESSIONS {
+        return Err(HelixError::validation_error(
+            "watchlist.suppressions".to_string(),
+            format!("too many items; max is {MAX_WATCHLIST_SUPPRESSIONS}"),
+        ));
+    }
 
-    if keywords.is_empty() && entities.is_empty() {
+    if keywords.is_empty() && entities.is_empty() && query.is_none() {
         return Err(HelixError::validation_error(
             "watchlist",
-            "keywords or entities must be provided",
+            "keywords, entities, or query must be provided",
         ));
     }
     if watchlist.min_source_trust > 100 {
@@ -410,12 +997,40 @@ pub fn canonicalize_watchlist(watchlist: Watchlist) -> Result<Watchlist, HelixEr
         description,
         keywords,
         entities,
+        aliases,
+        query,
+        trigger,
+        suppressions: watchlist.suppressions,
         min_source_trust: watchlist.min_source_trust,
         severity: watchlist.severity,
         enabled: watchlist.enabled,
     })
 }
 
+fn normalize_watchlist_aliases(
+    aliases: BTreeMap<String, Vec<String>>,
+    entities: &[String],
+) -> Result<BTreeMap<String, Vec<String>>, HelixError> {
+    let mut normalized = BTreeMap::new();
+    for (entity, values) in aliases {
+        let entity = entity.trim().to_lowercase();
+        if !entities.contains(&entity) {
+            return Err(HelixError::validation_error(
+                "watchlist.aliases".to_string(),
+                format!("aliases reference `{entity}`, which is not a watchlist entity"),
+            ));
+        }
+        let values = normalize_list(values, MAX_ALIASES_PER_ENTITY, true, "watchlist.aliases")?
+            .into_iter()
+            .filter(|alias| alias != &entity)
+            .collect::<Vec<_>>();
+        if !values.is_empty() {
+            normalized.insert(entity, values);
+        }
+    }
+    Ok(normalized)
+}
+
 pub fn canonicalize_evidence(
     evidence_id: String,
     provenance_hash: String,
@@ -443,6 +1058,7 @@ pub fn canonicalize_evidence(
         ));
     }
 
+    let minhash = evidence_minhash(&title, &summary, &content);
     Ok(EvidenceItem {
         id: evidence_id.trim().to_string(),
         source_id: draft.source_id.trim().to_string(),
@@ -454,9 +1070,18 @@ pub fn canonicalize_evidence(
         tags,
         entity_labels,
         provenance_hash,
+        minhash,
+        story_cluster_id: None,
+        extracted_entities: Vec::new(),
     })
 }
 
+/// MinHash signature of an evidence item's text, hex encoded.
+pub fn evidence_minhash(title: &str, summary: &str, content: &str) -> Option<String> {
+    MinHashSignature::from_text(&format!("{title}\n{summary}\n{content}"))
+        .map(|signature| signature.to_hex())
+}
+
 pub fn canonicalize_claims(
     evidence: &EvidenceItem,
     proposed_claims: Vec<ProposedClaim>,
@@ -508,11 +1133,42 @@ pub fn canonicalize_claims(
     Ok(canonical)
 }
 
+/// Watchlists with their queries parsed, ready to evaluate a pass over many evidence items.
+#[derive(Debug, Clone)]
+pub struct CompiledWatchlists<'a> {
+    entries: Vec<(&'a Watchlist, Option<WatchlistQuery>)>,
+}
+
+impl<'a> CompiledWatchlists<'a> {
+    /// Parses every watchlist query once. A stored query that no longer parses is an
+    /// error, not a watchlist that silently matches nothing.
+    pub fn compile(watchlists: &'a [Watchlist]) -> Result<Self, HelixError> {
+        let entries = watchlists
+            .iter()
+            .map(|watchlist| {
+                let query = watchlist
+                    .query
+                    .as_deref()
+                    .map(WatchlistQuery::parse)
+                    .transpose()
+                    .map_err(|error| {
+                        HelixError::validation_error(
+                            "watchlist.query".to_string(),
+                            format!("watchlist {} query does not parse: {error}", watchlist.id),
+                        )
+                    })?;
+                Ok((watchlist, query))
+            })
+            .collect::<Result<Vec<_>, HelixError>>()?;
+        Ok(Self { entries })
+    }
+}
+
 pub fn evaluate_watchlists(
     source: &SourceDefinition,
     evidence: &EvidenceItem,
     claims: &[ClaimRecord],
-    watchlists: &[Watchlist],
+    watchlists: &CompiledWatchlists<'_>,
 ) -> Vec<WatchlistHit> {
     let searchable = format!(
         "{}\n{}\n{}\n{}",
@@ -522,13 +1178,34 @@ pub fn evaluate_watchlists(
         evidence.tags.join(" ")
     )
     .to_lowercase();
+    let query_document = watchlists
+        .entries
+        .iter()
+        .any(|(_, query)| query.is_some())
+        .then(|| evidence_query_document(evidence, claims));
 
     let mut hits: Vec<WatchlistHit> = watchlists
+        .entries
         .iter()
-        .filter(|watchlist| watchlist.enabled)
+        .filter(|(watchlist, _)| watchlist.enabled)
         .filter(|_| source.enabled)
-        .filter(|watchlist| source.trust_score >= watchlist.min_source_trust)
-        .filter_map(|watchlist| {
+        .filter(|(watchlist, _)| source.trust_score >= watchlist.min_source_trust)
+        .filter_map(|&(watchlist, ref query)| {
+            if let (Some(query), Some(document)) = (query, &query_document) {
+                let matched = query.evaluate(document)?;
+                return Some(WatchlistHit {
+                    watchlist_id: watchlist.id.clone(),
+                    watchlist_name: watchlist.name.clone(),
+                    evidence_id: evidence.id.clone(),
+                    severity: watchlist.severity,
+                    matched_keywords: matched.keywords,
+                    matched_entities: matched.entities,
+                    reason: "query_match".to_string(),
+                    aggregate: None,
+                    suppressed_by: None,
+                });
+            }
+
             let mut matched_keywords: Vec<String> = watchlist
                 .keywords
                 .iter()
@@ -566,6 +1243,8 @@ pub fn evaluate_watchlists(
                 matched_keywords,
                 matched_entities,
                 reason: reason.to_string(),
+                aggregate: None,
+                suppressed_by: None,
             })
         })
         .collect();
@@ -607,6 +1286,57 @@ pub fn new_case(command: CaseCommand) -> Result<CaseTransition, HelixError> {
                 claim_ids: normalize_ids(claim_ids),
                 latest_reason: reason.trim().to_string(),
                 briefing_summary: None,
+                reasoning_gate: None,
+                merged_into: None,
+                assignee: None,
+                watch_group: None,
+            };
+            Ok(CaseTransition {
+                case,
+                decision: CaseDecision::Opened,
+            })
+        }
+        CaseCommand::SplitFrom {
+            case_id,
+            source_case_id,
+            title,
+            watchlist_id,
+            primary_entity,
+            evidence_ids,
+            claim_ids,
+        } => {
+            validate_identifier("case.id", &case_id)?;
+            validate_identifier("case.source_case_id", &source_case_id)?;
+            validate_identifier("case.watchlist_id", &watchlist_id)?;
+            validate_non_empty("case.title", &title)?;
+            let mut evidence = Vec::new();
+            for evidence_id in evidence_ids {
+                let evidence_id = evidence_id.trim().to_string();
+                if !evidence_id.is_empty() {
+                    push_unique(&mut evidence, evidence_id);
+                }
+            }
+            if evidence.is_empty() {
+                return Err(HelixError::validation_error(
+                    "case.evidence_ids",
+                    "must not be empty",
+                ));
+            }
+
+            let case = CaseFile {
+                id: case_id.trim().to_string(),
+                title: title.trim().to_string(),
+                watchlist_id: watchlist_id.trim().to_string(),
+                status: CaseStatus::Open,
+                primary_entity: primary_entity.map(|value| value.trim().to_lowercase()),
+                evidence_ids: evidence,
+                claim_ids: normalize_ids(claim_ids),
+                latest_reason: format!("split_from:{}", source_case_id.trim()),
+                briefing_summary: None,
+                reasoning_gate: None,
+                merged_into: None,
+                assignee: None,
+                watch_group: None,
             };
             Ok(CaseTransition {
                 case,
@@ -625,8 +1355,25 @@ pub fn transition_case(
     command: CaseCommand,
 ) -> Result<CaseTransition, HelixError> {
     let mut next = case.clone();
+    if next.status == CaseStatus::Merged
+        && !matches!(
+            command,
+            CaseCommand::Open { .. }
+                | CaseCommand::SplitFrom { .. }
+                | CaseCommand::AttachReasoningGate { .. }
+                | CaseCommand::DetachEvidence { .. }
+                | CaseCommand::RelabelPrimaryEntity { .. }
+        )
+    {
+        return Ok(CaseTransition {
+            case: next,
+            decision: CaseDecision::Denied {
+                reason: "merged_case".to_string(),
+            },
+        });
+    }
     let decision = match command {
-        CaseCommand::Open { .. } => {
+        CaseCommand::Open { .. } | CaseCommand::SplitFrom { .. } => {
             return Err(HelixError::validation_error(
                 "case",
                 "open command is only valid for new cases",
@@ -684,6 +1431,14 @@ pub fn transition_case(
                 CaseDecision::Denied {
                     reason: "closed_case".to_string(),
                 }
+            } else if next
+                .reasoning_gate
+                .as_ref()
+                .is_some_and(|gate| !gate.allows_escalation())
+            {
+                CaseDecision::Denied {
+                    reason: "reasoning_gate".to_string(),
+                }
             } else {
                 validate_non_empty("case.reason", &reason)?;
                 next.latest_reason = reason.trim().to_string();
@@ -713,6 +1468,154 @@ pub fn transition_case(
                 }
             }
         }
+        CaseCommand::Assign {
+            assignee,
+            watch_group,
+        } => {
+            if next.status == CaseStatus::Closed {
+                CaseDecision::Denied {
+                    reason: "closed_case".to_string(),
+                }
+            } else {
+                next.assignee = normalize_optional_text(assignee, 128, "case.assignee")?;
+                next.watch_group = normalize_optional_text(watch_group, 128, "case.watch_group")?;
+                CaseDecision::Updated
+            }
+        }
+        CaseCommand::AttachReasoningGate { gate } => {
+            next.reasoning_gate = Some(*gate);
+            CaseDecision::Updated
+        }
+        CaseCommand::DetachEvidence {
+            evidence_id,
+            claim_ids,
+            reason,
+        } => {
+            validate_non_empty("case.reason", &reason)?;
+            if next.evidence_ids.iter().all(|id| id == evidence_id.trim()) {
+                return Err(HelixError::validation_error(
+                    "case.evidence_id",
+                    "must leave at least one evidence item on the case",
+                ));
+            }
+            let claim_ids = normalize_ids(claim_ids);
+            next.evidence_ids.retain(|id| id != evidence_id.trim());
+            next.claim_ids.retain(|id| !claim_ids.contains(id));
+            next.latest_reason = reason.trim().to_string();
+            CaseDecision::Updated
+        }
+        CaseCommand::RelabelPrimaryEntity { primary_entity } => {
+            validate_non_empty("case.primary_entity", &primary_entity)?;
+            next.primary_entity = Some(primary_entity.trim().to_lowercase());
+            CaseDecision::Updated
+        }
+        CaseCommand::Merge { into_case_id } => {
+            validate_identifier("case.into_case_id", &into_case_id)?;
+            let into_case_id = into_case_id.trim().to_string();
+            if into_case_id == next.id {
+                return Err(HelixError::validation_error(
+                    "case.into_case_id",
+                    "cannot merge a case into itself",
+                ));
+            }
+            if next.status == CaseStatus::Closed {
+                CaseDecision::Denied {
+                    reason: "closed_case".to_string(),
+                }
+            } else {
+                next.latest_reason = format!("merged_into:{into_case_id}");
+                next.merged_into = Some(into_case_id);
+                next.status = CaseStatus::Merged;
+                CaseDecision::StatusChanged {
+                    status: next.status,
+                }
+            }
+        }
+        CaseCommand::Split {
+            evidence_ids,
+            new_case_id,
+            claim_ids,
+        } => {
+            validate_identifier("case.new_case_id", &new_case_id)?;
+            let new_case_id = new_case_id.trim().to_string();
+            if new_case_id == next.id {
+                return Err(HelixError::validation_error(
+                    "case.new_case_id",
+                    "must differ from the case being split",
+                ));
+            }
+            let moved = normalize_ids(evidence_ids);
+            if moved.is_empty() {
+                return Err(HelixError::validation_error(
+                    "case.evidence_ids",
+                    "must not be empty",
+                ));
+            }
+            if moved.iter().any(|id| !next.evidence_ids.contains(id)) {
+                return Err(HelixError::validation_error(
+                    "case.evidence_ids",
+                    "must all belong to the case",
+                ));
+            }
+            if next.evidence_ids.iter().all(|id| moved.contains(id)) {
+                return Err(HelixError::validation_error(
+                    "case.evidence_ids",
+                    "must leave at least one evidence item on the case",
+                ));
+            }
+            if next.status == CaseStatus::Closed {
+                CaseDecision::Denied {
+                    reason: "closed_case".to_string(),
+                }
+            } else {
+                let moved_claims = normalize_ids(claim_ids);
+                next.evidence_ids.retain(|id| !moved.contains(id));
+                next.claim_ids.retain(|id| !moved_claims.contains(id));
+                next.latest_reason = format!("split_into:{new_case_id}");
+                CaseDecision::Updated
+            }
+        }
+        CaseCommand::Absorb {
+            case_id,
+            evidence_ids,
+            claim_ids,
+            status,
+        } => {
+            if next.status == CaseStatus::Closed {
+                CaseDecision::Denied {
+                    reason: "closed_case".to_string(),
+                }
+            } else {
+                validate_identifier("case.absorbed_case_id", &case_id)?;
+                for evidence_id in evidence_ids {
+                    let evidence_id = evidence_id.trim().to_string();
+                    if !evidence_id.is_empty() {
+                        push_unique(&mut next.evidence_ids, evidence_id);
+                    }
+                }
+                for claim_id in normalize_ids(claim_ids) {
+                    push_unique(&mut next.claim_ids, claim_id);
+                }
+                next.latest_reason = format!("absorbed:{}", case_id.trim());
+                let gate_holds = status == CaseStatus::Escalated
+                    && next
+                        .reasoning_gate
+                        .as_ref()
+                        .is_some_and(|gate| !gate.allows_escalation());
+                if gate_holds && status.merge_rank() > next.status.merge_rank() {
+                    // The target's gate refuses escalation, so it keeps its own status.
+                    next.latest_reason = format!("absorbed:{}:reasoning_gate", case_id.trim());
+                    CaseDecision::Updated
+                } else if status.merge_rank() > next.status.merge_rank() {
+                    next.status = status;
+                    CaseDecision::StatusChanged {
+                        status: next.status,
+                    }
+                } else {
+                    CaseDecision::Updated
+                }
+            }
+        }
     };
 
     Ok(CaseTransition {
@@ -721,6 +1624,101 @@ pub fn transition_case(
     })
 }
 
+/// Merges `absorbed` into `target`: the absorbed case becomes a tombstone redirecting
+/// to the target, which takes the union of both cases' evidence and claims and the
+/// higher of the two statuses. Returns the merge step, and the absorb step unless the
+/// merge was denied.
+pub fn merge_cases(absorbed: &CaseFile, target: &CaseFile) -> Result<Vec<CaseStep>, HelixError> {
+    if !target.status.is_live() {
+        return Err(HelixError::validation_error(
+            "case.into_case_id",
+            "target case is closed or merged",
+        ));
+    }
+    let merge = CaseCommand::Merge {
+        into_case_id: target.id.clone(),
+    };
+    let merged = transition_case(absorbed, merge.clone())?;
+    let mut steps = vec![CaseStep {
+        command: merge,
+        transition: merged,
+    }];
+    if matches!(steps[0].transition.decision, CaseDecision::Denied { .. }) {
+        return Ok(steps);
+    }
+
+    let absorb = CaseCommand::Absorb {
+        case_id: absorbed.id.clone(),
+        evidence_ids: absorbed.evidence_ids.clone(),
+        claim_ids: absorbed.claim_ids.clone(),
+        status: absorbed.status,
+    };
+    let absorbed_into = transition_case(target, absorb.clone())?;
+    steps.push(CaseStep {
+        command: absorb,
+        transition: absorbed_into,
+    });
+    Ok(steps)
+}
+
+/// Applies a `Split` command to `case` and opens the new case with the evidence and
+/// claims that moved, in the order the source case held them. Returns the split step,
+/// and the opening step of the new case unless the split was denied.
+pub fn split_case(case: &CaseFile, command: CaseCommand) -> Result<Vec<CaseStep>, HelixError> {
+    let CaseCommand::Split {
+        evidence_ids,
+        new_case_id,
+        claim_ids,
+    } = &command
+    else {
+        return Err(HelixError::validation_error(
+            "case",
+            "split_case requires a split command",
+        ));
+    };
+    let moved = normalize_ids(evidence_ids.clone());
+    let moved_claims = normalize_ids(claim_ids.clone());
+    let open = CaseCommand::SplitFrom {
+        case_id: new_case_id.trim().to_string(),
+        source_case_id: case.id.clone(),
+        title: format!("{} (split)", case.title),
+        watchlist_id: case.watchlist_id.clone(),
+        primary_entity: case.primary_entity.clone(),
+        evidence_ids: case
+            .evidence_ids
+            .iter()
+            .filter(|id| moved.contains(id))
+            .cloned()
+            .collect(),
+        claim_ids: case
+            .claim_ids
+            .iter()
+            .filter(|id| moved_claims.contains(id))
+            .cloned()
+            .collect(),
+    };
+
+    let split = CaseCommand::Split {
+        evidence_ids: moved,
+        new_case_id: new_case_id.trim().to_string(),
+        claim_ids: moved_claims,
+    };
+    let transition = transition_case(case, split.clone())?;
+    let mut steps = vec![CaseStep {
+        command: split,
+        transition,
+    }];
+    if matches!(steps[0].transition.decision, CaseDecision::Denied { .. }) {
+        return Ok(steps);
+    }
+    let opened = new_case(open.clone())?;
+    steps.push(CaseStep {
+        command: open,
+        transition: opened,
+    });
+    Ok(steps)
+}
+
 fn derive_claims_from_entities(evidence: &EvidenceItem) -> Vec<ProposedClaim> {
     evidence
         .entity_labels
@@ -735,8 +1733,36 @@ fn derive_claims_from_entities(evidence: &EvidenceItem) -> Vec<ProposedClaim> {
         .collect()
 }
 
+fn evidence_query_document(evidence: &EvidenceItem, claims: &[ClaimRecord]) -> QueryDocument {
+    let entities = evidence
+        .entity_labels
+        .iter()
+        .chain(
+            evidence
+                .extracted_entities
+                .iter()
+                .map(|entity| &entity.label),
+        )
+        .chain(
+            claims
+                .iter()
+                .flat_map(|claim| [&claim.subject, &claim.object]),
+        );
+    QueryDocument::new(
+        &evidence.title,
+        &evidence.summary,
+        &evidence.content,
+        &evidence.tags,
+        entities,
+    )
+}
+
 fn entity_matches(entity: &str, evidence: &EvidenceItem, claims: &[ClaimRecord]) -> bool {
     evidence.entity_labels.iter().any(|value| value == entity)
+        || evidence
+            .extracted_entities
+            .iter()
+            .any(|extracted| extracted.label == entity)
         || claims
             .iter()
             .any(|claim| claim.subject == entity || claim.object == entity)
@@ -837,6 +1863,7 @@ fn push_unique(values: &mut Vec<String>, candidate: String) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::reasoning::{ReasoningBackend, ReasoningDecision, ReasoningTrace, ReasoningVerdict};
 
     fn test_source() -> SourceDefinition {
         SourceDefinition {
@@ -857,6 +1884,10 @@ mod tests {
                 "Signals".to_string(),
                 "signals".to_string(),
             ],
+            website_diff: None,
+            file_import: None,
+            json_api: None,
+            webhook_signature: None,
         }
     }
 
@@ -867,6 +1898,10 @@ mod tests {
             description: "Track executives and sensitive locations".to_string(),
             keywords: vec!["resigned".to_string(), "detained".to_string()],
             entities: vec!["alice north".to_string(), "orion dynamics".to_string()],
+            aliases: BTreeMap::new(),
+            query: None,
+            trigger: None,
+            suppressions: Vec::new(),
             min_source_trust: 50,
             severity: WatchlistSeverity::High,
             enabled: true,
@@ -935,6 +1970,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn canonicalize_source_normalizes_webhook_signature_config() {
+        let mut source = test_source();
+        source.kind = SourceKind::WebhookIngest;
+        source.credential_id = Some("50000000-0000-0000-0000-000000000011".to_string());
+        source.webhook_signature = Some(WebhookSignatureConfig {
+            scheme: WebhookSignatureScheme::GithubSha256,
+            signature_header: Some("  ".to_string()),
+            timestamp_header: None,
+            tolerance_seconds: 300,
+        });
+        let config = canonicalize_source(source.clone())
+            .unwrap()
+            .webhook_signature
+            .unwrap();
+        assert_eq!(
+            config.signature_header.as_deref(),
+            Some("X-Hub-Signature-256")
+        );
+
+        let mut missing_secret = source.clone();
+        missing_secret.credential_id = None;
+        assert!(canonicalize_source(missing_secret).is_err());
+
+        let mut wrong_kind = source.clone();
+        wrong_kind.kind = SourceKind::JsonApi;
+        assert!(canonicalize_source(wrong_kind).is_err());
+
+        let mut timestamp_on_github = source.clone();
+        timestamp_on_github
+            .webhook_signature
+            .as_mut()
+            .unwrap()
+            .timestamp_header = Some("X-Timestamp".to_string());
+        assert!(canonicalize_source(timestamp_on_github).is_err());
+
+        let mut zero_tolerance = source;
+        zero_tolerance
+            .webhook_signature
+            .as_mut()
+            .unwrap()
+            .tolerance_seconds = 0;
+        assert!(canonicalize_source(zero_tolerance).is_err());
+    }
+
     #[test]
     fn canonicalize_source_rejects_invalid_endpoint_url() {
         let mut source = test_source();
@@ -965,6 +2045,155 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn canonicalize_source_normalizes_website_diff_selectors() {
+        let mut source = test_source();
+        source.kind = SourceKind::WebsiteDiff;
+        source.website_diff = Some(WebsiteDiffConfig {
+            content_selector: Some("  main   article.post ".to_string()),
+            ignore_selectors: vec![
+                ".timestamp".to_string(),
+                "div#ad-slot".to_string(),
+                ".timestamp".to_string(),
+                " ".to_string(),
+            ],
+        });
+        let canonical = canonicalize_source(source).unwrap();
+        let config = canonical.website_diff.unwrap();
+        assert_eq!(
+            config.content_selector.as_deref(),
+            Some("main article.post")
+        );
+        assert_eq!(config.ignore_selectors, vec![".timestamp", "div#ad-slot"]);
+    }
+
+    #[test]
+    fn canonicalize_source_rejects_website_diff_boundaries() {
+        let mut wrong_kind = test_source();
+        wrong_kind.website_diff = Some(WebsiteDiffConfig {
+            content_selector: Some("main".to_string()),
+            ignore_selectors: Vec::new(),
+        });
+        assert!(matches!(
+            canonicalize_source(wrong_kind),
+            Err(HelixError::ValidationError { .. })
+        ));
+
+        let mut bad_selector = test_source();
+        bad_selector.kind = SourceKind::WebsiteDiff;
+        bad_selector.website_diff = Some(WebsiteDiffConfig {
+            content_selector: None,
+            ignore_selectors: vec!["div > .ad".to_string()],
+        });
+        assert!(matches!(
+            canonicalize_source(bad_selector),
+            Err(HelixError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn canonicalize_source_normalizes_file_import_mapping() {
+        let mut source = test_source();
+        source.kind = SourceKind::FileImport;
+        source.file_import = Some(FileImportMapping {
+            title: Some("  Headline ".to_string()),
+            url: Some(" ".to_string()),
+            list_delimiter: Some("|".to_string()),
+            ..FileImportMapping::default()
+        });
+        let mapping = canonicalize_source(source).unwrap().file_import.unwrap();
+        assert_eq!(mapping.title.as_deref(), Some("Headline"));
+        assert_eq!(mapping.url, None);
+        assert_eq!(mapping.list_delimiter.as_deref(), Some("|"));
+
+        let mut blank = test_source();
+        blank.file_import = Some(FileImportMapping {
+            summary: Some("   ".to_string()),
+            ..FileImportMapping::default()
+        });
+        assert_eq!(canonicalize_source(blank).unwrap().file_import, None);
+    }
+
+    #[test]
+    fn canonicalize_source_rejects_file_import_mapping_boundaries() {
+        let mut wrong_kind = test_source();
+        wrong_kind.file_import = Some(FileImportMapping {
+            title: Some("headline".to_string()),
+            ..FileImportMapping::default()
+        });
+        assert!(matches!(
+            canonicalize_source(wrong_kind),
+            Err(HelixError::ValidationError { .. })
+        ));
+
+        let mut bad_delimiter = test_source();
+        bad_delimiter.kind = SourceKind::FileImport;
+        bad_delimiter.file_import = Some(FileImportMapping {
+            list_delimiter: Some("ab".to_string()),
+            ..FileImportMapping::default()
+        });
+        assert!(matches!(
+            canonicalize_source(bad_delimiter),
+            Err(HelixError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn canonicalize_source_validates_json_api_mapping() {
+        let mapping = JsonApiMapping {
+            items_path: Some(" $.results ".to_string()),
+            title: " $.name ".to_string(),
+            summary: Some(" ".to_string()),
+            ..JsonApiMapping::default()
+        };
+        let mut source = test_source();
+        source.kind = SourceKind::JsonApi;
+        source.json_api = Some(mapping.clone());
+        let canonical = canonicalize_source(source).unwrap().json_api.unwrap();
+        assert_eq!(canonical.items_path.as_deref(), Some("$.results"));
+        assert_eq!(canonical.title, "$.name");
+        assert_eq!(canonical.summary, None);
+
+        let mut wrong_kind = test_source();
+        wrong_kind.json_api = Some(mapping);
+        assert!(matches!(
+            canonicalize_source(wrong_kind),
+            Err(HelixError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn diff_snapshot_blocks_classifies_added_changed_and_removed() {
+        let blocks = |values: &[&str]| {
+            values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+        };
+        let previous = blocks(&["intro", "pricing is 10", "team", "legacy footer"]);
+        let current = blocks(&["intro", "pricing is 12", "team", "new launch"]);
+        let diff = diff_snapshot_blocks(&previous, &current);
+
+        assert_eq!(diff.unchanged_count, 2);
+        assert_eq!(diff.count(BlockChangeKind::Changed), 2);
+        assert_eq!(diff.count(BlockChangeKind::Added), 0);
+        assert_eq!(diff.changes[0].previous.as_deref(), Some("pricing is 10"));
+        assert_eq!(diff.changes[0].current.as_deref(), Some("pricing is 12"));
+
+        let appended = diff_snapshot_blocks(&previous[..2], &previous);
+        assert_eq!(appended.count(BlockChangeKind::Added), 2);
+        assert!(appended.has_new_content());
+
+        let trimmed = diff_snapshot_blocks(&previous, &previous[..3]);
+        assert_eq!(trimmed.count(BlockChangeKind::Removed), 1);
+        assert!(!trimmed.has_new_content());
+
+        assert_eq!(
+            diff_snapshot_blocks(&previous, &previous).changes,
+            Vec::new()
+        );
+    }
+
     #[test]
     fn canonicalize_watchlist_requires_terms() {
         let mut watchlist = test_watchlist();
@@ -976,6 +2205,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn canonicalize_watchlist_aliases_must_name_an_entity() {
+        let mut watchlist = test_watchlist();
+        watchlist.aliases = BTreeMap::from([(
+            " Orion Dynamics ".to_string(),
+            vec!["ORDY".to_string(), "orion dynamics".to_string()],
+        )]);
+        let canonical = canonicalize_watchlist(watchlist.clone()).unwrap();
+        assert_eq!(
+            canonical.aliases,
+            BTreeMap::from([("orion dynamics".to_string(), vec!["ordy".to_string()])])
+        );
+
+        watchlist.aliases = BTreeMap::from([("vector works".to_string(), vec!["vw".to_string()])]);
+        assert!(matches!(
+            canonicalize_watchlist(watchlist),
+            Err(HelixError::ValidationError { .. })
+        ));
+    }
+
     #[test]
     fn canonicalize_claims_derives_mentions_when_empty() {
         let evidence = canonicalize_evidence(
@@ -1064,7 +2313,13 @@ mod tests {
             rationale: "derived".to_string(),
         }];
 
-        let hits = evaluate_watchlists(&source, &evidence, &claims, &[watchlist]);
+        let watchlists = [watchlist];
+        let hits = evaluate_watchlists(
+            &source,
+            &evidence,
+            &claims,
+            &CompiledWatchlists::compile(&watchlists).unwrap(),
+        );
         assert_eq!(hits.len(), 1);
         assert_eq!(hits[0].reason, "keyword_and_entity_match");
     }
@@ -1092,8 +2347,17 @@ mod tests {
         )
         .unwrap();
 
-        let hits = evaluate_watchlists(&source, &evidence, &[], &[watchlist]);
+        let mut watchlists = [watchlist];
+        let hits = evaluate_watchlists(
+            &source,
+            &evidence,
+            &[],
+            &CompiledWatchlists::compile(&watchlists).unwrap(),
+        );
         assert!(hits.is_empty());
+
+        watchlists[0].query = Some("entity:\"alice north\" AND (".to_string());
+        assert!(CompiledWatchlists::compile(&watchlists).is_err());
     }
 
     #[test]
@@ -1147,4 +2411,230 @@ mod tests {
         .unwrap();
         assert_eq!(reopened.case.status, CaseStatus::Open);
     }
+
+    fn open_case(case_id: &str, evidence: &[&str], claims: &[&str]) -> CaseFile {
+        let mut case = new_case(CaseCommand::Open {
+            case_id: case_id.to_string(),
+            title: "Executive movement".to_string(),
+            watchlist_id: "watch_exec_moves".to_string(),
+            primary_entity: Some("alice north".to_string()),
+            evidence_id: evidence[0].to_string(),
+            claim_ids: claims.iter().map(|id| id.to_string()).collect(),
+            reason: "keyword_match".to_string(),
+        })
+        .unwrap()
+        .case;
+        for evidence_id in &evidence[1..] {
+            case = transition_case(
+                &case,
+                CaseCommand::AppendEvidence {
+                    evidence_id: evidence_id.to_string(),
+                    claim_ids: Vec::new(),
+                    reason: "keyword_match".to_string(),
+                },
+            )
+            .unwrap()
+            .case;
+        }
+        case
+    }
+
+    #[test]
+    fn merge_unions_evidence_keeps_higher_status_and_leaves_a_tombstone() {
+        let absorbed = transition_case(
+            &open_case("case_beta", &["ev_b", "ev_shared"], &["claim_b"]),
+            CaseCommand::Escalate {
+                reason: "board exit".to_string(),
+            },
+        )
+        .unwrap()
+        .case;
+        let target = transition_case(
+            &open_case("case_alpha", &["ev_a", "ev_shared"], &["claim_a"]),
+            CaseCommand::MarkMonitoring,
+        )
+        .unwrap()
+        .case;
+
+        let steps = merge_cases(&absorbed, &target).unwrap();
+        assert_eq!(steps.len(), 2);
+        let tombstone = &steps[0].transition.case;
+        assert_eq!(tombstone.status, CaseStatus::Merged);
+        assert_eq!(tombstone.merged_into.as_deref(), Some("case_alpha"));
+        let merged = &steps[1].transition.case;
+        assert_eq!(merged.evidence_ids, vec!["ev_a", "ev_shared", "ev_b"]);
+        assert_eq!(merged.claim_ids, vec!["claim_a", "claim_b"]);
+        assert_eq!(merged.status, CaseStatus::Escalated);
+
+        let denied = transition_case(tombstone, CaseCommand::MarkMonitoring).unwrap();
+        assert!(matches!(
+            denied.decision,
+            CaseDecision::Denied { ref reason } if reason == "merged_case"
+        ));
+        assert!(merge_cases(&target, &target).is_err());
+        assert!(merge_cases(&target, tombstone).is_err());
+    }
+
+    #[test]
+    fn merge_keeps_the_target_status_when_its_reasoning_gate_refuses_escalation() {
+        let absorbed = transition_case(
+            &open_case("case_beta", &["ev_b"], &["claim_b"]),
+            CaseCommand::Escalate {
+                reason: "board exit".to_string(),
+            },
+        )
+        .unwrap()
+        .case;
+        let gate = |verdict| CaseReasoningGate {
+            rule_pack_id: "exec_appointments".to_string(),
+            query: "escalate(case_alpha)".to_string(),
+            include_needs_review: false,
+            claim_ids: vec!["claim_a".to_string()],
+            evaluated_at: "2026-03-06T12:00:00Z".to_string(),
+            decision: ReasoningDecision {
+                backend: ReasoningBackend::KrrSymbolic,
+                verdict,
+                confidence: 0.0,
+                rationale: "not entailed".to_string(),
+                trace: ReasoningTrace {
+                    derived_facts: Vec::new(),
+                    matched_rules: Vec::new(),
+                    symbolic_entailed: Some(false),
+                    neural_probability: None,
+                    support_graph: Vec::new(),
+                    contradictions: Vec::new(),
+                    symbolic_status: None,
+                    symbolic_rounds: None,
+                    pending_rule_count: None,
+                    program_fingerprint: None,
+                    consistency_scope: None,
+                    query_support: Vec::new(),
+                    blocking_contradictions: Vec::new(),
+                },
+            },
+        };
+        let gated = |verdict| {
+            transition_case(
+                &open_case("case_alpha", &["ev_a"], &["claim_a"]),
+                CaseCommand::AttachReasoningGate {
+                    gate: Box::new(gate(verdict)),
+                },
+            )
+            .unwrap()
+            .case
+        };
+
+        let steps = merge_cases(&absorbed, &gated(ReasoningVerdict::Deny)).unwrap();
+        let merged = &steps[1].transition;
+        assert_eq!(merged.decision, CaseDecision::Updated);
+        assert_eq!(merged.case.status, CaseStatus::Open);
+        assert_eq!(merged.case.evidence_ids, vec!["ev_a", "ev_b"]);
+        assert_eq!(
+            merged.case.latest_reason,
+            "absorbed:case_beta:reasoning_gate"
+        );
+
+        let steps = merge_cases(&absorbed, &gated(ReasoningVerdict::Allow)).unwrap();
+        assert_eq!(steps[1].transition.case.status, CaseStatus::Escalated);
+    }
+
+    #[test]
+    fn detach_keeps_the_last_evidence_item_on_the_case() {
+        let detach = |evidence_id: &str| CaseCommand::DetachEvidence {
+            evidence_id: evidence_id.to_string(),
+            claim_ids: vec!["claim_b".to_string()],
+            reason: format!("evidence_expired:{evidence_id}"),
+        };
+        let case = open_case("case_alpha", &["ev_a", "ev_b"], &["claim_a", "claim_b"]);
+
+        let detached = transition_case(&case, detach("ev_b")).unwrap().case;
+        assert_eq!(detached.evidence_ids, vec!["ev_a"]);
+        assert_eq!(detached.claim_ids, vec!["claim_a"]);
+        assert!(transition_case(&detached, detach("ev_a")).is_err());
+    }
+
+    #[test]
+    fn split_moves_evidence_without_losing_any() {
+        let case = open_case(
+            "case_alpha",
+            &["ev_a", "ev_b", "ev_c"],
+            &["claim_a", "claim_c"],
+        );
+        let split = CaseCommand::Split {
+            evidence_ids: vec!["ev_c".to_string(), "ev_b".to_string()],
+            new_case_id: "case_gamma".to_string(),
+            claim_ids: vec!["claim_c".to_string(), "claim_other".to_string()],
+        };
+        let steps = split_case(&case, split).unwrap();
+        let source = &steps[0].transition.case;
+        let opened = &steps[1].transition.case;
+        assert_eq!(source.evidence_ids, vec!["ev_a"]);
+        assert_eq!(source.claim_ids, vec!["claim_a"]);
+        assert_eq!(opened.id, "case_gamma");
+        assert_eq!(opened.evidence_ids, vec!["ev_b", "ev_c"]);
+        assert_eq!(opened.claim_ids, vec!["claim_c"]);
+        assert_eq!(opened.status, CaseStatus::Open);
+        for evidence_id in &case.evidence_ids {
+            assert!(
+                source.evidence_ids.contains(evidence_id)
+                    || opened.evidence_ids.contains(evidence_id)
+            );
+        }
+
+        let everything = CaseCommand::Split {
+            evidence_ids: case.evidence_ids.clone(),
+            new_case_id: "case_gamma".to_string(),
+            claim_ids: Vec::new(),
+        };
+        assert!(split_case(&case, everything).is_err());
+        let foreign = CaseCommand::Split {
+            evidence_ids: vec!["ev_z".to_string()],
+            new_case_id: "case_gamma".to_string(),
+            claim_ids: Vec::new(),
+        };
+        assert!(split_case(&case, foreign).is_err());
+    }
+
+    #[test]
+    fn assign_sets_and_clears_case_ownership() {
+        let case = open_case("case_alpha", &["ev_a"], &[]);
+        let assigned = transition_case(
+            &case,
+            CaseCommand::Assign {
+                assignee: Some(" dana ".to_string()),
+                watch_group: Some("exec-desk".to_string()),
+            },
+        )
+        .unwrap();
+        assert_eq!(assigned.decision, CaseDecision::Updated);
+        assert_eq!(assigned.case.assignee.as_deref(), Some("dana"));
+        assert_eq!(assigned.case.watch_group.as_deref(), Some("exec-desk"));
+
+        let cleared = transition_case(
+            &assigned.case,
+            CaseCommand::Assign {
+                assignee: Some("  ".to_string()),
+                watch_group: assigned.case.watch_group.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(cleared.case.assignee, None);
+        assert_eq!(cleared.case.watch_group.as_deref(), Some("exec-desk"));
+
+        let closed = transition_case(&cleared.case, CaseCommand::Close)
+            .unwrap()
+            .case;
+        assert!(matches!(
+            transition_case(
+                &closed,
+                CaseCommand::Assign {
+                    assignee: Some("dana".to_string()),
+                    watch_group: None,
+                },
+            )
+            .unwrap()
+            .decision,
+            CaseDecision::Denied { .. }
+        ));
+    }
 }
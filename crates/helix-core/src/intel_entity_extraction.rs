@@ -0,0 +1,526 @@
+//! Offline entity extraction for evidence text.
+//!
+//! Three recognizers run over each text field: a gazetteer of watchlist entities and their
+//! aliases, pattern recognizers for identifiers (emails, CVE ids, wallet addresses, IPs,
+//! domains, and tickers), and a capitalization heuristic for multi-word proper names. When
+//! spans overlap, the earlier recognizer in [`ExtractedEntityKind`] order wins. Output
+//! depends only on the text and the gazetteer.
+
+use crate::intel_desk::Watchlist;
+use crate::intel_entity_registry::EntityRegistry;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::OnceLock;
+
+/// Spans kept per evidence item, in field then offset order.
+pub const MAX_EXTRACTED_ENTITIES: usize = 128;
+const MIN_PROPER_NAME_WORDS: usize = 2;
+const MAX_PROPER_NAME_WORDS: usize = 6;
+/// Fields with at least this share of capitalized words are headline case, where
+/// capitalization says nothing about names.
+const HEADLINE_CASE_MIN_SHARE_PCT: usize = 60;
+const HEADLINE_CASE_MIN_WORDS: usize = 4;
+
+const FILE_EXTENSIONS: &[&str] = &[
+    "csv", "doc", "docx", "gif", "htm", "html", "jpeg", "jpg", "json", "md", "pdf", "png", "ppt",
+    "pptx", "rs", "svg", "txt", "xls", "xlsx", "xml", "zip",
+];
+const LEADING_FUNCTION_WORDS: &[&str] = &[
+    "a",
+    "according",
+    "after",
+    "an",
+    "and",
+    "as",
+    "at",
+    "before",
+    "but",
+    "by",
+    "during",
+    "for",
+    "from",
+    "if",
+    "in",
+    "it",
+    "on",
+    "or",
+    "our",
+    "since",
+    "that",
+    "the",
+    "these",
+    "this",
+    "those",
+    "to",
+    "when",
+    "while",
+    "with",
+];
+
+/// Recognizer that produced a span, in precedence order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractedEntityKind {
+    Watchlist,
+    Email,
+    Cve,
+    WalletAddress,
+    IpAddress,
+    Domain,
+    Ticker,
+    ProperName,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceTextField {
+    Title,
+    Summary,
+    Content,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractedEntity {
+    /// Lowercase label compared with watchlist entities; gazetteer hits carry the
+    /// watchlist entity even when an alias matched.
+    pub label: String,
+    pub kind: ExtractedEntityKind,
+    pub field: EvidenceTextField,
+    /// UTF-8 byte offsets of `text` within the field.
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// Case-insensitive lookup of enabled watchlist entities and aliases.
+#[derive(Debug, Clone, Default)]
+pub struct EntityGazetteer {
+    pattern: Option<Regex>,
+    labels: BTreeMap<String, String>,
+}
+
+impl EntityGazetteer {
+    pub fn from_watchlists<'a, I>(watchlists: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Watchlist>,
+    {
+        Self::from_watchlists_with_registry(watchlists, &EntityRegistry::default())
+    }
+
+    /// Like [`Self::from_watchlists`], and also matches the registry aliases of each
+    /// watched entity.
+    pub fn from_watchlists_with_registry<'a, I>(watchlists: I, registry: &EntityRegistry) -> Self
+    where
+        I: IntoIterator<Item = &'a Watchlist>,
+    {
+        let mut labels = BTreeMap::new();
+        for watchlist in watchlists.into_iter().filter(|watchlist| watchlist.enabled) {
+            for entity in &watchlist.entities {
+                labels
+                    .entry(normalized_label(entity))
+                    .or_insert_with(|| entity.clone());
+                for alias in registry
+                    .get(entity)
+                    .into_iter()
+                    .flat_map(|registered| registered.aliases.iter())
+                {
+                    labels
+                        .entry(normalized_label(alias))
+                        .or_insert_with(|| entity.clone());
+                }
+            }
+            for (entity, aliases) in &watchlist.aliases {
+                for alias in aliases {
+                    labels
+                        .entry(normalized_label(alias))
+                        .or_insert_with(|| entity.clone());
+                }
+            }
+        }
+        labels.retain(|term, _| !term.is_empty());
+
+        // Longest terms first so "orion dynamics" wins over "orion" at the same offset.
+        let mut terms = labels.keys().collect::<Vec<_>>();
+        terms.sort_by(|left, right| right.len().cmp(&left.len()).then(left.cmp(right)));
+        let alternation = terms
+            .iter()
+            .map(|term| gazetteer_term_pattern(term))
+            .collect::<Vec<_>>()
+            .join("|");
+        let pattern = (!terms.is_empty()).then(|| {
+            Regex::new(&format!("(?i)(?:{alternation})"))
+                .expect("escaped gazetteer terms form a valid pattern")
+        });
+        Self { pattern, labels }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    fn spans(&self, text: &str) -> Vec<Candidate> {
+        let Some(pattern) = &self.pattern else {
+            return Vec::new();
+        };
+        pattern
+            .find_iter(text)
+            .filter_map(|found| {
+                let label = self.labels.get(&normalized_label(found.as_str()))?;
+                Some(Candidate {
+                    kind: ExtractedEntityKind::Watchlist,
+                    start: found.start(),
+                    end: found.end(),
+                    label: label.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Extracts entities from an evidence item's title, summary, and content.
+pub fn extract_evidence_entities(
+    gazetteer: &EntityGazetteer,
+    title: &str,
+    summary: &str,
+    content: &str,
+) -> Vec<ExtractedEntity> {
+    let mut entities = extract_entities(gazetteer, EvidenceTextField::Title, title);
+    entities.extend(extract_entities(
+        gazetteer,
+        EvidenceTextField::Summary,
+        summary,
+    ));
+    entities.extend(extract_entities(
+        gazetteer,
+        EvidenceTextField::Content,
+        content,
+    ));
+    entities.truncate(MAX_EXTRACTED_ENTITIES);
+    entities
+}
+
+/// Extracts non-overlapping entity spans from one field, ordered by offset.
+pub fn extract_entities(
+    gazetteer: &EntityGazetteer,
+    field: EvidenceTextField,
+    text: &str,
+) -> Vec<ExtractedEntity> {
+    let mut candidates = gazetteer.spans(text);
+    for (kind, pattern) in identifier_patterns() {
+        candidates.extend(pattern.find_iter(text).filter_map(|found| {
+            let label = identifier_label(*kind, text, found.start(), found.end())?;
+            Some(Candidate {
+                kind: *kind,
+                start: found.start(),
+                end: found.end(),
+                label,
+            })
+        }));
+    }
+    if !is_headline_case(text) {
+        candidates.extend(proper_name_spans(text));
+    }
+
+    candidates.sort_by(|left, right| {
+        left.kind
+            .cmp(&right.kind)
+            .then(left.start.cmp(&right.start))
+            .then(right.end.cmp(&left.end))
+    });
+    let mut accepted: Vec<Candidate> = Vec::new();
+    for candidate in candidates {
+        let overlaps = accepted
+            .iter()
+            .any(|kept| candidate.start < kept.end && kept.start < candidate.end);
+        if !overlaps {
+            accepted.push(candidate);
+        }
+    }
+    accepted.sort_by_key(|candidate| candidate.start);
+
+    accepted
+        .into_iter()
+        .map(|candidate| ExtractedEntity {
+            text: text[candidate.start..candidate.end].to_string(),
+            label: candidate.label,
+            kind: candidate.kind,
+            field,
+            start: candidate.start,
+            end: candidate.end,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Candidate {
+    kind: ExtractedEntityKind,
+    start: usize,
+    end: usize,
+    label: String,
+}
+
+fn identifier_patterns() -> &'static [(ExtractedEntityKind, Regex)] {
+    static PATTERNS: OnceLock<Vec<(ExtractedEntityKind, Regex)>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        [
+            (
+                ExtractedEntityKind::Email,
+                r"(?i)\b[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,24}\b",
+            ),
+            (ExtractedEntityKind::Cve, r"(?i)\bCVE-\d{4}-\d{4,7}\b"),
+            (
+                ExtractedEntityKind::WalletAddress,
+                r"\b0x[0-9a-fA-F]{40}\b|\bbc1[02-9ac-hj-np-z]{11,71}\b|\b[13][1-9A-HJ-NP-Za-km-z]{25,34}\b",
+            ),
+            (
+                ExtractedEntityKind::IpAddress,
+                r"\b(?:\d{1,3}\.){3}\d{1,3}\b|(?i)[0-9a-f]{0,4}(?::[0-9a-f]{0,4}){2,7}",
+            ),
+            (
+                ExtractedEntityKind::Domain,
+                r"(?i)\b(?:[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?\.)+[a-z]{2,24}\b",
+            ),
+            (
+                ExtractedEntityKind::Ticker,
+                r"\$[A-Z]{1,5}(?:\.[A-Z])?\b|\b(?:NYSE|NASDAQ|Nasdaq|AMEX|LSE|TSX)\s?:\s?[A-Z]{1,5}(?:\.[A-Z])?\b",
+            ),
+        ]
+        .into_iter()
+        .map(|(kind, pattern)| {
+            (
+                kind,
+                Regex::new(pattern).expect("identifier patterns are valid"),
+            )
+        })
+        .collect()
+    })
+}
+
+fn proper_name_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(
+            r"\b\p{Lu}[\p{L}\p{N}&'’-]*(?:[ \t]+(?:(?:of|de|del|van|von|der|&)[ \t]+)?\p{Lu}[\p{L}\p{N}&'’-]*)+",
+        )
+        .expect("proper name pattern is valid")
+    })
+}
+
+/// Label for a pattern match, or `None` when the match fails validation.
+fn identifier_label(
+    kind: ExtractedEntityKind,
+    text: &str,
+    start: usize,
+    end: usize,
+) -> Option<String> {
+    let value = &text[start..end];
+    match kind {
+        ExtractedEntityKind::IpAddress => {
+            // A trailing '.' ends the sentence unless more address characters follow it.
+            let mut rest = text[end..].chars();
+            let trailing_bounded = match rest.next() {
+                Some('.') => !rest.next().is_some_and(is_address_char),
+                Some(ch) => !is_address_char(ch),
+                None => true,
+            };
+            let bounded = trailing_bounded && !text[..start].ends_with(is_address_char);
+            let valid = value.parse::<Ipv4Addr>().is_ok() || value.parse::<Ipv6Addr>().is_ok();
+            (bounded && valid).then(|| value.to_lowercase())
+        }
+        ExtractedEntityKind::Domain => {
+            let tld = value.rsplit('.').next()?;
+            let consistent_case = tld == tld.to_lowercase() || tld == tld.to_uppercase();
+            let file_name = FILE_EXTENSIONS.contains(&tld.to_lowercase().as_str());
+            (consistent_case && !file_name && !text[..start].ends_with('@'))
+                .then(|| value.to_lowercase())
+        }
+        ExtractedEntityKind::Ticker => {
+            let symbol = value.rsplit([':', '$']).next()?.trim();
+            Some(format!("${}", symbol.to_lowercase()))
+        }
+        _ => Some(value.to_lowercase()),
+    }
+}
+
+fn is_address_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == ':' || ch == '.'
+}
+
+fn proper_name_spans(text: &str) -> Vec<Candidate> {
+    proper_name_pattern()
+        .find_iter(text)
+        .filter_map(|found| {
+            let mut start = found.start();
+            let mut words = found.as_str().split_whitespace().peekable();
+            while let Some(word) =
+                words.next_if(|word| LEADING_FUNCTION_WORDS.contains(&word.to_lowercase().as_str()))
+            {
+                let offset = text[start..].find(word)?;
+                start += offset + word.len();
+            }
+            let name = text[start..found.end()].trim_start();
+            start = found.end() - name.len();
+            let word_count = name.split_whitespace().count();
+            if !(MIN_PROPER_NAME_WORDS..=MAX_PROPER_NAME_WORDS).contains(&word_count) {
+                return None;
+            }
+            Some(Candidate {
+                kind: ExtractedEntityKind::ProperName,
+                start,
+                end: found.end(),
+                label: normalized_label(name),
+            })
+        })
+        .collect()
+}
+
+fn is_headline_case(text: &str) -> bool {
+    let words = text
+        .split_whitespace()
+        .filter_map(|word| word.chars().find(|ch| ch.is_alphabetic()))
+        .collect::<Vec<_>>();
+    let capitalized = words.iter().filter(|first| first.is_uppercase()).count();
+    words.len() >= HEADLINE_CASE_MIN_WORDS
+        && capitalized * 100 >= words.len() * HEADLINE_CASE_MIN_SHARE_PCT
+}
+
+fn gazetteer_term_pattern(term: &str) -> String {
+    let escaped = term
+        .split_whitespace()
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(r"\s+");
+    let leading = if term.starts_with(is_word_char) {
+        r"\b"
+    } else {
+        ""
+    };
+    let trailing = if term.ends_with(is_word_char) {
+        r"\b"
+    } else {
+        ""
+    };
+    format!("{leading}{escaped}{trailing}")
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+fn normalized_label(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intel_desk::WatchlistSeverity;
+
+    fn watchlist(entities: &[&str], aliases: &[(&str, &[&str])]) -> Watchlist {
+        Watchlist {
+            id: "watch".to_string(),
+            name: "Watch".to_string(),
+            description: String::new(),
+            keywords: Vec::new(),
+            entities: entities.iter().map(|entity| entity.to_string()).collect(),
+            aliases: aliases
+                .iter()
+                .map(|(entity, aliases)| {
+                    (
+                        entity.to_string(),
+                        aliases.iter().map(|alias| alias.to_string()).collect(),
+                    )
+                })
+                .collect(),
+            query: None,
+            trigger: None,
+            suppressions: Vec::new(),
+            min_source_trust: 0,
+            severity: WatchlistSeverity::High,
+            enabled: true,
+        }
+    }
+
+    fn labels(entities: &[ExtractedEntity]) -> Vec<(ExtractedEntityKind, &str)> {
+        entities
+            .iter()
+            .map(|entity| (entity.kind, entity.label.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn gazetteer_matches_entities_and_aliases_with_offsets() {
+        let gazetteer = EntityGazetteer::from_watchlists(&[watchlist(
+            &["orion dynamics", "alice north"],
+            &[("orion dynamics", &["Orion", "ORDY Labs"])],
+        )]);
+        let text = "Orion said ordy  labs hired Alice North; Orionx did not comment.";
+        let entities = extract_entities(&gazetteer, EvidenceTextField::Content, text);
+
+        assert_eq!(
+            labels(&entities),
+            vec![
+                (ExtractedEntityKind::Watchlist, "orion dynamics"),
+                (ExtractedEntityKind::Watchlist, "orion dynamics"),
+                (ExtractedEntityKind::Watchlist, "alice north"),
+            ]
+        );
+        assert_eq!((entities[1].start, entities[1].end), (11, 21));
+        assert_eq!(entities[1].text, "ordy  labs");
+        assert_eq!(&text[entities[2].start..entities[2].end], "Alice North");
+    }
+
+    #[test]
+    fn identifier_recognizers_validate_their_matches() {
+        let gazetteer = EntityGazetteer::default();
+        let text = "Patch CVE-2024-3094 on 10.0.4.17 and 2001:db8::1, not 999.1.1.1 at 12:30:00. \
+             Mail ops@orion.example.com, see status.orion.io or report.pdf. \
+             Funds moved to 0x52908400098527886E0F7030069857D2E4169EE7 as $ORCL and NYSE: IBM fell.";
+        let entities = extract_entities(&gazetteer, EvidenceTextField::Content, text);
+
+        assert_eq!(
+            labels(&entities),
+            vec![
+                (ExtractedEntityKind::Cve, "cve-2024-3094"),
+                (ExtractedEntityKind::IpAddress, "10.0.4.17"),
+                (ExtractedEntityKind::IpAddress, "2001:db8::1"),
+                (ExtractedEntityKind::Email, "ops@orion.example.com"),
+                (ExtractedEntityKind::Domain, "status.orion.io"),
+                (
+                    ExtractedEntityKind::WalletAddress,
+                    "0x52908400098527886e0f7030069857d2e4169ee7"
+                ),
+                (ExtractedEntityKind::Ticker, "$orcl"),
+                (ExtractedEntityKind::Ticker, "$ibm"),
+            ]
+        );
+    }
+
+    #[test]
+    fn proper_names_skip_leading_function_words_and_headline_case() {
+        let gazetteer = EntityGazetteer::from_watchlists(&[watchlist(&["orion dynamics"], &[])]);
+        let content = "According to Reuters, The Bank of Lisbon and Orion Dynamics signed a deal.";
+        let entities = extract_entities(&gazetteer, EvidenceTextField::Content, content);
+        assert_eq!(
+            labels(&entities),
+            vec![
+                (ExtractedEntityKind::ProperName, "bank of lisbon"),
+                (ExtractedEntityKind::Watchlist, "orion dynamics"),
+            ]
+        );
+        assert_eq!(entities[0].text, "Bank of Lisbon");
+
+        let title = "Bank Of Lisbon Signs Deal With Orion Dynamics";
+        let entities = extract_entities(&gazetteer, EvidenceTextField::Title, title);
+        assert_eq!(
+            labels(&entities),
+            vec![(ExtractedEntityKind::Watchlist, "orion dynamics")]
+        );
+    }
+}
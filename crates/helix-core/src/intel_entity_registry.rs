@@ -0,0 +1,338 @@
+//! Registry of canonical entities and their aliases.
+//!
+//! Entity labels across the desk are lowercased strings, so "Microsoft", "MSFT", and
+//! "Microsoft Corp." would otherwise be three entities. The registry maps each name and
+//! alias to one canonical id, which is the normalized primary name. Labels with no
+//! registry entry resolve to themselves, so unregistered entities behave as before.
+
+use crate::HelixError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const MAX_REGISTRY_ALIASES: usize = 32;
+const MAX_ENTITY_NAME_LEN: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistryEntityKind {
+    Org,
+    Person,
+    Ticker,
+    Domain,
+    Wallet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryEntity {
+    /// Canonical id: the normalized `name`.
+    pub id: String,
+    pub name: String,
+    pub kind: RegistryEntityKind,
+    /// Normalized alternate labels, excluding the id.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl RegistryEntity {
+    /// Builds a canonical entity from a display name; the id is derived from the name.
+    pub fn new(
+        name: &str,
+        kind: RegistryEntityKind,
+        aliases: Vec<String>,
+    ) -> Result<Self, HelixError> {
+        let name = name.trim();
+        if name.is_empty() || name.len() > MAX_ENTITY_NAME_LEN {
+            return Err(HelixError::validation_error(
+                "entity.name".to_string(),
+                format!("must be 1 to {MAX_ENTITY_NAME_LEN} bytes"),
+            ));
+        }
+        let id = normalize_entity_label(name);
+        if id.is_empty() {
+            return Err(HelixError::validation_error(
+                "entity.name",
+                "must contain a letter or digit",
+            ));
+        }
+        if aliases.len() > MAX_REGISTRY_ALIASES {
+            return Err(HelixError::validation_error(
+                "entity.aliases".to_string(),
+                format!("too many items; max is {MAX_REGISTRY_ALIASES}"),
+            ));
+        }
+        let mut aliases = aliases
+            .iter()
+            .map(|alias| normalize_entity_label(alias))
+            .filter(|alias| !alias.is_empty() && alias != &id)
+            .collect::<Vec<_>>();
+        aliases.sort();
+        aliases.dedup();
+        Ok(Self {
+            id,
+            name: name.to_string(),
+            kind,
+            aliases,
+        })
+    }
+
+    fn labels(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.id.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+/// Lowercases, collapses whitespace, and trims surrounding punctuation so
+/// "Microsoft Corp." and "microsoft  corp" compare equal. `$` and `@` are kept because
+/// they distinguish tickers and handles.
+pub fn normalize_entity_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|ch: char| !ch.is_alphanumeric() && ch != '$' && ch != '@')
+        .to_lowercase()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityRegistry {
+    entities: BTreeMap<String, RegistryEntity>,
+    /// Normalized label (id or alias) to canonical id.
+    index: BTreeMap<String, String>,
+}
+
+impl EntityRegistry {
+    pub fn from_entities<I>(entities: I) -> Result<Self, HelixError>
+    where
+        I: IntoIterator<Item = RegistryEntity>,
+    {
+        let mut registry = Self::default();
+        for entity in entities {
+            registry.insert(entity)?;
+        }
+        Ok(registry)
+    }
+
+    pub fn entities(&self) -> impl Iterator<Item = &RegistryEntity> {
+        self.entities.values()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&RegistryEntity> {
+        self.entities.get(id)
+    }
+
+    pub fn resolve(&self, label: &str) -> Option<&RegistryEntity> {
+        self.index
+            .get(&normalize_entity_label(label))
+            .and_then(|id| self.entities.get(id))
+    }
+
+    /// Canonical id for `label`, or the label trimmed and lowercased when it is not
+    /// registered.
+    pub fn canonical_label(&self, label: &str) -> String {
+        self.resolve(label)
+            .map(|entity| entity.id.clone())
+            .unwrap_or_else(|| label.trim().to_lowercase())
+    }
+
+    /// Resolves every label, then sorts and dedups since aliases collapse together.
+    pub fn canonical_labels(&self, labels: Vec<String>) -> Vec<String> {
+        let mut labels = labels
+            .iter()
+            .map(|label| self.canonical_label(label))
+            .collect::<Vec<_>>();
+        labels.sort();
+        labels.dedup();
+        labels
+    }
+
+    /// Adds `entity`, or replaces the entity with the same id. Fails when one of its
+    /// labels already belongs to another entity.
+    pub fn upsert(&mut self, entity: RegistryEntity) -> Result<RegistryEntity, HelixError> {
+        let previous = self.remove(&entity.id);
+        if let Err(error) = self.insert(entity.clone()) {
+            if let Some(previous) = previous {
+                self.insert(previous)?;
+            }
+            return Err(error);
+        }
+        Ok(entity)
+    }
+
+    /// Folds `source_id` into `target_id`: the source's id, name, and aliases become
+    /// aliases of the target, and the source entity is removed.
+    pub fn merge(
+        &mut self,
+        target_id: &str,
+        source_id: &str,
+    ) -> Result<RegistryEntity, HelixError> {
+        if target_id == source_id {
+            return Err(HelixError::validation_error(
+                "entity.merge",
+                "cannot merge an entity into itself",
+            ));
+        }
+        let mut target = self.require(target_id)?.clone();
+        let source = self.require(source_id)?.clone();
+        target.aliases.extend(source.labels().map(str::to_string));
+        target.aliases.push(normalize_entity_label(&source.name));
+        target.aliases.retain(|alias| alias != &target.id);
+        target.aliases.sort();
+        target.aliases.dedup();
+        if target.aliases.len() > MAX_REGISTRY_ALIASES {
+            return Err(HelixError::validation_error(
+                "entity.aliases".to_string(),
+                format!("merged entity would exceed {MAX_REGISTRY_ALIASES} aliases"),
+            ));
+        }
+        self.remove(source_id);
+        self.remove(target_id);
+        self.insert(target.clone())?;
+        Ok(target)
+    }
+
+    /// Moves aliases off `entity_id` into the new entity `split`. Each label of `split`
+    /// must be unregistered or an alias of `entity_id`, and at least one must move.
+    pub fn split(
+        &mut self,
+        entity_id: &str,
+        split: RegistryEntity,
+    ) -> Result<(RegistryEntity, RegistryEntity), HelixError> {
+        let mut original = self.require(entity_id)?.clone();
+        if split.id == original.id {
+            return Err(HelixError::validation_error(
+                "entity.split",
+                "the split entity needs a different name",
+            ));
+        }
+        for label in split.labels() {
+            let owner = self.index.get(label);
+            let moves_alias = owner == Some(&original.id) && label != original.id;
+            if owner.is_some() && !moves_alias {
+                return Err(HelixError::validation_error(
+                    "entity.split".to_string(),
+                    format!("`{label}` is not an alias of `{entity_id}`"),
+                ));
+            }
+        }
+        if split.aliases.is_empty() && !original.aliases.contains(&split.id) {
+            return Err(HelixError::validation_error(
+                "entity.split",
+                "must move at least one alias",
+            ));
+        }
+        original
+            .aliases
+            .retain(|alias| !split.labels().any(|label| label == alias));
+        self.remove(entity_id);
+        self.insert(original.clone())?;
+        self.insert(split.clone())?;
+        Ok((original, split))
+    }
+
+    fn require(&self, id: &str) -> Result<&RegistryEntity, HelixError> {
+        self.entities
+            .get(id)
+            .ok_or_else(|| HelixError::not_found(format!("entity {id}")))
+    }
+
+    fn insert(&mut self, entity: RegistryEntity) -> Result<(), HelixError> {
+        if let Some(owner) = entity
+            .labels()
+            .find_map(|label| self.index.get(label).filter(|owner| **owner != entity.id))
+        {
+            return Err(HelixError::validation_error(
+                "entity.aliases".to_string(),
+                format!("a label of `{}` already belongs to `{owner}`", entity.id),
+            ));
+        }
+        for label in entity.labels() {
+            self.index.insert(label.to_string(), entity.id.clone());
+        }
+        self.entities.insert(entity.id.clone(), entity);
+        Ok(())
+    }
+
+    fn remove(&mut self, id: &str) -> Option<RegistryEntity> {
+        let entity = self.entities.remove(id)?;
+        for label in entity.labels() {
+            self.index.remove(label);
+        }
+        Some(entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(name: &str, aliases: &[&str]) -> RegistryEntity {
+        RegistryEntity::new(
+            name,
+            RegistryEntityKind::Org,
+            aliases.iter().map(|alias| alias.to_string()).collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn aliases_resolve_to_the_canonical_id() {
+        let registry =
+            EntityRegistry::from_entities([entity("Microsoft", &["MSFT", "Microsoft Corp."])])
+                .unwrap();
+        for label in ["microsoft", "MSFT", "microsoft  corp", "Microsoft Corp."] {
+            assert_eq!(registry.canonical_label(label), "microsoft");
+        }
+        assert_eq!(registry.canonical_label(" Apple "), "apple");
+        assert_eq!(
+            registry.canonical_labels(vec!["msft".to_string(), "microsoft".to_string()]),
+            vec!["microsoft"]
+        );
+        assert!(matches!(
+            EntityRegistry::from_entities([entity("Microsoft", &[]), entity("MS", &["microsoft"])]),
+            Err(HelixError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn merge_and_split_move_labels_between_entities() {
+        let mut registry = EntityRegistry::from_entities([
+            entity("Microsoft", &["msft", "linkedin"]),
+            entity("Microsoft Corporation", &["microsoft corp"]),
+        ])
+        .unwrap();
+
+        let merged = registry
+            .merge("microsoft", "microsoft corporation")
+            .unwrap();
+        assert_eq!(
+            merged.aliases,
+            vec![
+                "linkedin",
+                "microsoft corp",
+                "microsoft corporation",
+                "msft"
+            ]
+        );
+        assert_eq!(registry.canonical_label("Microsoft Corp"), "microsoft");
+        assert!(registry.get("microsoft corporation").is_none());
+
+        let (original, split) = registry
+            .split("microsoft", entity("LinkedIn", &[]))
+            .unwrap();
+        assert!(!original.aliases.contains(&"linkedin".to_string()));
+        assert_eq!(split.id, "linkedin");
+        assert_eq!(registry.canonical_label("LinkedIn"), "linkedin");
+        assert_eq!(registry.canonical_label("MSFT"), "microsoft");
+
+        assert!(registry
+            .split("microsoft", entity("Azure", &["msft"]))
+            .is_ok());
+        assert!(matches!(
+            registry.split("microsoft", entity("Bing", &["linkedin"])),
+            Err(HelixError::ValidationError { .. })
+        ));
+        assert!(matches!(
+            registry.merge("microsoft", "missing"),
+            Err(HelixError::NotFound(_))
+        ));
+    }
+}
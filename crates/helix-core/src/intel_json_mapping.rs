@@ -0,0 +1,560 @@
+use crate::intel_desk::ProposedClaim;
+use crate::HelixError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const MAX_EXTRACTOR_LEN: usize = 512;
+const MAX_PATH_SEGMENTS: usize = 32;
+const MAX_LIST_EXTRACTORS: usize = 16;
+const MAX_CLAIM_MAPPINGS: usize = 8;
+const MAX_CONFIDENCE_BPS: u16 = 10_000;
+
+/// Declarative mapping from an arbitrary JSON API response to evidence fields.
+///
+/// `items_path` selects the item list. Every other field is an extractor: a value that
+/// starts with `$` is a path evaluated against one item (`$.author.name`, `$.tags[*]`),
+/// anything else is a template whose `{{ path }}` placeholders are filled from the item.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonApiMapping {
+    #[serde(default)]
+    pub items_path: Option<String>,
+    pub title: String,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub observed_at: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub entity_labels: Vec<String>,
+    #[serde(default)]
+    pub proposed_claims: Vec<JsonApiClaimMapping>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonApiClaimMapping {
+    pub subject: String,
+    pub predicate: String,
+    pub object: String,
+    pub confidence_bps: u16,
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+/// Evidence fields extracted from one JSON item, before source defaults are applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappedJsonItem {
+    pub title: String,
+    pub summary: Option<String>,
+    pub content: Option<String>,
+    pub url: Option<String>,
+    pub observed_at: Option<String>,
+    pub tags: Vec<String>,
+    pub entity_labels: Vec<String>,
+    pub proposed_claims: Vec<ProposedClaim>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Dotted path with `[n]`, `[*]`, `.*`, and `['quoted key']` segments; a leading `$`
+/// (the item or payload root) is optional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<JsonPathSegment>,
+}
+
+impl JsonPath {
+    pub fn parse(input: &str) -> Result<Self, HelixError> {
+        let trimmed = input.trim();
+        let invalid = |message: &str| {
+            HelixError::validation_error("json_path", &format!("`{trimmed}`: {message}"))
+        };
+        let (mut rest, mut expect_key) = match trimmed.strip_prefix('$') {
+            Some(rest) => (rest, false),
+            None if trimmed.is_empty() => return Err(invalid("path is empty")),
+            None => (trimmed, true),
+        };
+
+        let mut segments = Vec::new();
+        while !rest.is_empty() || expect_key {
+            if expect_key {
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                let key = &rest[..end];
+                if key == "*" {
+                    segments.push(JsonPathSegment::Wildcard);
+                } else if key.is_empty() || key.chars().any(char::is_whitespace) {
+                    return Err(invalid("expected a key name"));
+                } else {
+                    segments.push(JsonPathSegment::Key(key.to_string()));
+                }
+                rest = &rest[end..];
+                expect_key = false;
+            } else if let Some(after_dot) = rest.strip_prefix('.') {
+                rest = after_dot;
+                expect_key = true;
+            } else if let Some(after_bracket) = rest.strip_prefix('[') {
+                let end = after_bracket
+                    .find(']')
+                    .ok_or_else(|| invalid("unterminated `[`"))?;
+                let inner = after_bracket[..end].trim();
+                segments.push(
+                    bracket_segment(inner).ok_or_else(|| {
+                        invalid("brackets must hold an index, `*`, or a quoted key")
+                    })?,
+                );
+                rest = &after_bracket[end + 1..];
+            } else {
+                return Err(invalid("expected `.` or `[`"));
+            }
+            if segments.len() > MAX_PATH_SEGMENTS {
+                return Err(invalid(&format!(
+                    "paths may have at most {MAX_PATH_SEGMENTS} segments"
+                )));
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    pub fn segments(&self) -> &[JsonPathSegment] {
+        &self.segments
+    }
+
+    /// Returns every value the path reaches; wildcards fan out over arrays and objects.
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        let mut current = vec![root];
+        for segment in &self.segments {
+            let mut next = Vec::new();
+            for value in current {
+                match segment {
+                    JsonPathSegment::Key(key) => next.extend(value.get(key.as_str())),
+                    JsonPathSegment::Index(index) => next.extend(value.get(*index)),
+                    JsonPathSegment::Wildcard => match value {
+                        Value::Array(items) => next.extend(items),
+                        Value::Object(fields) => next.extend(fields.values()),
+                        _ => {}
+                    },
+                }
+            }
+            current = next;
+        }
+        current
+    }
+}
+
+fn bracket_segment(inner: &str) -> Option<JsonPathSegment> {
+    if inner == "*" {
+        return Some(JsonPathSegment::Wildcard);
+    }
+    if let Ok(index) = inner.parse::<usize>() {
+        return Some(JsonPathSegment::Index(index));
+    }
+    ['\'', '"'].into_iter().find_map(|quote| {
+        inner
+            .strip_prefix(quote)
+            .and_then(|key| key.strip_suffix(quote))
+            .filter(|key| !key.is_empty())
+            .map(|key| JsonPathSegment::Key(key.to_string()))
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplatePart {
+    Literal(String),
+    Placeholder(JsonPath),
+}
+
+/// A parsed field extractor: a path, or a template with `{{ path }}` placeholders. A
+/// template with no placeholders is a literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonExtractor {
+    Path(JsonPath),
+    Template(Vec<TemplatePart>),
+}
+
+impl JsonExtractor {
+    pub fn parse(context: &str, input: &str) -> Result<Self, HelixError> {
+        let trimmed = input.trim();
+        if trimmed.len() > MAX_EXTRACTOR_LEN {
+            return Err(HelixError::validation_error(
+                context,
+                &format!("must be <= {MAX_EXTRACTOR_LEN} characters"),
+            ));
+        }
+        let with_context = |error: HelixError| match error {
+            HelixError::ValidationError { message, .. } => {
+                HelixError::validation_error(context, &message)
+            }
+            other => other,
+        };
+        if trimmed.starts_with('$') {
+            return JsonPath::parse(trimmed)
+                .map(Self::Path)
+                .map_err(with_context);
+        }
+
+        let mut parts = Vec::new();
+        let mut rest = trimmed;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                parts.push(TemplatePart::Literal(rest[..start].to_string()));
+            }
+            let after_open = &rest[start + 2..];
+            let end = after_open.find("}}").ok_or_else(|| {
+                HelixError::validation_error(context, "template placeholder is missing `}}`")
+            })?;
+            let path = JsonPath::parse(&after_open[..end]).map_err(with_context)?;
+            parts.push(TemplatePart::Placeholder(path));
+            rest = &after_open[end + 2..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_string()));
+        }
+        Ok(Self::Template(parts))
+    }
+
+    /// Extracts one text value. Templates render only when every placeholder resolves.
+    pub fn extract_text(&self, item: &Value) -> Option<String> {
+        let text = match self {
+            Self::Path(path) => path.select(item).into_iter().find_map(value_text)?,
+            Self::Template(parts) => {
+                let mut rendered = String::new();
+                for part in parts {
+                    match part {
+                        TemplatePart::Literal(literal) => rendered.push_str(literal),
+                        TemplatePart::Placeholder(path) => {
+                            rendered.push_str(&path.select(item).into_iter().find_map(value_text)?)
+                        }
+                    }
+                }
+                rendered
+            }
+        };
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
+    /// Extracts a list; path matches that are arrays contribute each scalar element.
+    pub fn extract_list(&self, item: &Value) -> Vec<String> {
+        match self {
+            Self::Path(path) => path
+                .select(item)
+                .into_iter()
+                .flat_map(|value| match value {
+                    Value::Array(items) => items.iter().filter_map(value_text).collect(),
+                    _ => value_text(value).into_iter().collect::<Vec<_>>(),
+                })
+                .collect(),
+            Self::Template(_) => self.extract_text(item).into_iter().collect(),
+        }
+    }
+}
+
+fn value_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => text.trim().to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .filter(|item| !matches!(item, Value::Array(_) | Value::Object(_)))
+            .filter_map(value_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Null | Value::Object(_) => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+pub fn canonicalize_json_api_mapping(
+    mapping: JsonApiMapping,
+) -> Result<JsonApiMapping, HelixError> {
+    let items_path = mapping
+        .items_path
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty());
+    if let Some(path) = &items_path {
+        JsonPath::parse(path).map_err(|error| match error {
+            HelixError::ValidationError { message, .. } => {
+                HelixError::validation_error("source.json_api.items_path", &message)
+            }
+            other => other,
+        })?;
+    }
+    let title = required_extractor("source.json_api.title", &mapping.title)?;
+    let summary = optional_extractor("source.json_api.summary", mapping.summary)?;
+    let content = optional_extractor("source.json_api.content", mapping.content)?;
+    let url = optional_extractor("source.json_api.url", mapping.url)?;
+    let observed_at = optional_extractor("source.json_api.observed_at", mapping.observed_at)?;
+    let tags = extractor_list("source.json_api.tags", mapping.tags)?;
+    let entity_labels = extractor_list("source.json_api.entity_labels", mapping.entity_labels)?;
+
+    if mapping.proposed_claims.len() > MAX_CLAIM_MAPPINGS {
+        return Err(HelixError::validation_error(
+            "source.json_api.proposed_claims",
+            &format!("too many items; max is {MAX_CLAIM_MAPPINGS}"),
+        ));
+    }
+    let mut proposed_claims = Vec::new();
+    for claim in mapping.proposed_claims {
+        if claim.confidence_bps > MAX_CONFIDENCE_BPS {
+            return Err(HelixError::validation_error(
+                "source.json_api.proposed_claims.confidence_bps",
+                &format!("must be <= {MAX_CONFIDENCE_BPS}"),
+            ));
+        }
+        proposed_claims.push(JsonApiClaimMapping {
+            subject: required_extractor("source.json_api.proposed_claims.subject", &claim.subject)?,
+            predicate: required_extractor(
+                "source.json_api.proposed_claims.predicate",
+                &claim.predicate,
+            )?,
+            object: required_extractor("source.json_api.proposed_claims.object", &claim.object)?,
+            confidence_bps: claim.confidence_bps,
+            rationale: optional_extractor(
+                "source.json_api.proposed_claims.rationale",
+                claim.rationale,
+            )?,
+        });
+    }
+
+    Ok(JsonApiMapping {
+        items_path,
+        title,
+        summary,
+        content,
+        url,
+        observed_at,
+        tags,
+        entity_labels,
+        proposed_claims,
+    })
+}
+
+fn required_extractor(context: &str, value: &str) -> Result<String, HelixError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(HelixError::validation_error(context, "must not be empty"));
+    }
+    JsonExtractor::parse(context, value)?;
+    Ok(value.to_string())
+}
+
+fn optional_extractor(context: &str, value: Option<String>) -> Result<Option<String>, HelixError> {
+    value
+        .filter(|value| !value.trim().is_empty())
+        .map(|value| required_extractor(context, &value))
+        .transpose()
+}
+
+fn extractor_list(context: &str, values: Vec<String>) -> Result<Vec<String>, HelixError> {
+    let values = values
+        .into_iter()
+        .filter(|value| !value.trim().is_empty())
+        .collect::<Vec<_>>();
+    if values.len() > MAX_LIST_EXTRACTORS {
+        return Err(HelixError::validation_error(
+            context,
+            &format!("too many items; max is {MAX_LIST_EXTRACTORS}"),
+        ));
+    }
+    values
+        .iter()
+        .map(|value| required_extractor(context, value))
+        .collect()
+}
+
+/// Selects the item list from a payload. Without `items_path` a top-level array yields its
+/// elements and any other value is one item; a path that lands on a single array also
+/// yields that array's elements.
+pub fn select_json_items<'a>(
+    mapping: &JsonApiMapping,
+    payload: &'a Value,
+) -> Result<Vec<&'a Value>, HelixError> {
+    let selected = match &mapping.items_path {
+        Some(path) => JsonPath::parse(path)?.select(payload),
+        None => vec![payload],
+    };
+    Ok(match selected.as_slice() {
+        [Value::Array(items)] => items.iter().collect(),
+        _ => selected,
+    })
+}
+
+/// Applies the mapping to one item. Claims whose subject, predicate, or object do not
+/// resolve are skipped; a missing title is an error.
+pub fn map_json_item(mapping: &JsonApiMapping, item: &Value) -> Result<MappedJsonItem, HelixError> {
+    let resolve = |context: &str, value: &str| -> Result<Option<String>, HelixError> {
+        Ok(JsonExtractor::parse(context, value)?.extract_text(item))
+    };
+    let text = |context: &str, value: &Option<String>| -> Result<Option<String>, HelixError> {
+        match value {
+            Some(value) => resolve(context, value),
+            None => Ok(None),
+        }
+    };
+    let list = |context: &str, values: &[String]| -> Result<Vec<String>, HelixError> {
+        let mut collected = Vec::new();
+        for value in values {
+            collected.extend(JsonExtractor::parse(context, value)?.extract_list(item));
+        }
+        Ok(collected)
+    };
+
+    let title = resolve("source.json_api.title", &mapping.title)?.ok_or_else(|| {
+        HelixError::validation_error("source.json_api.title", "title did not resolve")
+    })?;
+    let mut proposed_claims = Vec::new();
+    for claim in &mapping.proposed_claims {
+        let subject = resolve("source.json_api.proposed_claims.subject", &claim.subject)?;
+        let predicate = resolve(
+            "source.json_api.proposed_claims.predicate",
+            &claim.predicate,
+        )?;
+        let object = resolve("source.json_api.proposed_claims.object", &claim.object)?;
+        if let (Some(subject), Some(predicate), Some(object)) = (subject, predicate, object) {
+            proposed_claims.push(ProposedClaim {
+                subject,
+                predicate,
+                object,
+                confidence_bps: claim.confidence_bps,
+                rationale: text(
+                    "source.json_api.proposed_claims.rationale",
+                    &claim.rationale,
+                )?,
+            });
+        }
+    }
+
+    Ok(MappedJsonItem {
+        title,
+        summary: text("source.json_api.summary", &mapping.summary)?,
+        content: text("source.json_api.content", &mapping.content)?,
+        url: text("source.json_api.url", &mapping.url)?,
+        observed_at: text("source.json_api.observed_at", &mapping.observed_at)?,
+        tags: list("source.json_api.tags", &mapping.tags)?,
+        entity_labels: list("source.json_api.entity_labels", &mapping.entity_labels)?,
+        proposed_claims,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mapping() -> JsonApiMapping {
+        JsonApiMapping {
+            items_path: Some("$.data.results".to_string()),
+            title: "$.headline".to_string(),
+            summary: Some("{{ company.name }} raised {{ $.round.amount }}".to_string()),
+            url: Some("$.links[0].href".to_string()),
+            tags: vec!["$.topics[*]".to_string(), "funding".to_string()],
+            entity_labels: vec!["$['company']['name']".to_string()],
+            proposed_claims: vec![JsonApiClaimMapping {
+                subject: "$.company.name".to_string(),
+                predicate: "raised".to_string(),
+                object: "$.round.amount".to_string(),
+                confidence_bps: 7_000,
+                rationale: None,
+            }],
+            ..JsonApiMapping::default()
+        }
+    }
+
+    #[test]
+    fn json_path_parses_dotted_bracket_and_wildcard_segments() {
+        let path = JsonPath::parse("$.data['first item'][2].*").unwrap();
+        assert_eq!(
+            path.segments(),
+            &[
+                JsonPathSegment::Key("data".to_string()),
+                JsonPathSegment::Key("first item".to_string()),
+                JsonPathSegment::Index(2),
+                JsonPathSegment::Wildcard,
+            ]
+        );
+        assert_eq!(JsonPath::parse("data.results").unwrap().segments().len(), 2);
+        assert!(JsonPath::parse("$").unwrap().segments().is_empty());
+        for invalid in ["", "$.", "$..a", "$[", "$[x]", "$a", "data results"] {
+            assert!(JsonPath::parse(invalid).is_err(), "{invalid} should fail");
+        }
+    }
+
+    #[test]
+    fn map_json_item_extracts_paths_templates_and_claims() {
+        let payload = json!({
+            "data": {"results": [
+                {
+                    "headline": "Orion Dynamics closes Series C",
+                    "company": {"name": "Orion Dynamics"},
+                    "round": {"amount": 42000000},
+                    "topics": ["robotics", "funding"],
+                    "links": [{"href": "https://example.com/orion"}]
+                },
+                {"headline": "  ", "company": {"name": "Nameless"}}
+            ]}
+        });
+        let mapping = canonicalize_json_api_mapping(mapping()).unwrap();
+        let items = select_json_items(&mapping, &payload).unwrap();
+        assert_eq!(items.len(), 2);
+
+        let mapped = map_json_item(&mapping, items[0]).unwrap();
+        assert_eq!(mapped.title, "Orion Dynamics closes Series C");
+        assert_eq!(
+            mapped.summary.as_deref(),
+            Some("Orion Dynamics raised 42000000")
+        );
+        assert_eq!(mapped.url.as_deref(), Some("https://example.com/orion"));
+        assert_eq!(mapped.tags, vec!["robotics", "funding", "funding"]);
+        assert_eq!(mapped.entity_labels, vec!["Orion Dynamics"]);
+        assert_eq!(mapped.proposed_claims.len(), 1);
+        assert_eq!(mapped.proposed_claims[0].object, "42000000");
+
+        assert!(map_json_item(&mapping, items[1]).is_err());
+    }
+
+    #[test]
+    fn canonicalize_json_api_mapping_rejects_invalid_extractors() {
+        let cases = [
+            JsonApiMapping {
+                title: " ".to_string(),
+                ..JsonApiMapping::default()
+            },
+            JsonApiMapping {
+                title: "$.headline[".to_string(),
+                ..JsonApiMapping::default()
+            },
+            JsonApiMapping {
+                title: "{{ headline".to_string(),
+                ..JsonApiMapping::default()
+            },
+            JsonApiMapping {
+                items_path: Some("$.data[-1]".to_string()),
+                title: "$.headline".to_string(),
+                ..JsonApiMapping::default()
+            },
+            JsonApiMapping {
+                proposed_claims: vec![JsonApiClaimMapping {
+                    confidence_bps: 10_001,
+                    ..mapping().proposed_claims[0].clone()
+                }],
+                ..mapping()
+            },
+        ];
+        for mapping in cases {
+            assert!(matches!(
+                canonicalize_json_api_mapping(mapping),
+                Err(HelixError::ValidationError { .. })
+            ));
+        }
+    }
+}
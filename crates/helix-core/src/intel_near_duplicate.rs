@@ -0,0 +1,210 @@
+//! Near-duplicate detection for evidence text.
+//!
+//! Each evidence item gets a MinHash signature over word shingles of its normalized title,
+//! summary, and content. Matching signature slots estimate the Jaccard similarity of the
+//! shingle sets, so syndicated copies that differ in a few words or a timestamp score
+//! high while unrelated stories about the same company do not.
+
+/// Words per shingle.
+pub const MINHASH_SHINGLE_WORDS: usize = 3;
+/// Hash functions (signature slots) per signature.
+pub const MINHASH_PERMUTATIONS: usize = 64;
+/// Estimated Jaccard similarity, in basis points, at which two items are the same story.
+pub const NEAR_DUPLICATE_MIN_SIMILARITY_BPS: u16 = 5_000;
+/// Signature slots per locality-sensitive hashing band.
+pub const MINHASH_BAND_ROWS: usize = 2;
+/// Locality-sensitive hashing bands per signature.
+pub const MINHASH_BANDS: usize = MINHASH_PERMUTATIONS / MINHASH_BAND_ROWS;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+const PERMUTATION_SEED_STEP: u64 = 0x9e37_79b9_7f4a_7c15;
+const SIGNATURE_HEX_LEN: usize = MINHASH_PERMUTATIONS * 4;
+
+/// MinHash signature keeping the low 16 bits of each slot minimum; at that width accidental
+/// slot collisions (1 in 65,536) do not move the similarity estimate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinHashSignature([u16; MINHASH_PERMUTATIONS]);
+
+impl MinHashSignature {
+    /// Signature of `text`, or `None` when it has no words. Case, punctuation, and
+    /// whitespace do not affect the result.
+    pub fn from_text(text: &str) -> Option<Self> {
+        let tokens = normalized_tokens(text);
+        if tokens.is_empty() {
+            return None;
+        }
+        let width = MINHASH_SHINGLE_WORDS.min(tokens.len());
+        let mut minima = [u64::MAX; MINHASH_PERMUTATIONS];
+        for shingle in tokens.windows(width) {
+            let base = shingle_hash(shingle);
+            for (slot, minimum) in minima.iter_mut().enumerate() {
+                let seed = PERMUTATION_SEED_STEP.wrapping_mul(slot as u64 + 1);
+                *minimum = (*minimum).min(mix64(base ^ seed));
+            }
+        }
+        Some(Self(minima.map(|minimum| minimum as u16)))
+    }
+
+    pub fn from_hex(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.len() != SIGNATURE_HEX_LEN || !value.is_ascii() {
+            return None;
+        }
+        let mut slots = [0_u16; MINHASH_PERMUTATIONS];
+        for (slot, chunk) in slots.iter_mut().zip(value.as_bytes().chunks(4)) {
+            *slot = u16::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+        }
+        Some(Self(slots))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|slot| format!("{slot:04x}")).collect()
+    }
+
+    /// Estimated Jaccard similarity of the two shingle sets, in basis points.
+    pub fn similarity_bps(&self, other: &Self) -> u16 {
+        let matches = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .filter(|(left, right)| left == right)
+            .count();
+        (matches * 10_000 / MINHASH_PERMUTATIONS) as u16
+    }
+
+    pub fn is_near_duplicate(&self, other: &Self) -> bool {
+        self.similarity_bps(other) >= NEAR_DUPLICATE_MIN_SIMILARITY_BPS
+    }
+
+    /// Band keys for locality-sensitive hashing: each band packs [`MINHASH_BAND_ROWS`]
+    /// consecutive slots. Two signatures that agree on a whole band are candidates for a
+    /// similarity check. Above the near-duplicate threshold they differ in fewer slots
+    /// than there are bands, so at least one band matches; only a pair exactly at the
+    /// threshold with one mismatch in every band goes unmatched.
+    pub fn bands(&self) -> [u32; MINHASH_BANDS] {
+        let mut bands = [0_u32; MINHASH_BANDS];
+        for (band, rows) in bands.iter_mut().zip(self.0.chunks(MINHASH_BAND_ROWS)) {
+            *band = rows
+                .iter()
+                .fold(0_u32, |key, slot| (key << 16) | u32::from(*slot));
+        }
+        bands
+    }
+}
+
+/// Picks the story cluster for `signature` from `(cluster_id, member_signature)` pairs: the
+/// most similar member at or above [`NEAR_DUPLICATE_MIN_SIMILARITY_BPS`], ties going to
+/// the smaller cluster id so the choice does not depend on iteration order.
+pub fn nearest_story_cluster<'a, I>(signature: &MinHashSignature, members: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = (&'a str, &'a MinHashSignature)>,
+{
+    members
+        .into_iter()
+        .map(|(cluster_id, member)| (signature.similarity_bps(member), cluster_id))
+        .filter(|(similarity, _)| *similarity >= NEAR_DUPLICATE_MIN_SIMILARITY_BPS)
+        .min_by(|left, right| right.0.cmp(&left.0).then(left.1.cmp(right.1)))
+        .map(|(_, cluster_id)| cluster_id)
+}
+
+fn normalized_tokens(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn shingle_hash(shingle: &[String]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for (index, token) in shingle.iter().enumerate() {
+        if index > 0 {
+            hash = (hash ^ u64::from(b' ')).wrapping_mul(FNV_PRIME);
+        }
+        for byte in token.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
+
+/// splitmix64 finalizer; turns one shingle hash into independent per-slot permutations.
+fn mix64(mut value: u64) -> u64 {
+    value ^= value >> 30;
+    value = value.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value ^= value >> 27;
+    value = value.wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIRE: &str = "Boreal Cloud raises enterprise seat prices. Boreal Cloud will raise \
+        enterprise seat prices by 12 percent from May, according to a customer notice \
+        reviewed by analysts, citing higher data center power costs across Europe.";
+    const SYNDICATED: &str = "Boreal Cloud raises enterprise seat prices! Boreal Cloud \
+        will raise enterprise seat prices by 12 percent from May, according to a customer \
+        notice reviewed by analysts on Tuesday, citing higher data centre power costs across Europe.";
+    const SAME_COMPANY: &str = "Boreal Cloud opens a Lisbon region. Boreal Cloud will open \
+        a hosting region in Lisbon from May for enterprise customers, adding capacity for \
+        data residency across Europe.";
+
+    fn signature(text: &str) -> MinHashSignature {
+        MinHashSignature::from_text(text).unwrap()
+    }
+
+    #[test]
+    fn signature_ignores_case_and_punctuation() {
+        assert_eq!(
+            signature("Boreal Cloud, raises PRICES."),
+            signature("boreal cloud raises prices")
+        );
+        assert_eq!(MinHashSignature::from_text("  ... "), None);
+        let wire = signature(WIRE);
+        assert_eq!(MinHashSignature::from_hex(&wire.to_hex()), Some(wire));
+        assert_eq!(MinHashSignature::from_hex("abc"), None);
+    }
+
+    #[test]
+    fn syndicated_rewording_is_near_duplicate_but_other_stories_are_not() {
+        let wire = signature(WIRE);
+        let syndicated = signature(SYNDICATED);
+        let same_company = signature(SAME_COMPANY);
+        assert!(wire.is_near_duplicate(&syndicated));
+        assert!(!wire.is_near_duplicate(&same_company));
+        assert!(wire.similarity_bps(&same_company) <= 2_000);
+    }
+
+    #[test]
+    fn near_duplicates_share_a_band_and_other_stories_mostly_do_not() {
+        let shared = |left: &MinHashSignature, right: &MinHashSignature| {
+            left.bands()
+                .iter()
+                .zip(right.bands().iter())
+                .filter(|(left, right)| left == right)
+                .count()
+        };
+        let wire = signature(WIRE);
+        assert_eq!(shared(&wire, &wire), MINHASH_BANDS);
+        assert!(shared(&wire, &signature(SYNDICATED)) >= 1);
+        assert!(shared(&wire, &signature(SAME_COMPANY)) <= 2);
+    }
+
+    #[test]
+    fn nearest_story_cluster_prefers_most_similar_then_smallest_id() {
+        let wire = signature(WIRE);
+        let other = signature(SAME_COMPANY);
+        let members = [
+            ("evidence_b", &wire),
+            ("evidence_a", &wire),
+            ("evidence_c", &other),
+        ];
+        assert_eq!(
+            nearest_story_cluster(&signature(SYNDICATED), members),
+            Some("evidence_a")
+        );
+        assert_eq!(nearest_story_cluster(&other, [("evidence_a", &wire)]), None);
+    }
+}
@@ -0,0 +1,342 @@
+//! Analyst notes on cases, evidence and claims.
+//!
+//! Notes are free text kept alongside a desk record. A reply joins the thread of the
+//! note it answers, so every thread is one root note with a flat list of replies. Only
+//! the author may rewrite a note, and each rewrite keeps the replaced body as a
+//! revision. `@handle` mentions are re-derived from the body on every write.
+
+use crate::HelixError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+const MAX_AUTHOR_LEN: usize = 128;
+const MAX_NOTE_BODY_LEN: usize = 4_000;
+const MAX_MENTION_LEN: usize = 64;
+pub const REDACTED_NOTE_BODY: &str = "[redacted]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteTargetKind {
+    Case,
+    Evidence,
+    Claim,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteRevision {
+    /// Body as it read before the edit.
+    pub body: String,
+    pub replaced_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalystNote {
+    pub id: String,
+    pub target_kind: NoteTargetKind,
+    pub target_id: String,
+    /// Root note of the thread this note replies to.
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    pub author: String,
+    pub body: String,
+    #[serde(default)]
+    pub mentions: Vec<String>,
+    #[serde(default)]
+    pub pinned: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub revisions: Vec<NoteRevision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteThread {
+    pub root: AnalystNote,
+    pub replies: Vec<AnalystNote>,
+}
+
+impl AnalystNote {
+    /// Writes a new note. A reply takes its target from `parent` and is filed under the
+    /// parent's thread root; only root notes can be pinned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: &str,
+        target_kind: NoteTargetKind,
+        target_id: &str,
+        parent: Option<&AnalystNote>,
+        author: &str,
+        body: &str,
+        pinned: bool,
+        at: &str,
+    ) -> Result<Self, HelixError> {
+        if let Some(parent) = parent {
+            if parent.target_kind != target_kind || parent.target_id != target_id {
+                return Err(HelixError::validation_error(
+                    "note.parent_id",
+                    "reply must be on the same record as its parent",
+                ));
+            }
+            if pinned {
+                return Err(HelixError::validation_error(
+                    "note.pinned",
+                    "only thread roots can be pinned",
+                ));
+            }
+        }
+        let body = canonicalize_note_body(body)?;
+        Ok(Self {
+            id: id.to_string(),
+            target_kind,
+            target_id: target_id.to_string(),
+            parent_id: parent.map(|parent| parent.thread_id().to_string()),
+            author: canonicalize_note_author(author, "note.author")?,
+            mentions: extract_mentions(&body),
+            body,
+            pinned,
+            created_at: at.to_string(),
+            updated_at: at.to_string(),
+            revisions: Vec::new(),
+        })
+    }
+
+    /// Id of the root note of this note's thread.
+    pub fn thread_id(&self) -> &str {
+        self.parent_id.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Replaces the body, keeping the previous one as a revision. Editing to the same
+    /// text is a no-op.
+    pub fn edit(&mut self, editor: &str, body: &str, at: &str) -> Result<(), HelixError> {
+        let editor = canonicalize_note_author(editor, "note.editor")?;
+        if !editor.eq_ignore_ascii_case(&self.author) {
+            return Err(HelixError::validation_error(
+                "note.editor",
+                "only the author can edit a note",
+            ));
+        }
+        let body = canonicalize_note_body(body)?;
+        if body == self.body {
+            return Ok(());
+        }
+        let previous = std::mem::replace(&mut self.body, body);
+        self.revisions.push(NoteRevision {
+            body: previous,
+            replaced_at: at.to_string(),
+        });
+        self.mentions = extract_mentions(&self.body);
+        self.updated_at = at.to_string();
+        Ok(())
+    }
+
+    pub fn set_pinned(&mut self, pinned: bool, at: &str) -> Result<(), HelixError> {
+        if pinned && self.parent_id.is_some() {
+            return Err(HelixError::validation_error(
+                "note.pinned",
+                "only thread roots can be pinned",
+            ));
+        }
+        if self.pinned != pinned {
+            self.pinned = pinned;
+            self.updated_at = at.to_string();
+        }
+        Ok(())
+    }
+
+    /// Case-insensitive match on body, author or a mention. `query` must already be
+    /// lowercase.
+    pub fn matches(&self, query: &str) -> bool {
+        self.body.to_lowercase().contains(query)
+            || self.author.to_lowercase().contains(query)
+            || self.mentions.iter().any(|mention| mention.contains(query))
+    }
+
+    /// Copy for export with the body, revision bodies and mentions withheld. Author,
+    /// thread shape and timestamps are kept so the packet still shows who wrote what when.
+    pub fn redacted(&self) -> Self {
+        Self {
+            body: REDACTED_NOTE_BODY.to_string(),
+            mentions: Vec::new(),
+            revisions: self
+                .revisions
+                .iter()
+                .map(|revision| NoteRevision {
+                    body: REDACTED_NOTE_BODY.to_string(),
+                    replaced_at: revision.replaced_at.clone(),
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+/// Lowercased `@handle` mentions in `body`, sorted and deduplicated. An `@` preceded by
+/// a word character (as in an email address) is not a mention.
+pub fn extract_mentions(body: &str) -> Vec<String> {
+    let mut mentions = BTreeSet::new();
+    let chars = body.char_indices().collect::<Vec<_>>();
+    for (index, &(_, ch)) in chars.iter().enumerate() {
+        if ch != '@' {
+            continue;
+        }
+        if index > 0 && is_handle_char(chars[index - 1].1) {
+            continue;
+        }
+        let handle = chars[index + 1..]
+            .iter()
+            .map(|&(_, ch)| ch)
+            .take_while(|&ch| is_handle_char(ch))
+            .collect::<String>();
+        let handle = handle.trim_end_matches(['.', '-']);
+        if !handle.is_empty() && handle.len() <= MAX_MENTION_LEN {
+            mentions.insert(handle.to_lowercase());
+        }
+    }
+    mentions.into_iter().collect()
+}
+
+/// Groups notes into threads: pinned threads first, then oldest first. Replies whose
+/// root is missing are shown as roots of their own.
+pub fn thread_notes(notes: Vec<AnalystNote>) -> Vec<NoteThread> {
+    let roots = notes
+        .iter()
+        .map(|note| note.id.clone())
+        .collect::<BTreeSet<_>>();
+    let (mut threads, replies): (Vec<_>, Vec<_>) = notes.into_iter().partition(|note| {
+        note.parent_id
+            .as_ref()
+            .map(|parent_id| !roots.contains(parent_id))
+            .unwrap_or(true)
+    });
+    threads.sort_by(|left, right| {
+        right
+            .pinned
+            .cmp(&left.pinned)
+            .then(left.created_at.cmp(&right.created_at))
+            .then(left.id.cmp(&right.id))
+    });
+    let mut threads = threads
+        .into_iter()
+        .map(|root| NoteThread {
+            root,
+            replies: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+    for reply in replies {
+        if let Some(thread) = threads
+            .iter_mut()
+            .find(|thread| Some(thread.root.id.as_str()) == reply.parent_id.as_deref())
+        {
+            thread.replies.push(reply);
+        }
+    }
+    for thread in &mut threads {
+        thread.replies.sort_by(|left, right| {
+            left.created_at
+                .cmp(&right.created_at)
+                .then(left.id.cmp(&right.id))
+        });
+    }
+    threads
+}
+
+fn is_handle_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || matches!(ch, '_' | '.' | '-')
+}
+
+fn canonicalize_note_author(author: &str, field: &str) -> Result<String, HelixError> {
+    let author = author.trim();
+    if author.is_empty() || author.len() > MAX_AUTHOR_LEN {
+        return Err(HelixError::validation_error(
+            field.to_string(),
+            format!("must be 1 to {MAX_AUTHOR_LEN} characters"),
+        ));
+    }
+    Ok(author.to_string())
+}
+
+fn canonicalize_note_body(body: &str) -> Result<String, HelixError> {
+    let body = body.trim();
+    if body.is_empty() || body.len() > MAX_NOTE_BODY_LEN {
+        return Err(HelixError::validation_error(
+            "note.body".to_string(),
+            format!("must be 1 to {MAX_NOTE_BODY_LEN} characters"),
+        ));
+    }
+    Ok(body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(id: &str, parent: Option<&AnalystNote>, at: &str) -> AnalystNote {
+        AnalystNote::new(
+            id,
+            NoteTargetKind::Case,
+            "case_1",
+            parent,
+            "alice",
+            "Board exit looks real, cc @Bob and @carol.",
+            false,
+            at,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn mentions_skip_email_addresses_and_trailing_punctuation() {
+        assert_eq!(
+            extract_mentions("ping @Bob, @carol. and @bob; mail ops@example.com @"),
+            vec!["bob".to_string(), "carol".to_string()]
+        );
+    }
+
+    #[test]
+    fn only_authors_edit_and_edits_keep_revisions() {
+        let mut first = note("note_1", None, "2026-03-01T00:00:00Z");
+        assert!(first
+            .edit("bob", "rewritten", "2026-03-01T01:00:00Z")
+            .is_err());
+        first
+            .edit(
+                "Alice",
+                "Confirmed by filing, @dave",
+                "2026-03-01T02:00:00Z",
+            )
+            .unwrap();
+        assert_eq!(first.mentions, vec!["dave".to_string()]);
+        assert_eq!(first.revisions.len(), 1);
+        assert!(first.revisions[0].body.contains("@Bob"));
+        assert!(first.matches("filing"));
+
+        let redacted = first.redacted();
+        assert_eq!(redacted.body, REDACTED_NOTE_BODY);
+        assert_eq!(redacted.revisions[0].body, REDACTED_NOTE_BODY);
+        assert!(redacted.mentions.is_empty());
+        assert_eq!(redacted.author, "alice");
+    }
+
+    #[test]
+    fn replies_join_the_root_thread_and_pinned_threads_lead() {
+        let first = note("note_1", None, "2026-03-01T00:00:00Z");
+        let reply = note("note_2", Some(&first), "2026-03-01T01:00:00Z");
+        let nested = note("note_3", Some(&reply), "2026-03-01T02:00:00Z");
+        assert_eq!(nested.parent_id.as_deref(), Some("note_1"));
+        let mut second = note("note_4", None, "2026-03-02T00:00:00Z");
+        second.set_pinned(true, "2026-03-02T00:00:00Z").unwrap();
+        let mut unpinnable = reply.clone();
+        assert!(unpinnable.set_pinned(true, "2026-03-02T00:00:00Z").is_err());
+
+        let threads = thread_notes(vec![nested, first, second, reply]);
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].root.id, "note_4");
+        assert_eq!(
+            threads[1]
+                .replies
+                .iter()
+                .map(|reply| reply.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["note_2", "note_3"]
+        );
+    }
+}
@@ -3,6 +3,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 const PRIORITY_RADIX: u64 = 6;
+const INDEPENDENT_SOURCE_SUPPORT_BPS: u16 = 5_000;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IntelSignalWindow {
@@ -100,7 +101,11 @@ pub struct CasePriorityInput {
     pub status: CaseStatus,
     pub severity: WatchlistSeverity,
     pub source_trust_scores: Vec<u8>,
+    /// Distinct story clusters behind the case; near-duplicate evidence counts once.
     pub evidence_count: usize,
+    /// Most distinct sources reporting any one story cluster in the case.
+    #[serde(default)]
+    pub cluster_source_count: usize,
     pub claim_count: usize,
     pub corroborated_claim_count: usize,
     pub rejected_claim_count: usize,
@@ -114,6 +119,9 @@ pub struct EvidencePriorityInput {
     pub linked_case_statuses: Vec<CaseStatus>,
     pub max_linked_severity: Option<WatchlistSeverity>,
     pub source_trust_scores: Vec<u8>,
+    /// Distinct sources reporting the evidence's story cluster, including its own.
+    #[serde(default)]
+    pub cluster_source_count: usize,
     pub claim_count: usize,
     pub corroborated_claim_count: usize,
     pub rejected_claim_count: usize,
@@ -138,18 +146,16 @@ pub struct ClaimPriorityInput {
 pub fn score_case(input: &CasePriorityInput, window: &IntelSignalWindow) -> IntelPriorityBreakdown {
     let attention_tier = case_attention_tier(input.status, input.attached_to_case);
     let severity_tier = severity_tier(Some(input.severity));
-    let credibility_bps = fused_credibility_bps(
-        input.claim_count,
-        input.corroborated_claim_count,
-        input.rejected_claim_count,
-        input.max_claim_confidence_bps,
-    );
-    let corroboration_tier = corroboration_tier(
-        input.claim_count,
-        input.corroborated_claim_count,
-        input.rejected_claim_count,
-        input.max_claim_confidence_bps,
+    let credibility_bps = independent_source_credibility_bps(
+        fused_credibility_bps(
+            input.claim_count,
+            input.corroborated_claim_count,
+            input.rejected_claim_count,
+            input.max_claim_confidence_bps,
+        ),
+        input.cluster_source_count,
     );
+    let corroboration_tier = credibility_tier(credibility_bps);
     let freshness_tier = window.freshness_tier(input.latest_signal_at.as_deref());
     let trust_tier = trust_tier(&input.source_trust_scores);
     let density_tier = density_tier(
@@ -175,18 +181,16 @@ pub fn score_evidence(
 ) -> IntelPriorityBreakdown {
     let attention_tier = evidence_attention_tier(&input.linked_case_statuses, input.claim_count);
     let severity_tier = severity_tier(input.max_linked_severity);
-    let credibility_bps = fused_credibility_bps(
-        input.claim_count,
-        input.corroborated_claim_count,
-        input.rejected_claim_count,
-        input.max_claim_confidence_bps,
-    );
-    let corroboration_tier = corroboration_tier(
-        input.claim_count,
-        input.corroborated_claim_count,
-        input.rejected_claim_count,
-        input.max_claim_confidence_bps,
+    let credibility_bps = independent_source_credibility_bps(
+        fused_credibility_bps(
+            input.claim_count,
+            input.corroborated_claim_count,
+            input.rejected_claim_count,
+            input.max_claim_confidence_bps,
+        ),
+        input.cluster_source_count,
     );
+    let corroboration_tier = credibility_tier(credibility_bps);
     let freshness_tier = window.freshness_tier(input.observed_at.as_deref());
     let trust_tier = trust_tier(&input.source_trust_scores);
     let density_tier = bucket_usize(
@@ -261,20 +265,6 @@ pub(crate) fn severity_tier(severity: Option<WatchlistSeverity>) -> u8 {
     severity.map(WatchlistSeverity::weight).unwrap_or(0)
 }
 
-pub(crate) fn corroboration_tier(
-    claim_count: usize,
-    corroborated_claim_count: usize,
-    rejected_claim_count: usize,
-    max_claim_confidence_bps: u16,
-) -> u8 {
-    credibility_tier(fused_credibility_bps(
-        claim_count,
-        corroborated_claim_count,
-        rejected_claim_count,
-        max_claim_confidence_bps,
-    ))
-}
-
 pub(crate) fn trust_tier(scores: &[u8]) -> u8 {
     if scores.is_empty() {
         return 0;
@@ -310,7 +300,7 @@ fn case_attention_tier(status: CaseStatus, attached_to_case: bool) -> u8 {
 
 fn case_status_attention_tier(status: CaseStatus) -> u8 {
     match status {
-        CaseStatus::Closed => 0,
+        CaseStatus::Closed | CaseStatus::Merged => 0,
         CaseStatus::Monitoring => 1,
         CaseStatus::Open => 2,
         CaseStatus::BriefReady => 3,
@@ -427,6 +417,20 @@ fn claim_credibility_bps(
     attenuate_support(support, rejection)
 }
 
+/// Folds independent reporting into claim credibility: every source beyond the first in
+/// the same story cluster is one more noisy-or signal. Syndicated copies from a single
+/// source add nothing.
+pub(crate) fn independent_source_credibility_bps(
+    credibility_bps: u16,
+    cluster_source_count: usize,
+) -> u16 {
+    accumulate_noisy_or(
+        credibility_bps,
+        INDEPENDENT_SOURCE_SUPPORT_BPS,
+        cluster_source_count.saturating_sub(1),
+    )
+}
+
 fn proposal_support_bps(confidence_bps: u16) -> u16 {
     ((u32::from(confidence_bps.min(10_000)) * 7) / 10)
         .try_into()
@@ -497,6 +501,7 @@ mod tests {
                 severity: WatchlistSeverity::Critical,
                 source_trust_scores: vec![92, 88],
                 evidence_count: 2,
+                cluster_source_count: 1,
                 claim_count: 2,
                 corroborated_claim_count: 1,
                 rejected_claim_count: 0,
@@ -512,6 +517,7 @@ mod tests {
                 severity: WatchlistSeverity::Low,
                 source_trust_scores: vec![55, 58, 60],
                 evidence_count: 6,
+                cluster_source_count: 1,
                 claim_count: 6,
                 corroborated_claim_count: 0,
                 rejected_claim_count: 2,
@@ -535,6 +541,7 @@ mod tests {
                 linked_case_statuses: vec![CaseStatus::Escalated],
                 max_linked_severity: Some(WatchlistSeverity::High),
                 source_trust_scores: vec![90],
+                cluster_source_count: 1,
                 claim_count: 2,
                 corroborated_claim_count: 1,
                 rejected_claim_count: 0,
@@ -549,6 +556,7 @@ mod tests {
                 linked_case_statuses: Vec::new(),
                 max_linked_severity: None,
                 source_trust_scores: vec![55],
+                cluster_source_count: 1,
                 claim_count: 0,
                 corroborated_claim_count: 0,
                 rejected_claim_count: 0,
@@ -563,6 +571,36 @@ mod tests {
         assert_eq!(urgent.attention_tier, 5);
     }
 
+    #[test]
+    fn independent_sources_in_story_cluster_raise_corroboration() {
+        let window = window();
+        let input = EvidencePriorityInput {
+            linked_case_statuses: Vec::new(),
+            max_linked_severity: None,
+            source_trust_scores: vec![80],
+            cluster_source_count: 1,
+            claim_count: 1,
+            corroborated_claim_count: 0,
+            rejected_claim_count: 0,
+            max_claim_confidence_bps: 0,
+            observed_at: Some("2026-03-10T11:30:00Z".to_string()),
+            linked_case_count: 0,
+        };
+        let single = score_evidence(&input, &window);
+        let corroborated = score_evidence(
+            &EvidencePriorityInput {
+                cluster_source_count: 3,
+                ..input
+            },
+            &window,
+        );
+
+        assert_eq!(single.corroboration_tier, 0);
+        assert_eq!(corroborated.credibility_bps, 7_500);
+        assert_eq!(corroborated.corroboration_tier, 3);
+        assert!(corroborated.total > single.total);
+    }
+
     #[test]
     fn needs_review_claim_outranks_rejected_claim() {
         let window = window();
@@ -653,6 +691,28 @@ mod tests {
         assert!(many <= 10_000);
     }
 
+    #[test]
+    fn independent_source_credibility_is_monotone_and_bounded() {
+        for credibility in [0, 2_500, 7_000, 9_999, 10_000] {
+            assert_eq!(
+                independent_source_credibility_bps(credibility, 0),
+                credibility
+            );
+            assert_eq!(
+                independent_source_credibility_bps(credibility, 1),
+                credibility
+            );
+            let mut previous = credibility;
+            for sources in 2..=12 {
+                let lifted = independent_source_credibility_bps(credibility, sources);
+                assert!(previous <= lifted);
+                assert!(lifted <= 10_000);
+                assert!(lifted >= independent_source_credibility_bps(credibility / 2, sources));
+                previous = lifted;
+            }
+        }
+    }
+
     #[test]
     fn fixed_claim_budget_prefers_full_corroboration_over_open_proposals() {
         for confidence_bps in [0_u16, 1, 8_499, 8_500, 10_000] {
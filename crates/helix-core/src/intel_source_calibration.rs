@@ -0,0 +1,311 @@
+//! Source trust calibration from claim review outcomes.
+//!
+//! A source's reviewed claims update a Beta prior centred on its baseline trust score,
+//! the operator-entered value before any calibration. The posterior mean is the target
+//! trust; the proposed change is capped by how much the source has already moved within
+//! the current period. Applied changes are kept as trust history, which also answers
+//! what a source's trust was at any earlier time.
+
+use crate::HelixError;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+const MAX_PRIOR_WEIGHT: u16 = 1_000;
+const MAX_PERIOD_HOURS: u32 = 24 * 365;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustCalibrationPolicy {
+    /// Pseudo-claims the baseline trust score counts for in the prior.
+    pub prior_weight: u16,
+    /// Reviewed claims needed before a source gets a recommendation.
+    pub min_reviewed_claims: u16,
+    /// Largest total trust change per source within one period.
+    pub max_step_per_period: u8,
+    pub period_hours: u32,
+    pub min_trust_score: u8,
+    pub max_trust_score: u8,
+    /// Apply recommendations as soon as a review changes a source's counts.
+    #[serde(default)]
+    pub auto_apply: bool,
+}
+
+impl Default for TrustCalibrationPolicy {
+    fn default() -> Self {
+        Self {
+            prior_weight: 10,
+            min_reviewed_claims: 5,
+            max_step_per_period: 5,
+            period_hours: 168,
+            min_trust_score: 10,
+            max_trust_score: 95,
+            auto_apply: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrustAdjustmentOrigin {
+    /// Applied automatically under `auto_apply`.
+    Auto,
+    /// Accepted by an operator from the recommendation list.
+    Accepted,
+    /// Set by an operator re-creating the source with a different score.
+    Manual,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustCalibrationInput {
+    pub source_id: String,
+    pub trust_score: u8,
+    pub corroborated_claim_count: usize,
+    pub rejected_claim_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustRecommendation {
+    pub source_id: String,
+    pub current_trust_score: u8,
+    pub baseline_trust_score: u8,
+    /// Posterior mean before the period cap, clamped to the policy bounds.
+    pub target_trust_score: u8,
+    pub proposed_trust_score: u8,
+    pub posterior_bps: u16,
+    pub corroborated_claim_count: usize,
+    pub rejected_claim_count: usize,
+    /// True when the period cap held the proposal short of the target.
+    pub limited_by_period: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustHistoryEntry {
+    pub id: String,
+    pub source_id: String,
+    pub previous_trust_score: u8,
+    pub trust_score: u8,
+    pub origin: TrustAdjustmentOrigin,
+    pub posterior_bps: u16,
+    pub corroborated_claim_count: usize,
+    pub rejected_claim_count: usize,
+    pub recorded_at: String,
+}
+
+/// Proposes a trust change for one source. `history` holds that source's entries in
+/// `recorded_at` order. Returns `None` below the review minimum or when nothing would
+/// change.
+pub fn calibrate_source(
+    input: &TrustCalibrationInput,
+    history: &[TrustHistoryEntry],
+    policy: &TrustCalibrationPolicy,
+    as_of: DateTime<Utc>,
+) -> Option<TrustRecommendation> {
+    let reviewed = input.corroborated_claim_count + input.rejected_claim_count;
+    if reviewed < usize::from(policy.min_reviewed_claims) {
+        return None;
+    }
+    let baseline = baseline_trust_score(history, input.trust_score);
+    let prior_weight = u64::from(policy.prior_weight);
+    let numerator =
+        input.corroborated_claim_count as u64 * 100 + prior_weight * u64::from(baseline);
+    let denominator = (reviewed as u64 + prior_weight) * 100;
+    let posterior_bps = ((numerator * 10_000 + denominator / 2) / denominator) as u16;
+    let target = ((u32::from(posterior_bps) + 50) / 100) as u8;
+    let target = target.clamp(policy.min_trust_score, policy.max_trust_score);
+
+    let period_start = as_of - Duration::hours(i64::from(policy.period_hours));
+    let used = history
+        .iter()
+        .filter(|entry| entry.origin != TrustAdjustmentOrigin::Manual)
+        .filter(|entry| {
+            parse_time(&entry.recorded_at).is_some_and(|at| at > period_start && at <= as_of)
+        })
+        .map(|entry| u32::from(entry.trust_score.abs_diff(entry.previous_trust_score)))
+        .sum::<u32>();
+    let budget = u32::from(policy.max_step_per_period).saturating_sub(used) as u8;
+    let proposed = if target >= input.trust_score {
+        input.trust_score.saturating_add(budget).min(target)
+    } else {
+        input.trust_score.saturating_sub(budget).max(target)
+    };
+    (proposed != input.trust_score).then(|| TrustRecommendation {
+        source_id: input.source_id.clone(),
+        current_trust_score: input.trust_score,
+        baseline_trust_score: baseline,
+        target_trust_score: target,
+        proposed_trust_score: proposed,
+        posterior_bps,
+        corroborated_claim_count: input.corroborated_claim_count,
+        rejected_claim_count: input.rejected_claim_count,
+        limited_by_period: proposed != target,
+    })
+}
+
+/// Trust score calibration is anchored to: the latest manual setting, else the score before
+/// the first calibration, or `current` when there is no history.
+pub fn baseline_trust_score(history: &[TrustHistoryEntry], current: u8) -> u8 {
+    history
+        .iter()
+        .rev()
+        .find(|entry| entry.origin == TrustAdjustmentOrigin::Manual)
+        .map(|entry| entry.trust_score)
+        .or_else(|| history.first().map(|entry| entry.previous_trust_score))
+        .unwrap_or(current)
+}
+
+/// Trust score in effect at `at`: the value before the first change recorded after `at`,
+/// or `current` when no change followed it.
+pub fn trust_score_at(history: &[TrustHistoryEntry], current: u8, at: DateTime<Utc>) -> u8 {
+    history
+        .iter()
+        .find(|entry| parse_time(&entry.recorded_at).is_some_and(|recorded| recorded > at))
+        .map_or(current, |entry| entry.previous_trust_score)
+}
+
+pub fn canonicalize_trust_calibration_policy(
+    policy: TrustCalibrationPolicy,
+) -> Result<TrustCalibrationPolicy, HelixError> {
+    if policy.prior_weight > MAX_PRIOR_WEIGHT {
+        return Err(HelixError::validation_error(
+            "trust_calibration.prior_weight".to_string(),
+            format!("must be at most {MAX_PRIOR_WEIGHT}"),
+        ));
+    }
+    if policy.period_hours == 0 || policy.period_hours > MAX_PERIOD_HOURS {
+        return Err(HelixError::validation_error(
+            "trust_calibration.period_hours".to_string(),
+            format!("must be between 1 and {MAX_PERIOD_HOURS}"),
+        ));
+    }
+    if policy.max_trust_score > 100 || policy.min_trust_score > policy.max_trust_score {
+        return Err(HelixError::validation_error(
+            "trust_calibration.max_trust_score",
+            "bounds must satisfy min_trust_score <= max_trust_score <= 100",
+        ));
+    }
+    Ok(policy)
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|value| value.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        parse_time(value).unwrap()
+    }
+
+    fn input(trust_score: u8, corroborated: usize, rejected: usize) -> TrustCalibrationInput {
+        TrustCalibrationInput {
+            source_id: "rss".to_string(),
+            trust_score,
+            corroborated_claim_count: corroborated,
+            rejected_claim_count: rejected,
+        }
+    }
+
+    fn entry(previous: u8, trust_score: u8, recorded_at: &str) -> TrustHistoryEntry {
+        TrustHistoryEntry {
+            id: format!("trust_{recorded_at}"),
+            source_id: "rss".to_string(),
+            previous_trust_score: previous,
+            trust_score,
+            origin: TrustAdjustmentOrigin::Accepted,
+            posterior_bps: 0,
+            corroborated_claim_count: 0,
+            rejected_claim_count: 0,
+            recorded_at: recorded_at.to_string(),
+        }
+    }
+
+    #[test]
+    fn rejected_claims_pull_trust_down_within_the_period_cap() {
+        let policy = TrustCalibrationPolicy::default();
+        let as_of = at("2026-03-10T00:00:00Z");
+        // Prior 80 with weight 10, then 2 corroborated and 8 rejected: (200 + 800) / 2000.
+        let recommendation = calibrate_source(&input(80, 2, 8), &[], &policy, as_of).unwrap();
+        assert_eq!(recommendation.posterior_bps, 5_000);
+        assert_eq!(recommendation.target_trust_score, 50);
+        assert_eq!(recommendation.proposed_trust_score, 75);
+        assert!(recommendation.limited_by_period);
+
+        let history = [entry(80, 77, "2026-03-09T00:00:00Z")];
+        let recommendation = calibrate_source(&input(77, 2, 8), &history, &policy, as_of).unwrap();
+        assert_eq!(recommendation.baseline_trust_score, 80);
+        assert_eq!(recommendation.proposed_trust_score, 75);
+
+        let history = [
+            entry(80, 77, "2026-03-09T00:00:00Z"),
+            entry(77, 75, "2026-03-09T12:00:00Z"),
+        ];
+        assert_eq!(
+            calibrate_source(&input(75, 2, 8), &history, &policy, as_of),
+            None
+        );
+        let later = at("2026-03-20T00:00:00Z");
+        assert_eq!(
+            calibrate_source(&input(75, 2, 8), &history, &policy, later)
+                .unwrap()
+                .proposed_trust_score,
+            70
+        );
+    }
+
+    #[test]
+    fn sources_need_enough_reviews_and_stay_in_bounds() {
+        let policy = TrustCalibrationPolicy {
+            max_step_per_period: 100,
+            ..TrustCalibrationPolicy::default()
+        };
+        let as_of = at("2026-03-10T00:00:00Z");
+        assert_eq!(
+            calibrate_source(&input(80, 2, 2), &[], &policy, as_of),
+            None
+        );
+        let recommendation = calibrate_source(&input(90, 200, 0), &[], &policy, as_of).unwrap();
+        assert_eq!(recommendation.proposed_trust_score, 95);
+        assert!(!recommendation.limited_by_period);
+        assert!(
+            canonicalize_trust_calibration_policy(TrustCalibrationPolicy {
+                min_trust_score: 60,
+                max_trust_score: 50,
+                ..policy
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn manual_settings_rebase_calibration_outside_the_period_cap() {
+        let policy = TrustCalibrationPolicy::default();
+        let as_of = at("2026-03-10T00:00:00Z");
+        let history = [
+            entry(80, 77, "2026-03-08T00:00:00Z"),
+            TrustHistoryEntry {
+                origin: TrustAdjustmentOrigin::Manual,
+                ..entry(77, 60, "2026-03-09T00:00:00Z")
+            },
+        ];
+        // Prior 60 with weight 10: (200 + 600) / 2000; only the automatic 3 points count.
+        let recommendation = calibrate_source(&input(60, 2, 8), &history, &policy, as_of).unwrap();
+        assert_eq!(recommendation.baseline_trust_score, 60);
+        assert_eq!(recommendation.target_trust_score, 40);
+        assert_eq!(recommendation.proposed_trust_score, 58);
+        assert_eq!(trust_score_at(&history, 60, at("2026-03-08T12:00:00Z")), 77);
+    }
+
+    #[test]
+    fn trust_history_answers_past_scores() {
+        let history = [
+            entry(80, 77, "2026-03-09T00:00:00Z"),
+            entry(77, 75, "2026-03-12T00:00:00Z"),
+        ];
+        assert_eq!(trust_score_at(&history, 75, at("2026-03-01T00:00:00Z")), 80);
+        assert_eq!(trust_score_at(&history, 75, at("2026-03-10T00:00:00Z")), 77);
+        assert_eq!(trust_score_at(&history, 75, at("2026-03-13T00:00:00Z")), 75);
+    }
+}
@@ -0,0 +1,694 @@
+//! Boolean query expressions for watchlists.
+//!
+//! Grammar, loosest binding first:
+//!
+//! ```text
+//! query     := and ("OR" and)*
+//! and       := unary (["AND"] unary)*        adjacent clauses are ANDed
+//! unary     := "NOT" unary | primary
+//! primary   := "(" query ")" | term ("NEAR/" n term)?
+//! term      := [field ":"] (word | "\"" phrase "\"")
+//! field     := title | summary | content | tag | entity
+//! ```
+//!
+//! Operators are upper case. Words match whole tokens, so `ransomware` does not match
+//! `anti-ransomware`; `*` inside a word matches any run of characters. Unscoped terms
+//! search the title, summary, content, and tags. `tag:` and `entity:` compare against
+//! whole labels. `a NEAR/5 b` holds when both appear in one text field within five token
+//! positions of each other.
+
+use crate::HelixError;
+
+pub const MAX_QUERY_LEN: usize = 1_024;
+const MAX_QUERY_TERMS: usize = 64;
+const MAX_QUERY_DEPTH: usize = 16;
+const MAX_NEAR_DISTANCE: usize = 50;
+const MIN_WILDCARD_LITERAL_CHARS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryField {
+    Title,
+    Summary,
+    Content,
+    Tag,
+    Entity,
+}
+
+impl QueryField {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "title" => Some(Self::Title),
+            "summary" => Some(Self::Summary),
+            "content" => Some(Self::Content),
+            "tag" => Some(Self::Tag),
+            "entity" => Some(Self::Entity),
+            _ => None,
+        }
+    }
+
+    fn is_label(self) -> bool {
+        matches!(self, Self::Tag | Self::Entity)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct QueryTerm {
+    field: Option<QueryField>,
+    words: Vec<String>,
+}
+
+impl QueryTerm {
+    fn display(&self) -> String {
+        self.words.join(" ")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryNode {
+    Term(QueryTerm),
+    Near(QueryTerm, QueryTerm, usize),
+    Not(Box<QueryNode>),
+    And(Vec<QueryNode>),
+    Or(Vec<QueryNode>),
+}
+
+/// A parsed, validated watchlist query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchlistQuery {
+    root: QueryNode,
+}
+
+/// Positive terms that held when a query matched, split the way `WatchlistHit` reports
+/// them: `entity:` terms carry the entity label they matched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryMatch {
+    pub keywords: Vec<String>,
+    pub entities: Vec<String>,
+}
+
+/// Tokenized evidence text and labels a query runs against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryDocument {
+    title: Vec<String>,
+    summary: Vec<String>,
+    content: Vec<String>,
+    tag_tokens: Vec<String>,
+    tags: Vec<String>,
+    entities: Vec<String>,
+}
+
+impl QueryDocument {
+    pub fn new<T, E>(title: &str, summary: &str, content: &str, tags: T, entities: E) -> Self
+    where
+        T: IntoIterator,
+        T::Item: AsRef<str>,
+        E: IntoIterator,
+        E::Item: AsRef<str>,
+    {
+        let tags = normalized_labels(tags);
+        Self {
+            title: text_tokens(title),
+            summary: text_tokens(summary),
+            content: text_tokens(content),
+            tag_tokens: text_tokens(&tags.join(" ")),
+            tags,
+            entities: normalized_labels(entities),
+        }
+    }
+
+    fn text_fields(&self, field: Option<QueryField>) -> Vec<&[String]> {
+        match field {
+            None => vec![&self.title, &self.summary, &self.content, &self.tag_tokens],
+            Some(QueryField::Title) => vec![&self.title],
+            Some(QueryField::Summary) => vec![&self.summary],
+            Some(QueryField::Content) => vec![&self.content],
+            Some(QueryField::Tag | QueryField::Entity) => Vec::new(),
+        }
+    }
+
+    fn labels(&self, field: QueryField) -> &[String] {
+        match field {
+            QueryField::Tag => &self.tags,
+            _ => &self.entities,
+        }
+    }
+}
+
+impl WatchlistQuery {
+    pub fn parse(input: &str) -> Result<Self, HelixError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(query_error("query is empty"));
+        }
+        if input.len() > MAX_QUERY_LEN {
+            return Err(query_error(format!(
+                "query is longer than {MAX_QUERY_LEN} bytes"
+            )));
+        }
+        let tokens = lex(input)?;
+        let term_count = tokens
+            .iter()
+            .filter(|token| matches!(token, Token::Term(_)))
+            .count();
+        if term_count > MAX_QUERY_TERMS {
+            return Err(query_error(format!(
+                "query has more than {MAX_QUERY_TERMS} terms"
+            )));
+        }
+
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+        let root = parser.parse_or(0)?;
+        if let Some(token) = parser.peek() {
+            return Err(query_error(format!("unexpected {}", token.describe())));
+        }
+        let query = Self { root };
+        if query.evaluate(&QueryDocument::default()).is_some() {
+            return Err(query_error(
+                "query matches evidence with no text; it needs a term outside NOT",
+            ));
+        }
+        Ok(query)
+    }
+
+    /// The terms behind a match, or `None` when the document does not match.
+    pub fn evaluate(&self, document: &QueryDocument) -> Option<QueryMatch> {
+        let mut matched = evaluate_node(&self.root, document)?;
+        matched.keywords.sort();
+        matched.keywords.dedup();
+        matched.entities.sort();
+        matched.entities.dedup();
+        Some(matched)
+    }
+}
+
+fn evaluate_node(node: &QueryNode, document: &QueryDocument) -> Option<QueryMatch> {
+    match node {
+        QueryNode::Term(term) => evaluate_term(term, document),
+        QueryNode::Near(left, right, distance) => {
+            // Parsing guarantees scoped NEAR terms share one field.
+            let near = document
+                .text_fields(left.field.or(right.field))
+                .into_iter()
+                .any(|tokens| {
+                    let left_spans = phrase_spans(tokens, &left.words);
+                    let right_spans = phrase_spans(tokens, &right.words);
+                    left_spans.iter().any(|left_span| {
+                        right_spans
+                            .iter()
+                            .any(|right_span| span_gap(*left_span, *right_span) <= *distance)
+                    })
+                });
+            near.then(|| QueryMatch {
+                keywords: vec![left.display(), right.display()],
+                entities: Vec::new(),
+            })
+        }
+        QueryNode::Not(inner) => match evaluate_node(inner, document) {
+            Some(_) => None,
+            None => Some(QueryMatch::default()),
+        },
+        QueryNode::And(children) => {
+            let mut matched = QueryMatch::default();
+            for child in children {
+                let child = evaluate_node(child, document)?;
+                matched.keywords.extend(child.keywords);
+                matched.entities.extend(child.entities);
+            }
+            Some(matched)
+        }
+        QueryNode::Or(children) => {
+            let mut matched: Option<QueryMatch> = None;
+            for child in children {
+                if let Some(child) = evaluate_node(child, document) {
+                    let merged = matched.get_or_insert_with(QueryMatch::default);
+                    merged.keywords.extend(child.keywords);
+                    merged.entities.extend(child.entities);
+                }
+            }
+            matched
+        }
+    }
+}
+
+fn evaluate_term(term: &QueryTerm, document: &QueryDocument) -> Option<QueryMatch> {
+    match term.field {
+        Some(field) if field.is_label() => {
+            let pattern = term.display();
+            let labels = document
+                .labels(field)
+                .iter()
+                .filter(|label| glob_matches(&pattern, label))
+                .cloned()
+                .collect::<Vec<_>>();
+            if labels.is_empty() {
+                None
+            } else if field == QueryField::Entity {
+                Some(QueryMatch {
+                    keywords: Vec::new(),
+                    entities: labels,
+                })
+            } else {
+                Some(QueryMatch {
+                    keywords: vec![pattern],
+                    entities: Vec::new(),
+                })
+            }
+        }
+        _ => document
+            .text_fields(term.field)
+            .into_iter()
+            .any(|tokens| !phrase_spans(tokens, &term.words).is_empty())
+            .then(|| QueryMatch {
+                keywords: vec![term.display()],
+                entities: Vec::new(),
+            }),
+    }
+}
+
+/// Token ranges `[start, end)` where `words` occur consecutively.
+fn phrase_spans(tokens: &[String], words: &[String]) -> Vec<(usize, usize)> {
+    if words.is_empty() || tokens.len() < words.len() {
+        return Vec::new();
+    }
+    tokens
+        .windows(words.len())
+        .enumerate()
+        .filter(|(_, window)| {
+            window
+                .iter()
+                .zip(words)
+                .all(|(token, word)| glob_matches(word, token))
+        })
+        .map(|(start, _)| (start, start + words.len()))
+        .collect()
+}
+
+/// Token positions separating two spans; overlapping spans are zero apart.
+fn span_gap(left: (usize, usize), right: (usize, usize)) -> usize {
+    if left.1 <= right.0 {
+        right.0 + 1 - left.1
+    } else if right.1 <= left.0 {
+        left.0 + 1 - right.1
+    } else {
+        0
+    }
+}
+
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    if !pattern.contains('*') {
+        return pattern == value;
+    }
+    let parts = pattern.split('*').collect::<Vec<_>>();
+    let (first, rest) = parts.split_first().expect("split yields at least one part");
+    let Some(mut remaining) = value.strip_prefix(first) else {
+        return false;
+    };
+    let (last, middle) = rest.split_last().expect("pattern contains a wildcard");
+    for part in middle {
+        match remaining.find(part) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.len() >= last.len() && remaining.ends_with(last)
+}
+
+/// Lowercase word tokens. Hyphens, apostrophes, dots, and `@` inside a word keep it whole
+/// (`anti-ransomware`, `orion.io`); punctuation around a word is dropped.
+fn text_tokens(text: &str) -> Vec<String> {
+    split_tokens(text, false)
+}
+
+fn split_tokens(text: &str, keep_wildcards: bool) -> Vec<String> {
+    let is_word_char = |ch: char| ch.is_alphanumeric() || (keep_wildcards && ch == '*');
+    text.split(|ch: char| !(is_word_char(ch) || matches!(ch, '-' | '\'' | '’' | '.' | '_' | '@')))
+        .map(|token| token.trim_matches(|ch: char| !is_word_char(ch)))
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn normalized_labels<I>(labels: I) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    labels
+        .into_iter()
+        .map(|label| {
+            label
+                .as_ref()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        })
+        .filter(|label| !label.is_empty())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    LeftParen,
+    RightParen,
+    And,
+    Or,
+    Not,
+    Near(usize),
+    Term(QueryTerm),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::LeftParen => "`(`".to_string(),
+            Self::RightParen => "`)`".to_string(),
+            Self::And => "`AND`".to_string(),
+            Self::Or => "`OR`".to_string(),
+            Self::Not => "`NOT`".to_string(),
+            Self::Near(distance) => format!("`NEAR/{distance}`"),
+            Self::Term(term) => format!("term `{}`", term.display()),
+        }
+    }
+}
+
+fn lex(input: &str) -> Result<Vec<Token>, HelixError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    while let Some(&(start, ch)) = chars.peek() {
+        match ch {
+            _ if ch.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LeftParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RightParen);
+            }
+            '"' => {
+                chars.next();
+                let phrase = read_phrase(input, &mut chars)?;
+                tokens.push(Token::Term(term(None, phrase)?));
+            }
+            _ => {
+                let mut end = input.len();
+                while let Some(&(index, next)) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '(' | ')' | '"') {
+                        end = index;
+                        break;
+                    }
+                    chars.next();
+                }
+                let word = &input[start..end];
+                tokens.push(lex_word(input, word, &mut chars)?);
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn lex_word(
+    input: &str,
+    word: &str,
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+) -> Result<Token, HelixError> {
+    match word {
+        "AND" => return Ok(Token::And),
+        "OR" => return Ok(Token::Or),
+        "NOT" => return Ok(Token::Not),
+        _ => {}
+    }
+    if let Some(distance) = word.strip_prefix("NEAR/") {
+        let distance = distance
+            .parse::<usize>()
+            .ok()
+            .filter(|distance| (1..=MAX_NEAR_DISTANCE).contains(distance))
+            .ok_or_else(|| {
+                query_error(format!(
+                    "`{word}` needs a distance between 1 and {MAX_NEAR_DISTANCE}"
+                ))
+            })?;
+        return Ok(Token::Near(distance));
+    }
+
+    let Some((prefix, value)) = word
+        .split_once(':')
+        .filter(|(prefix, _)| !prefix.is_empty() && prefix.chars().all(char::is_alphabetic))
+    else {
+        return Ok(Token::Term(term(None, word)?));
+    };
+    let field = QueryField::parse(&prefix.to_lowercase()).ok_or_else(|| {
+        query_error(format!(
+            "unknown field `{prefix}`; use title, summary, content, tag, or entity, or quote the term"
+        ))
+    })?;
+    if !value.is_empty() {
+        return Ok(Token::Term(term(Some(field), value)?));
+    }
+    match chars.peek() {
+        Some(&(_, '"')) => {
+            chars.next();
+            let phrase = read_phrase(input, chars)?;
+            Ok(Token::Term(term(Some(field), phrase)?))
+        }
+        _ => Err(query_error(format!("field `{prefix}:` has no term"))),
+    }
+}
+
+fn read_phrase<'a>(
+    input: &'a str,
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+) -> Result<&'a str, HelixError> {
+    let start = chars.peek().map(|(index, _)| *index).unwrap_or(input.len());
+    for (index, ch) in chars.by_ref() {
+        if ch == '"' {
+            return Ok(&input[start..index]);
+        }
+    }
+    Err(query_error("unterminated quoted phrase"))
+}
+
+fn term(field: Option<QueryField>, value: &str) -> Result<QueryTerm, HelixError> {
+    let words = split_tokens(value, true);
+    if words.is_empty() {
+        return Err(query_error(format!(
+            "term `{}` has no letters or digits",
+            value.trim()
+        )));
+    }
+    if let Some(word) = words.iter().find(|word| {
+        word.contains('*')
+            && word.chars().filter(|ch| *ch != '*').count() < MIN_WILDCARD_LITERAL_CHARS
+    }) {
+        return Err(query_error(format!(
+            "wildcard `{word}` needs at least {MIN_WILDCARD_LITERAL_CHARS} other characters"
+        )));
+    }
+    Ok(QueryTerm { field, words })
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self, depth: usize) -> Result<QueryNode, HelixError> {
+        if depth > MAX_QUERY_DEPTH {
+            return Err(query_error(format!(
+                "query nests deeper than {MAX_QUERY_DEPTH} levels"
+            )));
+        }
+        let mut children = vec![self.parse_and(depth)?];
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            children.push(self.parse_and(depth)?);
+        }
+        Ok(collapse(children, QueryNode::Or))
+    }
+
+    fn parse_and(&mut self, depth: usize) -> Result<QueryNode, HelixError> {
+        let mut children = vec![self.parse_unary(depth)?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.next();
+                    children.push(self.parse_unary(depth)?);
+                }
+                Some(Token::LeftParen | Token::Not | Token::Term(_)) => {
+                    children.push(self.parse_unary(depth)?);
+                }
+                _ => break,
+            }
+        }
+        Ok(collapse(children, QueryNode::And))
+    }
+
+    fn parse_unary(&mut self, depth: usize) -> Result<QueryNode, HelixError> {
+        if self.peek() == Some(&Token::Not) {
+            self.next();
+            return Ok(QueryNode::Not(Box::new(self.parse_unary(depth + 1)?)));
+        }
+        self.parse_primary(depth)
+    }
+
+    fn parse_primary(&mut self, depth: usize) -> Result<QueryNode, HelixError> {
+        match self.next() {
+            Some(Token::LeftParen) => {
+                let inner = self.parse_or(depth + 1)?;
+                match self.next() {
+                    Some(Token::RightParen) => Ok(inner),
+                    _ => Err(query_error("missing closing `)`")),
+                }
+            }
+            Some(Token::Term(left)) => {
+                let Some(Token::Near(distance)) = self.peek().cloned() else {
+                    return Ok(QueryNode::Term(left));
+                };
+                self.next();
+                let Some(Token::Term(right)) = self.next() else {
+                    return Err(query_error(format!(
+                        "`NEAR/{distance}` needs a term after it"
+                    )));
+                };
+                if [left.field, right.field]
+                    .into_iter()
+                    .flatten()
+                    .any(QueryField::is_label)
+                {
+                    return Err(query_error(
+                        "`NEAR` only applies to text, not tag or entity terms",
+                    ));
+                }
+                if left.field.is_some() && right.field.is_some() && left.field != right.field {
+                    return Err(query_error("`NEAR` terms must search the same field"));
+                }
+                Ok(QueryNode::Near(left, right, distance))
+            }
+            Some(token) => Err(query_error(format!("unexpected {}", token.describe()))),
+            None => Err(query_error("query ends where a term was expected")),
+        }
+    }
+}
+
+fn collapse(mut children: Vec<QueryNode>, combine: fn(Vec<QueryNode>) -> QueryNode) -> QueryNode {
+    if children.len() == 1 {
+        children.remove(0)
+    } else {
+        combine(children)
+    }
+}
+
+fn query_error(message: impl Into<String>) -> HelixError {
+    HelixError::validation_error("watchlist.query".to_string(), message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(title: &str, content: &str) -> QueryDocument {
+        QueryDocument::new(
+            title,
+            "",
+            content,
+            ["security", "Threat Watch"],
+            ["orion dynamics", "alice north"],
+        )
+    }
+
+    fn matches(query: &str, document: &QueryDocument) -> Option<QueryMatch> {
+        WatchlistQuery::parse(query).unwrap().evaluate(document)
+    }
+
+    #[test]
+    fn words_match_whole_tokens_and_wildcards_extend_them() {
+        let vendor = document("Funding round", "An anti-ransomware vendor raised money.");
+        assert_eq!(matches("ransomware", &vendor), None);
+        assert_eq!(
+            matches("*ransomware", &vendor).unwrap().keywords,
+            vec!["*ransomware".to_string()]
+        );
+
+        let attack = document(
+            "Ransomware hits Orion",
+            "Ransomware crews encrypted servers.",
+        );
+        assert!(matches("ransomware", &attack).is_some());
+        assert!(matches("encrypt*", &attack).is_some());
+        assert!(matches("\"crews encrypted\"", &attack).is_some());
+        assert!(matches("\"encrypted crews\"", &attack).is_none());
+    }
+
+    #[test]
+    fn boolean_operators_fields_and_proximity() {
+        let doc = document(
+            "Orion Dynamics confirms ransomware attack",
+            "The attack on Orion Dynamics began Monday and encrypted payroll systems.",
+        );
+        let matched = matches(
+            "title:ransomware AND entity:orion* NOT (tag:phishing OR content:\"test exercise\")",
+            &doc,
+        )
+        .unwrap();
+        assert_eq!(matched.keywords, vec!["ransomware".to_string()]);
+        assert_eq!(matched.entities, vec!["orion dynamics".to_string()]);
+
+        assert!(matches("ransomware NOT tag:security", &doc).is_none());
+        assert!(matches("tag:\"threat watch\" encrypted", &doc).is_some());
+        assert!(matches("content:ransomware OR title:payroll", &doc).is_none());
+        assert!(matches("attack NEAR/4 payroll", &doc).is_none());
+        assert_eq!(
+            matches("content:attack NEAR/10 payroll", &doc)
+                .unwrap()
+                .keywords,
+            vec!["attack".to_string(), "payroll".to_string()]
+        );
+        assert!(matches("began NEAR/1 monday", &doc).is_some());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_or_unbounded_queries() {
+        for query in [
+            "",
+            "NOT ransomware",
+            "ransomware OR NOT phishing",
+            "(ransomware",
+            "ransomware AND",
+            "\"unterminated phrase",
+            "author:alice",
+            "title:",
+            "r*",
+            "attack NEAR/0 payroll",
+            "attack NEAR/5 entity:orion",
+            "title:attack NEAR/5 content:payroll",
+            "OR ransomware",
+        ] {
+            assert!(
+                matches!(
+                    WatchlistQuery::parse(query),
+                    Err(HelixError::ValidationError { .. })
+                ),
+                "{query} should be rejected"
+            );
+        }
+        let nested = format!("{}ransomware{}", "(".repeat(20), ")".repeat(20));
+        assert!(WatchlistQuery::parse(&nested).is_err());
+    }
+}
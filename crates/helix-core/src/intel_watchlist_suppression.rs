@@ -0,0 +1,392 @@
+//! Per-watchlist suppression rules for known-benign hits.
+//!
+//! A suppressed hit is still produced, with `suppressed_by` naming the rule, but it does
+//! not feed trigger windows or open and update cases. Snoozes compare against the
+//! evidence `observed_at` rather than the wall clock, so replays and backtests agree
+//! with live ingest.
+
+use crate::intel_desk::{EvidenceItem, Watchlist, WatchlistHit};
+use crate::HelixError;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const MAX_WATCHLIST_SUPPRESSIONS: usize = 64;
+const MAX_COOCCURRENCE_KEYWORDS: usize = 8;
+const MAX_SUPPRESSION_NOTE_LEN: usize = 512;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SuppressionRule {
+    /// Hits on evidence from this source.
+    Source { source_id: String },
+    /// Hits on evidence that mentions this entity.
+    Entity { entity: String },
+    /// Hits on evidence whose URL host is this domain or one of its subdomains.
+    UrlDomain { domain: String },
+    /// Hits on evidence whose text contains every one of these keywords.
+    KeywordCooccurrence { keywords: Vec<String> },
+    /// Hits on evidence observed before `until`; only those mentioning `entity` when set.
+    Snooze {
+        until: String,
+        #[serde(default)]
+        entity: Option<String>,
+    },
+    /// Hits on this one evidence item, marked not relevant to the watchlist.
+    Evidence { evidence_id: String },
+}
+
+impl SuppressionRule {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Source { .. } => "source",
+            Self::Entity { .. } => "entity",
+            Self::UrlDomain { .. } => "url_domain",
+            Self::KeywordCooccurrence { .. } => "keyword_cooccurrence",
+            Self::Snooze { .. } => "snooze",
+            Self::Evidence { .. } => "evidence",
+        }
+    }
+
+    fn matches(&self, evidence: &EvidenceItem, hit: &WatchlistHit) -> bool {
+        match self {
+            Self::Source { source_id } => evidence.source_id == *source_id,
+            Self::Entity { entity } => mentions_entity(evidence, hit, entity),
+            Self::UrlDomain { domain } => {
+                evidence
+                    .url
+                    .as_deref()
+                    .and_then(url_host)
+                    .is_some_and(|host| {
+                        host == *domain
+                            || host
+                                .strip_suffix(domain.as_str())
+                                .is_some_and(|prefix| prefix.ends_with('.'))
+                    })
+            }
+            Self::KeywordCooccurrence { keywords } => {
+                let searchable = format!(
+                    "{}\n{}\n{}\n{}",
+                    evidence.title,
+                    evidence.summary,
+                    evidence.content,
+                    evidence.tags.join(" ")
+                )
+                .to_lowercase();
+                keywords
+                    .iter()
+                    .all(|keyword| searchable.contains(keyword.as_str()))
+            }
+            Self::Snooze { until, entity } => {
+                let observed_before = parse_timestamp(&evidence.observed_at)
+                    .zip(parse_timestamp(until))
+                    .is_some_and(|(observed_at, until)| observed_at < until);
+                observed_before
+                    && entity
+                        .as_deref()
+                        .is_none_or(|entity| mentions_entity(evidence, hit, entity))
+            }
+            Self::Evidence { evidence_id } => evidence.id == *evidence_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistSuppression {
+    pub id: String,
+    #[serde(flatten)]
+    pub rule: SuppressionRule,
+    /// Why the operator added the rule.
+    #[serde(default)]
+    pub note: String,
+}
+
+impl WatchlistSuppression {
+    /// `WatchlistHit::suppressed_by` for hits this rule suppresses.
+    pub fn label(&self) -> String {
+        format!("{}:{}", self.rule.kind(), self.id)
+    }
+}
+
+/// Trims and lowercases rule values and validates their shape. Entity labels are
+/// expected to be resolved to canonical ids by the caller.
+pub fn canonicalize_suppression_rule(rule: SuppressionRule) -> Result<SuppressionRule, HelixError> {
+    let required_id = |context: &str, value: &str| {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(HelixError::validation_error(context, "must not be empty"));
+        }
+        Ok(value)
+    };
+    let required =
+        |context: &str, value: &str| required_id(context, value).map(|value| value.to_lowercase());
+    Ok(match rule {
+        SuppressionRule::Source { source_id } => SuppressionRule::Source {
+            source_id: required_id("suppression.source_id", &source_id)?,
+        },
+        SuppressionRule::Entity { entity } => SuppressionRule::Entity {
+            entity: required("suppression.entity", &entity)?,
+        },
+        SuppressionRule::UrlDomain { domain } => {
+            let domain = required("suppression.domain", &domain)?;
+            let domain = domain.trim_start_matches("*.").trim_end_matches('.');
+            if domain.is_empty()
+                || !domain.contains('.')
+                || !domain
+                    .chars()
+                    .all(|ch| ch.is_alphanumeric() || ch == '-' || ch == '.')
+            {
+                return Err(HelixError::validation_error(
+                    "suppression.domain",
+                    "must be a host name such as example.com",
+                ));
+            }
+            SuppressionRule::UrlDomain {
+                domain: domain.to_string(),
+            }
+        }
+        SuppressionRule::KeywordCooccurrence { keywords } => {
+            let mut keywords = keywords
+                .iter()
+                .map(|keyword| keyword.trim().to_lowercase())
+                .filter(|keyword| !keyword.is_empty())
+                .collect::<Vec<_>>();
+            keywords.sort();
+            keywords.dedup();
+            if keywords.is_empty() || keywords.len() > MAX_COOCCURRENCE_KEYWORDS {
+                return Err(HelixError::validation_error(
+                    "suppression.keywords".to_string(),
+                    format!("must list 1 to {MAX_COOCCURRENCE_KEYWORDS} keywords"),
+                ));
+            }
+            SuppressionRule::KeywordCooccurrence { keywords }
+        }
+        SuppressionRule::Snooze { until, entity } => {
+            let until = parse_timestamp(&until).ok_or_else(|| {
+                HelixError::validation_error("suppression.until", "must be an RFC 3339 timestamp")
+            })?;
+            SuppressionRule::Snooze {
+                until: until.to_rfc3339_opts(SecondsFormat::Secs, true),
+                entity: entity
+                    .map(|entity| required("suppression.entity", &entity))
+                    .transpose()?,
+            }
+        }
+        SuppressionRule::Evidence { evidence_id } => SuppressionRule::Evidence {
+            evidence_id: required_id("suppression.evidence_id", &evidence_id)?,
+        },
+    })
+}
+
+pub fn canonicalize_suppression_note(note: &str) -> Result<String, HelixError> {
+    let note = note.trim();
+    if note.len() > MAX_SUPPRESSION_NOTE_LEN {
+        return Err(HelixError::validation_error(
+            "suppression.note".to_string(),
+            format!("must be at most {MAX_SUPPRESSION_NOTE_LEN} bytes"),
+        ));
+    }
+    Ok(note.to_string())
+}
+
+/// Sets `suppressed_by` on hits that one of their watchlist's rules matches; the first
+/// matching rule in the watchlist's order wins.
+pub fn apply_watchlist_suppressions(
+    watchlists: &[Watchlist],
+    evidence: &EvidenceItem,
+    hits: Vec<WatchlistHit>,
+) -> Vec<WatchlistHit> {
+    hits.into_iter()
+        .map(|hit| {
+            let suppressed_by = watchlists
+                .iter()
+                .find(|watchlist| watchlist.id == hit.watchlist_id)
+                .and_then(|watchlist| {
+                    watchlist
+                        .suppressions
+                        .iter()
+                        .find(|suppression| suppression.rule.matches(evidence, &hit))
+                })
+                .map(WatchlistSuppression::label);
+            WatchlistHit {
+                suppressed_by,
+                ..hit
+            }
+        })
+        .collect()
+}
+
+fn mentions_entity(evidence: &EvidenceItem, hit: &WatchlistHit, entity: &str) -> bool {
+    hit.matched_entities.iter().any(|value| value == entity)
+        || evidence.entity_labels.iter().any(|value| value == entity)
+        || evidence
+            .extracted_entities
+            .iter()
+            .any(|extracted| extracted.label == entity)
+}
+
+fn url_host(value: &str) -> Option<String> {
+    url::Url::parse(value.trim())
+        .ok()?
+        .host_str()
+        .map(|host| host.trim_end_matches('.').to_lowercase())
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|value| value.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intel_desk::WatchlistSeverity;
+
+    fn evidence() -> EvidenceItem {
+        serde_json::from_value(serde_json::json!({
+            "id": "evidence_alpha",
+            "source_id": "rss_vendor_press",
+            "title": "Orion Dynamics ransomware tabletop exercise",
+            "summary": "Quarterly drill",
+            "content": "The tabletop exercise simulated a ransomware outbreak.",
+            "url": "https://news.orion.example.com/drill",
+            "observed_at": "2026-03-06T12:00:00Z",
+            "tags": [],
+            "entity_labels": ["orion dynamics"],
+            "provenance_hash": "abc123"
+        }))
+        .unwrap()
+    }
+
+    fn hit() -> WatchlistHit {
+        WatchlistHit {
+            watchlist_id: "ransomware".to_string(),
+            watchlist_name: "Ransomware".to_string(),
+            evidence_id: "evidence_alpha".to_string(),
+            severity: WatchlistSeverity::High,
+            matched_keywords: vec!["ransomware".to_string()],
+            matched_entities: Vec::new(),
+            reason: "keyword_match".to_string(),
+            aggregate: None,
+            suppressed_by: None,
+        }
+    }
+
+    fn rule(value: serde_json::Value) -> SuppressionRule {
+        canonicalize_suppression_rule(serde_json::from_value(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn rules_match_source_entity_domain_keywords_snooze_and_evidence() {
+        let evidence = evidence();
+        let hit = hit();
+        for (value, expected) in [
+            (
+                serde_json::json!({"kind": "source", "source_id": "rss_vendor_press"}),
+                true,
+            ),
+            (
+                serde_json::json!({"kind": "source", "source_id": "rss_other"}),
+                false,
+            ),
+            (
+                serde_json::json!({"kind": "entity", "entity": "Orion Dynamics"}),
+                true,
+            ),
+            (
+                serde_json::json!({"kind": "url_domain", "domain": "Example.com"}),
+                true,
+            ),
+            (
+                serde_json::json!({"kind": "url_domain", "domain": "ample.com"}),
+                false,
+            ),
+            (
+                serde_json::json!({"kind": "keyword_cooccurrence", "keywords": ["Tabletop", "drill"]}),
+                true,
+            ),
+            (
+                serde_json::json!({"kind": "keyword_cooccurrence", "keywords": ["tabletop", "breach"]}),
+                false,
+            ),
+            (
+                serde_json::json!({"kind": "snooze", "until": "2026-03-07T00:00:00+01:00"}),
+                true,
+            ),
+            (
+                serde_json::json!({"kind": "snooze", "until": "2026-03-06T12:00:00Z"}),
+                false,
+            ),
+            (
+                serde_json::json!({"kind": "snooze", "until": "2026-03-07T00:00:00Z", "entity": "atlas crm"}),
+                false,
+            ),
+            (
+                serde_json::json!({"kind": "evidence", "evidence_id": "evidence_alpha"}),
+                true,
+            ),
+        ] {
+            assert_eq!(
+                rule(value.clone()).matches(&evidence, &hit),
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn first_matching_rule_labels_the_hit() {
+        let mut watchlist: Watchlist = serde_json::from_value(serde_json::json!({
+            "id": "ransomware",
+            "name": "Ransomware",
+            "description": "",
+            "keywords": ["ransomware"],
+            "min_source_trust": 0,
+            "severity": "high",
+            "enabled": true
+        }))
+        .unwrap();
+        let hits = apply_watchlist_suppressions(&[watchlist.clone()], &evidence(), vec![hit()]);
+        assert_eq!(hits[0].suppressed_by, None);
+
+        watchlist.suppressions = vec![
+            WatchlistSuppression {
+                id: "suppression_a".to_string(),
+                rule: rule(serde_json::json!({"kind": "source", "source_id": "rss_other"})),
+                note: String::new(),
+            },
+            WatchlistSuppression {
+                id: "suppression_b".to_string(),
+                rule: rule(
+                    serde_json::json!({"kind": "keyword_cooccurrence", "keywords": ["tabletop exercise"]}),
+                ),
+                note: "Drills are not incidents".to_string(),
+            },
+        ];
+        let hits = apply_watchlist_suppressions(&[watchlist], &evidence(), vec![hit()]);
+        assert_eq!(
+            hits[0].suppressed_by.as_deref(),
+            Some("keyword_cooccurrence:suppression_b")
+        );
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        for value in [
+            serde_json::json!({"kind": "entity", "entity": " "}),
+            serde_json::json!({"kind": "url_domain", "domain": "localhost"}),
+            serde_json::json!({"kind": "url_domain", "domain": "exa mple.com"}),
+            serde_json::json!({"kind": "keyword_cooccurrence", "keywords": []}),
+            serde_json::json!({"kind": "snooze", "until": "next week"}),
+            serde_json::json!({"kind": "source", "source_id": ""}),
+        ] {
+            assert!(
+                matches!(
+                    canonicalize_suppression_rule(serde_json::from_value(value.clone()).unwrap()),
+                    Err(HelixError::ValidationError { .. })
+                ),
+                "{value}"
+            );
+        }
+    }
+}
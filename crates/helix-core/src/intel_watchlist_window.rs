@@ -0,0 +1,543 @@
+//! Windowed aggregation for watchlists that fire on repeated matches.
+//!
+//! A watchlist with a [`WatchlistTrigger`] does not raise a hit for a single matching item.
+//! Each match is recorded as a signal in that watchlist's [`WatchlistWindow`], and the
+//! trigger is checked against the window ending at the new signal's `observed_at`. Time
+//! comes from evidence rather than the wall clock, so replaying the same evidence
+//! reproduces the same hits.
+
+use crate::intel_desk::{EvidenceItem, Watchlist, WatchlistHit};
+use crate::HelixError;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const MAX_TRIGGER_WINDOW_HOURS: u32 = 720;
+/// Signals kept per watchlist; the oldest are dropped first.
+pub const MAX_WINDOW_SIGNALS: usize = 1_024;
+const MAX_TRIGGER_MIN_SOURCES: u32 = 50;
+const MAX_SPIKE_RATIO_BPS: u32 = 1_000_000;
+const MAX_SPIKE_MIN_COUNT: u32 = 10_000;
+const DEFAULT_SPIKE_MIN_COUNT: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WatchlistTrigger {
+    /// Fires when at least `min_sources` distinct sources match the same entity or keyword
+    /// within `window_hours`.
+    DistinctSources { min_sources: u32, window_hours: u32 },
+    /// Fires when matches in the last `window_hours` reach `min_ratio_bps` of the average
+    /// per-window volume over the preceding `baseline_hours`, and number at least
+    /// `min_count`. `20_000` bps means the volume doubled.
+    VolumeSpike {
+        window_hours: u32,
+        baseline_hours: u32,
+        min_ratio_bps: u32,
+        #[serde(default = "default_spike_min_count")]
+        min_count: u32,
+    },
+}
+
+impl WatchlistTrigger {
+    /// `WatchlistHit::reason` for hits this trigger raises.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::DistinctSources { .. } => "aggregate_distinct_sources",
+            Self::VolumeSpike { .. } => "aggregate_volume_spike",
+        }
+    }
+
+    fn window(&self) -> Duration {
+        match self {
+            Self::DistinctSources { window_hours, .. } | Self::VolumeSpike { window_hours, .. } => {
+                Duration::hours(i64::from(*window_hours))
+            }
+        }
+    }
+
+    /// How far back signals still matter to this trigger.
+    fn retention(&self) -> Duration {
+        match self {
+            Self::DistinctSources { .. } => self.window(),
+            Self::VolumeSpike { baseline_hours, .. } => {
+                self.window() + Duration::hours(i64::from(*baseline_hours))
+            }
+        }
+    }
+}
+
+fn default_spike_min_count() -> u32 {
+    DEFAULT_SPIKE_MIN_COUNT
+}
+
+pub fn canonicalize_watchlist_trigger(
+    trigger: WatchlistTrigger,
+) -> Result<WatchlistTrigger, HelixError> {
+    let validate_hours = |context: &str, hours: u32| {
+        if (1..=MAX_TRIGGER_WINDOW_HOURS).contains(&hours) {
+            Ok(())
+        } else {
+            Err(HelixError::validation_error(
+                context.to_string(),
+                format!("must be between 1 and {MAX_TRIGGER_WINDOW_HOURS} hours"),
+            ))
+        }
+    };
+    match trigger {
+        WatchlistTrigger::DistinctSources {
+            min_sources,
+            window_hours,
+        } => {
+            validate_hours("watchlist.trigger.window_hours", window_hours)?;
+            if !(2..=MAX_TRIGGER_MIN_SOURCES).contains(&min_sources) {
+                return Err(HelixError::validation_error(
+                    "watchlist.trigger.min_sources".to_string(),
+                    format!("must be between 2 and {MAX_TRIGGER_MIN_SOURCES}"),
+                ));
+            }
+        }
+        WatchlistTrigger::VolumeSpike {
+            window_hours,
+            baseline_hours,
+            min_ratio_bps,
+            min_count,
+        } => {
+            validate_hours("watchlist.trigger.window_hours", window_hours)?;
+            validate_hours("watchlist.trigger.baseline_hours", baseline_hours)?;
+            if baseline_hours < window_hours {
+                return Err(HelixError::validation_error(
+                    "watchlist.trigger.baseline_hours",
+                    "must be at least window_hours",
+                ));
+            }
+            if !(10_001..=MAX_SPIKE_RATIO_BPS).contains(&min_ratio_bps) {
+                return Err(HelixError::validation_error(
+                    "watchlist.trigger.min_ratio_bps".to_string(),
+                    format!("must be above 10000 and at most {MAX_SPIKE_RATIO_BPS}"),
+                ));
+            }
+            if !(1..=MAX_SPIKE_MIN_COUNT).contains(&min_count) {
+                return Err(HelixError::validation_error(
+                    "watchlist.trigger.min_count".to_string(),
+                    format!("must be between 1 and {MAX_SPIKE_MIN_COUNT}"),
+                ));
+            }
+        }
+    }
+    Ok(trigger)
+}
+
+/// One matching evidence item recorded against a triggered watchlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowSignal {
+    pub evidence_id: String,
+    pub source_id: String,
+    pub observed_at: DateTime<Utc>,
+    #[serde(default)]
+    pub matched_keywords: Vec<String>,
+    #[serde(default)]
+    pub matched_entities: Vec<String>,
+}
+
+/// Persisted match history for one triggered watchlist, ordered by `observed_at` then
+/// evidence id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistWindow {
+    pub watchlist_id: String,
+    #[serde(default)]
+    pub signals: Vec<WindowSignal>,
+}
+
+/// Window figures behind an aggregate hit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchlistAggregate {
+    /// Exclusive start of the window.
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    pub signal_count: usize,
+    pub distinct_source_count: usize,
+    /// Entity or keyword the sources agreed on; distinct-source triggers only.
+    #[serde(default)]
+    pub matched_term: Option<String>,
+    /// Matches in the baseline period before the window; volume spikes only.
+    #[serde(default)]
+    pub baseline_count: Option<usize>,
+    /// Evidence in the window, oldest first.
+    pub evidence_ids: Vec<String>,
+}
+
+impl WatchlistWindow {
+    pub fn new(watchlist_id: impl Into<String>) -> Self {
+        Self {
+            watchlist_id: watchlist_id.into(),
+            signals: Vec::new(),
+        }
+    }
+
+    /// Records `signal` and checks `trigger` against the window ending at it. Returns
+    /// `None` when the trigger does not hold, the evidence was already recorded, or the
+    /// signal is older than anything the trigger still looks at.
+    pub fn observe(
+        &mut self,
+        trigger: &WatchlistTrigger,
+        signal: WindowSignal,
+    ) -> Option<WatchlistAggregate> {
+        if self
+            .signals
+            .iter()
+            .any(|existing| existing.evidence_id == signal.evidence_id)
+        {
+            return None;
+        }
+        let window_end = signal.observed_at;
+        let newest = signal.clone();
+        let evidence_id = signal.evidence_id.clone();
+        let position = self.signals.partition_point(|existing| {
+            (existing.observed_at, existing.evidence_id.as_str())
+                <= (signal.observed_at, signal.evidence_id.as_str())
+        });
+        self.signals.insert(position, signal);
+        self.prune(trigger);
+        if !self
+            .signals
+            .iter()
+            .any(|existing| existing.evidence_id == evidence_id)
+        {
+            return None;
+        }
+        self.evaluate(trigger, window_end, &newest)
+    }
+
+    /// Drops signals the trigger can no longer see from the newest signal, then the oldest
+    /// past [`MAX_WINDOW_SIGNALS`].
+    pub fn prune(&mut self, trigger: &WatchlistTrigger) {
+        let Some(newest) = self.signals.last().map(|signal| signal.observed_at) else {
+            return;
+        };
+        let horizon = newest - trigger.retention();
+        self.signals.retain(|signal| signal.observed_at > horizon);
+        let overflow = self.signals.len().saturating_sub(MAX_WINDOW_SIGNALS);
+        self.signals.drain(..overflow);
+    }
+
+    /// Signals with `start < observed_at <= end`.
+    fn between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<&WindowSignal> {
+        self.signals
+            .iter()
+            .filter(|signal| signal.observed_at > start && signal.observed_at <= end)
+            .collect()
+    }
+
+    fn evaluate(
+        &self,
+        trigger: &WatchlistTrigger,
+        window_end: DateTime<Utc>,
+        newest: &WindowSignal,
+    ) -> Option<WatchlistAggregate> {
+        let window_start = window_end - trigger.window();
+        let mut in_window = self.between(window_start, window_end);
+        let mut matched_term = None;
+
+        let baseline_count = match *trigger {
+            WatchlistTrigger::DistinctSources { min_sources, .. } => {
+                let (term, group) = strongest_term_group(&in_window, newest)?;
+                if distinct_sources(&group) < min_sources as usize {
+                    return None;
+                }
+                matched_term = Some(term);
+                in_window = group;
+                None
+            }
+            WatchlistTrigger::VolumeSpike {
+                window_hours,
+                baseline_hours,
+                min_ratio_bps,
+                min_count,
+            } => {
+                let baseline_start = window_start - Duration::hours(i64::from(baseline_hours));
+                let baseline_count = self.between(baseline_start, window_start).len();
+                // current / (baseline * window / baseline_hours) >= ratio, without division.
+                let current = in_window.len() as u128;
+                let spiked = current * 10_000 * u128::from(baseline_hours)
+                    >= u128::from(min_ratio_bps)
+                        * baseline_count as u128
+                        * u128::from(window_hours);
+                if in_window.len() < min_count as usize || !spiked {
+                    return None;
+                }
+                Some(baseline_count)
+            }
+        };
+
+        Some(WatchlistAggregate {
+            window_start,
+            window_end,
+            signal_count: in_window.len(),
+            distinct_source_count: distinct_sources(&in_window),
+            matched_term,
+            baseline_count,
+            evidence_ids: in_window
+                .iter()
+                .map(|signal| signal.evidence_id.clone())
+                .collect(),
+        })
+    }
+}
+
+fn distinct_sources(signals: &[&WindowSignal]) -> usize {
+    signals
+        .iter()
+        .map(|signal| signal.source_id.as_str())
+        .collect::<BTreeSet<_>>()
+        .len()
+}
+
+fn signal_terms(signal: &WindowSignal) -> BTreeSet<&str> {
+    signal
+        .matched_entities
+        .iter()
+        .chain(&signal.matched_keywords)
+        .map(String::as_str)
+        .collect()
+}
+
+/// Of the entities and keywords `newest` matched, the one reported by the most distinct
+/// sources in the window, with the signals that matched it. Ties go to the smaller term.
+fn strongest_term_group<'a>(
+    in_window: &[&'a WindowSignal],
+    newest: &WindowSignal,
+) -> Option<(String, Vec<&'a WindowSignal>)> {
+    signal_terms(newest)
+        .into_iter()
+        .map(|term| {
+            let group = in_window
+                .iter()
+                .copied()
+                .filter(|signal| signal_terms(signal).contains(term))
+                .collect::<Vec<_>>();
+            (term, group)
+        })
+        .min_by(|left, right| {
+            distinct_sources(&right.1)
+                .cmp(&distinct_sources(&left.1))
+                .then(left.0.cmp(right.0))
+        })
+        .map(|(term, group)| (term.to_string(), group))
+}
+
+/// Routes per-item hits for triggered watchlists through their windows. Hits for
+/// watchlists without a trigger pass through unchanged; triggered ones are replaced by an
+/// aggregate hit when the trigger holds and dropped otherwise.
+pub fn apply_watchlist_triggers(
+    watchlists: &[Watchlist],
+    windows: &mut BTreeMap<String, WatchlistWindow>,
+    evidence: &EvidenceItem,
+    hits: Vec<WatchlistHit>,
+) -> Vec<WatchlistHit> {
+    let observed_at = DateTime::parse_from_rfc3339(evidence.observed_at.trim())
+        .ok()
+        .map(|value| value.with_timezone(&Utc));
+    hits.into_iter()
+        .filter_map(|hit| {
+            let Some(trigger) = watchlists
+                .iter()
+                .find(|watchlist| watchlist.id == hit.watchlist_id)
+                .and_then(|watchlist| watchlist.trigger)
+            else {
+                return Some(hit);
+            };
+            let window = windows
+                .entry(hit.watchlist_id.clone())
+                .or_insert_with(|| WatchlistWindow::new(hit.watchlist_id.clone()));
+            let aggregate = window.observe(
+                &trigger,
+                WindowSignal {
+                    evidence_id: evidence.id.clone(),
+                    source_id: evidence.source_id.clone(),
+                    observed_at: observed_at?,
+                    matched_keywords: hit.matched_keywords.clone(),
+                    matched_entities: hit.matched_entities.clone(),
+                },
+            )?;
+
+            let mut matched_keywords = BTreeSet::new();
+            let mut matched_entities = BTreeSet::new();
+            for signal in window
+                .signals
+                .iter()
+                .filter(|signal| aggregate.evidence_ids.contains(&signal.evidence_id))
+            {
+                matched_keywords.extend(signal.matched_keywords.iter().cloned());
+                matched_entities.extend(signal.matched_entities.iter().cloned());
+            }
+            Some(WatchlistHit {
+                matched_keywords: matched_keywords.into_iter().collect(),
+                matched_entities: matched_entities.into_iter().collect(),
+                reason: trigger.reason().to_string(),
+                aggregate: Some(aggregate),
+                ..hit
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(evidence_id: &str, source_id: &str, observed_at: &str) -> WindowSignal {
+        mentioning(evidence_id, source_id, observed_at, "orion dynamics")
+    }
+
+    fn mentioning(
+        evidence_id: &str,
+        source_id: &str,
+        observed_at: &str,
+        entity: &str,
+    ) -> WindowSignal {
+        WindowSignal {
+            evidence_id: evidence_id.to_string(),
+            source_id: source_id.to_string(),
+            observed_at: DateTime::parse_from_rfc3339(observed_at)
+                .unwrap()
+                .with_timezone(&Utc),
+            matched_keywords: Vec::new(),
+            matched_entities: vec![entity.to_string()],
+        }
+    }
+
+    #[test]
+    fn distinct_sources_fire_only_inside_the_window() {
+        let trigger = WatchlistTrigger::DistinctSources {
+            min_sources: 3,
+            window_hours: 24,
+        };
+        let mut window = WatchlistWindow::new("watch");
+        assert!(window
+            .observe(&trigger, signal("e1", "rss_a", "2026-03-01T00:00:00Z"))
+            .is_none());
+        assert!(window
+            .observe(&trigger, signal("e2", "rss_a", "2026-03-01T06:00:00Z"))
+            .is_none());
+        assert!(window
+            .observe(&trigger, signal("e3", "rss_b", "2026-03-01T12:00:00Z"))
+            .is_none());
+        // The first item aged out, so rss_a still counts through e2.
+        let aggregate = window
+            .observe(&trigger, signal("e4", "rss_c", "2026-03-02T01:00:00Z"))
+            .unwrap();
+        assert_eq!(aggregate.distinct_source_count, 3);
+        assert_eq!(aggregate.matched_term.as_deref(), Some("orion dynamics"));
+        assert_eq!(aggregate.evidence_ids, vec!["e2", "e3", "e4"]);
+        assert_eq!(window.signals.len(), 3);
+
+        assert!(window
+            .observe(&trigger, signal("e4", "rss_c", "2026-03-02T01:00:00Z"))
+            .is_none());
+        assert!(window
+            .observe(&trigger, signal("e5", "rss_d", "2026-02-20T00:00:00Z"))
+            .is_none());
+    }
+
+    #[test]
+    fn distinct_sources_must_agree_on_one_entity() {
+        let trigger = WatchlistTrigger::DistinctSources {
+            min_sources: 3,
+            window_hours: 24,
+        };
+        let mut window = WatchlistWindow::new("watch");
+        for (evidence_id, source_id, entity) in [
+            ("e1", "rss_a", "orion dynamics"),
+            ("e2", "rss_b", "kestrel freight"),
+            ("e3", "rss_c", "halvard cloud"),
+        ] {
+            assert!(window
+                .observe(
+                    &trigger,
+                    mentioning(evidence_id, source_id, "2026-03-01T06:00:00Z", entity)
+                )
+                .is_none());
+        }
+        // Two sources each for two terms is still short of three on either one.
+        assert!(window
+            .observe(
+                &trigger,
+                WindowSignal {
+                    matched_keywords: vec!["orion dynamics".to_string()],
+                    ..mentioning("e4", "rss_d", "2026-03-01T07:00:00Z", "halvard cloud")
+                },
+            )
+            .is_none());
+        let aggregate = window
+            .observe(
+                &trigger,
+                mentioning("e5", "rss_e", "2026-03-01T08:00:00Z", "orion dynamics"),
+            )
+            .unwrap();
+        assert_eq!(aggregate.matched_term.as_deref(), Some("orion dynamics"));
+        assert_eq!(aggregate.evidence_ids, vec!["e1", "e4", "e5"]);
+    }
+
+    #[test]
+    fn volume_spike_compares_against_the_baseline_rate() {
+        let trigger = WatchlistTrigger::VolumeSpike {
+            window_hours: 24,
+            baseline_hours: 168,
+            min_ratio_bps: 20_000,
+            min_count: 2,
+        };
+        let mut window = WatchlistWindow::new("watch");
+        // Seven matches across the baseline week: one per day on average.
+        for day in 1..=7 {
+            let observed_at = format!("2026-03-0{day}T09:00:00Z");
+            window.observe(&trigger, signal(&format!("b{day}"), "rss_a", &observed_at));
+        }
+        assert!(window
+            .observe(&trigger, signal("s1", "rss_a", "2026-03-08T10:00:00Z"))
+            .is_none());
+        let aggregate = window
+            .observe(&trigger, signal("s2", "rss_b", "2026-03-08T11:00:00Z"))
+            .unwrap();
+        assert_eq!(aggregate.signal_count, 2);
+        assert_eq!(aggregate.baseline_count, Some(7));
+    }
+
+    #[test]
+    fn trigger_validation_bounds_windows_and_ratios() {
+        for trigger in [
+            WatchlistTrigger::DistinctSources {
+                min_sources: 1,
+                window_hours: 24,
+            },
+            WatchlistTrigger::DistinctSources {
+                min_sources: 3,
+                window_hours: 0,
+            },
+            WatchlistTrigger::VolumeSpike {
+                window_hours: 48,
+                baseline_hours: 24,
+                min_ratio_bps: 20_000,
+                min_count: 3,
+            },
+            WatchlistTrigger::VolumeSpike {
+                window_hours: 24,
+                baseline_hours: 168,
+                min_ratio_bps: 10_000,
+                min_count: 3,
+            },
+        ] {
+            assert!(matches!(
+                canonicalize_watchlist_trigger(trigger),
+                Err(HelixError::ValidationError { .. })
+            ));
+        }
+        let trigger: WatchlistTrigger = serde_json::from_value(serde_json::json!({
+            "kind": "volume_spike",
+            "window_hours": 24,
+            "baseline_hours": 168,
+            "min_ratio_bps": 20_000
+        }))
+        .unwrap();
+        assert_eq!(canonicalize_watchlist_trigger(trigger).unwrap(), trigger);
+    }
+}
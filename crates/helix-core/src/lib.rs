@@ -38,8 +38,23 @@ pub mod state;
 pub mod types;
 
 pub mod filter_agent;
+pub mod intel_case_reasoning;
+pub mod intel_case_sla;
+pub mod intel_case_timeline;
+pub mod intel_claim_adjudication;
+pub mod intel_claim_graph;
+pub mod intel_decay;
 pub mod intel_desk;
+pub mod intel_entity_extraction;
+pub mod intel_entity_registry;
+pub mod intel_json_mapping;
+pub mod intel_near_duplicate;
+pub mod intel_notes;
 pub mod intel_priority;
+pub mod intel_source_calibration;
+pub mod intel_watchlist_query;
+pub mod intel_watchlist_suppression;
+pub mod intel_watchlist_window;
 pub mod llm_agent;
 pub mod market_intel;
 pub mod timer_agent;
@@ -138,6 +138,7 @@ mod tests {
                 severity: WatchlistSeverity::High,
                 source_trust_scores: vec![86],
                 evidence_count: 2,
+                cluster_source_count: 1,
                 claim_count: 2,
                 corroborated_claim_count: 1,
                 rejected_claim_count: 0,
@@ -153,6 +154,7 @@ mod tests {
                 severity: WatchlistSeverity::High,
                 source_trust_scores: vec![86],
                 evidence_count: 2,
+                cluster_source_count: 1,
                 claim_count: 2,
                 corroborated_claim_count: 0,
                 rejected_claim_count: 0,
@@ -1,2 +1,3 @@
 import HelixProofs.IntelPriority
 import HelixProofs.Credibility
+import HelixProofs.CaseMerge
@@ -0,0 +1,109 @@
+import Mathlib.Data.List.Basic
+
+namespace HelixProofs.CaseMerge
+
+/-- `push_unique` in `intel_desk.rs`: append the id unless it is already listed. -/
+def pushUnique (values : List String) (candidate : String) : List String :=
+  if candidate ∈ values then values else values ++ [candidate]
+
+/-- Evidence or claim ids of a merge target after absorbing another case's ids. -/
+def mergeIds (target incoming : List String) : List String :=
+  incoming.foldl pushUnique target
+
+/-- Ids a split leaves on the source case. -/
+def splitKeep (ids moved : List String) : List String :=
+  ids.filter (fun id => decide (id ∉ moved))
+
+/-- Ids a split opens the new case with, in the source case's order. -/
+def splitMove (ids moved : List String) : List String :=
+  ids.filter (fun id => decide (id ∈ moved))
+
+theorem mem_pushUnique_of_mem {values : List String} {id : String} (candidate : String)
+    (h : id ∈ values) : id ∈ pushUnique values candidate := by
+  by_cases hc : candidate ∈ values <;> simp [pushUnique, hc, h]
+
+theorem candidate_mem_pushUnique (values : List String) (candidate : String) :
+    candidate ∈ pushUnique values candidate := by
+  by_cases hc : candidate ∈ values <;> simp [pushUnique, hc]
+
+theorem mem_mergeIds_of_mem_target (incoming : List String) :
+    ∀ {target : List String} {id : String}, id ∈ target → id ∈ mergeIds target incoming := by
+  induction incoming with
+  | nil =>
+    intro target id h
+    simpa [mergeIds] using h
+  | cons candidate rest ih =>
+    intro target id h
+    simp only [mergeIds, List.foldl_cons]
+    exact ih (target := pushUnique target candidate) (mem_pushUnique_of_mem candidate h)
+
+theorem mem_mergeIds_of_mem_incoming (incoming : List String) :
+    ∀ {target : List String} {id : String}, id ∈ incoming → id ∈ mergeIds target incoming := by
+  induction incoming with
+  | nil =>
+    intro target id h
+    simp at h
+  | cons candidate rest ih =>
+    intro target id h
+    simp only [mergeIds, List.foldl_cons]
+    rcases List.mem_cons.mp h with hEq | hRest
+    · rw [hEq]
+      exact mem_mergeIds_of_mem_target rest (candidate_mem_pushUnique target candidate)
+    · exact ih (target := pushUnique target candidate) hRest
+
+/-- A merge loses no evidence: every id of either case is on the merge target. -/
+theorem merge_keeps_every_id (target incoming : List String) (id : String)
+    (h : id ∈ target ∨ id ∈ incoming) : id ∈ mergeIds target incoming := by
+  rcases h with h | h
+  · exact mem_mergeIds_of_mem_target incoming h
+  · exact mem_mergeIds_of_mem_incoming incoming h
+
+/-- A merge invents no evidence: every id on the target came from one of the cases. -/
+theorem mergeIds_mem_source (incoming : List String) :
+    ∀ {target : List String} {id : String},
+      id ∈ mergeIds target incoming → id ∈ target ∨ id ∈ incoming := by
+  induction incoming with
+  | nil =>
+    intro target id h
+    exact Or.inl (by simpa [mergeIds] using h)
+  | cons candidate rest ih =>
+    intro target id h
+    simp only [mergeIds, List.foldl_cons] at h
+    rcases ih (target := pushUnique target candidate) h with hPushed | hRest
+    · by_cases hc : candidate ∈ target
+      · simp [pushUnique, hc] at hPushed
+        exact Or.inl hPushed
+      · simp [pushUnique, hc] at hPushed
+        rcases hPushed with hTarget | hCandidate
+        · exact Or.inl hTarget
+        · exact Or.inr (by simp [hCandidate])
+    · exact Or.inr (List.mem_cons_of_mem candidate hRest)
+
+/-- A split loses no evidence: every id stays on the source or moves to the new case. -/
+theorem split_keeps_every_id (ids moved : List String) (id : String) (h : id ∈ ids) :
+    id ∈ splitKeep ids moved ∨ id ∈ splitMove ids moved := by
+  by_cases hm : id ∈ moved
+  · exact Or.inr (by simp [splitMove, h, hm])
+  · exact Or.inl (by simp [splitKeep, h, hm])
+
+/-- No id ends up on both sides of a split. -/
+theorem split_sides_disjoint (ids moved : List String) (id : String)
+    (hKeep : id ∈ splitKeep ids moved) : id ∉ splitMove ids moved := by
+  simp [splitKeep] at hKeep
+  simp [splitMove, hKeep.2]
+
+/-- A merge target holds exactly the union of both cases' ids. -/
+theorem mem_mergeIds_iff (target incoming : List String) (id : String) :
+    id ∈ mergeIds target incoming ↔ id ∈ target ∨ id ∈ incoming :=
+  ⟨fun h => mergeIds_mem_source incoming h, merge_keeps_every_id target incoming id⟩
+
+/-- The two sides of a split together hold exactly the source case's ids. -/
+theorem mem_split_iff (ids moved : List String) (id : String) :
+    id ∈ splitKeep ids moved ∨ id ∈ splitMove ids moved ↔ id ∈ ids := by
+  constructor
+  · rintro (h | h)
+    · exact (List.mem_filter.mp h).1
+    · exact (List.mem_filter.mp h).1
+  · exact split_keeps_every_id ids moved id
+
+end HelixProofs.CaseMerge
@@ -17,6 +17,11 @@ def stepNoisyOr (aggregate signal : Nat) : Nat :=
 def accumulateNoisyOr (aggregate signal count : Nat) : Nat :=
   Nat.rec (clampBps aggregate) (fun _ acc => stepNoisyOr acc signal) count
 
+def independentSourceSupportBps : Nat := 5_000
+
+def independentSourceCredibility (credibility clusterSourceCount : Nat) : Nat :=
+  accumulateNoisyOr credibility independentSourceSupportBps (clusterSourceCount - 1)
+
 def attenuateSupport (support rejection : Nat) : Nat :=
   let support := clampBps support
   let rejection := clampBps rejection
@@ -871,6 +876,35 @@ theorem accumulateNoisyOr_mono_signal {aggregate signal₁ signal₂ count : Nat
       simp [accumulateNoisyOr]
       exact le_trans (stepNoisyOr_mono_aggregate ih) (stepNoisyOr_mono_signal h)
 
+theorem accumulateNoisyOr_mono_count {aggregate signal count₁ count₂ : Nat}
+    (h : count₁ ≤ count₂) :
+    accumulateNoisyOr aggregate signal count₁ ≤ accumulateNoisyOr aggregate signal count₂ := by
+  induction h with
+  | refl => exact Nat.le_refl _
+  | step _ ih => exact Nat.le_trans ih (accumulateNoisyOr_le_succ aggregate signal _)
+
+theorem independentSourceCredibility_one_source (credibility : Nat) :
+    independentSourceCredibility credibility 1 = clampBps credibility := by
+  rfl
+
+theorem independentSourceCredibility_bounds (credibility sources : Nat) :
+    clampBps credibility ≤ independentSourceCredibility credibility sources ∧
+      independentSourceCredibility credibility sources ≤ maxBps :=
+  accumulateNoisyOr_bounds credibility independentSourceSupportBps (sources - 1)
+
+theorem independentSourceCredibility_mono_sources {credibility sources₁ sources₂ : Nat}
+    (h : sources₁ ≤ sources₂) :
+    independentSourceCredibility credibility sources₁ ≤
+      independentSourceCredibility credibility sources₂ := by
+  unfold independentSourceCredibility
+  exact accumulateNoisyOr_mono_count (by omega)
+
+theorem independentSourceCredibility_mono_credibility {credibility₁ credibility₂ sources : Nat}
+    (h : credibility₁ ≤ credibility₂) :
+    independentSourceCredibility credibility₁ sources ≤
+      independentSourceCredibility credibility₂ sources :=
+  accumulateNoisyOr_mono_aggregate h
+
 lemma unresolvedClaimCount_replace_one
     {claimCount corroboratedCount rejectedCount : Nat}
     (hOpen : corroboratedCount + rejectedCount < claimCount) :
@@ -414,6 +414,96 @@ export type SourceDefinition = {
   trust_score: number;
   enabled: boolean;
   tags: string[];
+  website_diff?: WebsiteDiffConfig | null;
+  file_import?: FileImportMapping | null;
+  json_api?: JsonApiMapping | null;
+  webhook_signature?: WebhookSignatureConfig | null;
+};
+
+export type WebhookSignatureScheme = "github_sha256" | "stripe_v1" | "hmac_sha256";
+
+export type WebhookSignatureConfig = {
+  scheme: WebhookSignatureScheme;
+  signature_header?: string | null;
+  timestamp_header?: string | null;
+  tolerance_seconds?: number;
+};
+
+export type WebsiteDiffConfig = {
+  content_selector?: string | null;
+  ignore_selectors?: string[];
+};
+
+export type FileImportMapping = {
+  title?: string | null;
+  summary?: string | null;
+  content?: string | null;
+  url?: string | null;
+  observed_at?: string | null;
+  tags?: string | null;
+  entity_labels?: string | null;
+  list_delimiter?: string | null;
+};
+
+export type JsonApiClaimMapping = {
+  subject: string;
+  predicate: string;
+  object: string;
+  confidence_bps: number;
+  rationale?: string | null;
+};
+
+export type JsonApiMapping = {
+  items_path?: string | null;
+  title: string;
+  summary?: string | null;
+  content?: string | null;
+  url?: string | null;
+  observed_at?: string | null;
+  tags?: string[];
+  entity_labels?: string[];
+  proposed_claims?: JsonApiClaimMapping[];
+};
+
+export type MappedJsonItem = {
+  title: string;
+  summary?: string | null;
+  content?: string | null;
+  url?: string | null;
+  observed_at?: string | null;
+  tags: string[];
+  entity_labels: string[];
+  proposed_claims: ProposedClaim[];
+};
+
+export type JsonMappingPreviewRequest = {
+  mapping: JsonApiMapping;
+  payload: unknown;
+};
+
+export type JsonMappingPreviewItem = {
+  index: number;
+  item?: MappedJsonItem | null;
+  error?: string | null;
+};
+
+export type JsonMappingPreviewResponse = {
+  mapping: JsonApiMapping;
+  item_count: number;
+  mapped_count: number;
+  items: JsonMappingPreviewItem[];
+};
+
+export type WebsiteDiffSummary = {
+  baseline: boolean;
+  previous_observed_at?: string | null;
+  previous_content_hash?: string | null;
+  content_hash: string;
+  block_count: number;
+  added_blocks: number;
+  changed_blocks: number;
+  removed_blocks: number;
+  unchanged_blocks: number;
 };
 
 export type Watchlist = {
@@ -422,11 +512,67 @@ export type Watchlist = {
   description: string;
   keywords: string[];
   entities: string[];
+  aliases?: Record<string, string[]>;
+  query?: string | null;
+  trigger?: WatchlistTrigger | null;
+  suppressions?: WatchlistSuppression[];
   min_source_trust: number;
   severity: WatchlistSeverity;
   enabled: boolean;
 };
 
+export type SuppressionRule =
+  | { kind: "source"; source_id: string }
+  | { kind: "entity"; entity: string }
+  | { kind: "url_domain"; domain: string }
+  | { kind: "keyword_cooccurrence"; keywords: string[] }
+  | { kind: "snooze"; until: string; entity?: string | null }
+  | { kind: "evidence"; evidence_id: string };
+
+export type WatchlistSuppression = SuppressionRule & {
+  id: string;
+  note: string;
+};
+
+export type CreateSuppressionRequest = SuppressionRule & {
+  note?: string;
+};
+
+export type SuppressedHitRecord = {
+  id: string;
+  observed_at: string;
+  hit: WatchlistHit;
+};
+
+export type WatchlistTrigger =
+  | { kind: "distinct_sources"; min_sources: number; window_hours: number }
+  | {
+      kind: "volume_spike";
+      window_hours: number;
+      baseline_hours: number;
+      min_ratio_bps: number;
+      min_count?: number;
+    };
+
+export type ExtractedEntityKind =
+  | "watchlist"
+  | "email"
+  | "cve"
+  | "wallet_address"
+  | "ip_address"
+  | "domain"
+  | "ticker"
+  | "proper_name";
+
+export type ExtractedEntity = {
+  label: string;
+  kind: ExtractedEntityKind;
+  field: "title" | "summary" | "content";
+  start: number;
+  end: number;
+  text: string;
+};
+
 export type ProposedClaim = {
   subject: string;
   predicate: string;
@@ -446,6 +592,9 @@ export type EvidenceItem = {
   tags: string[];
   entity_labels: string[];
   provenance_hash: string;
+  minhash?: string | null;
+  story_cluster_id?: string | null;
+  extracted_entities?: ExtractedEntity[];
 };
 
 export type EvidenceQueueEntry = {
@@ -466,6 +615,8 @@ export type EvidenceQueueFilters = {
   linked_status?: CaseStatus;
   min_trust?: number;
   q?: string;
+  /** RFC 3339 time whose source trust scores the ranking uses. */
+  trust_as_of?: string;
   limit?: number;
 };
 
@@ -489,16 +640,139 @@ export type ClaimQueueEntry = {
   priority: PriorityBreakdown;
   linked_case_count: number;
   max_linked_severity: WatchlistSeverity | null;
+  assertion_id: string;
+  supporting_evidence_ids: string[];
+  contested: boolean;
+  contradictions: ClaimContradiction[];
+  adjudication: ClaimAdjudication | null;
   semantic_score_bps: number | null;
 };
 
+export type ClaimVoteChoice = "corroborate" | "reject";
+
+export type ClaimVote = {
+  reviewer: string;
+  choice: ClaimVoteChoice;
+  rationale: string;
+  cast_at: string;
+};
+
+export type ClaimAdjudicationOutcome = "pending" | "contested" | "corroborated" | "rejected";
+
+export type ClaimAdjudication = {
+  claim_id: string;
+  quorum: number;
+  reviewers: number;
+  votes: ClaimVote[];
+  outcome: ClaimAdjudicationOutcome;
+  closed_rounds?: ClaimAdjudicationRound[];
+};
+
+export type ClaimAdjudicationRound = {
+  quorum: number;
+  reviewers: number;
+  votes: ClaimVote[];
+  outcome: ClaimAdjudicationOutcome;
+  closed_at: string;
+  closed_reason: string;
+};
+
+export type ClaimAdjudicationConfig = {
+  min_severity?: WatchlistSeverity | null;
+  quorum: number;
+  reviewers: number;
+};
+
+export type ClaimAdjudicationResponse = {
+  claim: ClaimRecord;
+  adjudication: ClaimAdjudication;
+  trust_adjustments: TrustHistoryEntry[];
+};
+
+export type TrustCalibrationPolicy = {
+  prior_weight: number;
+  min_reviewed_claims: number;
+  max_step_per_period: number;
+  period_hours: number;
+  min_trust_score: number;
+  max_trust_score: number;
+  auto_apply?: boolean;
+};
+
+export type TrustRecommendation = {
+  source_id: string;
+  current_trust_score: number;
+  baseline_trust_score: number;
+  target_trust_score: number;
+  proposed_trust_score: number;
+  posterior_bps: number;
+  corroborated_claim_count: number;
+  rejected_claim_count: number;
+  limited_by_period: boolean;
+};
+
+export type TrustHistoryEntry = {
+  id: string;
+  source_id: string;
+  previous_trust_score: number;
+  trust_score: number;
+  origin: "auto" | "accepted" | "manual";
+  posterior_bps: number;
+  corroborated_claim_count: number;
+  rejected_claim_count: number;
+  recorded_at: string;
+};
+
+export type TrustCalibrationResponse = {
+  as_of: string;
+  policy: TrustCalibrationPolicy;
+  recommendations: TrustRecommendation[];
+};
+
+export type TrustHistoryResponse = {
+  source_id: string;
+  trust_score: number;
+  entries: TrustHistoryEntry[];
+  trust_score_at: number | null;
+};
+
+export type ClaimContradictionKind = "conflicting_object" | "negation";
+
+export type ClaimContradiction = {
+  assertion_id: string;
+  kind: ClaimContradictionKind;
+};
+
+export type ClaimAssertion = {
+  id: string;
+  subject: string;
+  predicate: string;
+  object: string;
+  negated: boolean;
+  claim_ids: string[];
+  evidence_ids: string[];
+  max_confidence_bps: number;
+  corroborated_claim_count: number;
+  rejected_claim_count: number;
+  contradictions: ClaimContradiction[];
+};
+
+export type ClaimGraphFilters = {
+  contested?: boolean;
+  subject?: string;
+  limit?: number;
+};
+
 export type ClaimQueueFilters = {
   review_status?: ClaimReviewStatus;
   predicate?: string;
   subject?: string;
   linked_status?: CaseStatus;
   min_confidence_bps?: number;
+  contested?: boolean;
   q?: string;
+  /** RFC 3339 time whose source trust scores the ranking uses. */
+  trust_as_of?: string;
   limit?: number;
 };
 
@@ -510,9 +784,27 @@ export type WatchlistHit = {
   matched_keywords: string[];
   matched_entities: string[];
   reason: string;
+  aggregate?: WatchlistAggregate | null;
+  suppressed_by?: string | null;
+};
+
+export type WatchlistAggregate = {
+  window_start: string;
+  window_end: string;
+  signal_count: number;
+  distinct_source_count: number;
+  matched_term?: string | null;
+  baseline_count?: number | null;
+  evidence_ids: string[];
 };
 
-export type CaseStatus = "open" | "monitoring" | "brief_ready" | "escalated" | "closed";
+export type CaseStatus =
+  | "open"
+  | "monitoring"
+  | "brief_ready"
+  | "escalated"
+  | "closed"
+  | "merged";
 
 export type CaseFile = {
   id: string;
@@ -524,6 +816,105 @@ export type CaseFile = {
   claim_ids: string[];
   latest_reason: string;
   briefing_summary: string | null;
+  reasoning_gate?: CaseReasoningGate | null;
+  merged_into?: string | null;
+  assignee?: string | null;
+  watch_group?: string | null;
+};
+
+export type ReasoningVerdict = "allow" | "review" | "deny";
+
+export type SymbolicRule = {
+  id: string;
+  antecedents: string[];
+  consequent: string;
+};
+
+export type ReasoningSupportNode = {
+  fact: string;
+  kind: "seed" | "derived";
+  rule_id: string | null;
+  supports: string[];
+};
+
+export type ReasoningDecision = {
+  backend: string;
+  verdict: ReasoningVerdict;
+  confidence: number;
+  rationale: string;
+  trace: {
+    derived_facts: string[];
+    matched_rules: string[];
+    symbolic_entailed: boolean | null;
+    support_graph?: ReasoningSupportNode[];
+    query_support?: string[];
+    program_fingerprint?: string;
+  };
+};
+
+export type CaseReasoningGate = {
+  rule_pack_id: string;
+  query: string;
+  include_needs_review: boolean;
+  claim_ids: string[];
+  evaluated_at: string;
+  decision: ReasoningDecision;
+};
+
+export type IntelRulePack = {
+  id: string;
+  name: string;
+  description?: string;
+  rules: SymbolicRule[];
+};
+
+export type CaseReasoningRequest = {
+  rule_pack_id: string;
+  query?: string;
+  include_needs_review?: boolean;
+  consistency_scope?: "global" | "query_support";
+  max_rounds?: number;
+};
+
+export type CaseReasoningResponse = {
+  case: CaseFile;
+  request: Record<string, unknown>;
+  gate: CaseReasoningGate;
+};
+
+export type DecayPolicy = {
+  default_half_life_hours: number;
+  source_kind_half_life_hours?: Partial<Record<SourceKind, number>>;
+  tag_half_life_hours?: Record<string, number>;
+  claim_review_threshold_bps: number;
+  evidence_expiry_bps: number;
+};
+
+export type DecayApplyRequest = {
+  as_of?: string;
+  dry_run?: boolean;
+};
+
+export type DecayedClaim = {
+  claim_id: string;
+  evidence_id: string;
+  confidence_bps: number;
+  relevance_bps: number;
+  decayed_confidence_bps: number;
+};
+
+export type ExpiredEvidence = {
+  evidence_id: string;
+  relevance_bps: number;
+  detached_case_ids: string[];
+};
+
+export type DecayApplyResponse = {
+  as_of: string;
+  dry_run: boolean;
+  policy: DecayPolicy;
+  claims: DecayedClaim[];
+  expired_evidence: ExpiredEvidence[];
 };
 
 export type CaseCommand =
@@ -531,7 +922,10 @@ export type CaseCommand =
   | { type: "attach_brief"; summary: string }
   | { type: "escalate"; reason: string }
   | { type: "close" }
-  | { type: "reopen"; reason: string };
+  | { type: "reopen"; reason: string }
+  | { type: "assign"; assignee: string | null; watch_group: string | null }
+  | { type: "merge"; into_case_id: string }
+  | { type: "split"; evidence_ids: string[]; new_case_id: string; claim_ids?: string[] };
 
 export type CaseTransition = {
   case: CaseFile;
@@ -542,6 +936,62 @@ export type CaseTransition = {
     | { kind: "denied"; reason: string };
 };
 
+export type CaseTransitionResponse = {
+  transition: CaseTransition;
+  related: CaseTransition[];
+};
+
+export type CaseEventCommand =
+  | CaseCommand
+  | {
+      type: "open";
+      case_id: string;
+      title: string;
+      watchlist_id: string;
+      primary_entity: string | null;
+      evidence_id: string;
+      claim_ids: string[];
+      reason: string;
+    }
+  | { type: "append_evidence"; evidence_id: string; claim_ids: string[]; reason: string }
+  | { type: "attach_reasoning_gate"; gate: CaseReasoningGate }
+  | { type: "detach_evidence"; evidence_id: string; claim_ids: string[]; reason: string }
+  | { type: "relabel_primary_entity"; primary_entity: string }
+  | {
+      type: "absorb";
+      case_id: string;
+      evidence_ids: string[];
+      claim_ids: string[];
+      status: CaseStatus;
+    }
+  | {
+      type: "split_from";
+      case_id: string;
+      source_case_id: string;
+      title: string;
+      watchlist_id: string;
+      primary_entity: string | null;
+      evidence_ids: string[];
+      claim_ids: string[];
+    };
+
+export type CaseEvent = {
+  sequence: number;
+  actor: string;
+  recorded_at: string;
+  command: CaseEventCommand;
+  decision: CaseTransition["decision"];
+};
+
+export type CaseTimelineResponse = {
+  case: CaseFile;
+  replay_matches: boolean;
+  baseline: CaseFile | null;
+  events: CaseEvent[];
+  as_of: string | null;
+  case_as_of: CaseFile | null;
+};
+
 export type IntelDeskOverviewResponse = {
   source_count: number;
   watchlist_count: number;
@@ -576,6 +1026,7 @@ export type MarketIntelThemeCard = {
 
 export type MarketIntelCompanyCard = {
   company: string;
+  display_name?: string;
   priority: PriorityBreakdown;
   mention_count: number;
   claim_count: number;
@@ -634,6 +1085,137 @@ export type CaseQueueEntry = {
   severity: WatchlistSeverity;
   priority: PriorityBreakdown;
   latest_signal_at: string | null;
+  sla?: CaseSla | null;
+};
+
+export type CaseSlaStatus = "pending" | "breached" | "met_on_time" | "met_late" | "cancelled";
+
+export type CaseSla = {
+  case_id: string;
+  severity: WatchlistSeverity;
+  deadline_hours: number;
+  status: CaseSlaStatus;
+  started_at: string;
+  deadline_at: string;
+  ticked_through: string;
+  breached_at: string | null;
+  completed_at: string | null;
+};
+
+export type CaseSlaPolicy = {
+  critical_hours: number;
+  high_hours: number;
+  medium_hours: number;
+  low_hours: number;
+};
+
+export type CaseSlaBreach = {
+  case_id: string;
+  title: string;
+  watchlist_id: string;
+  severity: WatchlistSeverity;
+  assignee: string | null;
+  watch_group: string | null;
+  started_at: string;
+  deadline_at: string;
+};
+
+export type CaseSlaTickResponse = {
+  now: string;
+  open_count: number;
+  breaches: CaseSlaBreach[];
+  events: { id: string; type: string; source: string; subject?: string; data?: CaseSlaBreach }[];
+  trigger_plans: RecipeTriggerPlan[];
+};
+
+export type CaseQueueView = "mine" | "unassigned" | "breaching";
+
+export type CaseDossierFormat = "html" | "markdown";
+
+export type CaseDossierItem = {
+  kind: string;
+  id: string;
+  record: unknown;
+};
+
+export type CaseDossierManifestEntry = {
+  kind: string;
+  id: string;
+  sha256: string;
+};
+
+/** Records embedded in a dossier under `helix-dossier-data`, with their manifest. */
+export type CaseDossierData = {
+  case_id: string;
+  items: CaseDossierItem[];
+  manifest: {
+    algorithm: string;
+    entries: CaseDossierManifestEntry[];
+    root_sha256: string;
+  };
+};
+
+export type CaseDossierVerification = {
+  valid: boolean;
+  root_sha256: string;
+  computed_root_sha256: string;
+  mismatched_items: string[];
+};
+
+export type NoteTargetKind = "case" | "evidence" | "claim";
+
+export type NoteRevision = {
+  body: string;
+  replaced_at: string;
+};
+
+export type AnalystNote = {
+  id: string;
+  target_kind: NoteTargetKind;
+  target_id: string;
+  /** Root note of the thread this note replies to. */
+  parent_id?: string | null;
+  author: string;
+  body: string;
+  mentions: string[];
+  pinned: boolean;
+  created_at: string;
+  updated_at: string;
+  revisions: NoteRevision[];
+};
+
+export type NoteThread = {
+  root: AnalystNote;
+  replies: AnalystNote[];
+};
+
+export type NoteThreadsResponse = {
+  target_kind: NoteTargetKind;
+  target_id: string;
+  threads: NoteThread[];
+};
+
+export type CreateNoteRequest = {
+  author: string;
+  body: string;
+  parent_id?: string;
+  pinned?: boolean;
+};
+
+export type UpdateNoteRequest = {
+  editor: string;
+  body?: string;
+  pinned?: boolean;
+};
+
+export type NoteQueueFilters = {
+  target_kind?: NoteTargetKind;
+  target_id?: string;
+  author?: string;
+  mention?: string;
+  pinned?: boolean;
+  q?: string;
+  limit?: number;
 };
 
 export type CaseQueueFilters = {
@@ -641,6 +1223,12 @@ export type CaseQueueFilters = {
   severity?: WatchlistSeverity;
   watchlist_id?: string;
   primary_entity?: string;
+  view?: CaseQueueView;
+  /** Operator whose cases the `mine` view lists; also filters on its own. */
+  assignee?: string;
+  watch_group?: string;
+  /** RFC 3339 time whose source trust scores the ranking uses. */
+  trust_as_of?: string;
   limit?: number;
 };
 
@@ -688,6 +1276,8 @@ export type AutopilotReviewExportPacketResponse = {
   supporting_cases: CaseQueueEntry[];
   supporting_claims: ClaimQueueEntry[];
   supporting_evidence: EvidenceQueueEntry[];
+  notes: AnalystNote[];
+  notes_redacted: boolean;
 };
 
 export type MarketIntelBriefExportPacketResponse = {
@@ -698,6 +1288,27 @@ export type MarketIntelBriefExportPacketResponse = {
   watchlist: Watchlist;
   evidence: EvidenceQueueEntry[];
   claims: ClaimQueueEntry[];
+  notes: AnalystNote[];
+  notes_redacted: boolean;
+};
+
+export type SourceHealthStatus = "unknown" | "healthy" | "degraded" | "circuit_open";
+
+export type SourceHealth = {
+  status: SourceHealthStatus;
+  breaker: "Closed" | "Open" | "HalfOpen";
+  last_status: number | null;
+  last_success_at: string | null;
+  last_failure_at: string | null;
+  last_error: string | null;
+  consecutive_failures: number;
+  last_bytes_fetched: number;
+  bytes_fetched_total: number;
+};
+
+export type SourceCatalog = {
+  sources: SourceDefinition[];
+  health: Record<string, SourceHealth>;
 };
 
 export type CreateSourceRequest = {
@@ -713,6 +1324,10 @@ export type CreateSourceRequest = {
   trust_score: number;
   enabled: boolean;
   tags: string[];
+  website_diff?: WebsiteDiffConfig | null;
+  file_import?: FileImportMapping | null;
+  json_api?: JsonApiMapping | null;
+  webhook_signature?: WebhookSignatureConfig | null;
 };
 
 export type CollectSourceRequest = {
@@ -734,6 +1349,8 @@ export type ScheduledSourceCollection = {
   collected_count: number;
   duplicate_count: number;
   results: IngestEvidenceResponse[];
+  website_diff?: WebsiteDiffSummary | null;
+  not_modified?: boolean;
 };
 
 export type CollectDueSourcesResponse = {
@@ -769,6 +1386,8 @@ export type CollectSourceResponse = {
   collected_count: number;
   duplicate_count: number;
   results: IngestEvidenceResponse[];
+  website_diff?: WebsiteDiffSummary | null;
+  not_modified?: boolean;
 };
 
 export type WebhookIngestResponse = {
@@ -788,41 +1407,134 @@ export type FileImportRequest = {
   tags?: string[];
   entity_labels?: string[];
   proposed_claims?: ProposedClaim[];
+  format?: FileImportFormat | null;
+  dry_run?: boolean;
 };
 
+export type FileImportFormat = "text" | "csv" | "tsv" | "jsonl";
+
 export type FileImportResponse = {
   source: SourceDefinition;
   result: IngestEvidenceResponse;
 };
 
+export type FileImportRowStatus = "accepted" | "duplicate" | "invalid";
+
+export type FileImportRowResult = {
+  line: number;
+  status: FileImportRowStatus;
+  provenance_hash?: string | null;
+  error?: string | null;
+  result?: IngestEvidenceResponse | null;
+};
+
+export type FileRowImportResponse = {
+  source: SourceDefinition;
+  format: FileImportFormat;
+  dry_run: boolean;
+  row_count: number;
+  accepted_count: number;
+  duplicate_count: number;
+  error_count: number;
+  rows: FileImportRowResult[];
+};
+
+export type EmailImportRequest = {
+  content: string;
+  observed_at: string;
+  tags?: string[];
+};
+
+export type EmailImportResponse = {
+  source: SourceDefinition;
+  message_count: number;
+  accepted_count: number;
+  duplicate_count: number;
+  results: IngestEvidenceResponse[];
+};
+
 export type CreateWatchlistRequest = {
   name: string;
   description: string;
   keywords: string[];
   entities: string[];
+  aliases?: Record<string, string[]>;
+  query?: string | null;
+  trigger?: WatchlistTrigger | null;
   min_source_trust: number;
   severity: WatchlistSeverity;
   enabled: boolean;
 };
 
-export type IngestEvidenceRequest = {
-  source_id: string;
-  title: string;
-  summary: string;
-  content: string;
-  url?: string | null;
-  observed_at: string;
-  tags: string[];
-  entity_labels: string[];
-  proposed_claims: ProposedClaim[];
-};
+export type RegistryEntityKind = "org" | "person" | "ticker" | "domain" | "wallet";
 
-export type IngestEvidenceResponse = {
-  duplicate: boolean;
-  evidence: EvidenceItem;
-  claims: ClaimRecord[];
-  hits: WatchlistHit[];
-  case_updates: CaseTransition[];
+export type RegistryEntity = {
+  id: string;
+  name: string;
+  kind: RegistryEntityKind;
+  aliases: string[];
+};
+
+export type EntityRequest = {
+  name: string;
+  kind: RegistryEntityKind;
+  aliases?: string[];
+};
+
+export type EntitySplitResponse = {
+  entity: RegistryEntity;
+  split: RegistryEntity;
+};
+
+export type WatchlistBacktestRequest = {
+  watchlist_id?: string | null;
+  watchlist: CreateWatchlistRequest;
+  observed_from?: string | null;
+  observed_to?: string | null;
+};
+
+export type WatchlistBacktestCase = {
+  case_id: string;
+  title: string;
+  primary_entity?: string | null;
+  evidence_ids: string[];
+  escalated: boolean;
+  existing: boolean;
+};
+
+export type WatchlistBacktestResponse = {
+  watchlist: Watchlist;
+  baseline_watchlist_id?: string | null;
+  evaluated_evidence: number;
+  hit_count: number;
+  baseline_hit_count: number;
+  hits: WatchlistHit[];
+  suppressed_hits: WatchlistHit[];
+  newly_matched: string[];
+  no_longer_matched: string[];
+  cases: WatchlistBacktestCase[];
+  severity_distribution: Record<string, number>;
+  baseline_severity_distribution: Record<string, number>;
+};
+
+export type IngestEvidenceRequest = {
+  source_id: string;
+  title: string;
+  summary: string;
+  content: string;
+  url?: string | null;
+  observed_at: string;
+  tags: string[];
+  entity_labels: string[];
+  proposed_claims: ProposedClaim[];
+};
+
+export type IngestEvidenceResponse = {
+  duplicate: boolean;
+  evidence: EvidenceItem;
+  claims: ClaimRecord[];
+  hits: WatchlistHit[];
+  case_updates: CaseTransition[];
 };
 
 export type CloudEventInput = {
@@ -1116,11 +1828,12 @@ export async function proposeAutopilotFromReviewItem(
 
 export async function fetchAutopilotReviewExportPacket(
   reviewKind: AutopilotReviewKind,
-  itemId: string
+  itemId: string,
+  redactNotes = false
 ): Promise<AutopilotReviewExportPacketResponse> {
   return requestJson<AutopilotReviewExportPacketResponse>(
     API_BASE,
-    `/api/v1/autopilot/review-queue/export?review_kind=${encodeURIComponent(reviewKind)}&item_id=${encodeURIComponent(itemId)}`
+    `/api/v1/autopilot/review-queue/export?review_kind=${encodeURIComponent(reviewKind)}&item_id=${encodeURIComponent(itemId)}${redactNotes ? "&redact_notes=true" : ""}`
   );
 }
 
@@ -1149,11 +1862,12 @@ export async function generateMarketIntelBrief(
 }
 
 export async function fetchMarketIntelBriefExportPacket(
-  caseId: string
+  caseId: string,
+  redactNotes = false
 ): Promise<MarketIntelBriefExportPacketResponse> {
   return requestJson<MarketIntelBriefExportPacketResponse>(
     API_BASE,
-    `/api/v1/market-intel/cases/${encodeURIComponent(caseId)}/export`
+    `/api/v1/market-intel/cases/${encodeURIComponent(caseId)}/export${redactNotes ? "?redact_notes=true" : ""}`
   );
 }
 
@@ -1162,6 +1876,10 @@ export async function fetchSources(): Promise<SourceDefinition[]> {
   return payload.sources;
 }
 
+export async function fetchSourceCatalog(): Promise<SourceCatalog> {
+  return requestJson<SourceCatalog>(API_BASE, "/api/v1/sources");
+}
+
 export async function createSource(request: CreateSourceRequest): Promise<SourceDefinition> {
   const payload = await requestJson<{ source: SourceDefinition }>(
     API_BASE,
@@ -1223,6 +1941,21 @@ export async function ingestSourceWebhook(
   );
 }
 
+export async function previewJsonMapping(
+  request: JsonMappingPreviewRequest
+): Promise<JsonMappingPreviewResponse> {
+  return requestJson<JsonMappingPreviewResponse>(
+    API_BASE,
+    "/api/v1/sources/json-mapping/preview",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
 export async function importSourceFile(
   sourceId: string,
   request: FileImportRequest
@@ -1239,6 +1972,38 @@ export async function importSourceFile(
   );
 }
 
+export async function importSourceRows(
+  sourceId: string,
+  request: FileImportRequest
+): Promise<FileRowImportResponse> {
+  return requestJson<FileRowImportResponse>(
+    API_BASE,
+    `/api/v1/sources/${encodeURIComponent(sourceId)}/import`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
+export async function importSourceEmail(
+  sourceId: string,
+  request: EmailImportRequest
+): Promise<EmailImportResponse> {
+  return requestJson<EmailImportResponse>(
+    API_BASE,
+    `/api/v1/sources/${encodeURIComponent(sourceId)}/email`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
 export async function fetchWatchlists(): Promise<Watchlist[]> {
   const payload = await requestJson<{ watchlists: Watchlist[] }>(
     API_BASE,
@@ -1261,6 +2026,110 @@ export async function createWatchlist(request: CreateWatchlistRequest): Promise<
   return payload.watchlist;
 }
 
+export async function fetchEntities(): Promise<RegistryEntity[]> {
+  const payload = await requestJson<{ entities: RegistryEntity[] }>(API_BASE, "/api/v1/entities");
+  return payload.entities;
+}
+
+export async function upsertEntity(request: EntityRequest): Promise<RegistryEntity> {
+  const payload = await requestJson<{ entity: RegistryEntity }>(
+    API_BASE,
+    "/api/v1/entities",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+  return payload.entity;
+}
+
+export async function mergeEntity(entityId: string, mergeId: string): Promise<RegistryEntity> {
+  const payload = await requestJson<{ entity: RegistryEntity }>(
+    API_BASE,
+    `/api/v1/entities/${encodeURIComponent(entityId)}/merge`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ merge_id: mergeId }),
+    },
+    { retry: false }
+  );
+  return payload.entity;
+}
+
+export async function splitEntity(
+  entityId: string,
+  request: EntityRequest
+): Promise<EntitySplitResponse> {
+  return requestJson<EntitySplitResponse>(
+    API_BASE,
+    `/api/v1/entities/${encodeURIComponent(entityId)}/split`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
+export async function backtestWatchlist(
+  request: WatchlistBacktestRequest
+): Promise<WatchlistBacktestResponse> {
+  return requestJson<WatchlistBacktestResponse>(
+    API_BASE,
+    "/api/v1/watchlists/backtest",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
+export async function addWatchlistSuppression(
+  watchlistId: string,
+  request: CreateSuppressionRequest
+): Promise<Watchlist> {
+  const payload = await requestJson<{ watchlist: Watchlist }>(
+    API_BASE,
+    `/api/v1/watchlists/${encodeURIComponent(watchlistId)}/suppressions`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+  return payload.watchlist;
+}
+
+export async function removeWatchlistSuppression(
+  watchlistId: string,
+  suppressionId: string
+): Promise<Watchlist> {
+  const payload = await requestJson<{ watchlist: Watchlist }>(
+    API_BASE,
+    `/api/v1/watchlists/${encodeURIComponent(watchlistId)}/suppressions/${encodeURIComponent(
+      suppressionId
+    )}`,
+    { method: "DELETE" },
+    { retry: false }
+  );
+  return payload.watchlist;
+}
+
+export async function fetchSuppressedHits(watchlistId: string): Promise<SuppressedHitRecord[]> {
+  const payload = await requestJson<{ hits: SuppressedHitRecord[] }>(
+    API_BASE,
+    `/api/v1/watchlists/${encodeURIComponent(watchlistId)}/suppressed-hits`
+  );
+  return payload.hits;
+}
+
 export async function fetchEvidence(filters?: EvidenceQueueFilters): Promise<EvidenceQueueEntry[]> {
   const params = new URLSearchParams();
   if (filters?.source_id) params.set("source_id", filters.source_id);
@@ -1269,6 +2138,7 @@ export async function fetchEvidence(filters?: EvidenceQueueFilters): Promise<Evi
   if (filters?.linked_status) params.set("linked_status", filters.linked_status);
   if (filters?.min_trust !== undefined) params.set("min_trust", String(filters.min_trust));
   if (filters?.q) params.set("q", filters.q);
+  if (filters?.trust_as_of) params.set("trust_as_of", filters.trust_as_of);
   if (filters?.limit !== undefined) params.set("limit", String(filters.limit));
   const query = params.toString();
   const payload = await requestJson<{ evidence: EvidenceQueueEntry[] }>(
@@ -1300,7 +2170,9 @@ export async function fetchClaims(filters?: ClaimQueueFilters): Promise<ClaimQue
   if (filters?.min_confidence_bps !== undefined) {
     params.set("min_confidence_bps", String(filters.min_confidence_bps));
   }
+  if (filters?.contested !== undefined) params.set("contested", String(filters.contested));
   if (filters?.q) params.set("q", filters.q);
+  if (filters?.trust_as_of) params.set("trust_as_of", filters.trust_as_of);
   if (filters?.limit !== undefined) params.set("limit", String(filters.limit));
   const query = params.toString();
   const payload = await requestJson<{ claims: ClaimQueueEntry[] }>(
@@ -1310,6 +2182,19 @@ export async function fetchClaims(filters?: ClaimQueueFilters): Promise<ClaimQue
   return payload.claims;
 }
 
+export async function fetchClaimGraph(filters?: ClaimGraphFilters): Promise<ClaimAssertion[]> {
+  const params = new URLSearchParams();
+  if (filters?.contested !== undefined) params.set("contested", String(filters.contested));
+  if (filters?.subject) params.set("subject", filters.subject);
+  if (filters?.limit !== undefined) params.set("limit", String(filters.limit));
+  const query = params.toString();
+  const payload = await requestJson<{ assertions: ClaimAssertion[] }>(
+    API_BASE,
+    query ? `/api/v1/claims/graph?${query}` : "/api/v1/claims/graph"
+  );
+  return payload.assertions;
+}
+
 export async function reviewClaim(
   claimId: string,
   status: ClaimReviewStatus
@@ -1327,12 +2212,108 @@ export async function reviewClaim(
   return payload.claim;
 }
 
+export async function fetchTrustCalibration(): Promise<TrustCalibrationResponse> {
+  return requestJson<TrustCalibrationResponse>(API_BASE, "/api/v1/sources/trust-calibration");
+}
+
+export async function updateTrustCalibrationPolicy(
+  policy: TrustCalibrationPolicy
+): Promise<TrustCalibrationPolicy> {
+  const payload = await requestJson<{ policy: TrustCalibrationPolicy }>(
+    API_BASE,
+    "/api/v1/sources/trust-calibration/policy",
+    {
+      method: "PUT",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(policy),
+    },
+    { retry: false }
+  );
+  return payload.policy;
+}
+
+export async function applyTrustCalibration(sourceIds?: string[]): Promise<TrustHistoryEntry[]> {
+  const payload = await requestJson<{ applied: TrustHistoryEntry[] }>(
+    API_BASE,
+    "/api/v1/sources/trust-calibration/apply",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(sourceIds ? { source_ids: sourceIds } : {}),
+    },
+    { retry: false }
+  );
+  return payload.applied;
+}
+
+export async function fetchSourceTrustHistory(
+  sourceId: string,
+  at?: string
+): Promise<TrustHistoryResponse> {
+  const query = at ? `?at=${encodeURIComponent(at)}` : "";
+  return requestJson<TrustHistoryResponse>(
+    API_BASE,
+    `/api/v1/sources/${encodeURIComponent(sourceId)}/trust-history${query}`
+  );
+}
+
+export async function fetchClaimVotes(claimId: string): Promise<ClaimAdjudicationResponse> {
+  return requestJson<ClaimAdjudicationResponse>(
+    API_BASE,
+    `/api/v1/claims/${encodeURIComponent(claimId)}/votes`
+  );
+}
+
+export async function castClaimVote(
+  claimId: string,
+  vote: { reviewer: string; choice: ClaimVoteChoice; rationale: string }
+): Promise<ClaimAdjudicationResponse> {
+  return requestJson<ClaimAdjudicationResponse>(
+    API_BASE,
+    `/api/v1/claims/${encodeURIComponent(claimId)}/votes`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(vote),
+    },
+    { retry: false }
+  );
+}
+
+export async function fetchAdjudicationConfig(): Promise<ClaimAdjudicationConfig> {
+  const payload = await requestJson<{ config: ClaimAdjudicationConfig }>(
+    API_BASE,
+    "/api/v1/claims/adjudication-config"
+  );
+  return payload.config;
+}
+
+export async function updateAdjudicationConfig(
+  config: ClaimAdjudicationConfig
+): Promise<ClaimAdjudicationConfig> {
+  const payload = await requestJson<{ config: ClaimAdjudicationConfig }>(
+    API_BASE,
+    "/api/v1/claims/adjudication-config",
+    {
+      method: "PUT",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(config),
+    },
+    { retry: false }
+  );
+  return payload.config;
+}
+
 export async function fetchCases(filters?: CaseQueueFilters): Promise<CaseQueueEntry[]> {
   const params = new URLSearchParams();
   if (filters?.status) params.set("status", filters.status);
   if (filters?.severity) params.set("severity", filters.severity);
   if (filters?.watchlist_id) params.set("watchlist_id", filters.watchlist_id);
   if (filters?.primary_entity) params.set("primary_entity", filters.primary_entity);
+  if (filters?.view) params.set("view", filters.view);
+  if (filters?.assignee) params.set("assignee", filters.assignee);
+  if (filters?.watch_group) params.set("watch_group", filters.watch_group);
+  if (filters?.trust_as_of) params.set("trust_as_of", filters.trust_as_of);
   if (filters?.limit !== undefined) params.set("limit", String(filters.limit));
   const query = params.toString();
   const payload = await requestJson<{ cases: CaseQueueEntry[] }>(
@@ -1342,21 +2323,231 @@ export async function fetchCases(filters?: CaseQueueFilters): Promise<CaseQueueE
   return payload.cases;
 }
 
+export async function fetchCaseSlaPolicy(): Promise<CaseSlaPolicy> {
+  const payload = await requestJson<{ policy: CaseSlaPolicy }>(
+    API_BASE,
+    "/api/v1/cases/sla/policy"
+  );
+  return payload.policy;
+}
+
+export async function updateCaseSlaPolicy(policy: CaseSlaPolicy): Promise<CaseSlaPolicy> {
+  const payload = await requestJson<{ policy: CaseSlaPolicy }>(
+    API_BASE,
+    "/api/v1/cases/sla/policy",
+    {
+      method: "PUT",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(policy),
+    },
+    { retry: false }
+  );
+  return payload.policy;
+}
+
+export async function tickCaseSla(now?: string): Promise<CaseSlaTickResponse> {
+  return requestJson<CaseSlaTickResponse>(
+    API_BASE,
+    "/api/v1/cases/sla/tick",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ now }),
+    },
+    { retry: false }
+  );
+}
+
+export async function fetchCaseDossier(
+  caseId: string,
+  format: CaseDossierFormat = "html",
+  redactNotes = false
+): Promise<string> {
+  const params = new URLSearchParams({ format });
+  if (redactNotes) params.set("redact_notes", "true");
+  const path = `/api/v1/cases/${encodeURIComponent(caseId)}/dossier?${params.toString()}`;
+  const response = await requestResponse(API_BASE, path);
+  return readTextResponse(response, "GET", path);
+}
+
+export async function verifyCaseDossier(data: CaseDossierData): Promise<CaseDossierVerification> {
+  return requestJson<CaseDossierVerification>(
+    API_BASE,
+    "/api/v1/cases/dossier/verify",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(data),
+    },
+    { retry: false }
+  );
+}
+
+function noteTargetPath(kind: NoteTargetKind, targetId: string): string {
+  const collection = kind === "case" ? "cases" : kind === "claim" ? "claims" : "evidence";
+  return `/api/v1/${collection}/${encodeURIComponent(targetId)}/notes`;
+}
+
+export async function fetchNoteThreads(
+  kind: NoteTargetKind,
+  targetId: string
+): Promise<NoteThreadsResponse> {
+  return requestJson<NoteThreadsResponse>(API_BASE, noteTargetPath(kind, targetId));
+}
+
+export async function createNote(
+  kind: NoteTargetKind,
+  targetId: string,
+  request: CreateNoteRequest
+): Promise<AnalystNote> {
+  return requestJson<AnalystNote>(
+    API_BASE,
+    noteTargetPath(kind, targetId),
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
+export async function updateNote(noteId: string, request: UpdateNoteRequest): Promise<AnalystNote> {
+  return requestJson<AnalystNote>(
+    API_BASE,
+    `/api/v1/notes/${encodeURIComponent(noteId)}`,
+    {
+      method: "PUT",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
+export async function searchNotes(filters?: NoteQueueFilters): Promise<AnalystNote[]> {
+  const params = new URLSearchParams();
+  if (filters?.target_kind) params.set("target_kind", filters.target_kind);
+  if (filters?.target_id) params.set("target_id", filters.target_id);
+  if (filters?.author) params.set("author", filters.author);
+  if (filters?.mention) params.set("mention", filters.mention);
+  if (filters?.pinned !== undefined) params.set("pinned", String(filters.pinned));
+  if (filters?.q) params.set("q", filters.q);
+  if (filters?.limit !== undefined) params.set("limit", String(filters.limit));
+  const query = params.toString();
+  const payload = await requestJson<{ notes: AnalystNote[] }>(
+    API_BASE,
+    query ? `/api/v1/notes?${query}` : "/api/v1/notes"
+  );
+  return payload.notes;
+}
+
+export async function fetchRulePacks(): Promise<IntelRulePack[]> {
+  const payload = await requestJson<{ rule_packs: IntelRulePack[] }>(
+    API_BASE,
+    "/api/v1/reasoning/rule-packs"
+  );
+  return payload.rule_packs;
+}
+
+export async function upsertRulePack(pack: IntelRulePack): Promise<IntelRulePack> {
+  const payload = await requestJson<{ rule_pack: IntelRulePack }>(
+    API_BASE,
+    "/api/v1/reasoning/rule-packs",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(pack),
+    },
+    { retry: false }
+  );
+  return payload.rule_pack;
+}
+
+export async function evaluateCaseReasoning(
+  caseId: string,
+  request: CaseReasoningRequest
+): Promise<CaseReasoningResponse> {
+  return requestJson<CaseReasoningResponse>(
+    API_BASE,
+    `/api/v1/cases/${encodeURIComponent(caseId)}/reasoning`,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
+export async function fetchDecayPolicy(): Promise<DecayPolicy> {
+  const payload = await requestJson<{ policy: DecayPolicy }>(API_BASE, "/api/v1/decay/policy");
+  return payload.policy;
+}
+
+export async function updateDecayPolicy(policy: DecayPolicy): Promise<DecayPolicy> {
+  const payload = await requestJson<{ policy: DecayPolicy }>(
+    API_BASE,
+    "/api/v1/decay/policy",
+    {
+      method: "PUT",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(policy),
+    },
+    { retry: false }
+  );
+  return payload.policy;
+}
+
+export async function applyDecay(request: DecayApplyRequest = {}): Promise<DecayApplyResponse> {
+  return requestJson<DecayApplyResponse>(
+    API_BASE,
+    "/api/v1/decay/apply",
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(request),
+    },
+    { retry: false }
+  );
+}
+
 export async function transitionCase(
   caseId: string,
-  command: CaseCommand
+  command: CaseCommand,
+  actor?: string
 ): Promise<CaseTransition> {
-  const payload = await requestJson<{ transition: CaseTransition }>(
+  const payload = await operateCase(caseId, command, actor);
+  return payload.transition;
+}
+
+/** Applies a case command and also returns the other case a merge or split touched. */
+export async function operateCase(
+  caseId: string,
+  command: CaseCommand,
+  actor?: string
+): Promise<CaseTransitionResponse> {
+  return requestJson<CaseTransitionResponse>(
     API_BASE,
     `/api/v1/cases/${encodeURIComponent(caseId)}/transition`,
     {
       method: "POST",
       headers: { "Content-Type": "application/json" },
-      body: JSON.stringify({ command }),
+      body: JSON.stringify({ command, actor }),
     },
     { retry: false }
   );
-  return payload.transition;
+}
+
+export async function fetchCaseTimeline(
+  caseId: string,
+  asOf?: string
+): Promise<CaseTimelineResponse> {
+  const query = asOf ? `?as_of=${encodeURIComponent(asOf)}` : "";
+  return requestJson<CaseTimelineResponse>(
+    API_BASE,
+    `/api/v1/cases/${encodeURIComponent(caseId)}/timeline${query}`
+  );
 }
 
 export async function fetchAuditLog(limit = 50): Promise<AuditLogResponse> {
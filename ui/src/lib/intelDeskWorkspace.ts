@@ -1,5 +1,6 @@
 import {
   CaseQueueFilters,
+  CaseQueueView,
   CaseStatus,
   ClaimQueueFilters,
   ClaimReviewStatus,
@@ -18,6 +19,7 @@ const VALID_CASE_STATUSES: ReadonlySet<string> = new Set([
   "escalated",
   "closed",
 ]);
+const VALID_CASE_QUEUE_VIEWS: ReadonlySet<string> = new Set(["mine", "unassigned", "breaching"]);
 const VALID_SEVERITIES: ReadonlySet<string> = new Set(["low", "medium", "high", "critical"]);
 const VALID_CLAIM_REVIEW_STATUSES: ReadonlySet<string> = new Set([
   "needs_review",
@@ -58,6 +60,10 @@ function isCaseStatus(value: unknown): value is CaseStatus {
   return typeof value === "string" && VALID_CASE_STATUSES.has(value);
 }
 
+function isCaseQueueView(value: unknown): value is CaseQueueView {
+  return typeof value === "string" && VALID_CASE_QUEUE_VIEWS.has(value);
+}
+
 function isSeverity(value: unknown): value is WatchlistSeverity {
   return typeof value === "string" && VALID_SEVERITIES.has(value);
 }
@@ -111,6 +117,12 @@ function normalizeCaseFilters(value: unknown): CaseQueueFilters {
     primary_entity:
       normalizedString((candidate as { primary_entity?: unknown }).primary_entity).trim() ||
       undefined,
+    view: isCaseQueueView((candidate as { view?: unknown }).view)
+      ? (candidate as { view?: CaseQueueView }).view
+      : undefined,
+    assignee: normalizedString((candidate as { assignee?: unknown }).assignee).trim() || undefined,
+    watch_group:
+      normalizedString((candidate as { watch_group?: unknown }).watch_group).trim() || undefined,
     limit: normalizedLimit((candidate as { limit?: unknown }).limit),
   };
 }
@@ -3,6 +3,7 @@ import {
   CaseCommand,
   CaseQueueFilters,
   CaseQueueEntry,
+  CaseQueueView,
   CaseStatus,
   IntelDeskOverviewResponse,
   PriorityBreakdown,
@@ -22,10 +23,11 @@ import {
 const DEFAULT_CASE_LIMIT = "25";
 type CaseStatusSelection = CaseStatus | "all";
 type CaseSeveritySelection = WatchlistSeverity | "all";
+type CaseViewSelection = CaseQueueView | "all";
 
 function statusClass(status: CaseStatus) {
   if (status === "escalated") return "danger";
-  if (status === "closed") return "warn";
+  if (status === "closed" || status === "merged") return "warn";
   return "ok";
 }
 
@@ -45,6 +47,9 @@ function describeFilters(filters: CaseQueueFilters) {
     filters.severity ? `severity=${filters.severity}` : null,
     filters.watchlist_id ? `watchlist=${filters.watchlist_id}` : null,
     filters.primary_entity ? `entity=${filters.primary_entity}` : null,
+    filters.view ? `view=${filters.view}` : null,
+    filters.assignee ? `assignee=${filters.assignee}` : null,
+    filters.watch_group ? `group=${filters.watch_group}` : null,
     filters.limit ? `limit=${filters.limit}` : null,
   ].filter(Boolean);
   return labels.length > 0 ? labels.join(" | ") : "unfiltered";
@@ -58,6 +63,10 @@ function caseSeveritySelection(filters: CaseQueueFilters): CaseSeveritySelection
   return filters.severity ?? "all";
 }
 
+function caseViewSelection(filters: CaseQueueFilters): CaseViewSelection {
+  return filters.view ?? "all";
+}
+
 function caseLimitSelection(filters: CaseQueueFilters): string {
   return filters.limit !== undefined ? String(filters.limit) : "all";
 }
@@ -75,6 +84,11 @@ export function CasesPage() {
   );
   const [watchlistFilter, setWatchlistFilter] = useState(initialWorkspace.filters.watchlist_id ?? "");
   const [entityFilter, setEntityFilter] = useState(initialWorkspace.filters.primary_entity ?? "");
+  const [viewFilter, setViewFilter] = useState<CaseViewSelection>(
+    caseViewSelection(initialWorkspace.filters)
+  );
+  const [assigneeFilter, setAssigneeFilter] = useState(initialWorkspace.filters.assignee ?? "");
+  const [watchGroupFilter, setWatchGroupFilter] = useState(initialWorkspace.filters.watch_group ?? "");
   const [limitFilter, setLimitFilter] = useState(caseLimitSelection(initialWorkspace.filters));
   const [savedViews, setSavedViews] = useState<SavedCaseView[]>(initialWorkspace.savedViews);
   const [activeViewId, setActiveViewId] = useState<string | null>(initialWorkspace.activeViewId);
@@ -86,6 +100,9 @@ export function CasesPage() {
       severity: severityFilter === "all" ? undefined : severityFilter,
       watchlist_id: watchlistFilter.trim() || undefined,
       primary_entity: entityFilter.trim() || undefined,
+      view: viewFilter === "all" ? undefined : viewFilter,
+      assignee: assigneeFilter.trim() || undefined,
+      watch_group: watchGroupFilter.trim() || undefined,
       limit: limitFilter === "all" ? undefined : Number(limitFilter),
     };
   }
@@ -96,7 +113,18 @@ export function CasesPage() {
       savedViews,
       activeViewId,
     });
-  }, [statusFilter, severityFilter, watchlistFilter, entityFilter, limitFilter, savedViews, activeViewId]);
+  }, [
+    statusFilter,
+    severityFilter,
+    watchlistFilter,
+    entityFilter,
+    viewFilter,
+    assigneeFilter,
+    watchGroupFilter,
+    limitFilter,
+    savedViews,
+    activeViewId,
+  ]);
 
   async function loadCases(message?: string, filters: CaseQueueFilters = currentFilters()) {
     try {
@@ -137,6 +165,9 @@ export function CasesPage() {
     setSeverityFilter("all");
     setWatchlistFilter("");
     setEntityFilter("");
+    setViewFilter("all");
+    setAssigneeFilter("");
+    setWatchGroupFilter("");
     setLimitFilter(DEFAULT_CASE_LIMIT);
     void loadCases("Reset case queue filters.", { limit: Number(DEFAULT_CASE_LIMIT) });
   }
@@ -169,6 +200,9 @@ export function CasesPage() {
     setSeverityFilter(caseSeveritySelection(view.filters));
     setWatchlistFilter(view.filters.watchlist_id ?? "");
     setEntityFilter(view.filters.primary_entity ?? "");
+    setViewFilter(caseViewSelection(view.filters));
+    setAssigneeFilter(view.filters.assignee ?? "");
+    setWatchGroupFilter(view.filters.watch_group ?? "");
     setLimitFilter(caseLimitSelection(view.filters));
     void loadCases(`Applied saved view '${view.name}'.`, view.filters);
   }
@@ -185,6 +219,9 @@ export function CasesPage() {
     setSeverityFilter("all");
     setWatchlistFilter("");
     setEntityFilter("");
+    setViewFilter("all");
+    setAssigneeFilter("");
+    setWatchGroupFilter("");
     setLimitFilter(DEFAULT_CASE_LIMIT);
     setSavedViews([]);
     setActiveViewId(null);
@@ -260,6 +297,7 @@ export function CasesPage() {
               <option value="brief_ready">brief_ready</option>
               <option value="escalated">escalated</option>
               <option value="closed">closed</option>
+              <option value="merged">merged</option>
             </select>
           </label>
           <label className="field">
@@ -293,6 +331,34 @@ export function CasesPage() {
               placeholder="orion dynamics"
             />
           </label>
+          <label className="field">
+            <span>Queue View</span>
+            <select
+              value={viewFilter}
+              onChange={(event) => setViewFilter(event.target.value as CaseViewSelection)}
+            >
+              <option value="all">all</option>
+              <option value="mine">mine</option>
+              <option value="unassigned">unassigned</option>
+              <option value="breaching">breaching</option>
+            </select>
+          </label>
+          <label className="field">
+            <span>Assignee</span>
+            <input
+              value={assigneeFilter}
+              onChange={(event) => setAssigneeFilter(event.target.value)}
+              placeholder="analyst-1"
+            />
+          </label>
+          <label className="field">
+            <span>Watch Group</span>
+            <input
+              value={watchGroupFilter}
+              onChange={(event) => setWatchGroupFilter(event.target.value)}
+              placeholder="pricing-desk"
+            />
+          </label>
           <label className="field">
             <span>Limit</span>
             <select
@@ -389,6 +455,12 @@ export function CasesPage() {
                 <span className={`status-pill ${severityClass(entry.severity)}`}>{entry.severity}</span>
                 <span className="info-pill">{entry.watchlist_name}</span>
                 <span className="info-pill">latest: {entry.latest_signal_at ?? "unknown"}</span>
+                <span className="info-pill">assignee: {entry.case.assignee ?? "unassigned"}</span>
+                {entry.sla ? (
+                  <span className={`status-pill ${entry.sla.status === "breached" ? "danger" : "info"}`}>
+                    sla {entry.sla.status} by {entry.sla.deadline_at}
+                  </span>
+                ) : null}
               </div>
               <p className="mono-detail">{entry.case.id}</p>
               <div className="command-stack">
@@ -844,6 +844,14 @@ export function EvidencePage() {
                 </span>
                 <span className="info-pill">{entry.source_name}</span>
                 <span className="info-pill">trust: {entry.source_trust_score}</span>
+                <span className="info-pill">
+                  evidence: {entry.supporting_evidence_ids.length}
+                </span>
+                {entry.contested ? (
+                  <span className="status-pill danger">
+                    contested: {entry.contradictions.length}
+                  </span>
+                ) : null}
                 {entry.semantic_score_bps != null ? (
                   <span className="info-pill">semantic: {entry.semantic_score_bps}</span>
                 ) : null}
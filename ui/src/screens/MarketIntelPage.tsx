@@ -83,7 +83,7 @@ function renderCompany(card: MarketIntelCompanyCard) {
   return (
     <div key={card.company} className="command-row">
       <div className="agent-card-head">
-        <h3>{card.company}</h3>
+        <h3>{card.display_name ?? card.company}</h3>
         <span className={`status-pill ${caseStatusClass(card.active_case_count)}`}>
           {card.active_case_count > 0 ? `${card.active_case_count} cases` : "tracked"}
         </span>